@@ -166,4 +166,42 @@ impl<B: Backend, C: CheckpointStrategy> ActivationOps<Autodiff<B, C>> for Autodi
             OpsKind::UnTracked(prep) => prep.finish(B::log_sigmoid(tensor.primitive)),
         }
     }
+
+    fn mish<const D: usize>(tensor: FloatTensor<Self, D>) -> FloatTensor<Self, D> {
+        #[derive(Debug)]
+        struct Mish<const D: usize>;
+
+        retro_unary!(RetroMish, B::mish);
+
+        impl<const D: usize, B: Backend> Backward<B, D, 1> for Mish<D> {
+            type State = NodeID;
+
+            fn backward(
+                self,
+                ops: Ops<Self::State, 1>,
+                grads: &mut Gradients,
+                checkpointer: &mut Checkpointer,
+            ) {
+                let input = checkpointer.retrieve_node_output(ops.state);
+
+                unary::<B, D, D, _>(ops.parents, ops.node, grads, |grad| {
+                    B::mish_backward(input, grad)
+                });
+            }
+        }
+
+        match Mish::<D>
+            .prepare::<C>([tensor.node.clone()])
+            .memory_bound()
+            .retro_forward(RetroMish::<B, D>::new(tensor.node.id))
+            .parents([&tensor])
+            .stateful()
+        {
+            OpsKind::Tracked(mut prep) => {
+                let state = prep.checkpoint(&tensor);
+                prep.finish(state, B::mish(tensor.primitive.clone()))
+            }
+            OpsKind::UnTracked(prep) => prep.finish(B::mish(tensor.primitive)),
+        }
+    }
 }
@@ -16,6 +16,7 @@ use crate::{
 
 use burn_tensor::{
     backend::Backend,
+    dlpack::DLPackTensor,
     ops::{BoolTensor, FloatElem, FloatTensor, FloatTensorOps, IntTensor},
     Device, ElementConversion, Reader, Shape, Tensor, TensorData,
 };
@@ -58,6 +59,17 @@ impl<B: Backend, C: CheckpointStrategy> FloatTensorOps<Self> for Autodiff<B, C>
         B::float_into_data(tensor.primitive)
     }
 
+    fn float_to_dlpack<const D: usize>(tensor: FloatTensor<Self, D>) -> Reader<DLPackTensor> {
+        B::float_to_dlpack(tensor.primitive)
+    }
+
+    fn float_from_dlpack<const D: usize>(
+        tensor: DLPackTensor,
+        device: &Device<Self>,
+    ) -> FloatTensor<Self, D> {
+        AutodiffTensor::new(B::float_from_dlpack(tensor, device))
+    }
+
     fn float_device<const D: usize>(tensor: &FloatTensor<Self, D>) -> Device<Self> {
         B::float_device(&tensor.primitive)
     }
@@ -1590,6 +1602,19 @@ impl<B: Backend, C: CheckpointStrategy> FloatTensorOps<Self> for Autodiff<B, C>
         B::float_argmin(tensor.primitive, dim)
     }
 
+    // The maxima aren't tracked, the tensor API gathers them back from their positions.
+    #[cfg(not(target_family = "wasm"))]
+    fn float_scatter_max<const D: usize>(
+        tensor: FloatTensor<Self, D>,
+        dim: usize,
+        indices: IntTensor<B, D>,
+        num_segments: usize,
+    ) -> (FloatTensor<Self, D>, IntTensor<B, D>) {
+        let (maxima, argmax) = B::float_scatter_max(tensor.primitive, dim, indices, num_segments);
+
+        (AutodiffTensor::new(maxima), argmax)
+    }
+
     fn float_exp<const D: usize>(tensor: FloatTensor<Self, D>) -> FloatTensor<Self, D> {
         #[derive(Debug)]
         struct Exp;
@@ -1,7 +1,10 @@
 #[burn_tensor_testgen::testgen(module_backward)]
 mod tests {
     use super::*;
-    use burn_tensor::{module::embedding, Int, Tensor, TensorData};
+    use burn_tensor::{
+        module::{embedding, embedding_with_padding},
+        Int, Tensor, TensorData,
+    };
 
     #[test]
     fn test_embedding_backward() {
@@ -24,4 +27,20 @@ mod tests {
         grad.to_data()
             .assert_eq(&TensorData::from([[3., 9., 7.], [21., 35., 27.]]), false);
     }
+
+    #[test]
+    fn test_embedding_with_padding_backward() {
+        let weights = TensorData::from([[0.0, 1.0], [2.0, 3.0], [4.0, 5.0]]);
+        let indices = TensorData::from([[0, 1], [1, 2]]);
+        let device = Default::default();
+        let weights = Tensor::<TestAutodiffBackend, 2>::from_data(weights, &device).require_grad();
+        let indices = Tensor::<TestAutodiffBackend, 2, Int>::from_data(indices, &device);
+
+        let output = embedding_with_padding(weights.clone(), indices, 1);
+        let grads = output.sum().backward();
+
+        let grad = weights.grad(&grads).unwrap();
+        grad.to_data()
+            .assert_eq(&TensorData::from([[1., 1.], [0., 0.], [1., 1.]]), false);
+    }
 }
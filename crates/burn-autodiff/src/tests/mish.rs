@@ -0,0 +1,21 @@
+#[burn_tensor_testgen::testgen(ad_mish)]
+mod tests {
+    use super::*;
+    use burn_tensor::{activation, TensorData};
+
+    #[test]
+    fn should_diff_mish() {
+        let data = TensorData::from([[0.8762, -0.1423], [-300., 200.]]);
+
+        let device = Default::default();
+        let tensor_1 = TestAutodiffTensor::<2>::from_data(data, &device).require_grad();
+        let tensor_2 = activation::mish(tensor_1.clone());
+        let grads = tensor_2.backward();
+
+        let grad = tensor_1.grad(&grads).unwrap();
+
+        // Softplus underflows for large negative inputs, where the gradient is zero, not NaN.
+        let expected = TensorData::from([[1.022087, 0.508481], [0.000000, 1.000000]]);
+        grad.to_data().assert_approx_eq(&expected, 4);
+    }
+}
@@ -36,6 +36,7 @@ mod maxmin;
 mod maxpool1d;
 mod maxpool2d;
 mod memory_management;
+mod mish;
 mod mul;
 mod multithread;
 mod nearest_interpolate;
@@ -122,6 +123,7 @@ macro_rules! testgen_all {
         burn_autodiff::testgen_ad_tanh!();
         burn_autodiff::testgen_ad_sigmoid!();
         burn_autodiff::testgen_ad_log_sigmoid!();
+        burn_autodiff::testgen_ad_mish!();
         burn_autodiff::testgen_ad_transpose!();
         burn_autodiff::testgen_ad_permute!();
         burn_autodiff::testgen_ad_flip!();
@@ -25,4 +25,20 @@ mod tests {
         let expected = TensorData::from([[8.00092, 8.000153], [8.000003, 7.999995]]);
         grad_2.to_data().assert_approx_eq(&expected, 3);
     }
+
+    #[test]
+    fn should_diff_tanh_saturated() {
+        let device = Default::default();
+        let tensor_1 = TestAutodiffTensor::<1>::from_data([-20.0, -1.0, 0.0, 1.0, 20.0], &device)
+            .require_grad();
+
+        let tensor_2 = tensor_1.clone().tanh();
+        let grads = tensor_2.backward();
+
+        let grad = tensor_1.grad(&grads).unwrap();
+
+        // 1 - tanh(x)^2, which saturates to 0 without producing NaNs.
+        let expected = TensorData::from([0.0, 0.419974, 1.0, 0.419974, 0.0]);
+        grad.to_data().assert_approx_eq(&expected, 4);
+    }
 }
@@ -9,6 +9,9 @@ extern crate derive_new;
 /// Re-export serde for proc macros.
 pub use serde;
 
+/// Re-export the random number generators, used by the models generated with a seeded RNG.
+pub use burn_common::rand;
+
 /// The configuration module.
 pub mod config;
 
@@ -5,7 +5,11 @@ use crate::nn::Initializer;
 use crate::{
     config::Config,
     module::{Module, Param, RunningState},
-    tensor::{backend::Backend, Tensor},
+    tensor::{
+        backend::Backend,
+        module::{batch_norm_stats, running_stats_update},
+        Tensor,
+    },
 };
 
 /// Configuration to create a [BatchNorm](BatchNorm) layer using the [init function](BatchNormConfig::init).
@@ -116,54 +120,32 @@ impl<const D: usize, B: Backend> BatchNorm<B, D> {
 
     fn forward_train<const DI: usize>(&self, input: Tensor<B, DI>) -> Tensor<B, DI> {
         let device = input.device();
-        let dims = input.dims();
-        let batch_size = dims[0];
-        let channels = dims[1];
+        let channels = input.dims()[1];
 
         let mut shape_unsqueeze = [1; DI];
-        let mut flatten_size = batch_size;
         shape_unsqueeze[1] = channels;
 
-        for dim in dims.iter().take(DI).skip(2) {
-            flatten_size *= dim;
-        }
-
-        let mean = input
-            .clone()
-            .swap_dims(0, 1)
-            .reshape([channels, flatten_size])
-            .mean_dim(1)
-            .reshape(shape_unsqueeze);
-
-        let var = input
-            .clone()
-            .sub(mean.clone())
-            .powf_scalar(2.0)
-            .swap_dims(0, 1)
-            .reshape([channels, flatten_size])
-            .mean_dim(1)
-            .reshape(shape_unsqueeze);
+        let (mean, var) = batch_norm_stats(input.clone());
 
         let running_mean = self.running_mean.value_sync().to_device(&device);
         let running_var = self.running_var.value_sync().to_device(&device);
 
-        let running_mean = running_mean.mul_scalar(1.0 - self.momentum).add(
-            mean.clone()
-                .detach()
-                .mul_scalar(self.momentum)
-                .reshape([channels]),
-        );
-        let running_var = running_var.mul_scalar(1.0 - self.momentum).add(
-            var.clone()
-                .detach()
-                .mul_scalar(self.momentum)
-                .reshape([channels]),
-        );
-
-        self.running_mean.update(running_mean.detach());
-        self.running_var.update(running_var.detach());
-
-        self.forward_shared(input, mean, var)
+        self.running_mean.update(running_stats_update(
+            running_mean,
+            mean.clone(),
+            self.momentum,
+        ));
+        self.running_var.update(running_stats_update(
+            running_var,
+            var.clone(),
+            self.momentum,
+        ));
+
+        self.forward_shared(
+            input,
+            mean.reshape(shape_unsqueeze),
+            var.reshape(shape_unsqueeze),
+        )
     }
 
     fn forward_shared<const DI: usize>(
@@ -182,10 +182,77 @@ impl<B: Backend> RotaryEncoding<B> {
     }
 }
 
+/// Applies the rotary positional encoding transform to a tensor of dimensions
+/// (..., seq_len, d_model) with precomputed `cos` and `sin` tables.
+///
+/// Arguments:
+/// * `x` - Input tensor of shape (..., seq_len, d_model).
+/// * `cos` - Cosine of the rotation angles, of shape (seq_len, d_model / 2).
+/// * `sin` - Sine of the rotation angles, of shape (seq_len, d_model / 2).
+/// * `interleaved` - When true, rotates the adjacent pairs `(x[2i], x[2i + 1])` like
+///   [RotaryEncoding](RotaryEncoding). Otherwise, rotates the pairs `(x[i], x[i + d_model / 2])`
+///   formed by splitting the hidden dimension in two halves (rotate-half).
+///
+/// Returns:
+/// * Output tensor with the same shape as input tensor after applying rotary encoding.
+///
+/// Panics if the input tensor does not have at least 2 dimensions, if the hidden dimension is
+/// not even or if the `cos` and `sin` tables don't have the shape (seq_len, d_model / 2).
+pub fn apply_rope<B: Backend, const D: usize>(
+    x: Tensor<B, D>,
+    cos: Tensor<B, 2>,
+    sin: Tensor<B, 2>,
+    interleaved: bool,
+) -> Tensor<B, D> {
+    assert!(
+        D >= 2,
+        "Input tensor must have at least 2 dimensions for sequence length and hidden dimension"
+    );
+
+    let input_shape = x.shape();
+    let (seq_len, d_model) = (x.dims()[D - 2], x.dims()[D - 1]);
+    assert_eq!(d_model % 2, 0, "The input embedding dimension must be even");
+
+    let half = d_model / 2;
+    assert_eq!(
+        cos.dims(),
+        [seq_len, half],
+        "The cos table must have the shape (seq_len, d_model / 2)"
+    );
+    assert_eq!(
+        sin.dims(),
+        [seq_len, half],
+        "The sin table must have the shape (seq_len, d_model / 2)"
+    );
+    let dummy_dim_size = input_shape.num_elements() / (seq_len * d_model);
+
+    // Both conventions rotate pairs `(x1, x2)` into `(x1 * cos - x2 * sin, x1 * sin + x2 * cos)`,
+    // they only differ in how the pairs are laid out along the hidden dimension.
+    let (x, pair_dim) = match interleaved {
+        true => (x.reshape([dummy_dim_size, seq_len, half, 2]), 3),
+        false => (x.reshape([dummy_dim_size, seq_len, 2, half]), 2),
+    };
+    let [x1, x2] = [0, 1].map(|i| x.clone().narrow(pair_dim, i, 1));
+
+    let table_shape = match interleaved {
+        true => [1, seq_len, half, 1],
+        false => [1, seq_len, 1, half],
+    };
+    let cos = cos.reshape(table_shape);
+    let sin = sin.reshape(table_shape);
+
+    let out1 = x1.clone() * cos.clone() - x2.clone() * sin.clone();
+    let out2 = x1 * sin + x2 * cos;
+
+    Tensor::cat(vec![out1, out2], pair_dim).reshape(input_shape)
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::tensor::TensorData;
     use crate::TestBackend;
+    use alloc::vec::Vec;
 
     #[test]
     fn test_rotary_encoding_forward() {
@@ -265,6 +332,82 @@ mod tests {
         let _output = pe.forward(input);
     }
 
+    /// Scalar reference implementation of the rotary transform for a single position.
+    fn reference_rope(x: &[f32], angles: &[f32], interleaved: bool) -> Vec<f32> {
+        let half = x.len() / 2;
+        let mut out = vec![0.0; x.len()];
+
+        for (i, angle) in angles.iter().enumerate() {
+            let (i1, i2) = match interleaved {
+                true => (2 * i, 2 * i + 1),
+                false => (i, i + half),
+            };
+            out[i1] = x[i1] * angle.cos() - x[i2] * angle.sin();
+            out[i2] = x[i1] * angle.sin() + x[i2] * angle.cos();
+        }
+
+        out
+    }
+
+    fn assert_apply_rope_matches_reference(interleaved: bool) {
+        let device = Default::default();
+        let x = [
+            [0.5, -1.0, 2.0, 0.25, -0.75, 1.5],
+            [1.0, 2.0, -3.0, 4.0, 0.5, -2.0],
+            [-1.25, 0.0, 0.75, -0.5, 3.0, 1.0],
+        ];
+        let angles = [[0.0, 0.0, 0.0], [1.0, 0.1, 0.01], [2.0, 0.2, 0.02]];
+
+        let expected = x
+            .iter()
+            .zip(angles.iter())
+            .flat_map(|(x, angles)| reference_rope(x, angles, interleaved))
+            .collect::<Vec<_>>();
+
+        let angles = Tensor::<TestBackend, 2>::from_floats(angles, &device);
+        let input = Tensor::<TestBackend, 2>::from_floats(x, &device).unsqueeze::<4>();
+        let output = apply_rope(input, angles.clone().cos(), angles.sin(), interleaved);
+
+        assert_eq!(output.dims(), [1, 1, 3, 6]);
+        output
+            .to_data()
+            .assert_approx_eq(&TensorData::new(expected, [1, 1, 3, 6]), 4);
+    }
+
+    #[test]
+    fn test_apply_rope_interleaved() {
+        assert_apply_rope_matches_reference(true);
+    }
+
+    #[test]
+    fn test_apply_rope_half_split() {
+        assert_apply_rope_matches_reference(false);
+    }
+
+    #[test]
+    fn test_apply_rope_interleaved_matches_rotary_encoding() {
+        let device = Default::default();
+        let rotary_encoding = RotaryEncodingConfig::new(10, 4).init::<TestBackend>(&device);
+        let input = Tensor::<TestBackend, 3>::from_floats(
+            [
+                [[1.0, 2.0, 3.0, 4.0], [5.0, 6.0, 7.0, 8.0]],
+                [[9.0, 10.0, 11.0, 12.0], [13.0, 14.0, 15.0, 16.0]],
+            ],
+            &device,
+        );
+        let input = input.unsqueeze::<4>();
+
+        // With d_model = 4 and theta = 10000, the frequencies are [1, 1 / 100] so the angles of
+        // the two positions are [[0, 0], [1, 0.01]].
+        let angles = Tensor::<TestBackend, 2>::from_floats([[0.0, 0.0], [1.0, 0.01]], &device);
+        let (cos, sin) = (angles.clone().cos(), angles.sin());
+
+        let expected = rotary_encoding.forward(input.clone());
+        let output = apply_rope(input, cos, sin, true);
+
+        output.to_data().assert_approx_eq(&expected.to_data(), 4);
+    }
+
     #[test]
     fn display() {
         let config = RotaryEncodingConfig::new(10, 4);
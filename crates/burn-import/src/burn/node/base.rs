@@ -4,13 +4,16 @@ use super::{
     argmax::ArgMaxNode, avg_pool1d::AvgPool1dNode, avg_pool2d::AvgPool2dNode,
     batch_norm::BatchNormNode, binary::BinaryNode, clip::ClipNode, concat::ConcatNode,
     constant::ConstantNode, conv1d::Conv1dNode, conv2d::Conv2dNode,
-    conv_transpose_2d::ConvTranspose2dNode, dropout::DropoutNode, expand::ExpandNode,
-    gather::GatherNode, gather_elements::GatherElementsNode, global_avg_pool::GlobalAvgPoolNode,
+    conv_transpose_2d::ConvTranspose2dNode, cumsum::CumSumNode, depth_to_space::DepthToSpaceNode,
+    dropout::DropoutNode, einsum::EinsumNode, expand::ExpandNode, gather::GatherNode,
+    gather_elements::GatherElementsNode, global_avg_pool::GlobalAvgPoolNode,
     layer_norm::LayerNormNode, linear::LinearNode, mask_where::WhereNode, matmul::MatmulNode,
-    max_pool1d::MaxPool1dNode, max_pool2d::MaxPool2dNode, prelu::PReluNode,
-    random_normal::RandomNormalNode, random_uniform::RandomUniformNode, range::RangeNode,
-    reshape::ReshapeNode, resize::ResizeNode, slice::SliceNode, squeeze::SqueezeNode, sum::SumNode,
-    unary::UnaryNode, unsqueeze::UnsqueezeNode,
+    max_pool1d::MaxPool1dNode, max_pool2d::MaxPool2dNode, max_unpool2d::MaxUnpool2dNode,
+    nonzero::NonZeroNode, pad::PadNode, prelu::PReluNode, random_normal::RandomNormalNode,
+    random_uniform::RandomUniformNode, range::RangeNode, reshape::ReshapeNode, resize::ResizeNode,
+    scatter_elements::ScatterElementsNode, slice::SliceNode, space_to_depth::SpaceToDepthNode,
+    squeeze::SqueezeNode, sum::SumNode, trilu::TriluNode, unary::UnaryNode,
+    unsqueeze::UnsqueezeNode,
 };
 use crate::burn::{BurnImports, Scope, Type};
 use burn::backend::NdArray;
@@ -91,8 +94,11 @@ pub enum Node<PS: PrecisionSettings> {
     Conv1d(Conv1dNode),
     Conv2d(Conv2dNode),
     ConvTranspose2d(ConvTranspose2dNode),
+    CumSum(CumSumNode),
     PRelu(PReluNode),
+    DepthToSpace(DepthToSpaceNode),
     Dropout(DropoutNode),
+    Einsum(EinsumNode),
     Expand(ExpandNode),
     Gather(GatherNode),
     GatherElements(GatherElementsNode),
@@ -102,12 +108,18 @@ pub enum Node<PS: PrecisionSettings> {
     Matmul(MatmulNode),
     MaxPool1d(MaxPool1dNode),
     MaxPool2d(MaxPool2dNode),
+    MaxUnpool2d(MaxUnpool2dNode),
+    NonZero(NonZeroNode),
+    Pad(PadNode),
     Range(RangeNode),
     Reshape(ReshapeNode),
     Resize(ResizeNode),
+    ScatterElements(ScatterElementsNode),
     Slice(SliceNode),
+    SpaceToDepth(SpaceToDepthNode),
     Squeeze(SqueezeNode),
     Sum(SumNode),
+    Trilu(TriluNode),
     Unary(UnaryNode),
     Unsqueeze(UnsqueezeNode),
     Where(WhereNode),
@@ -133,8 +145,11 @@ macro_rules! match_all {
             Node::Conv1d(node) => $func(node),
             Node::Conv2d(node) => $func(node),
             Node::ConvTranspose2d(node) => $func(node),
+            Node::CumSum(node) => $func(node),
             Node::PRelu(node) => $func(node),
+            Node::DepthToSpace(node) => $func(node),
             Node::Dropout(node) => $func(node),
+            Node::Einsum(node) => $func(node),
             Node::Expand(node) => $func(node),
             Node::Gather(node) => $func(node),
             Node::GatherElements(node) => $func(node),
@@ -144,12 +159,18 @@ macro_rules! match_all {
             Node::Matmul(node) => $func(node),
             Node::MaxPool1d(node) => $func(node),
             Node::MaxPool2d(node) => $func(node),
+            Node::MaxUnpool2d(node) => $func(node),
+            Node::NonZero(node) => $func(node),
+            Node::Pad(node) => $func(node),
             Node::Range(node) => $func(node),
             Node::Reshape(node) => $func(node),
             Node::Resize(node) => $func(node),
+            Node::ScatterElements(node) => $func(node),
             Node::Slice(node) => $func(node),
+            Node::SpaceToDepth(node) => $func(node),
             Node::Squeeze(node) => $func(node),
             Node::Sum(node) => $func(node),
+            Node::Trilu(node) => $func(node),
             Node::Unary(node) => $func(node),
             Node::Unsqueeze(node) => $func(node),
             Node::Where(node) => $func(node),
@@ -183,8 +204,11 @@ impl<PS: PrecisionSettings> Node<PS> {
             Node::Conv1d(_) => "conv1d",
             Node::Conv2d(_) => "conv2d",
             Node::ConvTranspose2d(_) => "conv_transpose2d",
+            Node::CumSum(_) => "cumsum",
             Node::PRelu(_) => "prelu",
+            Node::DepthToSpace(_) => "depth_to_space",
             Node::Dropout(_) => "dropout",
+            Node::Einsum(_) => "einsum",
             Node::Expand(_) => "expand",
             Node::Gather(_) => "gather",
             Node::GatherElements(_) => "gather_elements",
@@ -194,12 +218,18 @@ impl<PS: PrecisionSettings> Node<PS> {
             Node::Matmul(_) => "matmul",
             Node::MaxPool1d(_) => "max_pool1d",
             Node::MaxPool2d(_) => "max_pool2d",
+            Node::MaxUnpool2d(_) => "max_unpool2d",
+            Node::NonZero(_) => "nonzero",
+            Node::Pad(_) => "pad",
             Node::Range(_) => "range",
             Node::Reshape(_) => "reshape",
             Node::Resize(_) => "resize",
+            Node::ScatterElements(_) => "scatter_elements",
             Node::Slice(_) => "slice",
+            Node::SpaceToDepth(_) => "space_to_depth",
             Node::Squeeze(_) => "squeeze",
             Node::Sum(_) => "add",
+            Node::Trilu(_) => "trilu",
             Node::Unary(unary) => unary.kind.as_str(),
             Node::Unsqueeze(_) => "unsqueeze",
             Node::Where(_) => "where",
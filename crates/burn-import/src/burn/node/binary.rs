@@ -1,5 +1,5 @@
 use super::{Node, NodeCodegen};
-use crate::burn::{Scope, Type};
+use crate::burn::{BurnImports, Scope, Type};
 use burn::record::PrecisionSettings;
 use proc_macro2::TokenStream;
 use quote::quote;
@@ -20,6 +20,11 @@ pub enum BinaryType {
     GreaterOrEqual,
     Less,
     LessOrEqual,
+    And,
+    Or,
+    Xor,
+    Mod,
+    Fmod,
 }
 
 impl BinaryType {
@@ -38,6 +43,11 @@ impl BinaryType {
             BinaryType::GreaterOrEqual => "greater_equal",
             BinaryType::Less => "lower",
             BinaryType::LessOrEqual => "lower_equal",
+            BinaryType::And => "bool_and",
+            BinaryType::Or => "bool_or",
+            BinaryType::Xor => "bool_xor",
+            BinaryType::Mod => "remainder",
+            BinaryType::Fmod => "fmod",
         }
     }
 }
@@ -111,6 +121,15 @@ impl<PS: PrecisionSettings> NodeCodegen<PS> for BinaryNode {
     fn into_node(self) -> Node<PS> {
         Node::Binary(self)
     }
+
+    fn register_imports(&self, imports: &mut BurnImports) {
+        // Logical operators work on bool tensors.
+        if let BinaryType::And | BinaryType::Or | BinaryType::Xor = self.binary_type {
+            if let Type::Tensor(_) = self.output {
+                imports.register("burn::tensor::Bool");
+            }
+        }
+    }
 }
 
 impl BinaryNode {
@@ -247,6 +266,55 @@ impl BinaryNode {
             Arc::new(function),
         )
     }
+
+    pub(crate) fn bool_and(lhs: Type, rhs: Type, output: Type) -> Self {
+        let function = match (&lhs, &rhs) {
+            (Type::Tensor(_), Type::Tensor(_)) => move |lhs, rhs| quote! { #lhs.bool_and(#rhs) },
+            (Type::Scalar(_), Type::Scalar(_)) => move |lhs, rhs| quote! { #lhs && #rhs },
+            _ => panic!("and is supported for tensor to tensor and scalar to scalar only"),
+        };
+        Self::new(lhs, rhs, output, BinaryType::And, Arc::new(function))
+    }
+
+    pub(crate) fn bool_or(lhs: Type, rhs: Type, output: Type) -> Self {
+        let function = match (&lhs, &rhs) {
+            (Type::Tensor(_), Type::Tensor(_)) => move |lhs, rhs| quote! { #lhs.bool_or(#rhs) },
+            (Type::Scalar(_), Type::Scalar(_)) => move |lhs, rhs| quote! { #lhs || #rhs },
+            _ => panic!("or is supported for tensor to tensor and scalar to scalar only"),
+        };
+        Self::new(lhs, rhs, output, BinaryType::Or, Arc::new(function))
+    }
+
+    pub(crate) fn bool_xor(lhs: Type, rhs: Type, output: Type) -> Self {
+        let function = match (&lhs, &rhs) {
+            (Type::Tensor(_), Type::Tensor(_)) => move |lhs, rhs| quote! { #lhs.bool_xor(#rhs) },
+            (Type::Scalar(_), Type::Scalar(_)) => move |lhs, rhs| quote! { #lhs ^ #rhs },
+            _ => panic!("xor is supported for tensor to tensor and scalar to scalar only"),
+        };
+        Self::new(lhs, rhs, output, BinaryType::Xor, Arc::new(function))
+    }
+
+    pub(crate) fn remainder(lhs: Type, rhs: Type, output: Type) -> Self {
+        let function = match (&lhs, &rhs) {
+            (Type::Tensor(_), Type::Tensor(_)) => move |lhs, rhs| quote! { #lhs.remainder(#rhs) },
+            (Type::Tensor(_), Type::Scalar(_)) => {
+                move |lhs, rhs| quote! { #lhs.remainder_scalar(#rhs) }
+            }
+            _ => panic!("remainder is supported for tensor and scalar only"),
+        };
+        Self::new(lhs, rhs, output, BinaryType::Mod, Arc::new(function))
+    }
+
+    pub(crate) fn fmod(lhs: Type, rhs: Type, output: Type) -> Self {
+        let function = match (&lhs, &rhs) {
+            (Type::Tensor(_), Type::Tensor(_)) => move |lhs, rhs| quote! { #lhs.fmod(#rhs) },
+            (Type::Tensor(_), Type::Scalar(_)) => move |lhs, rhs| quote! { #lhs.fmod_scalar(#rhs) },
+            // The Rust remainder operator keeps the sign of the dividend, like fmod.
+            (Type::Scalar(_), Type::Scalar(_)) => move |lhs, rhs| quote! { #lhs % #rhs },
+            _ => panic!("fmod is supported for tensor and scalar only"),
+        };
+        Self::new(lhs, rhs, output, BinaryType::Fmod, Arc::new(function))
+    }
 }
 
 #[cfg(test)]
@@ -402,6 +470,31 @@ mod tests {
         test_binary_operator_on_scalar_and_scalar!(div, /);
     }
 
+    #[test]
+    fn test_binary_codegen_remainder() {
+        test_binary_operator_on_tensors!(remainder);
+    }
+
+    #[test]
+    fn test_binary_codegen_remainder_scalar() {
+        test_binary_operator_on_tensor_and_scalar!(remainder, remainder_scalar);
+    }
+
+    #[test]
+    fn test_binary_codegen_fmod() {
+        test_binary_operator_on_tensors!(fmod);
+    }
+
+    #[test]
+    fn test_binary_codegen_fmod_scalar() {
+        test_binary_operator_on_tensor_and_scalar!(fmod, fmod_scalar);
+    }
+
+    #[test]
+    fn test_binary_codegen_fmod_scalars() {
+        test_binary_operator_on_scalar_and_scalar!(fmod, %);
+    }
+
     #[test]
     fn test_binary_codegen_min() {
         test_binary_operator_on_tensors!(min_pair);
@@ -490,4 +583,44 @@ mod tests {
     fn test_binary_codegen_equal_scalars() {
         test_binary_operator_on_scalar_and_scalar!(equal, ==);
     }
+
+    macro_rules! test_binary_operator_on_bool_tensors {
+        ($operator:ident) => {{
+            one_node_graph(
+                BinaryNode::$operator(
+                    Type::Tensor(TensorType::new_bool("tensor1", 4)),
+                    Type::Tensor(TensorType::new_bool("tensor2", 4)),
+                    Type::Tensor(TensorType::new_bool("tensor3", 4)),
+                ),
+                quote! {
+                    pub fn forward(
+                        &self,
+                        tensor1: Tensor<B, 4, Bool>,
+                        tensor2: Tensor<B, 4, Bool>
+                    ) -> Tensor<B, 4, Bool> {
+                        let tensor3 = tensor1.$operator(tensor2);
+
+                        tensor3
+                    }
+                },
+                vec!["tensor1".to_string(), "tensor2".to_string()],
+                vec!["tensor3".to_string()],
+            );
+        }};
+    }
+
+    #[test]
+    fn test_binary_codegen_and() {
+        test_binary_operator_on_bool_tensors!(bool_and);
+    }
+
+    #[test]
+    fn test_binary_codegen_or() {
+        test_binary_operator_on_bool_tensors!(bool_or);
+    }
+
+    #[test]
+    fn test_binary_codegen_xor() {
+        test_binary_operator_on_bool_tensors!(bool_xor);
+    }
 }
@@ -2,9 +2,12 @@ use super::{Node, NodeCodegen, SerializationBackend};
 use crate::burn::{BurnImports, OtherType, Scope, TensorType, ToTokens, Type};
 use burn::{
     module::{ConstantRecord, Param, ParamId},
-    nn::conv::{Conv2dConfig, Conv2dRecord},
+    nn::{
+        conv::{Conv2dConfig, Conv2dRecord},
+        PaddingConfig2d,
+    },
     record::{PrecisionSettings, Record},
-    tensor::{Tensor, TensorData},
+    tensor::{ops::PadMode, Tensor, TensorData},
 };
 use proc_macro2::TokenStream;
 use quote::quote;
@@ -18,6 +21,8 @@ pub struct Conv2dNode {
     pub data_weights: TensorData,
     pub data_bias: Option<TensorData>,
     pub config: Conv2dConfig,
+    /// How the input is padded, the amount being the explicit padding of the config.
+    pub pad_mode: PadMode,
 }
 
 impl Conv2dNode {
@@ -41,6 +46,23 @@ impl Conv2dNode {
             data_weights,
             data_bias,
             config,
+            pad_mode: PadMode::Zeros,
+        }
+    }
+
+    /// Sets the padding mode, used when a reflect or edge `Pad` node was fused into the
+    /// convolution.
+    pub fn with_pad_mode(mut self, pad_mode: PadMode) -> Self {
+        self.pad_mode = pad_mode;
+        self
+    }
+
+    /// The amount of padding applied with the padding mode instead of the module.
+    fn mode_padding(&self) -> Option<[usize; 2]> {
+        match (self.pad_mode, &self.config.padding) {
+            (PadMode::Zeros, _) => None,
+            (_, PaddingConfig2d::Explicit(height, width)) => Some([*height, *width]),
+            _ => None,
         }
     }
 }
@@ -63,7 +85,10 @@ impl<PS: PrecisionSettings> NodeCodegen<PS> for Conv2dNode {
         let stride = self.config.stride.to_tokens();
         let dilation = self.config.dilation.to_tokens();
         let groups = self.config.groups.to_tokens();
-        let padding = self.config.padding.to_tokens();
+        let padding = match self.mode_padding() {
+            Some(_) => PaddingConfig2d::Valid.to_tokens(),
+            None => self.config.padding.to_tokens(),
+        };
         let bias = self.config.bias;
 
         let tokens = quote! {
@@ -111,14 +136,44 @@ impl<PS: PrecisionSettings> NodeCodegen<PS> for Conv2dNode {
         let output = &self.output.name;
         let field = &self.field.name;
 
+        let Some(padding) = self.mode_padding() else {
+            return quote! {
+                let #output = self.#field.forward(#input);
+            };
+        };
+
+        let padding = padding.to_tokens();
+        let pad_mode = match self.pad_mode {
+            PadMode::Reflect => quote! { PadMode::Reflect },
+            PadMode::Replicate => quote! { PadMode::Replicate },
+            PadMode::Zeros => unreachable!(),
+        };
+
         quote! {
-            let #output = self.#field.forward(#input);
+            let #output = conv2d_with_pad_mode(
+                #input,
+                self.#field.weight.val(),
+                self.#field.bias.as_ref().map(|bias| bias.val()),
+                ConvOptions::new(
+                    self.#field.stride,
+                    #padding,
+                    self.#field.dilation,
+                    self.#field.groups,
+                ),
+                #pad_mode,
+            );
         }
     }
     fn register_imports(&self, imports: &mut BurnImports) {
         imports.register("burn::nn::PaddingConfig2d");
         imports.register("burn::nn::conv::Conv2d");
         imports.register("burn::nn::conv::Conv2dConfig");
+
+        if self.mode_padding().is_some() {
+            imports.register("burn::tensor::module::conv2d_with_pad_mode");
+            imports.register("burn::tensor::ops::ConvOptions");
+            imports.register("burn::tensor::ops::PadMode");
+        }
     }
 
     fn into_node(self) -> Node<PS> {
@@ -195,4 +250,81 @@ mod tests {
 
         assert_tokens(graph.codegen(), expected);
     }
+
+    #[test]
+    fn test_codegen_reflect_padding() {
+        let mut graph = BurnGraph::<FullPrecisionSettings>::default();
+
+        graph.register(
+            Conv2dNode::new(
+                "conv2d",
+                TensorType::new_float("input", 4),
+                TensorType::new_float("output", 4),
+                TensorData::from([2f32]),
+                None,
+                Conv2dConfig::new([3, 3], [3, 3]).with_padding(PaddingConfig2d::Explicit(1, 2)),
+            )
+            .with_pad_mode(PadMode::Reflect),
+        );
+
+        graph.register_input_output(vec!["input".to_string()], vec!["output".to_string()]);
+
+        let expected = quote! {
+            use burn::{
+                module::Module,
+                tensor::{backend::Backend, Tensor},
+            };
+            use burn::nn::PaddingConfig2d;
+            use burn::nn::conv::Conv2d;
+            use burn::nn::conv::Conv2dConfig;
+            use burn::tensor::module::conv2d_with_pad_mode;
+            use burn::tensor::ops::ConvOptions;
+            use burn::tensor::ops::PadMode;
+
+            #[derive(Module, Debug)]
+            pub struct Model <B: Backend> {
+                conv2d: Conv2d<B>,
+                phantom: core::marker::PhantomData<B>,
+                device: burn::module::Ignored<B::Device>,
+            }
+
+            impl<B: Backend> Model <B> {
+                #[allow(unused_variables)]
+                pub fn new(device: &B::Device) -> Self {
+                    let conv2d = Conv2dConfig::new([3, 3], [3, 3])
+                        .with_stride([1, 1])
+                        .with_padding(PaddingConfig2d::Valid)
+                        .with_dilation([1, 1])
+                        .with_groups(1)
+                        .with_bias(true)
+                        .init(device);
+
+                    Self {
+                        conv2d,
+                        phantom: core::marker::PhantomData,
+                        device: burn::module::Ignored(device.clone()),
+                    }
+                }
+                #[allow(clippy::let_and_return, clippy::approx_constant)]
+                pub fn forward(&self, input: Tensor<B, 4>) -> Tensor<B, 4> {
+                    let output = conv2d_with_pad_mode(
+                        input,
+                        self.conv2d.weight.val(),
+                        self.conv2d.bias.as_ref().map(|bias| bias.val()),
+                        ConvOptions::new(
+                            self.conv2d.stride,
+                            [1, 2],
+                            self.conv2d.dilation,
+                            self.conv2d.groups,
+                        ),
+                        PadMode::Reflect,
+                    );
+
+                    output
+                }
+            }
+        };
+
+        assert_tokens(graph.codegen(), expected);
+    }
 }
@@ -0,0 +1,245 @@
+use super::{Node, NodeCodegen};
+use crate::burn::{ScalarType, Scope, TensorType, ToTokens, Type};
+use burn::record::PrecisionSettings;
+use proc_macro2::TokenStream;
+use quote::quote;
+
+/// Axis of a [CumSum node](CumSumNode).
+#[derive(Debug, Clone)]
+pub enum CumSumAxis {
+    /// Axis known when the model is imported, negative values already resolved.
+    Static(usize),
+    /// Axis provided as a scalar input of the forward pass, possibly negative.
+    Runtime(ScalarType),
+}
+
+/// Cumulative sum of the input along an axis.
+///
+/// With `exclusive`, each output element doesn't include its own input element, which is
+/// computed by shifting the inclusive sums by one position. With `reverse`, the sums are
+/// accumulated from the end of the axis by flipping the input before and after the cumulative sum.
+#[derive(Debug, Clone, new)]
+pub struct CumSumNode {
+    pub input: TensorType,
+    pub output: TensorType,
+    pub axis: CumSumAxis,
+    pub exclusive: bool,
+    pub reverse: bool,
+}
+
+impl<PS: PrecisionSettings> NodeCodegen<PS> for CumSumNode {
+    fn output_types(&self) -> Vec<Type> {
+        vec![Type::Tensor(self.output.clone())]
+    }
+
+    fn input_types(&self) -> Vec<Type> {
+        let mut inputs = vec![Type::Tensor(self.input.clone())];
+
+        if let CumSumAxis::Runtime(axis) = &self.axis {
+            inputs.push(Type::Scalar(axis.clone()));
+        }
+
+        inputs
+    }
+
+    fn forward(&self, scope: &mut Scope, node_position: usize) -> TokenStream {
+        let input = scope.tensor_use_owned(&self.input, node_position);
+        let output = &self.output.name;
+
+        let (axis, flip_axis) = match &self.axis {
+            CumSumAxis::Static(axis) => (axis.to_tokens(), axis.to_tokens()),
+            CumSumAxis::Runtime(_) => (quote! { axis }, quote! { axis as isize }),
+        };
+
+        let sums = if self.reverse {
+            quote! { #input.flip([#flip_axis]).cumsum(#axis).flip([#flip_axis]) }
+        } else {
+            quote! { #input.cumsum(#axis) }
+        };
+
+        // The exclusive sums are the inclusive sums shifted by one position along the axis, with
+        // a zero for the first position (the last one when reversed).
+        let sums = if self.exclusive {
+            let shifted = if self.reverse {
+                quote! { Tensor::cat([sums, zeros].into(), #axis).narrow(#axis, 1, size) }
+            } else {
+                quote! { Tensor::cat([zeros, sums].into(), #axis).narrow(#axis, 0, size) }
+            };
+
+            quote! {
+                let sums = #sums;
+                let mut shape = sums.dims();
+                let size = shape[#axis];
+                shape[#axis] = 1;
+                let zeros = Tensor::zeros(shape, &sums.device());
+                #shifted
+            }
+        } else {
+            sums
+        };
+
+        match (&self.axis, self.exclusive) {
+            (CumSumAxis::Static(_), false) => quote! {
+                let #output = #sums;
+            },
+            (CumSumAxis::Static(_), true) => quote! {
+                let #output = {
+                    #sums
+                };
+            },
+            (CumSumAxis::Runtime(axis), _) => {
+                let name = &axis.name;
+                let rank = self.input.dim.to_tokens();
+
+                quote! {
+                    let #output = {
+                        let axis = #name.rem_euclid(#rank) as usize;
+                        #sums
+                    };
+                }
+            }
+        }
+    }
+
+    fn into_node(self) -> Node<PS> {
+        Node::CumSum(self)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use burn::record::FullPrecisionSettings;
+
+    use super::*;
+    use crate::burn::{
+        graph::BurnGraph,
+        node::{cumsum::CumSumNode, test::assert_tokens},
+        ScalarKind, TensorType,
+    };
+
+    fn graph(axis: CumSumAxis, exclusive: bool, reverse: bool) -> BurnGraph<FullPrecisionSettings> {
+        let mut graph = BurnGraph::<FullPrecisionSettings>::default();
+        let mut inputs = vec!["tensor1".to_string()];
+
+        if let CumSumAxis::Runtime(axis) = &axis {
+            inputs.push(axis.name.to_string());
+        }
+
+        graph.register(CumSumNode::new(
+            TensorType::new_float("tensor1", 3),
+            TensorType::new_float("tensor2", 3),
+            axis,
+            exclusive,
+            reverse,
+        ));
+        graph.register_input_output(inputs, vec!["tensor2".to_string()]);
+
+        graph
+    }
+
+    fn expected_model(inputs: TokenStream, body: TokenStream) -> TokenStream {
+        quote! {
+            use burn::{
+                module::Module,
+                tensor::{backend::Backend, Tensor},
+            };
+
+            #[derive(Module, Debug)]
+            pub struct Model<B: Backend> {
+                phantom: core::marker::PhantomData<B>,
+                device: burn::module::Ignored<B::Device>,
+            }
+
+            impl<B: Backend> Model <B> {
+                #[allow(unused_variables)]
+                pub fn new(device: &B::Device) -> Self {
+                    Self {
+                        phantom: core::marker::PhantomData,
+                        device: burn::module::Ignored(device.clone()),
+                    }
+                }
+                #[allow(clippy::let_and_return, clippy::approx_constant)]
+                pub fn forward(&self, #inputs) -> Tensor<B, 3> {
+                    #body
+
+                    tensor2
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn test_codegen_cumsum() {
+        let graph = graph(CumSumAxis::Static(1), false, false);
+
+        let expected = expected_model(
+            quote! { tensor1: Tensor<B, 3> },
+            quote! {
+                let tensor2 = tensor1.cumsum(1);
+            },
+        );
+
+        assert_tokens(graph.codegen(), expected);
+    }
+
+    #[test]
+    fn test_codegen_cumsum_exclusive() {
+        let graph = graph(CumSumAxis::Static(1), true, false);
+
+        let expected = expected_model(
+            quote! { tensor1: Tensor<B, 3> },
+            quote! {
+                let tensor2 = {
+                    let sums = tensor1.cumsum(1);
+                    let mut shape = sums.dims();
+                    let size = shape[1];
+                    shape[1] = 1;
+                    let zeros = Tensor::zeros(shape, &sums.device());
+                    Tensor::cat([zeros, sums].into(), 1).narrow(1, 0, size)
+                };
+            },
+        );
+
+        assert_tokens(graph.codegen(), expected);
+    }
+
+    #[test]
+    fn test_codegen_cumsum_reverse() {
+        let graph = graph(CumSumAxis::Static(2), false, true);
+
+        let expected = expected_model(
+            quote! { tensor1: Tensor<B, 3> },
+            quote! {
+                let tensor2 = tensor1.flip([2]).cumsum(2).flip([2]);
+            },
+        );
+
+        assert_tokens(graph.codegen(), expected);
+    }
+
+    #[test]
+    fn test_codegen_cumsum_runtime_axis() {
+        let axis = ScalarType::new("axis1", ScalarKind::Int64);
+        let graph = graph(CumSumAxis::Runtime(axis), true, true);
+
+        let expected = expected_model(
+            quote! { tensor1: Tensor<B, 3>, axis1: i64 },
+            quote! {
+                let tensor2 = {
+                    let axis = axis1.rem_euclid(3) as usize;
+                    let sums = tensor1
+                        .flip([axis as isize])
+                        .cumsum(axis)
+                        .flip([axis as isize]);
+                    let mut shape = sums.dims();
+                    let size = shape[axis];
+                    shape[axis] = 1;
+                    let zeros = Tensor::zeros(shape, &sums.device());
+                    Tensor::cat([sums, zeros].into(), axis).narrow(axis, 1, size)
+                };
+            },
+        );
+
+        assert_tokens(graph.codegen(), expected);
+    }
+}
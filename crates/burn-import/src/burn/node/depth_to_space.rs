@@ -0,0 +1,162 @@
+use super::{Node, NodeCodegen};
+use crate::burn::{Scope, TensorType, ToTokens, Type};
+use burn::record::PrecisionSettings;
+use proc_macro2::TokenStream;
+use quote::quote;
+
+/// Order of the depth values moved to the spatial dimensions by a
+/// [DepthToSpace node](DepthToSpaceNode).
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum DepthToSpaceMode {
+    /// Depth-column-row: the channels are split as `[blocksize, blocksize, channels]`.
+    Dcr,
+    /// Column-row-depth: the channels are split as `[channels, blocksize, blocksize]`, as done by
+    /// the pixel shuffle of PyTorch.
+    Crd,
+}
+
+#[derive(Debug, Clone, new)]
+pub struct DepthToSpaceNode {
+    pub input: TensorType,
+    pub output: TensorType,
+    pub blocksize: usize,
+    pub mode: DepthToSpaceMode,
+}
+
+impl<PS: PrecisionSettings> NodeCodegen<PS> for DepthToSpaceNode {
+    fn output_types(&self) -> Vec<Type> {
+        vec![Type::Tensor(self.output.clone())]
+    }
+
+    fn input_types(&self) -> Vec<Type> {
+        vec![Type::Tensor(self.input.clone())]
+    }
+
+    fn forward(&self, scope: &mut Scope, node_position: usize) -> TokenStream {
+        let input_name = &self.input.name;
+        let input = scope.tensor_use_owned(&self.input, node_position);
+        let output = &self.output.name;
+        let block = self.blocksize.to_tokens();
+        let depth = (self.blocksize * self.blocksize).to_tokens();
+
+        // The channels are split in blocks, which are then moved next to the height and width.
+        let (split, axes) = match self.mode {
+            DepthToSpaceMode::Dcr => (
+                quote! { [batch_size, #block, #block, channels / #depth, height, width] },
+                quote! { [0, 3, 4, 1, 5, 2] },
+            ),
+            DepthToSpaceMode::Crd => (
+                quote! { [batch_size, channels / #depth, #block, #block, height, width] },
+                quote! { [0, 1, 4, 2, 5, 3] },
+            ),
+        };
+
+        quote! {
+            let #output = {
+                let [batch_size, channels, height, width] = #input_name.dims();
+
+                #input
+                    .reshape(#split)
+                    .permute(#axes)
+                    .reshape([batch_size, channels / #depth, height * #block, width * #block])
+            };
+        }
+    }
+
+    fn into_node(self) -> Node<PS> {
+        Node::DepthToSpace(self)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use burn::record::FullPrecisionSettings;
+
+    use super::*;
+    use crate::burn::{
+        graph::BurnGraph,
+        node::{depth_to_space::DepthToSpaceNode, test::assert_tokens},
+        TensorType,
+    };
+
+    fn expected_model(body: TokenStream) -> TokenStream {
+        quote! {
+            use burn::{
+                module::Module,
+                tensor::{backend::Backend, Tensor},
+            };
+
+            #[derive(Module, Debug)]
+            pub struct Model<B: Backend> {
+                phantom: core::marker::PhantomData<B>,
+                device: burn::module::Ignored<B::Device>,
+            }
+
+            impl<B: Backend> Model <B> {
+                #[allow(unused_variables)]
+                pub fn new(device: &B::Device) -> Self {
+                    Self {
+                        phantom: core::marker::PhantomData,
+                        device: burn::module::Ignored(device.clone()),
+                    }
+                }
+                #[allow(clippy::let_and_return, clippy::approx_constant)]
+                pub fn forward(&self, tensor1: Tensor<B, 4>) -> Tensor<B, 4> {
+                    #body
+
+                    tensor2
+                }
+            }
+        }
+    }
+
+    fn graph(mode: DepthToSpaceMode) -> BurnGraph<FullPrecisionSettings> {
+        let mut graph = BurnGraph::<FullPrecisionSettings>::default();
+
+        graph.register(DepthToSpaceNode::new(
+            TensorType::new_float("tensor1", 4),
+            TensorType::new_float("tensor2", 4),
+            2,
+            mode,
+        ));
+        graph.register_input_output(vec!["tensor1".to_string()], vec!["tensor2".to_string()]);
+
+        graph
+    }
+
+    #[test]
+    fn test_codegen_depth_to_space_dcr() {
+        let graph = graph(DepthToSpaceMode::Dcr);
+
+        let expected = expected_model(quote! {
+            let tensor2 = {
+                let [batch_size, channels, height, width] = tensor1.dims();
+
+                tensor1
+                    .reshape([batch_size, 2, 2, channels / 4, height, width])
+                    .permute([0, 3, 4, 1, 5, 2])
+                    .reshape([batch_size, channels / 4, height * 2, width * 2])
+            };
+        });
+
+        assert_tokens(graph.codegen(), expected);
+    }
+
+    #[test]
+    fn test_codegen_depth_to_space_crd() {
+        let graph = graph(DepthToSpaceMode::Crd);
+
+        let expected = expected_model(quote! {
+            let tensor2 = {
+                let [batch_size, channels, height, width] = tensor1.dims();
+
+                tensor1
+                    .reshape([batch_size, channels / 4, 2, 2, height, width])
+                    .permute([0, 1, 4, 2, 5, 3])
+                    .reshape([batch_size, channels / 4, height * 2, width * 2])
+            };
+        });
+
+        assert_tokens(graph.codegen(), expected);
+    }
+}
@@ -0,0 +1,480 @@
+use super::{Node, NodeCodegen};
+use crate::burn::{Scope, TensorType, ToTokens, Type};
+use burn::record::PrecisionSettings;
+use proc_macro2::TokenStream;
+use quote::quote;
+
+/// Einsum node supporting the equations that map to a permutation of a single operand or to a
+/// batched matrix multiplication of two operands.
+#[derive(Debug, Clone, new)]
+pub struct EinsumNode {
+    pub inputs: Vec<TensorType>,
+    pub output: TensorType,
+    pub equation: String,
+}
+
+/// Tensor operations an einsum equation is lowered to.
+#[derive(Debug, Clone, PartialEq)]
+enum EinsumPlan {
+    /// Permutes the dimensions of a single operand.
+    Permute { axes: Vec<i64> },
+    /// Permutes both operands to `[batch.., m, k]` and `[batch.., k, n]`, multiplies them and
+    /// permutes the `[batch.., m, n]` result to the output order.
+    Matmul {
+        lhs_axes: Vec<i64>,
+        rhs_axes: Vec<i64>,
+        output_axes: Vec<i64>,
+    },
+}
+
+impl EinsumNode {
+    fn plan(&self) -> EinsumPlan {
+        let ranks = self
+            .inputs
+            .iter()
+            .map(|input| input.dim)
+            .collect::<Vec<_>>();
+
+        match plan_equation(&self.equation, &ranks) {
+            Ok(plan) => plan,
+            Err(reason) => panic!("Einsum: unsupported equation '{}': {reason}", self.equation),
+        }
+    }
+}
+
+impl<PS: PrecisionSettings> NodeCodegen<PS> for EinsumNode {
+    fn output_types(&self) -> Vec<Type> {
+        vec![Type::Tensor(self.output.clone())]
+    }
+
+    fn input_types(&self) -> Vec<Type> {
+        self.inputs
+            .iter()
+            .map(|input| Type::Tensor(input.clone()))
+            .collect()
+    }
+
+    fn forward(&self, scope: &mut Scope, node_position: usize) -> TokenStream {
+        let output = &self.output.name;
+
+        match self.plan() {
+            EinsumPlan::Permute { axes } => {
+                let input = scope.tensor_use_owned(&self.inputs[0], node_position);
+                let input = permute(input, &axes);
+
+                quote! {
+                    let #output = #input;
+                }
+            }
+            EinsumPlan::Matmul {
+                lhs_axes,
+                rhs_axes,
+                output_axes,
+            } => {
+                let lhs = scope.tensor_use_owned(&self.inputs[0], node_position);
+                let rhs = scope.tensor_use_owned(&self.inputs[1], node_position);
+                let lhs = permute(lhs, &lhs_axes);
+                let rhs = permute(rhs, &rhs_axes);
+                let product = permute(quote! { #lhs.matmul(#rhs) }, &output_axes);
+
+                quote! {
+                    let #output = #product;
+                }
+            }
+        }
+    }
+
+    fn into_node(self) -> Node<PS> {
+        Node::Einsum(self)
+    }
+}
+
+/// Appends a permute call to the tensor expression, unless the axes are the identity.
+fn permute(tensor: TokenStream, axes: &[i64]) -> TokenStream {
+    if axes.iter().enumerate().all(|(i, axis)| i as i64 == *axis) {
+        return tensor;
+    }
+
+    let axes = axes.to_vec().to_tokens();
+    quote! { #tensor.permute(#axes) }
+}
+
+/// Returns the labels of each dimension of an operand, expanding the ellipsis to one private
+/// label per broadcasted dimension.
+fn parse_term(term: &str, rank: usize) -> Result<Vec<char>, String> {
+    let (before, after) = match term.split_once("...") {
+        Some((before, after)) => (before, Some(after)),
+        None => (term, None),
+    };
+
+    let mut labels = before.chars().collect::<Vec<_>>();
+
+    if let Some(after) = after {
+        let num_explicit = before.chars().count() + after.chars().count();
+        let num_ellipsis = rank.checked_sub(num_explicit).ok_or(format!(
+            "term '{term}' has more labels than the rank {rank}"
+        ))?;
+
+        // Ellipsis dimensions are aligned from the right, like broadcasting.
+        labels.extend((0..num_ellipsis).map(|i| ellipsis_label(num_ellipsis - i)));
+        labels.extend(after.chars());
+    }
+
+    if let Some(label) = labels
+        .iter()
+        .find(|label| !label.is_ascii_alphabetic() && !is_ellipsis_label(**label))
+    {
+        return Err(format!("invalid label '{label}'"));
+    }
+
+    if labels.len() != rank {
+        return Err(format!(
+            "term '{term}' has {} labels but the input has rank {rank}",
+            labels.len()
+        ));
+    }
+
+    for (i, label) in labels.iter().enumerate() {
+        if labels[i + 1..].contains(label) {
+            return Err(format!("repeated label '{label}' in term '{term}'"));
+        }
+    }
+
+    Ok(labels)
+}
+
+/// Label of the n-th ellipsis dimension, counted from the right.
+fn ellipsis_label(n: usize) -> char {
+    char::from_u32(0xE000 + n as u32).unwrap()
+}
+
+fn is_ellipsis_label(label: char) -> bool {
+    ('\u{E000}'..='\u{F8FF}').contains(&label)
+}
+
+/// Returns the output labels of an equation without an explicit output: the ellipsis dimensions
+/// followed by the labels used once, in alphabetical order.
+fn implicit_output(inputs: &[Vec<char>]) -> Vec<char> {
+    let labels = inputs.iter().flatten().copied().collect::<Vec<_>>();
+    let count = |label: &char| labels.iter().filter(|other| *other == label).count();
+
+    let mut ellipsis = labels
+        .iter()
+        .copied()
+        .filter(|label| is_ellipsis_label(*label))
+        .collect::<Vec<_>>();
+    // Higher private labels are further left.
+    ellipsis.sort_by(|a, b| b.cmp(a));
+    ellipsis.dedup();
+
+    let mut output = labels
+        .iter()
+        .copied()
+        .filter(|label| !is_ellipsis_label(*label) && count(label) == 1)
+        .collect::<Vec<_>>();
+    output.sort();
+
+    ellipsis.into_iter().chain(output).collect()
+}
+
+fn position(labels: &[char], label: char) -> i64 {
+    labels.iter().position(|other| *other == label).unwrap() as i64
+}
+
+/// Parses an einsum equation into the labels of the dimensions of each input and of the output,
+/// the ellipsis being expanded to private labels given the ranks of the inputs.
+pub(crate) fn parse_equation(
+    equation: &str,
+    ranks: &[usize],
+) -> Result<(Vec<Vec<char>>, Vec<char>), String> {
+    let equation = equation
+        .chars()
+        .filter(|c| !c.is_whitespace())
+        .collect::<String>();
+
+    let (inputs, output) = match equation.split_once("->") {
+        Some((inputs, output)) => (inputs, Some(output)),
+        None => (equation.as_str(), None),
+    };
+
+    let terms = inputs.split(',').collect::<Vec<_>>();
+    if terms.len() != ranks.len() {
+        return Err(format!("{} terms for {} inputs", terms.len(), ranks.len()));
+    }
+
+    let inputs = terms
+        .iter()
+        .zip(ranks)
+        .map(|(term, rank)| parse_term(term, *rank))
+        .collect::<Result<Vec<_>, _>>()?;
+
+    let output = match output {
+        Some(output) => {
+            let num_ellipsis = inputs
+                .iter()
+                .map(|labels| labels.iter().filter(|l| is_ellipsis_label(**l)).count())
+                .max()
+                .unwrap_or(0);
+            let num_explicit = output.replace("...", "").chars().count();
+            let rank = num_explicit
+                + if output.contains("...") {
+                    num_ellipsis
+                } else {
+                    0
+                };
+            parse_term(output, rank)?
+        }
+        None => implicit_output(&inputs),
+    };
+
+    for label in output.iter() {
+        if !inputs.iter().any(|labels| labels.contains(label)) {
+            return Err(format!("output label '{label}' is not in any input"));
+        }
+    }
+
+    Ok((inputs, output))
+}
+
+fn plan_equation(equation: &str, ranks: &[usize]) -> Result<EinsumPlan, String> {
+    let (inputs, output) = parse_equation(equation, ranks)?;
+
+    match inputs.as_slice() {
+        [input] => plan_permute(input, &output),
+        [lhs, rhs] => plan_matmul(lhs, rhs, &output),
+        _ => Err("only one or two inputs are supported".to_string()),
+    }
+}
+
+fn plan_permute(input: &[char], output: &[char]) -> Result<EinsumPlan, String> {
+    if input.len() != output.len() {
+        return Err("reductions of a single input are not supported".to_string());
+    }
+
+    let axes = output.iter().map(|label| position(input, *label)).collect();
+
+    Ok(EinsumPlan::Permute { axes })
+}
+
+fn plan_matmul(lhs: &[char], rhs: &[char], output: &[char]) -> Result<EinsumPlan, String> {
+    let mut batch = Vec::new();
+    let mut contracted = Vec::new();
+    let mut lhs_free = Vec::new();
+
+    for label in lhs.iter().copied() {
+        match (rhs.contains(&label), output.contains(&label)) {
+            (true, true) => batch.push(label),
+            (true, false) => contracted.push(label),
+            (false, true) => lhs_free.push(label),
+            (false, false) => return Err(format!("label '{label}' is summed over a single input")),
+        }
+    }
+
+    let mut rhs_free = Vec::new();
+
+    for label in rhs.iter().copied() {
+        match (lhs.contains(&label), output.contains(&label)) {
+            (true, _) => {}
+            (false, true) => rhs_free.push(label),
+            (false, false) => return Err(format!("label '{label}' is summed over a single input")),
+        }
+    }
+
+    let (m, k, n) = match (&lhs_free[..], &contracted[..], &rhs_free[..]) {
+        ([m], [k], [n]) => (*m, *k, *n),
+        _ => {
+            return Err(
+                "only one free dimension per input and one contracted dimension are supported"
+                    .to_string(),
+            )
+        }
+    };
+
+    let lhs_axes = batch
+        .iter()
+        .chain([m, k].iter())
+        .map(|label| position(lhs, *label))
+        .collect();
+    let rhs_axes = batch
+        .iter()
+        .chain([k, n].iter())
+        .map(|label| position(rhs, *label))
+        .collect();
+
+    let product = batch.iter().copied().chain([m, n]).collect::<Vec<_>>();
+    let output_axes = output
+        .iter()
+        .map(|label| position(&product, *label))
+        .collect();
+
+    Ok(EinsumPlan::Matmul {
+        lhs_axes,
+        rhs_axes,
+        output_axes,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use burn::record::FullPrecisionSettings;
+
+    use super::*;
+    use crate::burn::{
+        graph::BurnGraph,
+        node::{einsum::EinsumNode, test::assert_tokens},
+        TensorType,
+    };
+
+    fn expected_model(forward: TokenStream) -> TokenStream {
+        quote! {
+            use burn::{
+                module::Module,
+                tensor::{backend::Backend, Tensor},
+            };
+
+            #[derive(Module, Debug)]
+            pub struct Model<B: Backend> {
+                phantom: core::marker::PhantomData<B>,
+                device: burn::module::Ignored<B::Device>,
+            }
+
+            impl<B: Backend> Model <B> {
+                #[allow(unused_variables)]
+                pub fn new(device: &B::Device) -> Self {
+                    Self {
+                        phantom: core::marker::PhantomData,
+                        device: burn::module::Ignored(device.clone()),
+                    }
+                }
+                #forward
+            }
+        }
+    }
+
+    #[test]
+    fn test_codegen_einsum_batched_matmul() {
+        let mut graph = BurnGraph::<FullPrecisionSettings>::default();
+
+        graph.register(EinsumNode::new(
+            vec![
+                TensorType::new_float("tensor1", 3),
+                TensorType::new_float("tensor2", 3),
+            ],
+            TensorType::new_float("tensor3", 3),
+            "bij,bjk->bik".to_string(),
+        ));
+
+        graph.register_input_output(
+            vec!["tensor1".to_string(), "tensor2".to_string()],
+            vec!["tensor3".to_string()],
+        );
+
+        let expected = expected_model(quote! {
+            #[allow(clippy::let_and_return, clippy::approx_constant)]
+            pub fn forward(&self, tensor1: Tensor<B, 3>, tensor2: Tensor<B, 3>) -> Tensor<B, 3> {
+                let tensor3 = tensor1.matmul(tensor2);
+                tensor3
+            }
+        });
+
+        assert_tokens(graph.codegen(), expected);
+    }
+
+    #[test]
+    fn test_codegen_einsum_attention_scores() {
+        let mut graph = BurnGraph::<FullPrecisionSettings>::default();
+
+        graph.register(EinsumNode::new(
+            vec![
+                TensorType::new_float("query", 4),
+                TensorType::new_float("key", 4),
+            ],
+            TensorType::new_float("scores", 4),
+            "bhqd,bhkd->bhqk".to_string(),
+        ));
+
+        graph.register_input_output(
+            vec!["query".to_string(), "key".to_string()],
+            vec!["scores".to_string()],
+        );
+
+        let expected = expected_model(quote! {
+            #[allow(clippy::let_and_return, clippy::approx_constant)]
+            pub fn forward(&self, query: Tensor<B, 4>, key: Tensor<B, 4>) -> Tensor<B, 4> {
+                let scores = query.matmul(key.permute([0, 1, 3, 2]));
+                scores
+            }
+        });
+
+        assert_tokens(graph.codegen(), expected);
+    }
+
+    #[test]
+    fn test_codegen_einsum_transpose() {
+        let mut graph = BurnGraph::<FullPrecisionSettings>::default();
+
+        graph.register(EinsumNode::new(
+            vec![TensorType::new_float("tensor1", 4)],
+            TensorType::new_float("tensor2", 4),
+            "...ij->...ji".to_string(),
+        ));
+
+        graph.register_input_output(vec!["tensor1".to_string()], vec!["tensor2".to_string()]);
+
+        let expected = expected_model(quote! {
+            #[allow(clippy::let_and_return, clippy::approx_constant)]
+            pub fn forward(&self, tensor1: Tensor<B, 4>) -> Tensor<B, 4> {
+                let tensor2 = tensor1.permute([0, 1, 3, 2]);
+                tensor2
+            }
+        });
+
+        assert_tokens(graph.codegen(), expected);
+    }
+
+    #[test]
+    fn test_plan_einsum_output_permutation() {
+        let plan = plan_equation("ij,jk->ki", &[2, 2]).unwrap();
+
+        assert_eq!(
+            plan,
+            EinsumPlan::Matmul {
+                lhs_axes: vec![0, 1],
+                rhs_axes: vec![0, 1],
+                output_axes: vec![1, 0],
+            }
+        );
+    }
+
+    #[test]
+    fn test_plan_einsum_implicit_output() {
+        let plan = plan_equation("ba", &[2]).unwrap();
+
+        assert_eq!(plan, EinsumPlan::Permute { axes: vec![1, 0] });
+    }
+
+    #[test]
+    fn test_parse_einsum_output_labels() {
+        let (_, output) = parse_equation("...ij,...jk->...ik", &[4, 3]).unwrap();
+        assert_eq!(output.len(), 4);
+
+        // More labels than the rank of the input.
+        let error = parse_equation("abc...->...", &[2]).unwrap_err();
+        assert_eq!(error, "term 'abc...' has more labels than the rank 2");
+    }
+
+    #[test]
+    #[should_panic = "Einsum: unsupported equation 'ij->i'"]
+    fn test_codegen_einsum_unsupported_equation() {
+        let mut graph = BurnGraph::<FullPrecisionSettings>::default();
+
+        graph.register(EinsumNode::new(
+            vec![TensorType::new_float("tensor1", 2)],
+            TensorType::new_float("tensor2", 1),
+            "ij->i".to_string(),
+        ));
+
+        graph.register_input_output(vec!["tensor1".to_string()], vec!["tensor2".to_string()]);
+        graph.codegen();
+    }
+}
@@ -1,5 +1,5 @@
 use super::{Node, NodeCodegen};
-use crate::burn::{TensorType, ToTokens, Type};
+use crate::burn::{BurnImports, ScalarKind, TensorType, ToTokens, Type};
 
 use burn::record::PrecisionSettings;
 use quote::quote;
@@ -7,21 +7,20 @@ use quote::quote;
 #[derive(Debug, Clone, new)]
 pub struct GatherNode {
     pub input: TensorType,
-    pub index: TensorType,
-    pub output: TensorType,
+    /// Tensor of indices, or a scalar index removing the gathered axis.
+    pub index: Type,
+    /// Tensor output, or scalar output when a scalar index is gathered from a rank 1 input.
+    pub output: Type,
     pub dim: usize,
 }
 
 impl<PS: PrecisionSettings> NodeCodegen<PS> for GatherNode {
     fn output_types(&self) -> Vec<Type> {
-        vec![Type::Tensor(self.output.clone())]
+        vec![self.output.clone()]
     }
 
     fn input_types(&self) -> Vec<crate::burn::Type> {
-        vec![
-            Type::Tensor(self.input.clone()),
-            Type::Tensor(self.index.clone()),
-        ]
+        vec![Type::Tensor(self.input.clone()), self.index.clone()]
     }
 
     fn forward(
@@ -31,17 +30,68 @@ impl<PS: PrecisionSettings> NodeCodegen<PS> for GatherNode {
     ) -> proc_macro2::TokenStream {
         let dim = self.dim.to_tokens();
         let input = scope.tensor_use_owned(&self.input, node_position);
-        let index = scope.tensor_use_owned(&self.index, node_position);
-        let output = &self.output.name;
+        let output = self.output.name();
 
-        quote! {
-            let #output = #input.select(#dim, #index);
+        let index = match &self.index {
+            Type::Tensor(index) => {
+                let index = scope.tensor_use_owned(index, node_position);
+
+                return quote! {
+                    let #output = #input.select(#dim, #index);
+                };
+            }
+            // The axis size is compared as an `i64`, whatever the integer type of the index.
+            Type::Scalar(index) => {
+                let name = &index.name;
+
+                match index.kind {
+                    ScalarKind::Int64 => quote! { #name },
+                    _ => quote! { (#name as i64) },
+                }
+            }
+            Type::Other(_) => panic!("Gather needs a tensor or scalar index"),
+        };
+
+        // A scalar index selects a single element of the gathered axis, which is removed from
+        // the output. Negative indices count from the end of the axis.
+        let input_name = &self.input.name;
+        let selected = quote! {
+            let index = #index.rem_euclid(#input_name.dims()[#dim] as i64) as usize;
+            #input.narrow(#dim, index, 1)
+        };
+
+        match &self.output {
+            Type::Tensor(tensor) => {
+                let output_dim = tensor.dim.to_tokens();
+
+                quote! {
+                    let #output = {
+                        #selected.squeeze::<#output_dim>(#dim)
+                    };
+                }
+            }
+            Type::Scalar(scalar) => {
+                let ty = scalar.ty();
+
+                quote! {
+                    let #output = {
+                        #selected.into_scalar().elem::<#ty>()
+                    };
+                }
+            }
+            Type::Other(_) => panic!("Gather needs a tensor or scalar output"),
         }
     }
 
     fn into_node(self) -> super::Node<PS> {
         Node::Gather(self)
     }
+
+    fn register_imports(&self, imports: &mut BurnImports) {
+        if let Type::Scalar(_) = self.output {
+            imports.register("burn::tensor::ElementConversion");
+        }
+    }
 }
 
 #[cfg(test)]
@@ -53,7 +103,7 @@ mod tests {
     use crate::burn::{
         graph::BurnGraph,
         node::{gather::GatherNode, test::assert_tokens},
-        TensorType,
+        ScalarKind, ScalarType, TensorType,
     };
 
     #[test]
@@ -62,8 +112,8 @@ mod tests {
 
         graph.register(GatherNode::new(
             TensorType::new_float("tensor1", 2),
-            TensorType::new_int("tensor2", 1),
-            TensorType::new_float("tensor3", 2),
+            Type::Tensor(TensorType::new_int("tensor2", 1)),
+            Type::Tensor(TensorType::new_float("tensor3", 2)),
             0,
         ));
 
@@ -109,4 +159,162 @@ mod tests {
 
         assert_tokens(graph.codegen(), expected);
     }
+
+    #[test]
+    fn test_codegen_gather_scalar_index() {
+        let mut graph = BurnGraph::<FullPrecisionSettings>::default();
+
+        graph.register(GatherNode::new(
+            TensorType::new_float("tensor1", 3),
+            Type::Scalar(ScalarType::new("scalar1", ScalarKind::Int64)),
+            Type::Tensor(TensorType::new_float("tensor2", 2)),
+            1,
+        ));
+
+        graph.register_input_output(
+            vec!["tensor1".to_string(), "scalar1".to_string()],
+            vec!["tensor2".to_string()],
+        );
+
+        let expected = quote! {
+            use burn::{
+                module::Module,
+                tensor::{backend::Backend, Tensor},
+            };
+
+            #[derive(Module, Debug)]
+            pub struct Model<B: Backend> {
+                phantom: core::marker::PhantomData<B>,
+                device: burn::module::Ignored<B::Device>,
+            }
+
+            impl<B: Backend> Model <B> {
+                #[allow(unused_variables)]
+                pub fn new(device: &B::Device) -> Self {
+                    Self {
+                        phantom: core::marker::PhantomData,
+                        device: burn::module::Ignored(device.clone()),
+                    }
+                }
+
+                #[allow(clippy::let_and_return, clippy::approx_constant)]
+                pub fn forward(&self, tensor1: Tensor<B, 3>, scalar1: i64) -> Tensor<B, 2> {
+                    let tensor2 = {
+                        let index = scalar1.rem_euclid(tensor1.dims()[1] as i64) as usize;
+                        tensor1.narrow(1, index, 1).squeeze::<2>(1)
+                    };
+
+                    tensor2
+                }
+            }
+        };
+
+        assert_tokens(graph.codegen(), expected);
+    }
+
+    #[test]
+    fn test_codegen_gather_int32_scalar_index() {
+        let mut graph = BurnGraph::<FullPrecisionSettings>::default();
+
+        graph.register(GatherNode::new(
+            TensorType::new_float("tensor1", 2),
+            Type::Scalar(ScalarType::new("scalar1", ScalarKind::Int32)),
+            Type::Tensor(TensorType::new_float("tensor2", 1)),
+            0,
+        ));
+
+        graph.register_input_output(
+            vec!["tensor1".to_string(), "scalar1".to_string()],
+            vec!["tensor2".to_string()],
+        );
+
+        let expected = quote! {
+            use burn::{
+                module::Module,
+                tensor::{backend::Backend, Tensor},
+            };
+
+            #[derive(Module, Debug)]
+            pub struct Model<B: Backend> {
+                phantom: core::marker::PhantomData<B>,
+                device: burn::module::Ignored<B::Device>,
+            }
+
+            impl<B: Backend> Model <B> {
+                #[allow(unused_variables)]
+                pub fn new(device: &B::Device) -> Self {
+                    Self {
+                        phantom: core::marker::PhantomData,
+                        device: burn::module::Ignored(device.clone()),
+                    }
+                }
+
+                #[allow(clippy::let_and_return, clippy::approx_constant)]
+                pub fn forward(&self, tensor1: Tensor<B, 2>, scalar1: i32) -> Tensor<B, 1> {
+                    let tensor2 = {
+                        let index = (scalar1 as i64).rem_euclid(tensor1.dims()[0] as i64) as usize;
+                        tensor1.narrow(0, index, 1).squeeze::<1>(0)
+                    };
+
+                    tensor2
+                }
+            }
+        };
+
+        assert_tokens(graph.codegen(), expected);
+    }
+
+    #[test]
+    fn test_codegen_gather_scalar_index_scalar_output() {
+        let mut graph = BurnGraph::<FullPrecisionSettings>::default();
+
+        graph.register(GatherNode::new(
+            TensorType::new_int("tensor1", 1),
+            Type::Scalar(ScalarType::new("scalar1", ScalarKind::Int64)),
+            Type::Scalar(ScalarType::new("scalar2", ScalarKind::Int64)),
+            0,
+        ));
+
+        graph.register_input_output(
+            vec!["tensor1".to_string(), "scalar1".to_string()],
+            vec!["scalar2".to_string()],
+        );
+
+        let expected = quote! {
+            use burn::tensor::ElementConversion;
+            use burn::tensor::Int;
+            use burn::{
+                module::Module,
+                tensor::{backend::Backend, Tensor},
+            };
+
+            #[derive(Module, Debug)]
+            pub struct Model<B: Backend> {
+                phantom: core::marker::PhantomData<B>,
+                device: burn::module::Ignored<B::Device>,
+            }
+
+            impl<B: Backend> Model <B> {
+                #[allow(unused_variables)]
+                pub fn new(device: &B::Device) -> Self {
+                    Self {
+                        phantom: core::marker::PhantomData,
+                        device: burn::module::Ignored(device.clone()),
+                    }
+                }
+
+                #[allow(clippy::let_and_return, clippy::approx_constant)]
+                pub fn forward(&self, tensor1: Tensor<B, 1, Int>, scalar1: i64) -> i64 {
+                    let scalar2 = {
+                        let index = scalar1.rem_euclid(tensor1.dims()[0] as i64) as usize;
+                        tensor1.narrow(0, index, 1).into_scalar().elem::<i64>()
+                    };
+
+                    scalar2
+                }
+            }
+        };
+
+        assert_tokens(graph.codegen(), expected);
+    }
 }
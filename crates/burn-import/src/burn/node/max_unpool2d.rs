@@ -0,0 +1,140 @@
+use proc_macro2::TokenStream;
+use quote::quote;
+
+use burn::record::PrecisionSettings;
+
+use super::{Node, NodeCodegen};
+use crate::burn::{Scope, TensorType, ToTokens, Type};
+
+/// Attributes of the [max unpool 2d](burn::tensor::module::max_unpool2d) operation.
+#[derive(new, Debug, Clone)]
+pub struct MaxUnpool2dConfig {
+    pub kernel_size: [usize; 2],
+    pub strides: [usize; 2],
+    pub padding: [usize; 2],
+    pub output_size: Option<[usize; 2]>,
+}
+
+#[derive(Debug, Clone, new)]
+pub struct MaxUnpool2dNode {
+    pub input: TensorType,
+    pub indices: TensorType,
+    pub output: TensorType,
+    pub config: MaxUnpool2dConfig,
+}
+
+impl<PS: PrecisionSettings> NodeCodegen<PS> for MaxUnpool2dNode {
+    fn input_types(&self) -> Vec<Type> {
+        vec![
+            Type::Tensor(self.input.clone()),
+            Type::Tensor(self.indices.clone()),
+        ]
+    }
+
+    fn output_types(&self) -> Vec<Type> {
+        vec![Type::Tensor(self.output.clone())]
+    }
+
+    fn forward(&self, scope: &mut Scope, node_position: usize) -> TokenStream {
+        let input = scope.tensor_use_owned(&self.input, node_position);
+        let indices = scope.tensor_use_owned(&self.indices, node_position);
+        let output = &self.output.name;
+
+        let kernel_size = self.config.kernel_size.to_tokens();
+        let strides = self.config.strides.to_tokens();
+        let padding = self.config.padding.to_tokens();
+        let output_size = match self.config.output_size {
+            Some(output_size) => {
+                let output_size = output_size.to_tokens();
+                quote! { Some(#output_size) }
+            }
+            None => quote! { None },
+        };
+
+        quote! {
+            let #output = burn::tensor::module::max_unpool2d(
+                #input,
+                #indices,
+                #kernel_size,
+                #strides,
+                #padding,
+                #output_size,
+            );
+        }
+    }
+
+    fn into_node(self) -> Node<PS> {
+        Node::MaxUnpool2d(self)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::burn::{
+        graph::BurnGraph,
+        node::{max_unpool2d::MaxUnpool2dNode, test::assert_tokens},
+        TensorType,
+    };
+    use burn::record::FullPrecisionSettings;
+
+    #[test]
+    fn test_codegen() {
+        let mut graph = BurnGraph::<FullPrecisionSettings>::default();
+
+        graph.register(MaxUnpool2dNode::new(
+            TensorType::new_float("input", 4),
+            TensorType::new_int("indices", 4),
+            TensorType::new_float("output", 4),
+            MaxUnpool2dConfig::new([2, 2], [2, 2], [0, 0], Some([5, 5])),
+        ));
+
+        graph.register_input_output(
+            vec!["input".to_string(), "indices".to_string()],
+            vec!["output".to_string()],
+        );
+
+        let expected = quote! {
+            use burn::tensor::Int;
+            use burn::{
+                module::Module,
+                tensor::{backend::Backend, Tensor},
+            };
+
+            #[derive(Module, Debug)]
+            pub struct Model<B: Backend> {
+                phantom: core::marker::PhantomData<B>,
+                device: burn::module::Ignored<B::Device>,
+            }
+
+            impl<B: Backend> Model <B> {
+                #[allow(unused_variables)]
+                pub fn new(device: &B::Device) -> Self {
+                    Self {
+                        phantom: core::marker::PhantomData,
+                        device: burn::module::Ignored(device.clone()),
+                    }
+                }
+                #[allow(clippy::let_and_return, clippy::approx_constant)]
+                pub fn forward(
+                    &self,
+                    input: Tensor<B, 4>,
+                    indices: Tensor<B, 4, Int>
+                ) -> Tensor<B, 4> {
+                    let output = burn::tensor::module::max_unpool2d(
+                        input,
+                        indices,
+                        [2, 2],
+                        [2, 2],
+                        [0, 0],
+                        Some([5, 5]),
+                    );
+
+                    output
+                }
+            }
+        };
+
+        assert_tokens(graph.codegen(), expected);
+    }
+}
@@ -11,7 +11,10 @@ pub(crate) mod constant;
 pub(crate) mod conv1d;
 pub(crate) mod conv2d;
 pub(crate) mod conv_transpose_2d;
+pub(crate) mod cumsum;
+pub(crate) mod depth_to_space;
 pub(crate) mod dropout;
+pub(crate) mod einsum;
 pub(crate) mod expand;
 pub(crate) mod gather;
 pub(crate) mod gather_elements;
@@ -22,15 +25,21 @@ pub(crate) mod mask_where;
 pub(crate) mod matmul;
 pub(crate) mod max_pool1d;
 pub(crate) mod max_pool2d;
+pub(crate) mod max_unpool2d;
+pub(crate) mod nonzero;
+pub(crate) mod pad;
 pub(crate) mod prelu;
 pub(crate) mod random_normal;
 pub(crate) mod random_uniform;
 pub(crate) mod range;
 pub(crate) mod reshape;
 pub(crate) mod resize;
+pub(crate) mod scatter_elements;
 pub(crate) mod slice;
+pub(crate) mod space_to_depth;
 pub(crate) mod squeeze;
 pub(crate) mod sum;
+pub(crate) mod trilu;
 pub(crate) mod unary;
 pub(crate) mod unsqueeze;
 pub(crate) use base::*;
@@ -0,0 +1,132 @@
+use super::{Node, NodeCodegen};
+use crate::burn::{Scope, TensorKind, TensorType, Type};
+use burn::record::PrecisionSettings;
+use proc_macro2::TokenStream;
+use quote::quote;
+
+/// Indices of the non-zero elements of the input, as an `Int` tensor of shape
+/// `[rank, num_nonzero]`.
+///
+/// The number of non-zero elements depends on the input values, so it is computed at runtime
+/// with [argwhere](burn::tensor::Tensor::argwhere), which reads the input back from the device.
+/// Only the rank of the output is known when the model is imported: downstream nodes requiring a
+/// static shape can't consume it.
+#[derive(Debug, Clone, new)]
+pub struct NonZeroNode {
+    pub input: TensorType,
+    pub output: TensorType,
+}
+
+impl<PS: PrecisionSettings> NodeCodegen<PS> for NonZeroNode {
+    fn output_types(&self) -> Vec<Type> {
+        vec![Type::Tensor(self.output.clone())]
+    }
+
+    fn input_types(&self) -> Vec<Type> {
+        vec![Type::Tensor(self.input.clone())]
+    }
+
+    fn forward(&self, scope: &mut Scope, node_position: usize) -> TokenStream {
+        let input = scope.tensor_use_owned(&self.input, node_position);
+        let output = &self.output.name;
+
+        let mask = match self.input.kind {
+            TensorKind::Bool => input,
+            TensorKind::Int => quote! { #input.not_equal_elem(0) },
+            TensorKind::Float => quote! { #input.not_equal_elem(0.0) },
+        };
+
+        // Burn returns one row per element, ONNX one row per dimension.
+        quote! {
+            let #output = #mask.argwhere().transpose();
+        }
+    }
+
+    fn into_node(self) -> Node<PS> {
+        Node::NonZero(self)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use burn::record::FullPrecisionSettings;
+
+    use super::*;
+    use crate::burn::{
+        graph::BurnGraph,
+        node::{nonzero::NonZeroNode, test::assert_tokens},
+        TensorType,
+    };
+
+    fn expected_model(imports: TokenStream, input: TokenStream, body: TokenStream) -> TokenStream {
+        quote! {
+            #imports
+            use burn::tensor::Int;
+            use burn::{
+                module::Module,
+                tensor::{backend::Backend, Tensor},
+            };
+
+            #[derive(Module, Debug)]
+            pub struct Model<B: Backend> {
+                phantom: core::marker::PhantomData<B>,
+                device: burn::module::Ignored<B::Device>,
+            }
+
+            impl<B: Backend> Model <B> {
+                #[allow(unused_variables)]
+                pub fn new(device: &B::Device) -> Self {
+                    Self {
+                        phantom: core::marker::PhantomData,
+                        device: burn::module::Ignored(device.clone()),
+                    }
+                }
+                #[allow(clippy::let_and_return, clippy::approx_constant)]
+                pub fn forward(&self, tensor1: #input) -> Tensor<B, 2, Int> {
+                    #body
+
+                    tensor2
+                }
+            }
+        }
+    }
+
+    fn graph(input: TensorType) -> BurnGraph<FullPrecisionSettings> {
+        let mut graph = BurnGraph::<FullPrecisionSettings>::default();
+
+        graph.register(NonZeroNode::new(input, TensorType::new_int("tensor2", 2)));
+        graph.register_input_output(vec!["tensor1".to_string()], vec!["tensor2".to_string()]);
+
+        graph
+    }
+
+    #[test]
+    fn test_codegen_nonzero() {
+        let graph = graph(TensorType::new_float("tensor1", 3));
+
+        let expected = expected_model(
+            quote! {},
+            quote! { Tensor<B, 3> },
+            quote! {
+                let tensor2 = tensor1.not_equal_elem(0.0).argwhere().transpose();
+            },
+        );
+
+        assert_tokens(graph.codegen(), expected);
+    }
+
+    #[test]
+    fn test_codegen_nonzero_bool() {
+        let graph = graph(TensorType::new_bool("tensor1", 2));
+
+        let expected = expected_model(
+            quote! { use burn::tensor::Bool; },
+            quote! { Tensor<B, 2, Bool> },
+            quote! {
+                let tensor2 = tensor1.argwhere().transpose();
+            },
+        );
+
+        assert_tokens(graph.codegen(), expected);
+    }
+}
@@ -0,0 +1,207 @@
+use super::{Node, NodeCodegen};
+use crate::burn::{BurnImports, Scope, TensorType, ToTokens, Type};
+use burn::record::PrecisionSettings;
+use proc_macro2::{Literal, TokenStream};
+use quote::quote;
+
+/// How the values added by a [Pad node](PadNode) are computed.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum PadMode {
+    /// Pads with a constant value, only supported on the last two dimensions.
+    Constant,
+    /// Pads with the reflection of the input, excluding the edge.
+    Reflect,
+    /// Pads by repeating the edge of the input.
+    Edge,
+}
+
+#[derive(Debug, Clone, new)]
+pub struct PadNode {
+    pub input: TensorType,
+    pub output: TensorType,
+    /// The `(before, after)` padding of each dimension of the input.
+    pub pads: Vec<(usize, usize)>,
+    pub mode: PadMode,
+    /// The value of the padding in [constant](PadMode::Constant) mode.
+    pub value: f64,
+}
+
+impl<PS: PrecisionSettings> NodeCodegen<PS> for PadNode {
+    fn output_types(&self) -> Vec<Type> {
+        vec![Type::Tensor(self.output.clone())]
+    }
+
+    fn input_types(&self) -> Vec<Type> {
+        vec![Type::Tensor(self.input.clone())]
+    }
+
+    fn forward(&self, scope: &mut Scope, node_position: usize) -> TokenStream {
+        let input = scope.tensor_use_owned(&self.input, node_position);
+        let output = &self.output.name;
+
+        let mode = match self.mode {
+            PadMode::Constant => {
+                let rank = self.pads.len();
+                assert!(
+                    rank >= 2 && self.pads[..rank - 2].iter().all(|pad| *pad == (0, 0)),
+                    "Pad: constant mode only supports padding the last two dimensions"
+                );
+
+                // Burn pads the last two dimensions in the (left, right, top, bottom) order.
+                let (top, bottom) = self.pads[rank - 2];
+                let (left, right) = self.pads[rank - 1];
+                let padding = [left, right, top, bottom].map(|pad| pad.to_tokens());
+                let value = Literal::f64_suffixed(self.value);
+
+                return quote! {
+                    let #output = #input.pad((#(#padding),*), #value.elem());
+                };
+            }
+            PadMode::Reflect => quote! { burn::tensor::ops::PadMode::Reflect },
+            PadMode::Edge => quote! { burn::tensor::ops::PadMode::Replicate },
+        };
+
+        let pads = self.pads.iter().map(|(before, after)| {
+            let before = before.to_tokens();
+            let after = after.to_tokens();
+            quote! { (#before, #after) }
+        });
+
+        quote! {
+            let #output = burn::tensor::module::pad_with_mode(#input, [#(#pads),*], #mode);
+        }
+    }
+
+    fn into_node(self) -> Node<PS> {
+        Node::Pad(self)
+    }
+
+    fn register_imports(&self, imports: &mut BurnImports) {
+        if self.mode == PadMode::Constant {
+            imports.register("burn::tensor::ElementConversion");
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use burn::record::FullPrecisionSettings;
+
+    use super::*;
+    use crate::burn::{
+        graph::BurnGraph,
+        node::{pad::PadNode, test::assert_tokens},
+        TensorType,
+    };
+
+    fn graph(pads: Vec<(usize, usize)>, mode: PadMode) -> BurnGraph<FullPrecisionSettings> {
+        let rank = pads.len();
+        let mut graph = BurnGraph::<FullPrecisionSettings>::default();
+
+        graph.register(PadNode::new(
+            TensorType::new_float("tensor1", rank),
+            TensorType::new_float("tensor2", rank),
+            pads,
+            mode,
+            1.5,
+        ));
+        graph.register_input_output(vec!["tensor1".to_string()], vec!["tensor2".to_string()]);
+
+        graph
+    }
+
+    fn expected_model(imports: TokenStream, rank: usize, body: TokenStream) -> TokenStream {
+        let rank = rank.to_tokens();
+
+        quote! {
+            #imports
+            use burn::{
+                module::Module,
+                tensor::{backend::Backend, Tensor},
+            };
+
+            #[derive(Module, Debug)]
+            pub struct Model<B: Backend> {
+                phantom: core::marker::PhantomData<B>,
+                device: burn::module::Ignored<B::Device>,
+            }
+
+            impl<B: Backend> Model <B> {
+                #[allow(unused_variables)]
+                pub fn new(device: &B::Device) -> Self {
+                    Self {
+                        phantom: core::marker::PhantomData,
+                        device: burn::module::Ignored(device.clone()),
+                    }
+                }
+                #[allow(clippy::let_and_return, clippy::approx_constant)]
+                pub fn forward(&self, tensor1: Tensor<B, #rank>) -> Tensor<B, #rank> {
+                    #body
+
+                    tensor2
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn test_codegen_pad_constant() {
+        let graph = graph(vec![(0, 0), (0, 0), (1, 2), (3, 4)], PadMode::Constant);
+
+        let expected = expected_model(
+            quote! { use burn::tensor::ElementConversion; },
+            4,
+            quote! {
+                let tensor2 = tensor1.pad((3, 4, 1, 2), 1.5f64.elem());
+            },
+        );
+
+        assert_tokens(graph.codegen(), expected);
+    }
+
+    #[test]
+    fn test_codegen_pad_reflect() {
+        let graph = graph(vec![(0, 0), (0, 0), (2, 1)], PadMode::Reflect);
+
+        let expected = expected_model(
+            quote! {},
+            3,
+            quote! {
+                let tensor2 = burn::tensor::module::pad_with_mode(
+                    tensor1,
+                    [(0, 0), (0, 0), (2, 1)],
+                    burn::tensor::ops::PadMode::Reflect
+                );
+            },
+        );
+
+        assert_tokens(graph.codegen(), expected);
+    }
+
+    #[test]
+    fn test_codegen_pad_edge() {
+        let graph = graph(vec![(0, 0), (1, 1), (0, 0), (2, 2)], PadMode::Edge);
+
+        let expected = expected_model(
+            quote! {},
+            4,
+            quote! {
+                let tensor2 = burn::tensor::module::pad_with_mode(
+                    tensor1,
+                    [(0, 0), (1, 1), (0, 0), (2, 2)],
+                    burn::tensor::ops::PadMode::Replicate
+                );
+            },
+        );
+
+        assert_tokens(graph.codegen(), expected);
+    }
+
+    #[test]
+    #[should_panic(expected = "Pad: constant mode only supports padding the last two dimensions")]
+    fn test_codegen_pad_constant_leading_dims() {
+        let graph = graph(vec![(1, 0), (0, 0), (0, 0)], PadMode::Constant);
+
+        let _ = graph.codegen();
+    }
+}
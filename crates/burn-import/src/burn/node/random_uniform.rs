@@ -1,5 +1,5 @@
 use super::{Node, NodeCodegen};
-use crate::burn::{Scope, TensorType, Type};
+use crate::burn::{OtherType, Scope, TensorType, Type};
 use burn::record::PrecisionSettings;
 use proc_macro2::TokenStream;
 use quote::quote;
@@ -9,6 +9,10 @@ pub struct RandomUniformNode {
     pub low: f64,
     pub high: f64,
     pub output_ty: TensorType,
+    /// Seed of the RNG kept by the model for this node, so its outputs are reproducible.
+    pub seed: Option<u64>,
+    /// The model field holding the seeded RNG.
+    pub rng: Option<OtherType>,
 }
 
 impl RandomUniformNode {
@@ -17,9 +21,23 @@ impl RandomUniformNode {
             low,
             high,
             output_ty,
+            seed: None,
+            rng: None,
         }
     }
 
+    /// Samples the output from an RNG seeded once when the model is created, instead of the
+    /// backend RNG.
+    pub fn with_seed(mut self, seed: u64) -> Self {
+        let name = format!("{}_rng", self.output_ty.name);
+        self.seed = Some(seed);
+        self.rng = Some(OtherType::new(
+            name,
+            quote! { burn::module::Ignored<std::sync::Arc<std::sync::Mutex<StdRng>>> },
+        ));
+        self
+    }
+
     fn get_output_shape(&self) -> TokenStream {
         let shape_it = self
             .output_ty
@@ -46,12 +64,43 @@ impl<PS: PrecisionSettings> NodeCodegen<PS> for RandomUniformNode {
         vec![Type::Tensor(self.output_ty.clone())]
     }
 
+    fn field_type(&self) -> Option<Type> {
+        self.rng.clone().map(Type::Other)
+    }
+
+    fn field_init(&self) -> Option<TokenStream> {
+        let name = &self.rng.as_ref()?.name;
+        let seed = self.seed?;
+
+        Some(quote! {
+            let #name = burn::module::Ignored(std::sync::Arc::new(std::sync::Mutex::new(
+                StdRng::seed_from_u64(#seed),
+            )));
+        })
+    }
+
     fn forward(&self, _scope: &mut Scope, _node_position: usize) -> TokenStream {
         let output = &self.output_ty.name;
         let shape = self.get_output_shape();
         let dist = self.get_distribution();
-        quote! {
-            let #output = Tensor::random(#shape, #dist, &*self.device);
+
+        match &self.rng {
+            Some(rng) => {
+                let rng = &rng.name;
+                quote! {
+                    let #output = Tensor::from_data(
+                        TensorData::random::<B::FloatElem, _, _>(
+                            #shape,
+                            #dist,
+                            &mut *self.#rng.lock().unwrap(),
+                        ),
+                        &*self.device,
+                    );
+                }
+            }
+            None => quote! {
+                let #output = Tensor::random(#shape, #dist, &*self.device);
+            },
         }
     }
 
@@ -62,6 +111,16 @@ impl<PS: PrecisionSettings> NodeCodegen<PS> for RandomUniformNode {
     fn register_imports(&self, imports: &mut crate::burn::BurnImports) {
         imports.register("burn::tensor::Distribution");
         imports.register("burn::prelude::Shape");
+
+        if self.rng.is_some() {
+            imports.register("burn::tensor::TensorData");
+            imports.register("burn::rand::StdRng");
+            imports.register("burn::rand::SeedableRng");
+        }
+    }
+
+    fn field_serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        S::serialize_none(serializer)
     }
 }
 
@@ -125,4 +184,69 @@ mod tests {
 
         assert_tokens(graph.codegen(), expected);
     }
+
+    #[test]
+    fn test_codegen_nodes_seed() {
+        let mut graph = BurnGraph::<FullPrecisionSettings>::default();
+
+        graph.register(
+            RandomUniformNode::new(
+                TensorType::new("tensor1", 3, TensorKind::Float, Some(vec![2, 3, 4])),
+                -1.0f64,
+                1.0f64,
+            )
+            .with_seed(42),
+        );
+
+        graph.register_input_output(vec![], vec!["tensor1".to_string()]);
+
+        let expected = quote! {
+            use burn::prelude::Shape;
+            use burn::rand::SeedableRng;
+            use burn::rand::StdRng;
+            use burn::tensor::Distribution;
+            use burn::tensor::TensorData;
+            use burn::{
+                module::Module,
+                tensor::{backend::Backend, Tensor},
+            };
+
+            #[derive(Module, Debug)]
+            pub struct Model<B: Backend> {
+                tensor1_rng: burn::module::Ignored<std::sync::Arc<std::sync::Mutex<StdRng>>>,
+                phantom: core::marker::PhantomData<B>,
+                device: burn::module::Ignored<B::Device>,
+            }
+
+            impl<B: Backend> Model <B> {
+                #[allow(unused_variables)]
+                pub fn new(device: &B::Device) -> Self {
+                    let tensor1_rng = burn::module::Ignored(std::sync::Arc::new(
+                        std::sync::Mutex::new(StdRng::seed_from_u64(42u64)),
+                    ));
+
+                    Self {
+                        tensor1_rng,
+                        phantom: core::marker::PhantomData,
+                        device: burn::module::Ignored(device.clone()),
+                    }
+                }
+                #[allow(clippy::let_and_return, clippy::approx_constant)]
+                pub fn forward(&self) -> Tensor<B, 3> {
+                    let tensor1 = Tensor::from_data(
+                        TensorData::random::<B::FloatElem, _, _>(
+                            Shape::new([2usize, 3usize, 4usize]),
+                            Distribution::Uniform(-1f64, 1f64),
+                            &mut *self.tensor1_rng.lock().unwrap(),
+                        ),
+                        &*self.device,
+                    );
+
+                    tensor1
+                }
+            }
+        };
+
+        assert_tokens(graph.codegen(), expected);
+    }
 }
@@ -75,9 +75,7 @@ impl<PS: PrecisionSettings> NodeCodegen<PS> for ResizeNode {
         };
 
         let tokens = quote! {
-            let #name = InterpolateOptions {
-                mode: #mode,
-            };
+            let #name = InterpolateOptions::new(#mode);
             let #name = burn::module::Ignored(#name);
         };
 
@@ -172,9 +170,7 @@ mod tests {
             impl<B: Backend> Model <B> {
                 #[allow(unused_variables)]
                 pub fn new(device: &B::Device) -> Self {
-                    let resize = InterpolateOptions {
-                        mode: InterpolateMode::Bilinear,
-                    };
+                    let resize = InterpolateOptions::new(InterpolateMode::Bilinear);
                     let resize = burn::module::Ignored(resize);
                     Self {
                         resize,
@@ -0,0 +1,210 @@
+use super::{Node, NodeCodegen};
+use crate::burn::{Scope, TensorType, ToTokens, Type};
+use burn::record::PrecisionSettings;
+use proc_macro2::TokenStream;
+use quote::quote;
+
+/// How the updates of a [ScatterElements node](ScatterElementsNode) are combined with the data.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ScatterElementsReduction {
+    /// The updates replace the data, the indices having no duplicates as required by ONNX.
+    Replace,
+    /// The updates are added to the data.
+    Add,
+}
+
+#[derive(Debug, Clone, new)]
+pub struct ScatterElementsNode {
+    pub data: TensorType,
+    pub indices: TensorType,
+    pub updates: TensorType,
+    pub output: TensorType,
+    pub axis: usize,
+    pub reduction: ScatterElementsReduction,
+}
+
+impl<PS: PrecisionSettings> NodeCodegen<PS> for ScatterElementsNode {
+    fn output_types(&self) -> Vec<Type> {
+        vec![Type::Tensor(self.output.clone())]
+    }
+
+    fn input_types(&self) -> Vec<Type> {
+        vec![
+            Type::Tensor(self.data.clone()),
+            Type::Tensor(self.indices.clone()),
+            Type::Tensor(self.updates.clone()),
+        ]
+    }
+
+    fn forward(&self, scope: &mut Scope, node_position: usize) -> TokenStream {
+        let axis = self.axis.to_tokens();
+        let data = scope.tensor_use_owned(&self.data, node_position);
+        let indices = scope.tensor_use_owned(&self.indices, node_position);
+        let updates = scope.tensor_use_owned(&self.updates, node_position);
+        let output = &self.output.name;
+
+        let rank = self.indices.dim.to_tokens();
+
+        // Negative indices count from the end of the axis.
+        let indices = quote! {
+            let indices = #indices;
+            let size = data.dims()[#axis] as i64;
+            let negative = indices.clone().lower_elem(0);
+            let indices = indices.clone().mask_where(negative, indices.add_scalar(size));
+        };
+
+        // Burn's scatter sums the updates into the data. The indices have the same rank as the
+        // data, so they are used as is.
+        match self.reduction {
+            // The updates are scattered into zeros and selected at the written positions, which
+            // are found by scattering ones. ONNX requires the indices to be unique for this
+            // reduction, so the sums of the scatters are single updates.
+            ScatterElementsReduction::Replace => quote! {
+                let #output = {
+                    let data = #data;
+                    #indices
+
+                    let device = indices.device();
+                    let written = Tensor::<B, #rank, Int>::zeros(data.shape(), &device)
+                        .scatter(#axis, indices.clone(), Tensor::ones(indices.shape(), &device))
+                        .greater_elem(0);
+                    let updates = Tensor::zeros(data.shape(), &device)
+                        .scatter(#axis, indices, #updates);
+
+                    data.mask_where(written, updates)
+                };
+            },
+            ScatterElementsReduction::Add => quote! {
+                let #output = {
+                    let data = #data;
+                    #indices
+
+                    data.scatter(#axis, indices, #updates)
+                };
+            },
+        }
+    }
+
+    fn into_node(self) -> Node<PS> {
+        Node::ScatterElements(self)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use burn::record::FullPrecisionSettings;
+
+    use super::*;
+    use crate::burn::{
+        graph::BurnGraph,
+        node::{scatter_elements::ScatterElementsNode, test::assert_tokens},
+        TensorType,
+    };
+
+    fn graph(reduction: ScatterElementsReduction) -> BurnGraph<FullPrecisionSettings> {
+        let mut graph = BurnGraph::<FullPrecisionSettings>::default();
+
+        graph.register(ScatterElementsNode::new(
+            TensorType::new_float("tensor1", 2),
+            TensorType::new_int("tensor2", 2),
+            TensorType::new_float("tensor3", 2),
+            TensorType::new_float("tensor4", 2),
+            0,
+            reduction,
+        ));
+
+        graph.register_input_output(
+            vec![
+                "tensor1".to_string(),
+                "tensor2".to_string(),
+                "tensor3".to_string(),
+            ],
+            vec!["tensor4".to_string()],
+        );
+
+        graph
+    }
+
+    fn expected_model(body: TokenStream) -> TokenStream {
+        quote! {
+            use burn::tensor::Int;
+            use burn::{
+                module::Module,
+                tensor::{backend::Backend, Tensor},
+            };
+
+            #[derive(Module, Debug)]
+            pub struct Model<B: Backend> {
+                phantom: core::marker::PhantomData<B>,
+                device: burn::module::Ignored<B::Device>,
+            }
+
+            impl<B: Backend> Model <B> {
+                #[allow(unused_variables)]
+                pub fn new(device: &B::Device) -> Self {
+                    Self {
+                        phantom: core::marker::PhantomData,
+                        device: burn::module::Ignored(device.clone()),
+                    }
+                }
+
+                #[allow(clippy::let_and_return, clippy::approx_constant)]
+                pub fn forward(
+                    &self,
+                    tensor1: Tensor<B, 2>,
+                    tensor2: Tensor<B, 2, Int>,
+                    tensor3: Tensor<B, 2>
+                ) -> Tensor<B, 2> {
+                    #body
+
+                    tensor4
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn test_codegen_scatter_elements_replace() {
+        let graph = graph(ScatterElementsReduction::Replace);
+
+        let expected = expected_model(quote! {
+            let tensor4 = {
+                let data = tensor1;
+                let indices = tensor2;
+                let size = data.dims()[0] as i64;
+                let negative = indices.clone().lower_elem(0);
+                let indices = indices.clone().mask_where(negative, indices.add_scalar(size));
+
+                let device = indices.device();
+                let written = Tensor::<B, 2, Int>::zeros(data.shape(), &device)
+                    .scatter(0, indices.clone(), Tensor::ones(indices.shape(), &device))
+                    .greater_elem(0);
+                let updates = Tensor::zeros(data.shape(), &device)
+                    .scatter(0, indices, tensor3);
+
+                data.mask_where(written, updates)
+            };
+        });
+
+        assert_tokens(graph.codegen(), expected);
+    }
+
+    #[test]
+    fn test_codegen_scatter_elements_add() {
+        let graph = graph(ScatterElementsReduction::Add);
+
+        let expected = expected_model(quote! {
+            let tensor4 = {
+                let data = tensor1;
+                let indices = tensor2;
+                let size = data.dims()[0] as i64;
+                let negative = indices.clone().lower_elem(0);
+                let indices = indices.clone().mask_where(negative, indices.add_scalar(size));
+
+                data.scatter(0, indices, tensor3)
+            };
+        });
+
+        assert_tokens(graph.codegen(), expected);
+    }
+}
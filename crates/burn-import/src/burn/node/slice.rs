@@ -1,15 +1,162 @@
 use super::{Node, NodeCodegen};
-use crate::burn::{Scope, TensorType, Type};
+use crate::burn::{BurnImports, Scope, TensorKind, TensorType, ToTokens, Type};
 use burn::record::PrecisionSettings;
 use proc_macro2::TokenStream;
 use quote::quote;
 
+/// A slice that can't be converted, reported by
+/// [slice_config](crate::onnx::op_configuration::slice_config) and when generating the
+/// [SliceNode] code.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum SliceError {
+    /// The starts or the ends attribute is missing before opset 10.
+    MissingAttribute(&'static str),
+    /// The starts or the ends are not given as a tensor input since opset 10.
+    MissingInput(&'static str),
+    /// The bounds, the axes or the steps are not a 1D tensor.
+    NotVector(&'static str),
+    /// The bounds, the axes or the steps are not a constant int64 tensor.
+    NotConstant(&'static str),
+    /// The axes or the steps don't have the same length as the starts.
+    LengthMismatch(&'static str),
+    /// A step is zero.
+    ZeroStep,
+    /// The sliced input is not a tensor.
+    NotTensor,
+    /// An axis is out of bounds for the rank of the input.
+    AxisOutOfBounds { axis: i64, rank: i64 },
+    /// The static bounds of an axis describe an empty range, which Burn tensors don't support.
+    EmptyRange {
+        input: String,
+        axis: usize,
+        start: i64,
+        end: i64,
+        step: i64,
+    },
+}
+
+impl core::fmt::Display for SliceError {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        match self {
+            Self::MissingAttribute(name) => {
+                write!(f, "Slice: {name} attribute is required before opset 10")
+            }
+            Self::MissingInput(name) => write!(f, "Slice: {name} must be given as a tensor input"),
+            Self::NotVector(name) => write!(f, "Slice: {name} tensor must be 1D"),
+            Self::NotConstant(name) => write!(f, "Slice: {name} must be a constant int64 tensor"),
+            Self::LengthMismatch(name) => {
+                write!(f, "Slice: {name} must have the same length as starts")
+            }
+            Self::ZeroStep => write!(f, "Slice: step can't be zero"),
+            Self::NotTensor => write!(f, "Slice: only tensor input is valid"),
+            Self::AxisOutOfBounds { axis, rank } => {
+                write!(f, "Slice: axis {axis} is out of bounds for a rank of {rank}")
+            }
+            Self::EmptyRange {
+                input,
+                axis,
+                start,
+                end,
+                step,
+            } => match *step < 0 {
+                true => write!(
+                    f,
+                    "Slice: empty slices are not supported, start ({start}) is lower than end ({end}) on axis {axis} of {input} with a negative step"
+                ),
+                false => write!(
+                    f,
+                    "Slice: empty slices are not supported, start ({start}) is greater than end ({end}) on axis {axis} of {input}"
+                ),
+            },
+        }
+    }
+}
+
+impl std::error::Error for SliceError {}
+
 #[derive(Debug, Clone, new)]
 pub struct SliceNode {
     pub input: TensorType,
     pub output: TensorType,
-    pub starts: Vec<usize>,
-    pub ends: Vec<usize>,
+    /// Start of each sliced axis, negative values count from the end of the axis.
+    pub starts: Vec<i64>,
+    /// End (exclusive) of each sliced axis, negative values count from the end of the axis.
+    pub ends: Vec<i64>,
+    /// Step of each sliced axis, `None` when every step is 1.
+    #[new(default)]
+    pub steps: Option<Vec<i64>>,
+    /// Axis squeezed out of the output after slicing, see
+    /// [with_collapse_axis](Self::with_collapse_axis).
+    #[new(default)]
+    pub collapse_axis: Option<usize>,
+}
+
+impl SliceNode {
+    /// Sets the step of each sliced axis.
+    ///
+    /// A negative step walks the axis backward, from `starts[i]` down to `ends[i]` (exclusive).
+    pub fn with_steps(mut self, steps: Vec<i64>) -> Self {
+        self.steps = Some(steps).filter(|steps| steps.iter().any(|step| *step != 1));
+        self
+    }
+
+    /// Squeezes the sliced axis out of the output, fusing a following `Squeeze`.
+    ///
+    /// Only applies when exactly one axis is statically sliced to a width of 1 (e.g. `x[:, k, :]`),
+    /// in which case the output rank is reduced by one. Otherwise the node is left unchanged.
+    pub fn with_collapse_axis(mut self) -> Self {
+        let steps = self.steps.clone().unwrap_or_default();
+        let mut unit_axes = self
+            .starts
+            .iter()
+            .zip(self.ends.iter())
+            .enumerate()
+            .filter(|(axis, (start, end))| {
+                **start >= 0
+                    && end.checked_sub(**start) == Some(1)
+                    && steps.get(*axis).copied().unwrap_or(1) == 1
+            })
+            .map(|(axis, _)| axis);
+
+        if let (Some(axis), None) = (unit_axes.next(), unit_axes.next()) {
+            self.collapse_axis = Some(axis);
+            self.output.dim = self.input.dim - 1;
+        }
+
+        self
+    }
+
+    /// Checks that the static bounds of each axis do not describe an empty range. ONNX allows empty
+    /// slices but Burn tensors do not, so the unsupported slice is reported when generating the
+    /// model instead of panicking when running it.
+    ///
+    /// Bounds of different signs can only be compared once the input shape is known, and the end
+    /// may exceed the axis size, which is unknown at generation time.
+    fn check_static_bounds(&self) -> Result<(), SliceError> {
+        for (axis, (start, end)) in self.starts.iter().zip(self.ends.iter()).enumerate() {
+            if (*start >= 0) != (*end >= 0) {
+                continue;
+            }
+
+            let step = self
+                .steps
+                .as_ref()
+                .and_then(|steps| steps.get(axis).copied())
+                .unwrap_or(1);
+
+            if (step > 0 && start > end) || (step < 0 && start < end) {
+                return Err(SliceError::EmptyRange {
+                    input: self.input.name.to_string(),
+                    axis,
+                    start: *start,
+                    end: *end,
+                    step,
+                });
+            }
+        }
+
+        Ok(())
+    }
 }
 
 impl<PS: PrecisionSettings> NodeCodegen<PS> for SliceNode {
@@ -20,18 +167,200 @@ impl<PS: PrecisionSettings> NodeCodegen<PS> for SliceNode {
         vec![Type::Tensor(self.input.clone())]
     }
     fn forward(&self, scope: &mut Scope, node_position: usize) -> TokenStream {
+        if let Err(error) = self.check_static_bounds() {
+            panic!("{error}");
+        }
+
         let input = scope.tensor_use_owned(&self.input, node_position);
         let output = &self.output.name;
-        let starts = &self.starts;
-        let ends = &self.ends;
+        let squeeze = match self.collapse_axis {
+            Some(axis) => {
+                let dim = self.output.dim.to_tokens();
+                let axis = axis.to_tokens();
+                quote! { .squeeze::<#dim>(#axis) }
+            }
+            None => quote! {},
+        };
+
+        let input_name = &self.input.name;
+        let steps = match &self.steps {
+            Some(steps) => steps.clone(),
+            None => vec![1; self.starts.len()],
+        };
+
+        let resolved_at_runtime = self
+            .starts
+            .iter()
+            .zip(self.ends.iter())
+            .zip(steps.iter())
+            .any(|((start, end), step)| needs_axis_size(*start, *end, *step));
+
+        if !resolved_at_runtime {
+            let starts = self.starts.iter().map(|start| *start as usize);
+            let ends = self.ends.iter().map(|end| *end as usize);
+
+            return quote! {
+                let #output = #input.slice([#(#starts..#ends),*])#squeeze;
+            };
+        }
+
+        // Burn slices are contiguous, so the axes with a step other than 1 are first sliced to
+        // the covered range, reversed for negative steps, and then strided with a select.
+        let mut ranges = Vec::with_capacity(self.starts.len());
+        let mut strides = quote! {};
+        let mut selected = false;
+
+        for (axis, ((start, end), step)) in self
+            .starts
+            .iter()
+            .zip(self.ends.iter())
+            .zip(steps.iter())
+            .enumerate()
+        {
+            // The other axes keep their static bounds, as when no axis needs the axis size.
+            if !needs_axis_size(*start, *end, *step) {
+                let (start, end) = (*start as usize, *end as usize);
+                ranges.push(quote! { #start..#end });
+                continue;
+            }
 
+            let (start, end) = match *step < 0 {
+                // Walking backward from `start` down to `end` (exclusive) covers
+                // `end + 1..start + 1`.
+                true => (
+                    backward_bound(axis, *end, false),
+                    backward_bound(axis, *start, true),
+                ),
+                false => (
+                    forward_bound(axis, *start, true),
+                    forward_bound(axis, *end, false),
+                ),
+            };
+            ranges.push(quote! { #start..#end });
+
+            if *step == 1 {
+                continue;
+            }
+
+            let axis_flip = axis as isize;
+            let step_abs = step.unsigned_abs() as usize;
+
+            if *step < 0 {
+                strides.extend(quote! { .flip([#axis_flip]) });
+            }
+            // The covered range is empty when the start resolves past the end, e.g. a start
+            // beyond the end of a shorter axis, so its length is saturated at 0.
+            if step_abs != 1 {
+                selected = true;
+                strides.extend(quote! {
+                    .select(#axis, Tensor::<B, 1, Int>::arange_step(0..#end.saturating_sub(#start) as i64, #step_abs, &*self.device))
+                });
+            }
+        }
+
+        // Bool tensors can't be selected, so they are strided as int tensors.
+        if self.input.kind == TensorKind::Bool && selected {
+            strides = quote! { .int()#strides.bool() };
+        }
+
+        // The bounds are clamped to the axis, whose size is only known at runtime: exported
+        // models commonly slice until the end of an axis with an end of `INT64_MAX`, or
+        // `INT64_MIN` when walking backward.
         quote! {
-            let #output = #input.slice([#(#starts..#ends),*]);
+            let #output = {
+                let dims = #input_name.dims();
+                #input.slice([#(#ranges),*])#strides #squeeze
+            };
         }
     }
+
     fn into_node(self) -> Node<PS> {
         Node::Slice(self)
     }
+
+    fn register_imports(&self, imports: &mut BurnImports) {
+        let strided = self
+            .steps
+            .iter()
+            .flatten()
+            .any(|step| step.unsigned_abs() != 1);
+
+        if strided {
+            imports.register("burn::tensor::Int");
+        }
+    }
+}
+
+/// Ends from which an axis is sliced until its end, as exported for `x[k:]` (`INT64_MAX`, or
+/// `INT32_MAX` by some exporters).
+const UNBOUNDED_END: i64 = i32::MAX as i64;
+
+/// Whether the bounds of an axis can only be resolved with the size of the axis, known at
+/// runtime: negative bounds count from the end of the axis, unbounded ends are clamped to it, and
+/// stepped axes are sliced to the covered range before being strided.
+fn needs_axis_size(start: i64, end: i64, step: i64) -> bool {
+    start < 0 || !(0..UNBOUNDED_END).contains(&end) || step != 1
+}
+
+/// Resolves a bound of an axis sliced forward into an index of `0..=dims[axis]`.
+///
+/// Negative indices count from the end of the axis. A non-negative start is kept as is, since a
+/// start past the end of the axis would give an empty slice anyway. Unbounded indices resolve to
+/// the end or the start of the axis without being written, as they overflow a 32-bit `usize`.
+fn forward_bound(axis: usize, index: i64, is_start: bool) -> TokenStream {
+    if index >= UNBOUNDED_END {
+        return quote! { dims[#axis] };
+    }
+    if index <= -UNBOUNDED_END {
+        return quote! { 0usize };
+    }
+
+    match index < 0 {
+        true => {
+            let offset = index.unsigned_abs() as usize;
+            quote! { dims[#axis].saturating_sub(#offset) }
+        }
+        false if is_start => {
+            let index = index as usize;
+            quote! { #index }
+        }
+        false => {
+            let index = index as usize;
+            quote! { dims[#axis].min(#index) }
+        }
+    }
+}
+
+/// Resolves a bound of an axis sliced backward into the corresponding bound of the covered
+/// range, in `0..=dims[axis]`.
+///
+/// The start is clamped to `0..dims[axis]` and the end (exclusive) to `-1..dims[axis]` before
+/// adding 1, so the start always covers at least the first element of the axis. Unbounded
+/// indices resolve to the clamped bounds directly, as with [forward_bound].
+fn backward_bound(axis: usize, index: i64, is_start: bool) -> TokenStream {
+    if index >= UNBOUNDED_END {
+        return quote! { dims[#axis] };
+    }
+    if index <= -UNBOUNDED_END {
+        return match is_start {
+            true => quote! { 1usize },
+            false => quote! { 0usize },
+        };
+    }
+
+    match index < 0 {
+        true => {
+            let offset = (index.unsigned_abs() - 1) as usize;
+            match is_start {
+                true => quote! { dims[#axis].saturating_sub(#offset).max(1) },
+                false => quote! { dims[#axis].saturating_sub(#offset) },
+            }
+        }
+        false => {
+            let index = index as usize + 1;
+            quote! { dims[#axis].min(#index) }
+        }
+    }
 }
 
 #[cfg(test)]
@@ -87,4 +416,473 @@ mod tests {
 
         assert_tokens(graph.codegen(), expected);
     }
+
+    #[test]
+    fn test_codegen_slice_steps() {
+        let mut graph = BurnGraph::<FullPrecisionSettings>::default();
+        graph.register(
+            SliceNode::new(
+                TensorType::new_float("tensor1", 4),
+                TensorType::new_float("tensor2", 4),
+                vec![0, 0, 3, 5],
+                vec![1, 4, 0, 1],
+            )
+            .with_steps(vec![1, 2, -1, -2]),
+        );
+        graph.register_input_output(vec!["tensor1".to_string()], vec!["tensor2".to_string()]);
+
+        let expected = quote! {
+            use burn::tensor::Int;
+            use burn::{
+                module::Module,
+                tensor::{backend::Backend, Tensor},
+            };
+
+            #[derive(Module, Debug)]
+            pub struct Model<B: Backend> {
+                phantom: core::marker::PhantomData<B>,
+                device: burn::module::Ignored<B::Device>,
+            }
+
+            impl<B: Backend> Model <B> {
+                #[allow(unused_variables)]
+                pub fn new(device: &B::Device) -> Self {
+                    Self {
+                        phantom: core::marker::PhantomData,
+                        device: burn::module::Ignored(device.clone()),
+                    }
+                }
+                #[allow(clippy::let_and_return, clippy::approx_constant)]
+                pub fn forward(&self, tensor1: Tensor<B, 4>) -> Tensor<B, 4> {
+                    let tensor2 = {
+                        let dims = tensor1.dims();
+                        tensor1
+                            .slice([
+                                0usize..1usize,
+                                0usize..dims[1usize].min(4usize),
+                                dims[2usize].min(1usize)..dims[2usize].min(4usize),
+                                dims[3usize].min(2usize)..dims[3usize].min(6usize)
+                            ])
+                            .select(
+                                1usize,
+                                Tensor::<B, 1, Int>::arange_step(
+                                    0..dims[1usize].min(4usize).saturating_sub(0usize) as i64,
+                                    2usize,
+                                    &*self.device
+                                )
+                            )
+                            .flip([2isize])
+                            .flip([3isize])
+                            .select(
+                                3usize,
+                                Tensor::<B, 1, Int>::arange_step(
+                                    0..dims[3usize].min(6usize).saturating_sub(dims[3usize].min(2usize)) as i64,
+                                    2usize,
+                                    &*self.device
+                                )
+                            )
+                    };
+
+                    tensor2
+                }
+            }
+        };
+
+        assert_tokens(graph.codegen(), expected);
+    }
+
+    #[test]
+    fn test_codegen_slice_unit_steps() {
+        let node = SliceNode::new(
+            TensorType::new_float("tensor1", 2),
+            TensorType::new_float("tensor2", 2),
+            vec![0, 1],
+            vec![2, 3],
+        )
+        .with_steps(vec![1, 1]);
+
+        assert!(node.steps.is_none());
+    }
+
+    #[test]
+    fn test_codegen_slice_negative_indices() {
+        let mut graph = BurnGraph::<FullPrecisionSettings>::default();
+        graph.register(SliceNode::new(
+            TensorType::new_float("tensor1", 3),
+            TensorType::new_float("tensor2", 3),
+            vec![0, -3, 1],
+            vec![2, 4, -1],
+        ));
+        graph.register_input_output(vec!["tensor1".to_string()], vec!["tensor2".to_string()]);
+
+        let expected = quote! {
+            use burn::{
+                module::Module,
+                tensor::{backend::Backend, Tensor},
+            };
+
+            #[derive(Module, Debug)]
+            pub struct Model<B: Backend> {
+                phantom: core::marker::PhantomData<B>,
+                device: burn::module::Ignored<B::Device>,
+            }
+
+            impl<B: Backend> Model <B> {
+                #[allow(unused_variables)]
+                pub fn new(device: &B::Device) -> Self {
+                    Self {
+                        phantom: core::marker::PhantomData,
+                        device: burn::module::Ignored(device.clone()),
+                    }
+                }
+                #[allow(clippy::let_and_return, clippy::approx_constant)]
+                pub fn forward(&self, tensor1: Tensor<B, 3>) -> Tensor<B, 3> {
+                    let tensor2 = {
+                        let dims = tensor1.dims();
+                        tensor1.slice([
+                            0usize..2usize,
+                            dims[1usize].saturating_sub(3usize)..dims[1usize].min(4usize),
+                            1usize..dims[2usize].saturating_sub(1usize)
+                        ])
+                    };
+
+                    tensor2
+                }
+            }
+        };
+
+        assert_tokens(graph.codegen(), expected);
+    }
+
+    #[test]
+    fn test_codegen_slice_collapse_axis() {
+        let mut graph = BurnGraph::<FullPrecisionSettings>::default();
+        let node = SliceNode::new(
+            TensorType::new_float("tensor1", 3),
+            TensorType::new_float("tensor2", 3),
+            vec![0, 4, 0],
+            vec![2, 5, 8],
+        )
+        .with_collapse_axis();
+        assert_eq!(node.output.dim, 2);
+        graph.register(node);
+        graph.register_input_output(vec!["tensor1".to_string()], vec!["tensor2".to_string()]);
+
+        let expected = quote! {
+            use burn::{
+                module::Module,
+                tensor::{backend::Backend, Tensor},
+            };
+
+            #[derive(Module, Debug)]
+            pub struct Model<B: Backend> {
+                phantom: core::marker::PhantomData<B>,
+                device: burn::module::Ignored<B::Device>,
+            }
+
+            impl<B: Backend> Model <B> {
+                #[allow(unused_variables)]
+                pub fn new(device: &B::Device) -> Self {
+                    Self {
+                        phantom: core::marker::PhantomData,
+                        device: burn::module::Ignored(device.clone()),
+                    }
+                }
+                #[allow(clippy::let_and_return, clippy::approx_constant)]
+                pub fn forward(&self, tensor1: Tensor<B, 3>) -> Tensor<B, 2> {
+                    let tensor2 = tensor1
+                        .slice([0usize..2usize, 4usize..5usize, 0usize..8usize])
+                        .squeeze::<2>(1);
+
+                    tensor2
+                }
+            }
+        };
+
+        assert_tokens(graph.codegen(), expected);
+    }
+
+    #[test]
+    fn test_slice_collapse_axis_ambiguous() {
+        let node = SliceNode::new(
+            TensorType::new_float("tensor1", 3),
+            TensorType::new_float("tensor2", 3),
+            vec![0, 4],
+            vec![1, 5],
+        )
+        .with_collapse_axis();
+
+        assert_eq!(node.collapse_axis, None);
+        assert_eq!(node.output.dim, 3);
+    }
+
+    #[test]
+    fn test_codegen_slice_bool() {
+        let mut graph = BurnGraph::<FullPrecisionSettings>::default();
+        graph.register(SliceNode::new(
+            TensorType::new_bool("tensor1", 2),
+            TensorType::new_bool("tensor2", 2),
+            vec![0, 1],
+            vec![2, 3],
+        ));
+        graph.register_input_output(vec!["tensor1".to_string()], vec!["tensor2".to_string()]);
+
+        let expected = quote! {
+            use burn::tensor::Bool;
+            use burn::{
+                module::Module,
+                tensor::{backend::Backend, Tensor},
+            };
+
+            #[derive(Module, Debug)]
+            pub struct Model<B: Backend> {
+                phantom: core::marker::PhantomData<B>,
+                device: burn::module::Ignored<B::Device>,
+            }
+
+            impl<B: Backend> Model <B> {
+                #[allow(unused_variables)]
+                pub fn new(device: &B::Device) -> Self {
+                    Self {
+                        phantom: core::marker::PhantomData,
+                        device: burn::module::Ignored(device.clone()),
+                    }
+                }
+                #[allow(clippy::let_and_return, clippy::approx_constant)]
+                pub fn forward(&self, tensor1: Tensor<B, 2, Bool>) -> Tensor<B, 2, Bool> {
+                    let tensor2 = tensor1.slice([0usize..2usize, 1usize..3usize]);
+
+                    tensor2
+                }
+            }
+        };
+
+        assert_tokens(graph.codegen(), expected);
+    }
+
+    #[test]
+    fn test_codegen_slice_bool_steps() {
+        let mut graph = BurnGraph::<FullPrecisionSettings>::default();
+        graph.register(
+            SliceNode::new(
+                TensorType::new_bool("tensor1", 2),
+                TensorType::new_bool("tensor2", 2),
+                vec![0, 0],
+                vec![4, 1],
+            )
+            .with_steps(vec![2, 1]),
+        );
+        graph.register_input_output(vec!["tensor1".to_string()], vec!["tensor2".to_string()]);
+
+        let expected = quote! {
+            use burn::tensor::Bool;
+            use burn::tensor::Int;
+            use burn::{
+                module::Module,
+                tensor::{backend::Backend, Tensor},
+            };
+
+            #[derive(Module, Debug)]
+            pub struct Model<B: Backend> {
+                phantom: core::marker::PhantomData<B>,
+                device: burn::module::Ignored<B::Device>,
+            }
+
+            impl<B: Backend> Model <B> {
+                #[allow(unused_variables)]
+                pub fn new(device: &B::Device) -> Self {
+                    Self {
+                        phantom: core::marker::PhantomData,
+                        device: burn::module::Ignored(device.clone()),
+                    }
+                }
+                #[allow(clippy::let_and_return, clippy::approx_constant)]
+                pub fn forward(&self, tensor1: Tensor<B, 2, Bool>) -> Tensor<B, 2, Bool> {
+                    let tensor2 = {
+                        let dims = tensor1.dims();
+                        tensor1
+                            .slice([0usize..dims[0usize].min(4usize), 0usize..1usize])
+                            .int()
+                            .select(
+                                0usize,
+                                Tensor::<B, 1, Int>::arange_step(
+                                    0..dims[0usize].min(4usize).saturating_sub(0usize) as i64,
+                                    2usize,
+                                    &*self.device
+                                )
+                            )
+                            .bool()
+                    };
+
+                    tensor2
+                }
+            }
+        };
+
+        assert_tokens(graph.codegen(), expected);
+    }
+
+    fn assert_slice_forward(node: SliceNode, forward: TokenStream) {
+        let mut graph = BurnGraph::<FullPrecisionSettings>::default();
+        graph.register(node);
+        graph.register_input_output(vec!["tensor1".to_string()], vec!["tensor2".to_string()]);
+
+        let expected = quote! {
+            use burn::tensor::Int;
+            use burn::{
+                module::Module,
+                tensor::{backend::Backend, Tensor},
+            };
+
+            #[derive(Module, Debug)]
+            pub struct Model<B: Backend> {
+                phantom: core::marker::PhantomData<B>,
+                device: burn::module::Ignored<B::Device>,
+            }
+
+            impl<B: Backend> Model <B> {
+                #[allow(unused_variables)]
+                pub fn new(device: &B::Device) -> Self {
+                    Self {
+                        phantom: core::marker::PhantomData,
+                        device: burn::module::Ignored(device.clone()),
+                    }
+                }
+                #[allow(clippy::let_and_return, clippy::approx_constant)]
+                pub fn forward(&self, tensor1: Tensor<B, 1>) -> Tensor<B, 1> {
+                    #forward
+
+                    tensor2
+                }
+            }
+        };
+
+        assert_tokens(graph.codegen(), expected);
+    }
+
+    #[test]
+    fn test_codegen_slice_end_sentinel() {
+        // `x[::2]`, exported with an end of INT64_MAX, which is sliced until the end of the axis
+        // without writing the sentinel.
+        assert_slice_forward(
+            SliceNode::new(
+                TensorType::new_float("tensor1", 1),
+                TensorType::new_float("tensor2", 1),
+                vec![0],
+                vec![i64::MAX],
+            )
+            .with_steps(vec![2]),
+            quote! {
+                let tensor2 = {
+                    let dims = tensor1.dims();
+                    tensor1
+                        .slice([0usize..dims[0usize]])
+                        .select(
+                            0usize,
+                            Tensor::<B, 1, Int>::arange_step(
+                                0..dims[0usize].saturating_sub(0usize) as i64,
+                                2usize,
+                                &*self.device
+                            )
+                        )
+                };
+            },
+        );
+    }
+
+    #[test]
+    fn test_codegen_slice_reverse_sentinel() {
+        // `x[::-2]`, exported with a start of -1 and an end of INT64_MIN: the covered range
+        // resolves to `0..dims[0]`, so the first element is kept.
+        assert_slice_forward(
+            SliceNode::new(
+                TensorType::new_float("tensor1", 1),
+                TensorType::new_float("tensor2", 1),
+                vec![-1],
+                vec![i64::MIN],
+            )
+            .with_steps(vec![-2]),
+            quote! {
+                let tensor2 = {
+                    let dims = tensor1.dims();
+                    tensor1
+                        .slice([0usize..dims[0usize].saturating_sub(0usize).max(1)])
+                        .flip([0isize])
+                        .select(
+                            0usize,
+                            Tensor::<B, 1, Int>::arange_step(
+                                0..dims[0usize].saturating_sub(0usize).max(1).saturating_sub(0usize) as i64,
+                                2usize,
+                                &*self.device
+                            )
+                        )
+                };
+            },
+        );
+    }
+
+    #[test]
+    fn test_codegen_slice_empty_range_steps() {
+        // `x[5:-1:2]`, whose bounds of different signs resolve to an empty range on an axis
+        // shorter than 6.
+        assert_slice_forward(
+            SliceNode::new(
+                TensorType::new_float("tensor1", 1),
+                TensorType::new_float("tensor2", 1),
+                vec![5],
+                vec![-1],
+            )
+            .with_steps(vec![2]),
+            quote! {
+                let tensor2 = {
+                    let dims = tensor1.dims();
+                    tensor1
+                        .slice([5usize..dims[0usize].saturating_sub(1usize)])
+                        .select(
+                            0usize,
+                            Tensor::<B, 1, Int>::arange_step(
+                                0..dims[0usize].saturating_sub(1usize).saturating_sub(5usize) as i64,
+                                2usize,
+                                &*self.device
+                            )
+                        )
+                };
+            },
+        );
+    }
+
+    #[test]
+    #[should_panic(
+        expected = "Slice: empty slices are not supported, start (5) is greater than end (2) on axis 0"
+    )]
+    fn test_codegen_slice_start_greater_than_end() {
+        let mut graph = BurnGraph::<FullPrecisionSettings>::default();
+        graph.register(SliceNode::new(
+            TensorType::new_float("tensor1", 1),
+            TensorType::new_float("tensor2", 1),
+            vec![5],
+            vec![2],
+        ));
+        graph.register_input_output(vec!["tensor1".to_string()], vec!["tensor2".to_string()]);
+
+        let _ = graph.codegen();
+    }
+
+    #[test]
+    fn test_slice_empty_reverse_range_error() {
+        let slice = SliceNode::new(
+            TensorType::new_float("tensor1", 1),
+            TensorType::new_float("tensor2", 1),
+            vec![2],
+            vec![5],
+        )
+        .with_steps(vec![-1]);
+
+        let error = slice.check_static_bounds().unwrap_err();
+
+        assert_eq!(
+            error.to_string(),
+            "Slice: empty slices are not supported, start (2) is lower than end (5) on axis 0 of tensor1 with a negative step"
+        );
+    }
 }
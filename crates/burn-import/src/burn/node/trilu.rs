@@ -0,0 +1,229 @@
+use super::{Node, NodeCodegen};
+use crate::burn::{ScalarType, Scope, TensorKind, TensorType, ToTokens, Type};
+use burn::record::PrecisionSettings;
+use proc_macro2::TokenStream;
+use quote::quote;
+
+/// Diagonal offset of a [Trilu node](TriluNode).
+#[derive(Debug, Clone)]
+pub enum TriluDiagonal {
+    /// Offset known when the model is imported.
+    Static(i64),
+    /// Offset provided as a scalar input of the forward pass.
+    Runtime(ScalarType),
+}
+
+#[derive(Debug, Clone, new)]
+pub struct TriluNode {
+    pub input: TensorType,
+    pub output: TensorType,
+    pub upper: bool,
+    pub diagonal: TriluDiagonal,
+}
+
+impl<PS: PrecisionSettings> NodeCodegen<PS> for TriluNode {
+    fn output_types(&self) -> Vec<Type> {
+        vec![Type::Tensor(self.output.clone())]
+    }
+
+    fn input_types(&self) -> Vec<Type> {
+        let mut inputs = vec![Type::Tensor(self.input.clone())];
+
+        if let TriluDiagonal::Runtime(diagonal) = &self.diagonal {
+            inputs.push(Type::Scalar(diagonal.clone()));
+        }
+
+        inputs
+    }
+
+    fn forward(&self, scope: &mut Scope, node_position: usize) -> TokenStream {
+        let input = scope.tensor_use_owned(&self.input, node_position);
+        let output = &self.output.name;
+
+        let diagonal = match &self.diagonal {
+            TriluDiagonal::Static(diagonal) => diagonal.to_tokens(),
+            TriluDiagonal::Runtime(diagonal) => {
+                let name = &diagonal.name;
+                quote! { #name }
+            }
+        };
+
+        let triangle = if self.upper {
+            quote! { triu(#diagonal) }
+        } else {
+            quote! { tril(#diagonal) }
+        };
+
+        // Bool tensors have no triangular ops, they are applied on the values cast to int.
+        match self.input.kind {
+            TensorKind::Bool => quote! {
+                let #output = #input.int().#triangle.bool();
+            },
+            _ => quote! {
+                let #output = #input.#triangle;
+            },
+        }
+    }
+
+    fn into_node(self) -> Node<PS> {
+        Node::Trilu(self)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use burn::record::FullPrecisionSettings;
+
+    use super::*;
+    use crate::burn::{
+        graph::BurnGraph,
+        node::{test::assert_tokens, trilu::TriluNode},
+        ScalarKind, TensorType,
+    };
+
+    fn expected_model(body: TokenStream) -> TokenStream {
+        quote! {
+            use burn::{
+                module::Module,
+                tensor::{backend::Backend, Tensor},
+            };
+
+            #[derive(Module, Debug)]
+            pub struct Model<B: Backend> {
+                phantom: core::marker::PhantomData<B>,
+                device: burn::module::Ignored<B::Device>,
+            }
+
+            impl<B: Backend> Model <B> {
+                #[allow(unused_variables)]
+                pub fn new(device: &B::Device) -> Self {
+                    Self {
+                        phantom: core::marker::PhantomData,
+                        device: burn::module::Ignored(device.clone()),
+                    }
+                }
+                #body
+            }
+        }
+    }
+
+    #[test]
+    fn test_codegen_triu() {
+        let mut graph = BurnGraph::<FullPrecisionSettings>::default();
+
+        graph.register(TriluNode::new(
+            TensorType::new_float("tensor1", 2),
+            TensorType::new_float("tensor2", 2),
+            true,
+            TriluDiagonal::Static(1),
+        ));
+
+        graph.register_input_output(vec!["tensor1".to_string()], vec!["tensor2".to_string()]);
+
+        let expected = expected_model(quote! {
+            #[allow(clippy::let_and_return, clippy::approx_constant)]
+            pub fn forward(&self, tensor1: Tensor<B, 2>) -> Tensor<B, 2> {
+                let tensor2 = tensor1.triu(1);
+                tensor2
+            }
+        });
+
+        assert_tokens(graph.codegen(), expected);
+    }
+
+    #[test]
+    fn test_codegen_tril() {
+        let mut graph = BurnGraph::<FullPrecisionSettings>::default();
+
+        graph.register(TriluNode::new(
+            TensorType::new_float("tensor1", 3),
+            TensorType::new_float("tensor2", 3),
+            false,
+            TriluDiagonal::Static(-1),
+        ));
+
+        graph.register_input_output(vec!["tensor1".to_string()], vec!["tensor2".to_string()]);
+
+        let expected = expected_model(quote! {
+            #[allow(clippy::let_and_return, clippy::approx_constant)]
+            pub fn forward(&self, tensor1: Tensor<B, 3>) -> Tensor<B, 3> {
+                let tensor2 = tensor1.tril(-1);
+                tensor2
+            }
+        });
+
+        assert_tokens(graph.codegen(), expected);
+    }
+
+    #[test]
+    fn test_codegen_triu_runtime_diagonal() {
+        let mut graph = BurnGraph::<FullPrecisionSettings>::default();
+
+        graph.register(TriluNode::new(
+            TensorType::new_float("tensor1", 2),
+            TensorType::new_float("tensor2", 2),
+            true,
+            TriluDiagonal::Runtime(ScalarType::new("k", ScalarKind::Int64)),
+        ));
+
+        graph.register_input_output(
+            vec!["tensor1".to_string(), "k".to_string()],
+            vec!["tensor2".to_string()],
+        );
+
+        let expected = expected_model(quote! {
+            #[allow(clippy::let_and_return, clippy::approx_constant)]
+            pub fn forward(&self, tensor1: Tensor<B, 2>, k: i64) -> Tensor<B, 2> {
+                let tensor2 = tensor1.triu(k);
+                tensor2
+            }
+        });
+
+        assert_tokens(graph.codegen(), expected);
+    }
+
+    #[test]
+    fn test_codegen_triu_bool() {
+        let mut graph = BurnGraph::<FullPrecisionSettings>::default();
+
+        graph.register(TriluNode::new(
+            TensorType::new_bool("tensor1", 2),
+            TensorType::new_bool("tensor2", 2),
+            true,
+            TriluDiagonal::Static(0),
+        ));
+
+        graph.register_input_output(vec!["tensor1".to_string()], vec!["tensor2".to_string()]);
+
+        let expected = quote! {
+            use burn::tensor::Bool;
+            use burn::{
+                module::Module,
+                tensor::{backend::Backend, Tensor},
+            };
+
+            #[derive(Module, Debug)]
+            pub struct Model<B: Backend> {
+                phantom: core::marker::PhantomData<B>,
+                device: burn::module::Ignored<B::Device>,
+            }
+
+            impl<B: Backend> Model <B> {
+                #[allow(unused_variables)]
+                pub fn new(device: &B::Device) -> Self {
+                    Self {
+                        phantom: core::marker::PhantomData,
+                        device: burn::module::Ignored(device.clone()),
+                    }
+                }
+                #[allow(clippy::let_and_return, clippy::approx_constant)]
+                pub fn forward(&self, tensor1: Tensor<B, 2, Bool>) -> Tensor<B, 2, Bool> {
+                    let tensor2 = tensor1.int().triu(0).bool();
+                    tensor2
+                }
+            }
+        };
+
+        assert_tokens(graph.codegen(), expected);
+    }
+}
@@ -27,9 +27,12 @@ pub enum UnaryNodeKind {
     Exp,
     Flatten,
     Gelu,
+    HardSigmoid,
+    HardSwish,
     LeakyRelu,
     Log,
     LogSoftmax,
+    Mish,
     Neg,
     Not,
     ReduceMax,
@@ -42,6 +45,7 @@ pub enum UnaryNodeKind {
     Sigmoid,
     Sin,
     Softmax,
+    Softplus,
     Sqrt,
     Tanh,
     Transpose,
@@ -57,9 +61,12 @@ impl UnaryNodeKind {
             Self::Exp => "exp",
             Self::Flatten => "flatten",
             Self::Gelu => "gelu",
+            Self::HardSigmoid => "hard_sigmoid",
+            Self::HardSwish => "hard_swish",
             Self::LeakyRelu => "leaky_relu",
             Self::Log => "log",
             Self::LogSoftmax => "log_softmax",
+            Self::Mish => "mish",
             Self::Neg => "neg",
             Self::Not => "not",
             Self::ReduceMax => "reduce_max",
@@ -72,6 +79,7 @@ impl UnaryNodeKind {
             Self::Sigmoid => "sigmoid",
             Self::Sin => "sin",
             Self::Softmax => "softmax",
+            Self::Softplus => "softplus",
             Self::Sqrt => "sqrt",
             Self::Tanh => "tanh",
             Self::Transpose => "transpose",
@@ -177,6 +185,20 @@ impl UnaryNode {
         Self::new(input, output, UnaryNodeKind::Relu, Rc::new(function))
     }
 
+    pub(crate) fn hard_sigmoid(input: Type, output: Type, alpha: f64, beta: f64) -> Self {
+        let alpha = alpha.to_tokens();
+        let beta = beta.to_tokens();
+        let function = move |input| {
+            quote! { burn::tensor::activation::hard_sigmoid(#input, #alpha, #beta) }
+        };
+        Self::new(input, output, UnaryNodeKind::HardSigmoid, Rc::new(function))
+    }
+
+    pub(crate) fn hard_swish(input: Type, output: Type) -> Self {
+        let function = move |input| quote! { burn::tensor::activation::hard_swish(#input) };
+        Self::new(input, output, UnaryNodeKind::HardSwish, Rc::new(function))
+    }
+
     pub(crate) fn sigmoid(input: Type, output: Type) -> Self {
         let function = move |input| quote! { burn::tensor::activation::sigmoid(#input) };
         Self::new(input, output, UnaryNodeKind::Sigmoid, Rc::new(function))
@@ -194,6 +216,16 @@ impl UnaryNode {
         Self::new(input, output, UnaryNodeKind::Softmax, Rc::new(function))
     }
 
+    pub(crate) fn softplus(input: Type, output: Type) -> Self {
+        let function = move |input| quote! { burn::tensor::activation::softplus(#input, 1.0) };
+        Self::new(input, output, UnaryNodeKind::Softplus, Rc::new(function))
+    }
+
+    pub(crate) fn mish(input: Type, output: Type) -> Self {
+        let function = move |input| quote! { burn::tensor::activation::mish(#input) };
+        Self::new(input, output, UnaryNodeKind::Mish, Rc::new(function))
+    }
+
     pub(crate) fn sqrt(input: Type, output: Type) -> Self {
         let function = move |input| quote! { #input.sqrt()};
         Self::new(input, output, UnaryNodeKind::Sqrt, Rc::new(function))
@@ -235,6 +267,11 @@ impl UnaryNode {
         Self::new(input, output, UnaryNodeKind::Gelu, Rc::new(function))
     }
 
+    pub(crate) fn gelu_tanh(input: Type, output: Type) -> Self {
+        let function = move |input| quote! { burn::tensor::activation::gelu_tanh(#input) };
+        Self::new(input, output, UnaryNodeKind::Gelu, Rc::new(function))
+    }
+
     pub(crate) fn log(input: Type, output: Type) -> Self {
         let function = move |input| quote! { #input.log()};
         Self::new(input, output, UnaryNodeKind::Log, Rc::new(function))
@@ -524,6 +561,84 @@ mod tests {
         );
     }
 
+    #[test]
+    fn test_unary_codegen_hard_sigmoid() {
+        one_node_graph(
+            UnaryNode::hard_sigmoid(
+                Type::Tensor(TensorType::new_float("tensor1", 4)),
+                Type::Tensor(TensorType::new_float("tensor2", 4)),
+                0.25,
+                0.75,
+            ),
+            quote! {
+                pub fn forward(&self, tensor1: Tensor<B, 4>) -> Tensor<B, 4> {
+                    let tensor2 = burn::tensor::activation::hard_sigmoid(tensor1, 0.25, 0.75);
+
+                    tensor2
+                }
+            },
+            vec!["tensor1".to_string()],
+            vec!["tensor2".to_string()],
+        );
+    }
+
+    #[test]
+    fn test_unary_codegen_hard_swish() {
+        one_node_graph(
+            UnaryNode::hard_swish(
+                Type::Tensor(TensorType::new_float("tensor1", 4)),
+                Type::Tensor(TensorType::new_float("tensor2", 4)),
+            ),
+            quote! {
+                pub fn forward(&self, tensor1: Tensor<B, 4>) -> Tensor<B, 4> {
+                    let tensor2 = burn::tensor::activation::hard_swish(tensor1);
+
+                    tensor2
+                }
+            },
+            vec!["tensor1".to_string()],
+            vec!["tensor2".to_string()],
+        );
+    }
+
+    #[test]
+    fn test_unary_codegen_softplus() {
+        one_node_graph(
+            UnaryNode::softplus(
+                Type::Tensor(TensorType::new_float("tensor1", 4)),
+                Type::Tensor(TensorType::new_float("tensor2", 4)),
+            ),
+            quote! {
+                pub fn forward(&self, tensor1: Tensor<B, 4>) -> Tensor<B, 4> {
+                    let tensor2 = burn::tensor::activation::softplus(tensor1, 1.0);
+
+                    tensor2
+                }
+            },
+            vec!["tensor1".to_string()],
+            vec!["tensor2".to_string()],
+        );
+    }
+
+    #[test]
+    fn test_unary_codegen_mish() {
+        one_node_graph(
+            UnaryNode::mish(
+                Type::Tensor(TensorType::new_float("tensor1", 4)),
+                Type::Tensor(TensorType::new_float("tensor2", 4)),
+            ),
+            quote! {
+                pub fn forward(&self, tensor1: Tensor<B, 4>) -> Tensor<B, 4> {
+                    let tensor2 = burn::tensor::activation::mish(tensor1);
+
+                    tensor2
+                }
+            },
+            vec!["tensor1".to_string()],
+            vec!["tensor2".to_string()],
+        );
+    }
+
     #[test]
     fn test_unary_codegen_sigmoid() {
         one_node_graph(
@@ -944,6 +1059,25 @@ mod tests {
         );
     }
 
+    #[test]
+    fn test_unary_codegen_gelu_tanh() {
+        one_node_graph(
+            UnaryNode::gelu_tanh(
+                Type::Tensor(TensorType::new_float("tensor1", 4)),
+                Type::Tensor(TensorType::new_float("tensor2", 4)),
+            ),
+            quote! {
+                pub fn forward(&self, tensor1: Tensor<B, 4>) -> Tensor<B, 4> {
+                    let tensor2 = burn::tensor::activation::gelu_tanh(tensor1);
+
+                    tensor2
+                }
+            },
+            vec!["tensor1".to_string()],
+            vec!["tensor2".to_string()],
+        );
+    }
+
     #[test]
     fn test_unary_codegen_log() {
         one_node_graph(
@@ -144,7 +144,7 @@ pub(crate) fn convert_matmul_to_linear(
     log::debug!("peeking next node for bias conversion");
     // Check the next node for potential conversion
     if let Some(peek_node) = iter_mut.peek() {
-        let peek_node = convert_node_proto(peek_node, graph_data);
+        let peek_node = convert_node_proto(peek_node, graph_data, node.opset);
         if is_add_node_with_bias(&peek_node, node) {
             convert_and_remove_add_node(&peek_node, node);
 
@@ -3,9 +3,11 @@ use core::panic;
 
 use protobuf::Enum;
 
+use crate::burn::node::einsum::parse_equation;
+
 use super::{
     ir::{ArgType, AttributeValue, Data, ElementType, Node, NodeType, TensorType},
-    op_configuration::flatten_config,
+    op_configuration::{einsum_config, flatten_config},
     protos::tensor_proto::DataType,
 };
 
@@ -13,6 +15,7 @@ use super::{
 pub fn dim_inference(node: &mut Node) {
     match node.node_type {
         NodeType::Add => same_as_input(node),
+        NodeType::And => same_as_input(node),
         NodeType::ArgMax => argmax_update_outputs(node),
         NodeType::AveragePool1d => same_as_input(node),
         NodeType::AveragePool2d => same_as_input(node),
@@ -24,8 +27,10 @@ pub fn dim_inference(node: &mut Node) {
         NodeType::Conv1d => conv1d_update_outputs(node),
         NodeType::Conv2d => conv2d_update_outputs(node),
         NodeType::Cos => same_as_input(node),
+        NodeType::CumSum => same_as_input(node),
         NodeType::Div => same_as_input(node),
         NodeType::Dropout => same_as_input(node),
+        NodeType::Einsum => einsum_update_outputs(node),
         NodeType::Equal => equal_update_outputs(node),
         NodeType::Erf => same_as_input(node),
         NodeType::Exp => same_as_input(node),
@@ -45,9 +50,13 @@ pub fn dim_inference(node: &mut Node) {
         NodeType::Max => same_as_input(node),
         NodeType::MaxPool1d => same_as_input(node),
         NodeType::MaxPool2d => same_as_input(node),
+        NodeType::MaxUnpool => same_as_input(node),
+        NodeType::Mod => same_as_input(node),
         NodeType::Mul => same_as_input(node),
         NodeType::Neg => same_as_input(node),
         NodeType::Not => same_as_input(node),
+        NodeType::NonZero => nonzero_update_outputs(node),
+        NodeType::Or => same_as_input(node),
         NodeType::Greater => greater_update_outputs(node),
         NodeType::GreaterOrEqual => greater_or_equal_update_outputs(node),
         NodeType::Less => less_update_outputs(node),
@@ -75,11 +84,23 @@ pub fn dim_inference(node: &mut Node) {
         NodeType::Unsqueeze => unsqueeze_update_output(node),
         NodeType::Pow => same_as_input(node),
         NodeType::LeakyRelu => same_as_input(node),
+        NodeType::HardSigmoid => same_as_input(node),
+        NodeType::HardSwish => same_as_input(node),
+        NodeType::Mish => same_as_input(node),
+        NodeType::Softplus => same_as_input(node),
         NodeType::PRelu => same_as_input(node),
+        NodeType::Pad => same_rank_as_input(node),
         NodeType::Where => where_update_outputs(node),
+        NodeType::Xor => same_as_input(node),
         NodeType::Squeeze => squeeze_update_output(node),
+        NodeType::Trilu => same_as_input(node),
+        NodeType::ScatterElements => same_as_input(node),
+        NodeType::DepthToSpace => same_rank_as_input(node),
+        NodeType::SpaceToDepth => same_rank_as_input(node),
         NodeType::RandomUniform => random_update_output(node),
         NodeType::RandomNormal => random_update_output(node),
+        NodeType::StringNormalizer => same_as_input(node),
+        NodeType::TfIdfVectorizer => tfidf_vectorizer_update_outputs(node),
         // Intentionally letting outputs leave unchanged but issue a warning so IR file can be generated.
         _ => temporary_pass_through_stub(node),
     }
@@ -413,6 +434,37 @@ fn argmax_update_outputs(node: &mut Node) {
     });
 }
 
+/// The output holds the float n-gram counts of each row of strings, with the rank of the input.
+///
+/// The node itself isn't supported, but typing its output lets the nodes using it be converted
+/// while the unsupported nodes are collected.
+fn tfidf_vectorizer_update_outputs(node: &mut Node) {
+    let dim = match &node.inputs[0].ty {
+        ArgType::Tensor(tensor) => tensor.dim,
+        _ => panic!("TfIdfVectorizer: only tensor input is valid"),
+    };
+
+    node.outputs[0].ty = ArgType::Tensor(TensorType {
+        elem_type: ElementType::Float32,
+        dim,
+        shape: None,
+    });
+}
+
+/// The output holds the indices of the non-zero elements, one row per input dimension.
+fn nonzero_update_outputs(node: &mut Node) {
+    if !matches!(node.inputs[0].ty, ArgType::Tensor(_)) {
+        panic!("NonZero: only tensor input is valid");
+    }
+
+    // The number of non-zero elements is only known at runtime.
+    node.outputs[0].ty = ArgType::Tensor(TensorType {
+        dim: 2,
+        shape: None,
+        elem_type: ElementType::Int64,
+    });
+}
+
 /// Update the output tensor dimension
 fn squeeze_update_output(node: &mut Node) {
     let axes = if node.inputs.len() == 2 {
@@ -454,30 +506,23 @@ fn squeeze_update_output(node: &mut Node) {
 }
 
 fn slice_update_outputs(node: &mut Node) {
-    let shape = match &node.inputs[1].value {
-        Some(value) => match value {
-            Data::Int64s(shape) => Some(shape.clone()),
-            _ => panic!("Slice: invalid input types"),
-        },
-        None => None,
+    let input = match &node.inputs[0].ty {
+        ArgType::Tensor(tensor) => tensor.clone(),
+        _ => panic!("Slice: invalid input types"),
     };
 
-    if shape.is_none() {
-        panic!("Slice: invalid shape");
-    }
-
-    let output = match &node.outputs[0].ty {
-        ArgType::Tensor(tensor) => tensor.clone(),
-        _ => panic!("Slice: invalid output types"),
+    // Slicing keeps the rank, whether the bounds are inputs or attributes (before opset 10),
+    // unless a fused squeeze collapses the sliced axis.
+    let dim = match node.attrs.contains_key("collapse_axis") {
+        true => input.dim - 1,
+        false => input.dim,
     };
 
-    if let Some(shape) = shape {
-        node.outputs[0].ty = ArgType::Tensor(TensorType {
-            dim: shape.len(),
-            shape: None, // shape is calculated at runtime
-            ..output
-        });
-    }
+    node.outputs[0].ty = ArgType::Tensor(TensorType {
+        dim,
+        shape: None, // shape is calculated at runtime
+        elem_type: input.elem_type,
+    });
 }
 
 /// Update the output tensor dimension based on the "axes" attribute or the second input
@@ -519,6 +564,35 @@ fn unsqueeze_update_output(node: &mut Node) {
     }
 }
 
+/// Infers the output rank of an Einsum node from its equation.
+fn einsum_update_outputs(node: &mut Node) {
+    let ranks = node
+        .inputs
+        .iter()
+        .map(|input| match &input.ty {
+            ArgType::Tensor(tensor) => tensor.dim,
+            _ => panic!("Einsum: only tensor inputs are supported"),
+        })
+        .collect::<Vec<_>>();
+
+    let elem_type = match &node.inputs[0].ty {
+        ArgType::Tensor(tensor) => tensor.elem_type.clone(),
+        _ => unreachable!(),
+    };
+
+    let equation = einsum_config(node);
+    let dim = match parse_equation(&equation, &ranks) {
+        Ok((_, output)) => output.len(),
+        Err(reason) => panic!("Einsum: invalid equation '{equation}': {reason}"),
+    };
+
+    node.outputs[0].ty = ArgType::Tensor(TensorType {
+        elem_type,
+        dim,
+        shape: None,
+    });
+}
+
 fn same_as_input(node: &mut Node) {
     node.outputs[0].ty = node.inputs[0].ty.clone();
 }
@@ -620,6 +694,19 @@ fn flatten_update_outputs(node: &mut Node) {
     });
 }
 
+/// The output has the rank and the element type of the input, but a different shape.
+fn same_rank_as_input(node: &mut Node) {
+    let input = match &node.inputs[0].ty {
+        ArgType::Tensor(tensor) => tensor.clone(),
+        _ => panic!("{:?}: invalid input type", node.node_type),
+    };
+
+    node.outputs[0].ty = ArgType::Tensor(TensorType {
+        shape: None, // shape is calculated at runtime
+        ..input
+    });
+}
+
 /// Infers the shape of a Conv1d node and replaces the shape of the output tensor.
 fn conv1d_update_outputs(node: &mut Node) {
     // extract the channels from the weight tensor's shape [out_channels, in_channels, ...]
@@ -807,21 +894,26 @@ fn gather_update_outputs(node: &mut Node) {
         _ => panic!("Only tensor input is valid"),
     };
 
-    let indices_tensor = match &node.inputs[1].ty {
-        ArgType::Tensor(tensor) => tensor,
+    let indices_rank = match &node.inputs[1].ty {
+        ArgType::Tensor(tensor) => tensor.dim,
+        ArgType::Scalar(_) => 0,
         _ => panic!("Only tensor indices is valid"),
     };
 
-    if indices_tensor.dim != 1 {
+    if indices_rank > 1 {
         panic!("Gather: indices tensor rank above 1 not supported")
     }
 
     // Output of rank q+(r-1), where q is rank of indices tensor and r is rank of input
-    let output_rank = indices_tensor.dim + input_tensor.dim - 1;
-
-    node.outputs[0].ty = ArgType::Tensor(TensorType {
-        dim: output_rank,
-        shape: None,
-        elem_type: input_tensor.elem_type.clone(),
-    });
+    let output_rank = indices_rank + input_tensor.dim - 1;
+
+    // A scalar index on a rank 1 input gathers a single element
+    node.outputs[0].ty = match output_rank {
+        0 => ArgType::Scalar(input_tensor.elem_type.clone()),
+        _ => ArgType::Tensor(TensorType {
+            dim: output_rank,
+            shape: None,
+            elem_type: input_tensor.elem_type.clone(),
+        }),
+    };
 }
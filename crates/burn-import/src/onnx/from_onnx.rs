@@ -9,28 +9,32 @@ use crate::onnx::node_remap::remap_node_type;
 use super::{
     coalesce::coalesce,
     ir::{Data, OnnxGraph, TensorType},
-    proto_conversion::convert_node_proto,
+    proto_conversion::{convert_node_proto, convert_opset_version},
     protos::{ModelProto, NodeProto, TensorProto, ValueInfoProto},
 };
 
 use super::dim_inference::dim_inference;
-use super::ir::{ArgType, Argument, Node, NodeType};
+use super::ir::{ArgType, Argument, AttributeValue, Node, NodeType};
+use super::op_configuration::{concat_config, shape_config, slice_config, squeeze_config};
 
 use protobuf::Message;
 
-const LIFT_CONSTANTS_FOR_NODE_TYPES: [NodeType; 12] = [
+const LIFT_CONSTANTS_FOR_NODE_TYPES: [NodeType; 15] = [
     NodeType::BatchNormalization,
     NodeType::Clip,
     NodeType::Conv1d,
     NodeType::Conv2d,
+    NodeType::CumSum,
     NodeType::Dropout,
     NodeType::Expand,
+    NodeType::Pad,
     NodeType::Reshape,
     NodeType::Resize,
     NodeType::Unsqueeze,
     NodeType::ReduceSum,
     NodeType::Slice,
     NodeType::Squeeze,
+    NodeType::Trilu,
 ];
 
 #[derive(Debug, Clone)]
@@ -185,6 +189,15 @@ impl GraphData {
     }
 }
 
+/// A shape computation evaluated while building the graph.
+#[derive(Debug, Clone)]
+struct FoldedShape {
+    /// The computed shape values
+    value: Vec<i64>,
+    /// The indices of the nodes computing the value, removed unless the value is used at runtime
+    nodes: Vec<usize>,
+}
+
 #[derive(Default)]
 pub(crate) struct OnnxGraphBuilder {
     /// Nodes to remove. Note may be moved to graph data if we implement support for custom ops
@@ -195,10 +208,30 @@ pub(crate) struct OnnxGraphBuilder {
     constants_types: HashSet<NodeType>,
     /// Map from identity node output names to indices of identity nodes
     identity_idx: HashMap<String, usize>,
+    /// Map from the output names of pad nodes fused into a convolution to their indices
+    fused_pad_idx: HashMap<String, usize>,
+    /// Map from the output names of slice nodes fused into a squeeze to their indices
+    fused_slice_idx: HashMap<String, usize>,
+    /// Map from the output names of static shape computations to their folded values
+    folded_shapes: HashMap<String, FoldedShape>,
+    /// Map from the output names of constant nodes folded into a shape to their indices
+    folded_constant_idx: HashMap<String, usize>,
+    /// Map from the output names of nodes fused into a layer norm to the indices of every node
+    /// fused with them
+    fused_layer_norm_idx: HashMap<String, Vec<usize>>,
     node_name_counter: HashMap<NodeType, usize>,
+    /// Whether a slice taking a single index of an axis is fused into the squeeze removing it
+    collapse_slices: bool,
 }
 
 impl OnnxGraphBuilder {
+    /// Sets whether a `Slice` taking a single index of an axis is fused into the `Squeeze`
+    /// removing that axis. Disabled by default.
+    pub(crate) fn with_collapse_slices(mut self, collapse_slices: bool) -> Self {
+        self.collapse_slices = collapse_slices;
+        self
+    }
+
     pub(crate) fn build(mut self, model_proto: &ModelProto) -> OnnxGraph {
         self.constants_types = LIFT_CONSTANTS_FOR_NODE_TYPES.into_iter().collect();
 
@@ -208,16 +241,21 @@ impl OnnxGraphBuilder {
             &model_proto.graph.initializer,
         );
 
+        let opset = convert_opset_version(model_proto);
         let mut node_iter = model_proto.graph.node.iter().peekable();
 
         while let Some(node_proto) = node_iter.next() {
-            let mut node = convert_node_proto(node_proto, &graph_data);
+            let mut node = convert_node_proto(node_proto, &graph_data, opset);
 
             remap_node_type(&mut node);
             self.handle_node_renaming(&mut node);
             coalesce(&mut node, &mut node_iter, &graph_data);
             self.handle_identity(&mut node, &graph_data);
             self.check_constants(&mut node, &graph_data);
+            self.handle_pad_conv(&mut node, &graph_data);
+            self.handle_slice_squeeze(&mut node, &graph_data);
+            self.handle_layer_norm(&mut node, &graph_data);
+            self.handle_shape_folding(&mut node, &graph_data);
             // NOTE: potential start of custom functions
             // can filter, coalesce, or modify the nodes here
             // args : node, peek_iter, graph_data
@@ -228,6 +266,14 @@ impl OnnxGraphBuilder {
         }
 
         let (mut processed_nodes, inputs, outputs) = graph_data.consume();
+        // Folded shapes and fused nodes returned by the graph are still computed at runtime
+        for output in outputs.iter() {
+            self.restore_folded_shape(&output.name);
+            self.restore_folded_constant(&output.name);
+            self.restore_layer_norm(&output.name);
+            self.restore_fused_pad(&output.name);
+            self.restore_fused_slice(&output.name);
+        }
         // Remove the graph inputs/output that are not used by any node
         let mut i = 0;
         processed_nodes.retain(|_| {
@@ -305,6 +351,388 @@ impl OnnxGraphBuilder {
         }
     }
 
+    /// Fuses a reflect or edge `Pad` feeding a `Conv2d` into the convolution's padding.
+    ///
+    /// The pad node is only removed while its output has no other consumer.
+    /// Needs to be called after constant lifting so that the pads value exists.
+    fn handle_pad_conv(&mut self, node: &mut Node, graph_data: &GraphData) {
+        // Keep fused pad nodes that turn out to be used by other nodes.
+        for input in node.inputs.iter() {
+            self.restore_fused_pad(&input.name);
+        }
+
+        if node.node_type != NodeType::Conv2d {
+            return;
+        }
+
+        let Some((pad_idx, pad)) =
+            graph_data
+                .processed_nodes
+                .iter()
+                .enumerate()
+                .find(|(_, pad)| {
+                    pad.node_type == NodeType::Pad && pad.outputs[0].name == node.inputs[0].name
+                })
+        else {
+            return;
+        };
+
+        // The pad output must stay available to the nodes already reading it.
+        if has_other_consumer(&pad.outputs[0].name, &[], graph_data) {
+            return;
+        }
+
+        let Some((pad_mode, pads)) = fusable_conv_padding(pad, node) else {
+            return;
+        };
+
+        log::debug!("fusing pad node {} into {}", &pad.name, &node.name);
+        node.inputs[0].clone_from(&pad.inputs[0]);
+        node.attrs
+            .insert("pads".to_string(), AttributeValue::Int64s(pads));
+        node.attrs
+            .insert("pad_mode".to_string(), AttributeValue::String(pad_mode));
+
+        self.fused_pad_idx
+            .insert(pad.outputs[0].name.clone(), pad_idx);
+        self.nodes_to_remove.insert(pad_idx);
+    }
+
+    /// Fuses a `Slice` taking a single index of an axis into the `Squeeze` removing that axis
+    /// (e.g. `x[:, k]`), which becomes a slice node collapsing the axis. Only enabled with
+    /// [with_collapse_slices](OnnxGraphBuilder::with_collapse_slices).
+    ///
+    /// The slice node is only removed while its output has no other consumer.
+    /// Needs to be called after constant lifting so that the slice bounds and the axes exist.
+    fn handle_slice_squeeze(&mut self, node: &mut Node, graph_data: &GraphData) {
+        // Keep fused slice nodes that turn out to be used by other nodes.
+        for input in node.inputs.iter() {
+            self.restore_fused_slice(&input.name);
+        }
+
+        if !self.collapse_slices || node.node_type != NodeType::Squeeze {
+            return;
+        }
+
+        let Some((slice_idx, slice)) = producer(&node.inputs[0], NodeType::Slice, graph_data)
+        else {
+            return;
+        };
+
+        // The slice output must stay available to the nodes already reading it.
+        if has_other_consumer(&slice.outputs[0].name, &[], graph_data) {
+            return;
+        }
+
+        let Some(axis) = collapsible_slice_axis(slice, node) else {
+            return;
+        };
+
+        log::debug!("fusing slice node {} into {}", &slice.name, &node.name);
+        node.node_type = NodeType::Slice;
+        node.inputs.clone_from(&slice.inputs);
+        node.attrs.clone_from(&slice.attrs);
+        node.attrs.insert(
+            "collapse_axis".to_string(),
+            AttributeValue::Int64(axis as i64),
+        );
+        self.handle_node_renaming(node);
+
+        self.fused_slice_idx
+            .insert(slice.outputs[0].name.clone(), slice_idx);
+        self.nodes_to_remove.insert(slice_idx);
+    }
+
+    fn restore_fused_pad(&mut self, name: &str) {
+        if let Some(pad_idx) = self.fused_pad_idx.remove(name) {
+            self.nodes_to_remove.remove(&pad_idx);
+        }
+    }
+
+    fn restore_fused_slice(&mut self, name: &str) {
+        if let Some(slice_idx) = self.fused_slice_idx.remove(name) {
+            self.nodes_to_remove.remove(&slice_idx);
+        }
+    }
+
+    /// Fuses the decomposed layer norm exported before `LayerNormalization` became an operator
+    /// (opset 17) into a single `LayerNormalization` node:
+    ///
+    /// `ReduceMean -> Sub -> Pow -> ReduceMean -> Add -> Sqrt -> Div -> Mul -> Add`
+    ///
+    /// The match starts from the final `Add` of the bias. The fused nodes are only removed while
+    /// their outputs have no other consumer. Needs to be called after constant lifting so that the
+    /// constant nodes providing the epsilon and the weights are known.
+    fn handle_layer_norm(&mut self, node: &mut Node, graph_data: &GraphData) {
+        // Keep fused nodes that turn out to be used by other nodes.
+        for input in node.inputs.iter() {
+            self.restore_layer_norm(&input.name);
+        }
+
+        if node.node_type != NodeType::Add {
+            return;
+        }
+
+        let Some(layer_norm) = self.match_layer_norm(node, graph_data) else {
+            return;
+        };
+
+        log::debug!(
+            "fusing the decomposed layer norm ending with {}",
+            &node.name
+        );
+        node.node_type = NodeType::LayerNormalization;
+        node.inputs = vec![layer_norm.input, layer_norm.gamma, layer_norm.beta];
+        node.attrs = [
+            (
+                "epsilon".to_string(),
+                AttributeValue::Float32(layer_norm.epsilon as f32),
+            ),
+            // The decomposed graph computes in the input precision.
+            ("stash_type".to_string(), AttributeValue::Int64(0)),
+        ]
+        .into();
+        self.handle_node_renaming(node);
+
+        let fused_nodes = [layer_norm.nodes.as_slice(), layer_norm.constants.as_slice()].concat();
+        for node_idx in layer_norm.nodes.iter() {
+            for output in graph_data.processed_nodes[*node_idx].outputs.iter() {
+                self.fused_layer_norm_idx
+                    .insert(output.name.clone(), fused_nodes.clone());
+            }
+        }
+        // A constant read by a later node is kept on its own.
+        for const_idx in layer_norm.constants.iter() {
+            let output = &graph_data.processed_nodes[*const_idx].outputs[0];
+            self.fused_layer_norm_idx
+                .insert(output.name.clone(), vec![*const_idx]);
+        }
+        self.nodes_to_remove.extend(fused_nodes);
+    }
+
+    /// Matches the decomposed layer norm ending with the given `Add` node.
+    ///
+    /// The match is rejected when a processed node outside of the pattern reads one of its
+    /// intermediate outputs, while the constant nodes read by other nodes are kept.
+    fn match_layer_norm(&self, add: &Node, graph_data: &GraphData) -> Option<LayerNormMatch> {
+        let mut constants = Vec::new();
+        let constant = |arg: &Argument, constants: &mut Vec<usize>| {
+            if arg.value.is_some() {
+                return Some(arg.clone());
+            }
+            let const_idx = *self.constants_map.get(&arg.name)?;
+            constants.push(const_idx);
+            Some(convert_constant_value(
+                &graph_data.processed_nodes[const_idx],
+            ))
+        };
+
+        let (mul_idx, mul, beta) = producer_with_constant(add, NodeType::Mul, graph_data)?;
+        let beta = constant(beta, &mut constants)?;
+        let (div_idx, div, gamma) = producer_with_constant(mul, NodeType::Div, graph_data)?;
+        let gamma = constant(gamma, &mut constants)?;
+
+        let (sub_idx, sub) = producer(&div.inputs[0], NodeType::Sub, graph_data)?;
+        let (sqrt_idx, sqrt) = producer(&div.inputs[1], NodeType::Sqrt, graph_data)?;
+        let (add_eps_idx, add_eps) = producer(&sqrt.inputs[0], NodeType::Add, graph_data)?;
+        let (var_idx, var, epsilon) =
+            producer_with_constant(add_eps, NodeType::ReduceMean, graph_data)?;
+        let epsilon = scalar_value(&constant(epsilon, &mut constants)?)?;
+        let (pow_idx, pow) = producer(&var.inputs[0], NodeType::Pow, graph_data)?;
+        let (mean_idx, mean) = producer(&sub.inputs[1], NodeType::ReduceMean, graph_data)?;
+
+        let input = &sub.inputs[0];
+        let ArgType::Tensor(input_type) = &input.ty else {
+            return None;
+        };
+
+        let exponent = scalar_value(&constant(&pow.inputs[1], &mut constants)?)?;
+        if pow.inputs[0].name != sub.outputs[0].name
+            || mean.inputs[0].name != input.name
+            || exponent != 2.0
+            || !reduces_last_axis(mean, input_type.dim)
+            || !reduces_last_axis(var, input_type.dim)
+            || !is_feature_vector(&gamma)
+            || !is_feature_vector(&beta)
+        {
+            return None;
+        }
+
+        let nodes = vec![
+            mean_idx,
+            sub_idx,
+            pow_idx,
+            var_idx,
+            add_eps_idx,
+            sqrt_idx,
+            div_idx,
+            mul_idx,
+        ];
+        let read_outside = |node_idx: &usize| {
+            graph_data.processed_nodes[*node_idx]
+                .outputs
+                .iter()
+                .any(|output| has_other_consumer(&output.name, &nodes, graph_data))
+        };
+        if nodes.iter().any(read_outside) {
+            return None;
+        }
+        constants.retain(|const_idx| !read_outside(const_idx));
+
+        Some(LayerNormMatch {
+            input: input.clone(),
+            gamma,
+            beta,
+            epsilon,
+            nodes,
+            constants,
+        })
+    }
+
+    fn restore_layer_norm(&mut self, name: &str) {
+        if let Some(nodes) = self.fused_layer_norm_idx.remove(name) {
+            log::debug!("keeping the layer norm nodes computing {}", name);
+            for node_idx in nodes.iter() {
+                self.nodes_to_remove.remove(node_idx);
+            }
+        }
+    }
+
+    /// Folds the `Shape`, `Slice` and `Concat` nodes computing a shape from static dimensions into
+    /// the constant shape of the `Reshape` nodes using it.
+    ///
+    /// The folded nodes are only removed while their outputs have no other consumer.
+    /// Needs to be called after constant lifting so that the slice bounds exist.
+    fn handle_shape_folding(&mut self, node: &mut Node, graph_data: &GraphData) {
+        let folded = match node.node_type {
+            NodeType::Shape => fold_shape(node),
+            NodeType::Slice => self.fold_slice(node),
+            NodeType::Concat => self.fold_concat(node, graph_data),
+            _ => None,
+        };
+
+        if let Some(mut folded) = folded {
+            log::debug!("folding node {} into {:?}", &node.name, &folded.value);
+            for input in node.inputs.iter() {
+                match self.constants_map.get(&input.name) {
+                    Some(const_idx) if folded.nodes.contains(const_idx) => {
+                        self.folded_constant_idx
+                            .insert(input.name.clone(), *const_idx);
+                    }
+                    _ => {}
+                }
+            }
+            folded.nodes.push(graph_data.get_current_index());
+            self.nodes_to_remove.extend(folded.nodes.iter().copied());
+            self.folded_shapes
+                .insert(format!("{}_out{}", &node.name, 1), folded);
+            return;
+        }
+
+        for (i, input) in node.inputs.iter_mut().enumerate() {
+            if input.value.is_none() {
+                self.restore_folded_constant(&input.name);
+            }
+
+            let Some(folded) = self.folded_shapes.get(&input.name) else {
+                continue;
+            };
+
+            if node.node_type == NodeType::Reshape && i == 1 {
+                input.ty = ArgType::Tensor(TensorType {
+                    elem_type: super::ir::ElementType::Int64,
+                    dim: 1,
+                    shape: Some(vec![folded.value.len()]),
+                });
+                input.value = Some(Data::Int64s(folded.value.clone()));
+            } else {
+                let name = input.name.clone();
+                self.restore_folded_shape(&name);
+            }
+        }
+    }
+
+    /// Evaluates a slice of a folded shape.
+    fn fold_slice(&self, node: &Node) -> Option<FoldedShape> {
+        let input = self.folded_shapes.get(&node.inputs[0].name)?;
+
+        let bound = |index: usize| match node.inputs.get(index).map(|input| &input.value) {
+            Some(Some(Data::Int64s(values))) if values.len() == 1 => Some(Some(values[0])),
+            None => Some(None),
+            _ => None,
+        };
+        let start = bound(1)??;
+        let end = bound(2)??;
+        // The only axis of a shape is 0, or -1 counted from the end.
+        if !matches!(bound(3)?, None | Some(0) | Some(-1)) {
+            return None;
+        }
+        let step = bound(4)?.unwrap_or(1);
+        if node.attrs.contains_key("axes") || node.attrs.contains_key("steps") || step == 0 {
+            return None;
+        }
+
+        Some(FoldedShape {
+            value: slice_values(&input.value, start, end, step),
+            nodes: input.nodes.clone(),
+        })
+    }
+
+    /// Evaluates the concatenation of folded shapes and constants.
+    fn fold_concat(&self, node: &Node, graph_data: &GraphData) -> Option<FoldedShape> {
+        // Only folds the concatenation of shapes, leaving the other ones untouched.
+        if !node
+            .inputs
+            .iter()
+            .any(|input| self.folded_shapes.contains_key(&input.name))
+            || concat_config(node) != 0
+        {
+            return None;
+        }
+
+        let mut value = Vec::new();
+        let mut nodes = Vec::new();
+
+        for input in node.inputs.iter() {
+            if let Some(folded) = self.folded_shapes.get(&input.name) {
+                value.extend_from_slice(&folded.value);
+                nodes.extend_from_slice(&folded.nodes);
+            } else if let Some(Data::Int64s(values)) = &input.value {
+                value.extend_from_slice(values);
+            } else {
+                let const_idx = self.constants_map.get(&input.name)?;
+                match convert_constant_value(&graph_data.processed_nodes[*const_idx]).value {
+                    Some(Data::Int64s(values)) => value.extend(values),
+                    _ => return None,
+                }
+                // The constant stays available to the nodes already reading it.
+                if !has_other_consumer(&input.name, &[], graph_data) {
+                    nodes.push(*const_idx);
+                }
+            }
+        }
+
+        Some(FoldedShape { value, nodes })
+    }
+
+    /// Keeps the nodes computing a folded shape, since its value is used at runtime.
+    fn restore_folded_shape(&mut self, name: &str) {
+        if let Some(folded) = self.folded_shapes.remove(name) {
+            log::debug!("keeping the nodes computing {}", name);
+            for node_idx in folded.nodes.iter() {
+                self.nodes_to_remove.remove(node_idx);
+            }
+        }
+    }
+
+    /// Keeps a constant node folded into a shape, since another node reads it.
+    fn restore_folded_constant(&mut self, name: &str) {
+        if let Some(const_idx) = self.folded_constant_idx.remove(name) {
+            log::debug!("keeping the constant node computing {}", name);
+            self.nodes_to_remove.remove(&const_idx);
+        }
+    }
+
     fn handle_identity(&mut self, node: &mut Node, graph_data: &GraphData) {
         if node.node_type == NodeType::Identity && node.inputs[0].value.is_none() {
             log::debug!("\nfound identity node:\n{:?}\n", &node);
@@ -324,6 +752,229 @@ impl OnnxGraphBuilder {
     }
 }
 
+/// A decomposed layer norm matched by [OnnxGraphBuilder::match_layer_norm].
+struct LayerNormMatch {
+    /// The normalized input
+    input: Argument,
+    /// The scale applied after the normalization
+    gamma: Argument,
+    /// The bias added after the scale
+    beta: Argument,
+    /// The value added to the variance
+    epsilon: f64,
+    /// The indices of the matched nodes
+    nodes: Vec<usize>,
+    /// The indices of the constant nodes only read by the matched nodes
+    constants: Vec<usize>,
+}
+
+/// Returns the node of the given type producing the argument.
+fn producer<'a>(
+    arg: &Argument,
+    node_type: NodeType,
+    graph_data: &'a GraphData,
+) -> Option<(usize, &'a Node)> {
+    graph_data
+        .processed_nodes
+        .iter()
+        .enumerate()
+        .find(|(_, node)| node.node_type == node_type && node.outputs[0].name == arg.name)
+}
+
+/// Whether a processed node other than the given ones reads the argument at runtime.
+///
+/// Inputs whose value was lifted from a constant node don't need the node anymore.
+fn has_other_consumer(name: &str, excluded: &[usize], graph_data: &GraphData) -> bool {
+    graph_data
+        .processed_nodes
+        .iter()
+        .enumerate()
+        .filter(|(idx, _)| !excluded.contains(idx))
+        .any(|(_, node)| {
+            node.inputs
+                .iter()
+                .any(|input| input.name == name && input.value.is_none())
+        })
+}
+
+/// Returns the node of the given type producing one input of a binary node, with the other input.
+fn producer_with_constant<'a>(
+    node: &'a Node,
+    node_type: NodeType,
+    graph_data: &'a GraphData,
+) -> Option<(usize, &'a Node, &'a Argument)> {
+    if node.inputs.len() != 2 {
+        return None;
+    }
+
+    [(0, 1), (1, 0)].into_iter().find_map(|(lhs, rhs)| {
+        let (idx, producer) = producer(&node.inputs[lhs], node_type.clone(), graph_data)?;
+        Some((idx, producer, &node.inputs[rhs]))
+    })
+}
+
+/// Returns the value of a constant holding a single float or integer.
+fn scalar_value(arg: &Argument) -> Option<f64> {
+    match arg.value.as_ref()? {
+        Data::Float16(value) => Some(value.to_f64()),
+        Data::Float32(value) => Some(*value as f64),
+        Data::Float64(value) => Some(*value),
+        Data::Int32(value) => Some(*value as f64),
+        Data::Int64(value) => Some(*value as f64),
+        Data::Float16s(values) if values.len() == 1 => Some(values[0].to_f64()),
+        Data::Float32s(values) if values.len() == 1 => Some(values[0] as f64),
+        Data::Float64s(values) if values.len() == 1 => Some(values[0]),
+        Data::Int32s(values) if values.len() == 1 => Some(values[0] as f64),
+        Data::Int64s(values) if values.len() == 1 => Some(values[0] as f64),
+        _ => None,
+    }
+}
+
+/// Whether the `ReduceMean` node averages the last axis of a tensor of the given rank, keeping it.
+fn reduces_last_axis(node: &Node, rank: usize) -> bool {
+    let keepdims = match node.attrs.get("keepdims") {
+        Some(AttributeValue::Int64(keepdims)) => *keepdims,
+        _ => 1,
+    };
+
+    matches!(
+        node.attrs.get("axes"),
+        Some(AttributeValue::Int64s(axes))
+            if keepdims == 1 && axes.len() == 1 && (axes[0] == -1 || axes[0] == rank as i64 - 1)
+    )
+}
+
+/// Whether the constant is a float vector, as expected for the layer norm weights.
+fn is_feature_vector(arg: &Argument) -> bool {
+    matches!(
+        (&arg.ty, &arg.value),
+        (
+            ArgType::Tensor(TensorType { dim: 1, .. }),
+            Some(Data::Float16s(_) | Data::Float32s(_) | Data::Float64s(_))
+        )
+    )
+}
+
+/// Returns the pad mode and the convolution pads (`[top, left, bottom, right]`) when the pad node
+/// only pads the spatial dimensions of a convolution without padding of its own.
+fn fusable_conv_padding(pad: &Node, conv: &Node) -> Option<(String, Vec<i64>)> {
+    let pad_mode = match pad.attrs.get("mode") {
+        Some(AttributeValue::String(mode)) if mode == "reflect" || mode == "edge" => mode.clone(),
+        _ => return None,
+    };
+
+    // Since opset 11, the pads are given by the second input instead of an attribute.
+    let pads = match (pad.attrs.get("pads"), pad.inputs.get(1)) {
+        (Some(AttributeValue::Int64s(pads)), _) => pads.clone(),
+        (
+            None,
+            Some(Argument {
+                value: Some(Data::Int64s(pads)),
+                ..
+            }),
+        ) => pads.clone(),
+        _ => return None,
+    };
+
+    // Custom axes are not supported, pads must be [n, c, h, w] begins followed by ends.
+    if pad.inputs.len() > 3 || pads.len() != 8 || pads[..2] != [0, 0] || pads[4..6] != [0, 0] {
+        return None;
+    }
+    // The padding of a convolution only pads both sides of an axis by the same amount.
+    if pads[2] != pads[6] || pads[3] != pads[7] {
+        return None;
+    }
+
+    let conv_padded = match conv.attrs.get("pads") {
+        Some(AttributeValue::Int64s(conv_pads)) => conv_pads.iter().any(|pad| *pad != 0),
+        _ => false,
+    };
+    let auto_pad = match conv.attrs.get("auto_pad") {
+        Some(AttributeValue::String(auto_pad)) => auto_pad != "NOTSET",
+        _ => false,
+    };
+    if conv_padded || auto_pad {
+        return None;
+    }
+
+    Some((pad_mode, vec![pads[2], pads[3], pads[6], pads[7]]))
+}
+
+/// Returns the axis squeezed by the squeeze node when it is the only axis the slice node
+/// statically narrows to a single index, with a step of 1.
+fn collapsible_slice_axis(slice: &Node, squeeze: &Node) -> Option<usize> {
+    let rank = match &slice.inputs[0].ty {
+        ArgType::Tensor(tensor) => tensor.dim as i64,
+        _ => return None,
+    };
+    // The bounds and the axes are only known when they are constants.
+    let constant = |input: &Argument| input.name.is_empty() || input.value.is_some();
+    if !slice.inputs.iter().skip(1).all(constant) || !squeeze.inputs.iter().skip(1).all(constant) {
+        return None;
+    }
+
+    let (starts, ends, steps) = slice_config(slice).ok()?;
+    let mut unit_axes = starts
+        .iter()
+        .zip(ends.iter())
+        .zip(steps.iter())
+        .enumerate()
+        .filter(|(_, ((start, end), step))| {
+            **start >= 0 && end.checked_sub(**start) == Some(1) && **step == 1
+        })
+        .map(|(axis, _)| axis);
+    let (Some(axis), None) = (unit_axes.next(), unit_axes.next()) else {
+        return None;
+    };
+
+    match squeeze_config(squeeze).as_slice() {
+        [squeezed] if (*squeezed + rank) % rank == axis as i64 => Some(axis),
+        _ => None,
+    }
+}
+
+/// Returns the output of a shape node when the dimensions of its input are static.
+fn fold_shape(node: &Node) -> Option<FoldedShape> {
+    let dims = match &node.inputs.first()?.ty {
+        ArgType::Tensor(TensorType {
+            shape: Some(dims), ..
+        }) => dims.clone(),
+        _ => return None,
+    };
+    let (start, end) = shape_config(node);
+    let end = end.min(dims.len());
+    let dims = &dims[start.min(end)..end];
+
+    // Symbolic dimensions (`dim_param`) have no value and are converted to 0, so they are only
+    // known at runtime.
+    if dims.contains(&0) {
+        return None;
+    }
+
+    Some(FoldedShape {
+        value: dims.iter().map(|dim| *dim as i64).collect(),
+        nodes: Vec::new(),
+    })
+}
+
+/// Slices the values following the ONNX `Slice` semantics, where out of range bounds are clamped.
+fn slice_values(values: &[i64], start: i64, end: i64, step: i64) -> Vec<i64> {
+    let len = values.len() as i64;
+    let (low, high) = if step > 0 { (0, len) } else { (-1, len - 1) };
+    let clamp = |index: i64| {
+        let index = if index < 0 { index + len } else { index };
+        index.clamp(low, high)
+    };
+    let (mut index, end) = (clamp(start), clamp(end));
+
+    let mut sliced = Vec::new();
+    while (step > 0 && index < end) || (step < 0 && index > end) {
+        sliced.push(values[index as usize]);
+        index += step;
+    }
+    sliced
+}
+
 /// Open an onnx file and convert it to a Graph (intermediate representation)
 ///
 /// # Arguments
@@ -340,6 +991,11 @@ impl OnnxGraphBuilder {
 /// * If the file cannot be parsed
 /// * If the nodes are not topologically sorted
 pub fn parse_onnx(onnx_path: &Path) -> OnnxGraph {
+    parse_onnx_with(onnx_path, OnnxGraphBuilder::default())
+}
+
+/// Open an onnx file and convert it to a Graph with the given builder settings.
+pub(crate) fn parse_onnx_with(onnx_path: &Path, builder: OnnxGraphBuilder) -> OnnxGraph {
     log::info!("Parsing ONNX file: {}", onnx_path.display());
 
     // Open the file
@@ -362,7 +1018,6 @@ pub fn parse_onnx(onnx_path: &Path) -> OnnxGraph {
     );
 
     log::debug!("Number of outputs: {:?}", onnx_model.graph.output.len());
-    let builder = OnnxGraphBuilder::default();
     let graph = builder.build(&onnx_model);
 
     log::info!("Finished parsing ONNX file: {}", onnx_path.display());
@@ -464,3 +1119,699 @@ pub(crate) fn convert_constant_value(node: &Node) -> Argument {
 
     Argument::from(value)
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::onnx::ir::OpsetVersion;
+    use crate::onnx::protos::{
+        attribute_proto::AttributeType, tensor_proto::DataType, tensor_shape_proto, type_proto,
+        AttributeProto, GraphProto, OperatorSetIdProto, TensorShapeProto, TypeProto,
+    };
+    use burn::record::FullPrecisionSettings;
+
+    fn value_info(name: &str, dims: &[i64]) -> ValueInfoProto {
+        let mut shape = TensorShapeProto::new();
+        for value in dims {
+            let mut dim = tensor_shape_proto::Dimension::new();
+            dim.set_dim_value(*value);
+            shape.dim.push(dim);
+        }
+
+        let mut tensor = type_proto::Tensor::new();
+        tensor.elem_type = DataType::FLOAT as i32;
+        tensor.shape = Some(shape).into();
+
+        let mut ty = TypeProto::new();
+        ty.set_tensor_type(tensor);
+
+        let mut info = ValueInfoProto::new();
+        info.name = name.to_string();
+        info.type_ = Some(ty).into();
+        info
+    }
+
+    fn initializer(name: &str, dims: &[i64], data_type: DataType) -> TensorProto {
+        let mut tensor = TensorProto::new();
+        tensor.name = name.to_string();
+        tensor.dims = dims.to_vec();
+        tensor.data_type = data_type as i32;
+        tensor
+    }
+
+    fn attribute(name: &str, ty: AttributeType) -> AttributeProto {
+        let mut attr = AttributeProto::new();
+        attr.name = name.to_string();
+        attr.type_ = ty.into();
+        attr
+    }
+
+    fn node(op_type: &str, inputs: &[&str], output: &str, attrs: Vec<AttributeProto>) -> NodeProto {
+        let mut node = NodeProto::new();
+        node.op_type = op_type.to_string();
+        node.input = inputs.iter().map(|input| input.to_string()).collect();
+        node.output = vec![output.to_string()];
+        node.attribute = attrs;
+        node
+    }
+
+    /// Builds `input -> Pad(mode) -> Conv(3x3) -> output`, with extra outputs reading the pad.
+    fn pad_conv_model(mode: &str, pad_outputs: &[&str]) -> ModelProto {
+        pad_conv_model_with_pads(mode, [0, 0, 1, 2, 0, 0, 1, 2], pad_outputs)
+    }
+
+    fn pad_conv_model_with_pads(mode: &str, pads: [i64; 8], pad_outputs: &[&str]) -> ModelProto {
+        let (height, width) = (4 + pads[2] + pads[6], 4 + pads[3] + pads[7]);
+        let mut pads_init = initializer("pads", &[8], DataType::INT64);
+        pads_init.int64_data = pads.to_vec();
+        let mut weight = initializer("weight", &[2, 1, 3, 3], DataType::FLOAT);
+        weight.float_data = vec![1.0; 18];
+
+        let mut pad_mode = attribute("mode", AttributeType::STRING);
+        pad_mode.s = mode.as_bytes().to_vec();
+        let mut kernel_shape = attribute("kernel_shape", AttributeType::INTS);
+        kernel_shape.ints = vec![3, 3];
+
+        let mut graph = GraphProto::new();
+        graph.input = vec![value_info("input", &[1, 1, 4, 4])];
+        graph.output = vec![value_info("output", &[1, 2, 4, 4])];
+        graph.initializer = vec![pads_init, weight];
+        graph.node = vec![
+            node("Pad", &["input", "pads"], "padded", vec![pad_mode]),
+            node("Conv", &["padded", "weight"], "output", vec![kernel_shape]),
+        ];
+        for output in pad_outputs {
+            graph
+                .node
+                .push(node("Relu", &["padded"], output, Vec::new()));
+            graph
+                .output
+                .push(value_info(output, &[1, 1, height, width]));
+        }
+
+        let mut model = ModelProto::new();
+        model.graph = Some(graph).into();
+        model
+    }
+
+    /// Builds `Reshape(input, Concat(Slice(Shape(input), 0, 1), Constant([-1])))`, with the
+    /// concatenated shape also returned by the graph when `shape_output` is set.
+    fn shape_reshape_model(shape_output: bool) -> ModelProto {
+        let mut starts = initializer("starts", &[1], DataType::INT64);
+        starts.int64_data = vec![0];
+        let mut ends = initializer("ends", &[1], DataType::INT64);
+        ends.int64_data = vec![1];
+
+        let mut tail = initializer("tail", &[1], DataType::INT64);
+        tail.int64_data = vec![-1];
+        let mut value = attribute("value", AttributeType::TENSOR);
+        value.t = Some(tail).into();
+        let mut axis = attribute("axis", AttributeType::INT);
+        axis.i = 0;
+
+        let mut graph = GraphProto::new();
+        graph.input = vec![value_info("input", &[2, 3, 4])];
+        graph.output = vec![value_info("output", &[2, 12])];
+        graph.initializer = vec![starts, ends];
+        graph.node = vec![
+            node("Shape", &["input"], "shape", Vec::new()),
+            node("Slice", &["shape", "starts", "ends"], "batch", Vec::new()),
+            node("Constant", &[], "tail", vec![value]),
+            node("Concat", &["batch", "tail"], "target", vec![axis]),
+            node("Reshape", &["input", "target"], "output", Vec::new()),
+        ];
+        if shape_output {
+            graph.output.push(value_info("target", &[2]));
+        }
+
+        let mut model = ModelProto::new();
+        model.graph = Some(graph).into();
+        model
+    }
+
+    #[test]
+    fn shape_folding() {
+        let graph = OnnxGraphBuilder::default().build(&shape_reshape_model(false));
+
+        assert_eq!(graph.nodes.len(), 1);
+        let reshape = &graph.nodes[0];
+        assert_eq!(reshape.node_type, NodeType::Reshape);
+        assert!(matches!(
+            &reshape.inputs[1].value,
+            Some(Data::Int64s(shape)) if shape == &[2, -1]
+        ));
+        assert!(matches!(
+            &reshape.outputs[0].ty,
+            ArgType::Tensor(tensor) if tensor.dim == 2
+        ));
+    }
+
+    #[test]
+    fn shape_folding_keeps_nodes_used_at_runtime() {
+        let graph = OnnxGraphBuilder::default().build(&shape_reshape_model(true));
+
+        let node_types = graph
+            .nodes
+            .iter()
+            .map(|node| node.node_type.clone())
+            .collect::<Vec<_>>();
+        assert_eq!(
+            node_types,
+            vec![
+                NodeType::Shape,
+                NodeType::Slice,
+                NodeType::Constant,
+                NodeType::Concat,
+                NodeType::Reshape
+            ]
+        );
+        assert!(matches!(
+            &graph.nodes[4].inputs[1].value,
+            Some(Data::Int64s(shape)) if shape == &[2, -1]
+        ));
+    }
+
+    #[test]
+    fn shape_folding_skips_symbolic_dims() {
+        let mut model = shape_reshape_model(false);
+        let input = &mut model.graph.as_mut().unwrap().input[0];
+        let shape = input
+            .type_
+            .as_mut()
+            .unwrap()
+            .mut_tensor_type()
+            .shape
+            .as_mut()
+            .unwrap();
+        shape.dim[0].set_dim_param("batch".to_string());
+
+        let graph = OnnxGraphBuilder::default().build(&model);
+
+        let node_types = graph
+            .nodes
+            .iter()
+            .map(|node| node.node_type.clone())
+            .collect::<Vec<_>>();
+        assert_eq!(
+            node_types,
+            vec![
+                NodeType::Shape,
+                NodeType::Slice,
+                NodeType::Constant,
+                NodeType::Concat,
+                NodeType::Reshape
+            ]
+        );
+        assert!(graph.nodes[4].inputs[1].value.is_none());
+    }
+
+    /// Adds `Add(tail, tail)` to [shape_reshape_model] at the given node index, reading the
+    /// constant concatenated to the shape.
+    fn shape_reshape_model_reading_tail(index: usize) -> ModelProto {
+        let mut model = shape_reshape_model(false);
+        let graph = model.graph.as_mut().unwrap();
+        graph
+            .node
+            .insert(index, node("Add", &["tail", "tail"], "doubled", Vec::new()));
+        graph.output.push(value_info("doubled", &[1]));
+        model
+    }
+
+    #[test]
+    fn shape_folding_keeps_constants_read_before_the_concat() {
+        let graph = OnnxGraphBuilder::default().build(&shape_reshape_model_reading_tail(3));
+
+        let node_types = graph
+            .nodes
+            .iter()
+            .map(|node| node.node_type.clone())
+            .collect::<Vec<_>>();
+        assert_eq!(
+            node_types,
+            vec![NodeType::Constant, NodeType::Add, NodeType::Reshape]
+        );
+        assert_eq!(
+            graph.nodes[1].inputs[0].name,
+            graph.nodes[0].outputs[0].name
+        );
+        assert!(matches!(
+            &graph.nodes[2].inputs[1].value,
+            Some(Data::Int64s(shape)) if shape == &[2, -1]
+        ));
+    }
+
+    #[test]
+    fn shape_folding_keeps_constants_read_after_the_concat() {
+        let graph = OnnxGraphBuilder::default().build(&shape_reshape_model_reading_tail(5));
+
+        let node_types = graph
+            .nodes
+            .iter()
+            .map(|node| node.node_type.clone())
+            .collect::<Vec<_>>();
+        assert_eq!(
+            node_types,
+            vec![NodeType::Constant, NodeType::Reshape, NodeType::Add]
+        );
+        assert_eq!(
+            graph.nodes[2].inputs[0].name,
+            graph.nodes[0].outputs[0].name
+        );
+        assert!(matches!(
+            &graph.nodes[1].inputs[1].value,
+            Some(Data::Int64s(shape)) if shape == &[2, -1]
+        ));
+    }
+
+    #[test]
+    fn slice_values_clamps_bounds() {
+        let values = [2, 3, 4, 5];
+
+        assert_eq!(slice_values(&values, 1, i64::MAX, 1), vec![3, 4, 5]);
+        assert_eq!(slice_values(&values, -2, -1, 1), vec![4]);
+        assert_eq!(slice_values(&values, -1, i64::MIN, -2), vec![5, 3]);
+    }
+
+    #[test]
+    fn pad_conv_fusion() {
+        let graph = OnnxGraphBuilder::default().build(&pad_conv_model("reflect", &[]));
+
+        assert_eq!(graph.nodes.len(), 1);
+        let conv = &graph.nodes[0];
+        assert_eq!(conv.node_type, NodeType::Conv2d);
+        assert_eq!(conv.inputs[0].name, "input1");
+        assert!(matches!(
+            conv.attrs.get("pads"),
+            Some(AttributeValue::Int64s(pads)) if pads == &[1, 2, 1, 2]
+        ));
+        assert!(matches!(
+            conv.attrs.get("pad_mode"),
+            Some(AttributeValue::String(mode)) if mode == "reflect"
+        ));
+    }
+
+    #[test]
+    fn pad_conv_fusion_constant_mode_is_kept() {
+        let graph = OnnxGraphBuilder::default().build(&pad_conv_model("constant", &[]));
+
+        assert_eq!(graph.nodes.len(), 2);
+        assert_eq!(graph.nodes[0].node_type, NodeType::Pad);
+        assert!(!graph.nodes[1].attrs.contains_key("pad_mode"));
+    }
+
+    #[test]
+    fn pad_conv_fusion_keeps_shared_pad() {
+        let graph = OnnxGraphBuilder::default().build(&pad_conv_model("edge", &["relu"]));
+
+        assert_eq!(graph.nodes.len(), 3);
+        assert_eq!(graph.nodes[0].node_type, NodeType::Pad);
+        assert!(matches!(
+            graph.nodes[1].attrs.get("pad_mode"),
+            Some(AttributeValue::String(mode)) if mode == "edge"
+        ));
+    }
+
+    #[test]
+    fn pad_conv_fusion_keeps_pad_read_before_conv() {
+        let mut model = pad_conv_model("edge", &["relu"]);
+        // Reads the pad output before the convolution: `[Pad, Relu, Conv]`.
+        model.graph.as_mut().unwrap().node.swap(1, 2);
+        let graph = OnnxGraphBuilder::default().build(&model);
+
+        let node_types = graph
+            .nodes
+            .iter()
+            .map(|node| node.node_type.clone())
+            .collect::<Vec<_>>();
+        assert_eq!(
+            node_types,
+            vec![NodeType::Pad, NodeType::Relu, NodeType::Conv2d]
+        );
+        assert_eq!(
+            graph.nodes[2].inputs[0].name,
+            graph.nodes[0].outputs[0].name
+        );
+        assert!(!graph.nodes[2].attrs.contains_key("pad_mode"));
+    }
+
+    /// Builds `input -> Slice(x[:, 1:2]) -> Squeeze(1) -> output`, with extra outputs reading the
+    /// slice.
+    fn slice_squeeze_model(slice_outputs: &[&str]) -> ModelProto {
+        let bound = |name: &str, value: i64| {
+            let mut bound = initializer(name, &[1], DataType::INT64);
+            bound.int64_data = vec![value];
+            bound
+        };
+
+        let mut graph = GraphProto::new();
+        graph.input = vec![value_info("input", &[3, 4])];
+        graph.output = vec![value_info("output", &[3])];
+        graph.initializer = vec![
+            bound("starts", 1),
+            bound("ends", 2),
+            bound("axes", 1),
+            bound("squeezed", -1),
+        ];
+        graph.node = vec![
+            node(
+                "Slice",
+                &["input", "starts", "ends", "axes"],
+                "sliced",
+                Vec::new(),
+            ),
+            node("Squeeze", &["sliced", "squeezed"], "output", Vec::new()),
+        ];
+        for output in slice_outputs {
+            graph
+                .node
+                .push(node("Relu", &["sliced"], output, Vec::new()));
+            graph.output.push(value_info(output, &[3, 1]));
+        }
+
+        let mut opset_import = OperatorSetIdProto::new();
+        opset_import.version = 13;
+
+        let mut model = ModelProto::new();
+        model.graph = Some(graph).into();
+        model.opset_import = vec![opset_import];
+        model
+    }
+
+    #[test]
+    fn slice_squeeze_fusion() {
+        let graph = OnnxGraphBuilder::default()
+            .with_collapse_slices(true)
+            .build(&slice_squeeze_model(&[]));
+
+        assert_eq!(graph.nodes.len(), 1);
+        let slice = &graph.nodes[0];
+        assert_eq!(slice.node_type, NodeType::Slice);
+        assert_eq!(slice.inputs[0].name, "input1");
+        assert!(matches!(
+            slice.attrs.get("collapse_axis"),
+            Some(AttributeValue::Int64(1))
+        ));
+        assert!(matches!(
+            &slice.outputs[0].ty,
+            ArgType::Tensor(tensor) if tensor.dim == 1
+        ));
+
+        let codegen = graph
+            .into_burn::<FullPrecisionSettings>()
+            .codegen()
+            .to_string();
+        assert!(codegen.contains("squeeze :: < 1 > (1)"));
+    }
+
+    #[test]
+    fn slice_squeeze_fusion_keeps_shared_slice() {
+        let graph = OnnxGraphBuilder::default()
+            .with_collapse_slices(true)
+            .build(&slice_squeeze_model(&["relu"]));
+
+        let node_types = graph
+            .nodes
+            .iter()
+            .map(|node| node.node_type.clone())
+            .collect::<Vec<_>>();
+        assert_eq!(
+            node_types,
+            vec![NodeType::Slice, NodeType::Slice, NodeType::Relu]
+        );
+        assert!(!graph.nodes[0].attrs.contains_key("collapse_axis"));
+        assert!(graph.nodes[1].attrs.contains_key("collapse_axis"));
+    }
+
+    #[test]
+    fn slice_squeeze_fusion_is_disabled_by_default() {
+        let graph = OnnxGraphBuilder::default().build(&slice_squeeze_model(&[]));
+
+        let node_types = graph
+            .nodes
+            .iter()
+            .map(|node| node.node_type.clone())
+            .collect::<Vec<_>>();
+        assert_eq!(node_types, vec![NodeType::Slice, NodeType::Squeeze]);
+        assert!(!graph.nodes[0].attrs.contains_key("collapse_axis"));
+    }
+
+    #[test]
+    fn slice_squeeze_fusion_keeps_slice_read_before_squeeze() {
+        let mut model = slice_squeeze_model(&["relu"]);
+        // Reads the slice output before the squeeze: `[Slice, Relu, Squeeze]`.
+        model.graph.as_mut().unwrap().node.swap(1, 2);
+        let graph = OnnxGraphBuilder::default()
+            .with_collapse_slices(true)
+            .build(&model);
+
+        let node_types = graph
+            .nodes
+            .iter()
+            .map(|node| node.node_type.clone())
+            .collect::<Vec<_>>();
+        assert_eq!(
+            node_types,
+            vec![NodeType::Slice, NodeType::Relu, NodeType::Squeeze]
+        );
+        assert!(!graph.nodes[0].attrs.contains_key("collapse_axis"));
+    }
+
+    #[test]
+    fn pad_conv_fusion_asymmetric_pads_are_kept() {
+        let model = pad_conv_model_with_pads("reflect", [0, 0, 1, 2, 0, 0, 2, 1], &[]);
+        let graph = OnnxGraphBuilder::default().build(&model);
+
+        assert_eq!(graph.nodes.len(), 2);
+        assert_eq!(graph.nodes[0].node_type, NodeType::Pad);
+        assert!(!graph.nodes[1].attrs.contains_key("pad_mode"));
+    }
+
+    #[test]
+    fn pad_conv_fusion_keeps_pad_returned_by_graph() {
+        let mut model = pad_conv_model("reflect", &[]);
+        model
+            .graph
+            .as_mut()
+            .unwrap()
+            .output
+            .push(value_info("padded", &[1, 1, 6, 8]));
+        let graph = OnnxGraphBuilder::default().build(&model);
+
+        assert_eq!(graph.nodes.len(), 2);
+        assert_eq!(graph.nodes[0].node_type, NodeType::Pad);
+        assert_eq!(graph.outputs[1].name, graph.nodes[0].outputs[0].name);
+    }
+
+    /// Builds the layer norm decomposition exported before opset 17, with the epsilon given by a
+    /// constant node and the other constants by initializers. The centered input is also returned
+    /// by the graph when `centered_output` is set.
+    fn decomposed_layer_norm_model(centered_output: bool) -> ModelProto {
+        let mut two = initializer("two", &[], DataType::FLOAT);
+        two.float_data = vec![2.0];
+        let mut gamma = initializer("gamma", &[4], DataType::FLOAT);
+        gamma.float_data = vec![1.0, 2.0, 3.0, 4.0];
+        let mut beta = initializer("beta", &[4], DataType::FLOAT);
+        beta.float_data = vec![0.1, 0.2, 0.3, 0.4];
+
+        let mut epsilon = attribute("value_float", AttributeType::FLOAT);
+        epsilon.f = 1e-6;
+        let axes = || {
+            let mut axes = attribute("axes", AttributeType::INTS);
+            axes.ints = vec![-1];
+            vec![axes]
+        };
+
+        let mut graph = GraphProto::new();
+        graph.input = vec![value_info("input", &[2, 3, 4])];
+        graph.output = vec![value_info("output", &[2, 3, 4])];
+        graph.initializer = vec![two, gamma, beta];
+        graph.node = vec![
+            node("ReduceMean", &["input"], "mean", axes()),
+            node("Sub", &["input", "mean"], "centered", Vec::new()),
+            node("Pow", &["centered", "two"], "squared", Vec::new()),
+            node("ReduceMean", &["squared"], "var", axes()),
+            node("Constant", &[], "epsilon", vec![epsilon]),
+            node("Add", &["var", "epsilon"], "var_eps", Vec::new()),
+            node("Sqrt", &["var_eps"], "std", Vec::new()),
+            node("Div", &["centered", "std"], "normalized", Vec::new()),
+            node("Mul", &["normalized", "gamma"], "scaled", Vec::new()),
+            node("Add", &["scaled", "beta"], "output", Vec::new()),
+        ];
+        if centered_output {
+            graph.output.push(value_info("centered", &[2, 3, 4]));
+        }
+
+        let mut model = ModelProto::new();
+        model.graph = Some(graph).into();
+        model
+    }
+
+    #[test]
+    fn layer_norm_fusion() {
+        let graph = OnnxGraphBuilder::default().build(&decomposed_layer_norm_model(false));
+
+        assert_eq!(graph.nodes.len(), 1);
+        let layer_norm = &graph.nodes[0];
+        assert_eq!(layer_norm.node_type, NodeType::LayerNormalization);
+        assert_eq!(layer_norm.name, "layernormalization1");
+        assert_eq!(layer_norm.inputs[0].name, "input1");
+        assert!(matches!(
+            &layer_norm.inputs[1].value,
+            Some(Data::Float32s(gamma)) if gamma == &[1.0, 2.0, 3.0, 4.0]
+        ));
+        assert!(matches!(
+            &layer_norm.inputs[2].value,
+            Some(Data::Float32s(beta)) if beta == &[0.1, 0.2, 0.3, 0.4]
+        ));
+        assert!(matches!(
+            layer_norm.attrs.get("epsilon"),
+            Some(AttributeValue::Float32(epsilon)) if *epsilon == 1e-6
+        ));
+        assert_eq!(graph.outputs[0].name, layer_norm.outputs[0].name);
+
+        let codegen = graph
+            .into_burn::<FullPrecisionSettings>()
+            .codegen()
+            .to_string();
+        assert!(codegen.contains("LayerNormConfig"));
+        assert!(!codegen.contains("sqrt"));
+    }
+
+    #[test]
+    fn layer_norm_fusion_keeps_shared_nodes() {
+        let graph = OnnxGraphBuilder::default().build(&decomposed_layer_norm_model(true));
+
+        let node_types = graph
+            .nodes
+            .iter()
+            .map(|node| node.node_type.clone())
+            .collect::<Vec<_>>();
+        assert_eq!(
+            node_types,
+            vec![
+                NodeType::ReduceMean,
+                NodeType::Sub,
+                NodeType::Pow,
+                NodeType::ReduceMean,
+                NodeType::Constant,
+                NodeType::Add,
+                NodeType::Sqrt,
+                NodeType::Div,
+                NodeType::Mul,
+                NodeType::LayerNormalization,
+            ]
+        );
+    }
+
+    #[test]
+    fn layer_norm_fusion_keeps_nodes_read_before_the_match() {
+        let mut model = decomposed_layer_norm_model(false);
+        let graph = model.graph.as_mut().unwrap();
+        // Reads the centered input before the end of the pattern.
+        graph
+            .node
+            .insert(2, node("Relu", &["centered"], "relu", Vec::new()));
+        graph.output.push(value_info("relu", &[2, 3, 4]));
+        let graph = OnnxGraphBuilder::default().build(&model);
+
+        assert_eq!(graph.nodes.len(), 11);
+        assert!(graph
+            .nodes
+            .iter()
+            .all(|node| node.node_type != NodeType::LayerNormalization));
+    }
+
+    #[test]
+    fn layer_norm_fusion_keeps_shared_constants() {
+        let mut model = decomposed_layer_norm_model(false);
+        let graph = model.graph.as_mut().unwrap();
+        // Reads the epsilon constant outside of the pattern, as left by deduplicated constants.
+        graph
+            .node
+            .insert(5, node("Add", &["input", "epsilon"], "shifted", Vec::new()));
+        graph.output.push(value_info("shifted", &[2, 3, 4]));
+        let graph = OnnxGraphBuilder::default().build(&model);
+
+        let node_types = graph
+            .nodes
+            .iter()
+            .map(|node| node.node_type.clone())
+            .collect::<Vec<_>>();
+        assert_eq!(
+            node_types,
+            vec![
+                NodeType::Constant,
+                NodeType::Add,
+                NodeType::LayerNormalization
+            ]
+        );
+        assert_eq!(
+            graph.nodes[1].inputs[1].name,
+            graph.nodes[0].outputs[0].name
+        );
+    }
+
+    /// Builds `input -> Slice -> output`, with the bounds given as attributes before opset 10 and
+    /// as inputs since.
+    fn slice_model(opset: i64) -> ModelProto {
+        let (starts, ends) = (vec![0, 1], vec![2, -1]);
+
+        let mut graph = GraphProto::new();
+        graph.input = vec![value_info("input", &[3, 4])];
+        graph.output = vec![value_info("output", &[2, 2])];
+
+        if opset < 10 {
+            let mut starts_attr = attribute("starts", AttributeType::INTS);
+            starts_attr.ints = starts;
+            let mut ends_attr = attribute("ends", AttributeType::INTS);
+            ends_attr.ints = ends;
+
+            graph.node = vec![node(
+                "Slice",
+                &["input"],
+                "output",
+                vec![starts_attr, ends_attr],
+            )];
+        } else {
+            let mut starts_init = initializer("starts", &[2], DataType::INT64);
+            starts_init.int64_data = starts;
+            let mut ends_init = initializer("ends", &[2], DataType::INT64);
+            ends_init.int64_data = ends;
+
+            graph.initializer = vec![starts_init, ends_init];
+            graph.node = vec![node(
+                "Slice",
+                &["input", "starts", "ends"],
+                "output",
+                Vec::new(),
+            )];
+        }
+
+        let mut opset_import = OperatorSetIdProto::new();
+        opset_import.version = opset;
+
+        let mut model = ModelProto::new();
+        model.graph = Some(graph).into();
+        model.opset_import = vec![opset_import];
+        model
+    }
+
+    #[test]
+    fn slice_attributes_before_opset_10() {
+        let graph = OnnxGraphBuilder::default().build(&slice_model(9));
+        assert_eq!(graph.nodes[0].opset, OpsetVersion(9));
+        let codegen = graph
+            .into_burn::<FullPrecisionSettings>()
+            .codegen()
+            .to_string();
+
+        let expected = OnnxGraphBuilder::default()
+            .build(&slice_model(10))
+            .into_burn::<FullPrecisionSettings>()
+            .codegen()
+            .to_string();
+
+        assert!(codegen.contains("slice"));
+        assert_eq!(codegen, expected);
+    }
+}
@@ -171,6 +171,25 @@ pub enum Data {
     Strings(Vec<String>),
 }
 
+/// Version of the default operator set (`ai.onnx`) a node was exported with.
+///
+/// Some operators changed their signature across versions, e.g. the bounds of `Slice` moved from
+/// attributes to inputs in opset 10, so the node configuration can depend on it.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct OpsetVersion(pub i64);
+
+impl OpsetVersion {
+    /// Version assumed when the model doesn't declare the default operator set, which is the one
+    /// the supported operators are tested with.
+    pub const DEFAULT: Self = Self(16);
+}
+
+impl Default for OpsetVersion {
+    fn default() -> Self {
+        Self::DEFAULT
+    }
+}
+
 /// ONNX graph representation
 #[derive(Debug, Clone)]
 pub struct OnnxGraph {
@@ -202,6 +221,9 @@ pub struct Node {
 
     /// The attributes of the node.
     pub attrs: Attributes,
+
+    /// The version of the default operator set of the model the node comes from.
+    pub opset: OpsetVersion,
 }
 
 // Required by topological sort
@@ -4,9 +4,13 @@ use burn::nn::{
     BatchNormConfig, DropoutConfig, LayerNormConfig, LinearConfig, PaddingConfig1d,
     PaddingConfig2d,
 };
+use burn::tensor::ops::PadMode;
 
-use super::ir::{ArgType, AttributeValue, Data, Node};
-use crate::burn::node::resize::ResizeMode;
+use super::ir::{ArgType, Argument, AttributeValue, Data, ElementType, Node, OpsetVersion};
+use crate::burn::node::{
+    depth_to_space::DepthToSpaceMode, max_unpool2d::MaxUnpool2dConfig, pad::PadMode as PadNodeMode,
+    resize::ResizeMode, scatter_elements::ScatterElementsReduction, slice::SliceError,
+};
 
 /// Create a Conv1dConfig from the attributes of the node
 pub fn conv1d_config(curr: &Node) -> Conv1dConfig {
@@ -97,6 +101,15 @@ pub fn conv2d_config(curr: &Node) -> Conv2dConfig {
     .with_padding(padding)
 }
 
+/// Get the padding mode of a Conv2d node, set when a reflect or edge Pad node was fused into it
+pub fn conv2d_pad_mode(curr: &Node) -> PadMode {
+    match curr.attrs.get("pad_mode") {
+        Some(AttributeValue::String(mode)) if mode == "reflect" => PadMode::Reflect,
+        Some(AttributeValue::String(mode)) if mode == "edge" => PadMode::Replicate,
+        _ => PadMode::Zeros,
+    }
+}
+
 /// Create a MaxPool2dConfig from the attributes of the node
 pub fn max_pool1d_config(curr: &Node) -> MaxPool1dConfig {
     let mut kernel_shape = Vec::new();
@@ -148,6 +161,50 @@ pub fn max_pool2d_config(curr: &Node) -> MaxPool2dConfig {
         .with_padding(padding)
         .with_dilation([dilations[0] as usize, dilations[1] as usize])
 }
+/// Create a MaxUnpool2dConfig from the attributes and the optional output shape of the node
+pub fn max_unpool2d_config(curr: &Node) -> MaxUnpool2dConfig {
+    let mut kernel_shape = Vec::new();
+    let mut strides = vec![1, 1];
+    let mut pads = vec![0, 0, 0, 0];
+
+    for (key, value) in curr.attrs.iter() {
+        match key.as_str() {
+            "kernel_shape" => kernel_shape = value.clone().into_i64s(),
+            "strides" => strides = value.clone().into_i64s(),
+            "pads" => pads = value.clone().into_i64s(),
+            _ => {}
+        }
+    }
+
+    if kernel_shape.len() != 2 {
+        panic!("Only max_unpool 2d is supported");
+    }
+
+    let padding = match padding_config(&pads) {
+        PaddingConfig2d::Valid => [0, 0],
+        PaddingConfig2d::Explicit(height, width) => [height, width],
+        PaddingConfig2d::Same => unreachable!(),
+    };
+
+    // The optional output_shape input holds the full (N, C, H, W) shape.
+    let output_size = match curr.inputs.get(2) {
+        Some(output_shape) => match output_shape.value.as_ref() {
+            Some(Data::Int64s(shape)) if shape.len() == 4 => {
+                Some([shape[2] as usize, shape[3] as usize])
+            }
+            _ => panic!("MaxUnpool: output_shape must be a constant int64 tensor of 4 elements"),
+        },
+        None => None,
+    };
+
+    MaxUnpool2dConfig::new(
+        [kernel_shape[0] as usize, kernel_shape[1] as usize],
+        [strides[0] as usize, strides[1] as usize],
+        padding,
+        output_size,
+    )
+}
+
 pub fn conv_transpose2d_config(curr: &Node) -> ConvTranspose2dConfig {
     let mut attrs = curr.attrs.clone();
     let kernel_shape = attrs
@@ -664,6 +721,21 @@ fn padding_config(pads: &[i64]) -> PaddingConfig2d {
     }
 }
 
+/// Whether the Mod node uses the `fmod` semantics (sign of the dividend) instead of the
+/// remainder semantics (sign of the divisor).
+pub fn mod_config(node: &Node) -> bool {
+    let mut fmod = 0;
+
+    for (key, value) in node.attrs.iter() {
+        match key.as_str() {
+            "fmod" => fmod = value.clone().into_i64(),
+            _ => {}
+        }
+    }
+
+    fmod == 1
+}
+
 // Create a LeakyReluConfig from the alpha attribute of the node
 pub fn leaky_relu_config(node: &Node) -> f64 {
     let mut alpha = 0.01;
@@ -678,6 +750,252 @@ pub fn leaky_relu_config(node: &Node) -> f64 {
     alpha
 }
 
+// Create the (alpha, beta) parameters of a HardSigmoid from the attributes of the node
+pub fn hard_sigmoid_config(node: &Node) -> (f64, f64) {
+    let mut alpha = 0.2;
+    let mut beta = 0.5;
+
+    for (key, value) in node.attrs.iter() {
+        match key.as_str() {
+            "alpha" => alpha = value.clone().into_f32() as f64,
+            "beta" => beta = value.clone().into_f32() as f64,
+            _ => {}
+        }
+    }
+
+    (alpha, beta)
+}
+
+/// Returns whether the Gelu node uses the tanh approximation.
+pub fn gelu_config(node: &Node) -> bool {
+    let mut approximate = "none".to_string();
+
+    for (key, value) in node.attrs.iter() {
+        if key.as_str() == "approximate" {
+            approximate = value.clone().into_string();
+        }
+    }
+
+    match approximate.as_str() {
+        "none" => false,
+        "tanh" => true,
+        _ => panic!("Gelu: unsupported approximate mode {approximate}"),
+    }
+}
+
+/// Returns the block size and the mode of the DepthToSpace node.
+pub fn depth_to_space_config(node: &Node) -> (usize, DepthToSpaceMode) {
+    let blocksize = blocksize_config(node);
+    let mut mode = DepthToSpaceMode::Dcr;
+
+    if let Some(value) = node.attrs.get("mode") {
+        mode = match value.clone().into_string().as_str() {
+            "DCR" => DepthToSpaceMode::Dcr,
+            "CRD" => DepthToSpaceMode::Crd,
+            mode => panic!("DepthToSpace: mode '{mode}' is not supported"),
+        };
+    }
+
+    (blocksize, mode)
+}
+
+/// Returns the block size of the SpaceToDepth node.
+pub fn space_to_depth_config(node: &Node) -> usize {
+    blocksize_config(node)
+}
+
+/// Returns the block size of the DepthToSpace and SpaceToDepth nodes, which only support
+/// `[batch_size, channels, height, width]` inputs.
+fn blocksize_config(node: &Node) -> usize {
+    match &node.inputs[0].ty {
+        ArgType::Tensor(tensor) if tensor.dim == 4 => {}
+        _ => panic!("{:?}: only rank 4 tensor input is valid", node.node_type),
+    }
+
+    let blocksize = node
+        .attrs
+        .get("blocksize")
+        .unwrap_or_else(|| panic!("{:?}: blocksize must be provided", node.node_type))
+        .clone()
+        .into_i64();
+
+    if blocksize < 1 {
+        panic!("{:?}: blocksize must be positive", node.node_type);
+    }
+
+    blocksize as usize
+}
+
+/// Returns whether the Trilu node keeps the upper triangular part.
+/// Returns the `exclusive` and `reverse` flags of the CumSum node.
+pub fn cumsum_config(node: &Node) -> (bool, bool) {
+    let mut exclusive = false;
+    let mut reverse = false;
+
+    for (key, value) in node.attrs.iter() {
+        match key.as_str() {
+            "exclusive" => exclusive = value.clone().into_i64() != 0,
+            "reverse" => reverse = value.clone().into_i64() != 0,
+            _ => {}
+        }
+    }
+
+    (exclusive, reverse)
+}
+
+pub fn trilu_config(node: &Node) -> bool {
+    let mut upper = true;
+
+    for (key, value) in node.attrs.iter() {
+        if key.as_str() == "upper" {
+            upper = value.clone().into_i64() != 0;
+        }
+    }
+
+    upper
+}
+
+/// Returns the axis and the reduction of the ScatterElements node.
+pub fn scatter_elements_config(node: &Node) -> (usize, ScatterElementsReduction) {
+    if node.inputs.len() != 3 {
+        panic!("ScatterElements: data, indices and updates tensors must be present");
+    }
+
+    let tensor = match &node.inputs[0].ty {
+        ArgType::Tensor(tensor) => tensor,
+        _ => panic!("ScatterElements: only tensor input is valid"),
+    };
+
+    // Default: 0 and none per ONNX spec
+    let mut axis: i64 = 0;
+    let mut reduction = ScatterElementsReduction::Replace;
+
+    for (key, value) in node.attrs.iter() {
+        match key.as_str() {
+            "axis" => axis = value.clone().into_i64(),
+            "reduction" => {
+                reduction = match value.clone().into_string().as_str() {
+                    "none" => ScatterElementsReduction::Replace,
+                    "add" => ScatterElementsReduction::Add,
+                    // Burn has no scatter multiplying the data, and computing the product with
+                    // logarithms would be inexact and fail on zeros.
+                    "mul" => panic!("ScatterElements: the mul reduction is not supported"),
+                    mode => panic!("ScatterElements: reduction '{mode}' is not supported"),
+                }
+            }
+            _ => {}
+        }
+    }
+
+    // if axis is negative, it is counted from the end
+    if axis < 0 {
+        axis += tensor.dim as i64;
+    }
+
+    (axis as usize, reduction)
+}
+
+/// Returns the `(before, after)` padding of each dimension, the mode and the constant value of
+/// the Pad node.
+pub fn pad_config(node: &Node) -> (Vec<(usize, usize)>, PadNodeMode, f64) {
+    let tensor = match &node.inputs[0].ty {
+        ArgType::Tensor(tensor) => tensor,
+        _ => panic!("Pad: only tensor input is valid"),
+    };
+
+    let mut pads = None;
+    let mut mode = PadNodeMode::Constant;
+    let mut value = 0.0;
+
+    for (key, attr) in node.attrs.iter() {
+        match key.as_str() {
+            // Before opset 11, the pads and the value are attributes.
+            "pads" => pads = Some(attr.clone().into_i64s()),
+            "value" => value = attr.clone().into_f32() as f64,
+            "mode" => {
+                mode = match attr.clone().into_string().as_str() {
+                    "constant" => PadNodeMode::Constant,
+                    "reflect" => PadNodeMode::Reflect,
+                    "edge" => PadNodeMode::Edge,
+                    mode => panic!("Pad: mode '{mode}' is not supported"),
+                }
+            }
+            _ => {}
+        }
+    }
+
+    if let Some(input) = node.inputs.get(1) {
+        match &input.value {
+            Some(Data::Int64s(values)) => pads = Some(values.clone()),
+            _ => panic!("Pad: pads must be a constant int64 tensor"),
+        }
+    }
+    let pads = pads.expect("Pad: pads must be provided");
+
+    if let Some(input) = node.inputs.get(2).filter(|input| !input.name.is_empty()) {
+        value = match &input.value {
+            Some(Data::Float32(value)) => *value as f64,
+            Some(Data::Float64(value)) => *value,
+            Some(Data::Int32(value)) => *value as f64,
+            Some(Data::Int64(value)) => *value as f64,
+            Some(Data::Float32s(values)) if values.len() == 1 => values[0] as f64,
+            Some(Data::Float64s(values)) if values.len() == 1 => values[0],
+            Some(Data::Int64s(values)) if values.len() == 1 => values[0] as f64,
+            _ => panic!("Pad: constant_value must be a constant scalar"),
+        };
+    }
+
+    // Since opset 18, the padded axes are given by the optional fourth input.
+    let axes = match node.inputs.get(3) {
+        Some(input) => match &input.value {
+            Some(Data::Int64s(axes)) => axes
+                .iter()
+                .map(|axis| match *axis < 0 {
+                    true => (axis + tensor.dim as i64) as usize,
+                    false => *axis as usize,
+                })
+                .collect(),
+            _ => panic!("Pad: axes must be a constant int64 tensor"),
+        },
+        None => (0..tensor.dim).collect::<Vec<_>>(),
+    };
+
+    // The pads are ordered as [x1_begin, x2_begin, ..., x1_end, x2_end, ...].
+    assert_eq!(
+        pads.len(),
+        axes.len() * 2,
+        "Pad: pads must hold a begin and an end value for each axis"
+    );
+    assert!(
+        pads.iter().all(|pad| *pad >= 0),
+        "Pad: negative pads are not supported"
+    );
+
+    let mut padding = vec![(0, 0); tensor.dim];
+    for (i, axis) in axes.iter().enumerate() {
+        padding[*axis] = (pads[i] as usize, pads[i + axes.len()] as usize);
+    }
+
+    // The reflect and edge modes are computed on float tensors.
+    let float = matches!(
+        tensor.elem_type,
+        ElementType::Float16 | ElementType::Float32 | ElementType::Float64
+    );
+    if mode != PadNodeMode::Constant && !float {
+        panic!("Pad: the {mode:?} mode is only supported for float tensors");
+    }
+
+    (padding, mode, value)
+}
+
+/// Returns the equation of the Einsum node.
+pub fn einsum_config(node: &Node) -> String {
+    match node.attrs.get("equation") {
+        Some(equation) => equation.clone().into_string(),
+        None => panic!("Einsum: equation attribute is required"),
+    }
+}
+
 pub fn reshape_config(node: &Node) -> Vec<i64> {
     let mut allowzero = 0;
 
@@ -1080,65 +1398,110 @@ pub fn shape_config(curr: &Node) -> (usize, usize) {
     (start_dim as usize, end_dim as usize)
 }
 
-pub fn slice_config(node: &Node) -> (Vec<usize>, Vec<usize>) {
-    let start_value = &node.inputs[1].value;
-    let end_value = &node.inputs[2].value;
+/// The starts, ends and steps of each sliced axis, as given by [slice_config].
+pub type SliceBounds = (Vec<i64>, Vec<i64>, Vec<i64>);
 
-    let starts = match &node.inputs[1].ty {
-        ArgType::Tensor(tensor) => {
-            assert_eq!(tensor.dim, 1, "Slice: ends tensor must be 1D");
-            if let Some(Data::Int64s(shape)) = start_value.as_ref() {
-                shape
-                    .iter()
-                    .map(|x| {
-                        assert!(*x >= 0, "Slice: start must be positive");
-                        *x as usize
-                    })
-                    .collect()
-            } else {
-                panic!("Tensor data type must be int64")
-            }
-        }
-        _ => panic!("Only tensor input is valid for shape"),
-    };
+pub fn slice_config(node: &Node) -> Result<SliceBounds, SliceError> {
+    // Before opset 10, the starts and ends are attributes instead of inputs.
+    let (starts, ends) = if node.opset < OpsetVersion(10) {
+        let bound = |name: &'static str| match node.attrs.get(name) {
+            Some(value) => Ok(value.clone().into_i64s()),
+            None => Err(SliceError::MissingAttribute(name)),
+        };
 
-    let ends = match &node.inputs[2].ty {
-        ArgType::Tensor(tensor) => {
-            assert_eq!(tensor.dim, 1, "Slice: ends tensor must be 1D");
-            if let Some(Data::Int64s(shape)) = end_value.as_ref() {
-                shape
-                    .iter()
-                    .map(|x| {
-                        assert!(*x >= 0, "Slice: end must be positive");
-                        *x as usize
-                    })
-                    .collect()
-            } else {
-                panic!("Tensor data type must be int64")
+        (bound("starts")?, bound("ends")?)
+    } else {
+        let bound = |index: usize, name: &'static str| match node.inputs.get(index) {
+            Some(Argument {
+                ty: ArgType::Tensor(tensor),
+                value,
+                ..
+            }) => {
+                if tensor.dim != 1 {
+                    return Err(SliceError::NotVector(name));
+                }
+                match value {
+                    Some(Data::Int64s(values)) => Ok(values.clone()),
+                    _ => Err(SliceError::NotConstant(name)),
+                }
             }
-        }
-        _ => panic!("Only tensor input is valid for shape"),
+            _ => Err(SliceError::MissingInput(name)),
+        };
+
+        (bound(1, "starts")?, bound(2, "ends")?)
     };
 
+    let mut axes = None;
+    let mut steps = None;
+
     for (key, value) in node.attrs.iter() {
         match key.as_str() {
-            "axes" => {
-                let mut i = 0;
-                value.clone().into_i64s().iter().for_each(|x| {
-                    assert_eq!(*x, i, "Slice: axes must be consecutive");
-                    i += 1;
-                })
-            }
-            "steps" => value.clone().into_i64s().into_iter().for_each(|x| {
-                if x != 1 {
-                    panic!("Slice: steps other than 1 are not supported");
-                }
-            }),
+            "axes" => axes = Some(value.clone().into_i64s()),
+            "steps" => steps = Some(value.clone().into_i64s()),
             _ => {}
         }
     }
 
-    (starts, ends)
+    // Since opset 10, the axes and the steps are given by the optional fourth and fifth inputs.
+    let constant_input = |index: usize, name: &'static str| {
+        node.inputs
+            .get(index)
+            .filter(|input| !input.name.is_empty())
+            .map(|input| match &input.value {
+                Some(Data::Int64s(values)) => Ok(values.clone()),
+                _ => Err(SliceError::NotConstant(name)),
+            })
+            .transpose()
+    };
+    if let Some(values) = constant_input(3, "axes")? {
+        axes = Some(values);
+    }
+    if let Some(values) = constant_input(4, "steps")? {
+        steps = Some(values);
+    }
+
+    let steps = steps.unwrap_or_else(|| vec![1; starts.len()]);
+    if steps.len() != starts.len() {
+        return Err(SliceError::LengthMismatch("steps"));
+    }
+    if steps.contains(&0) {
+        return Err(SliceError::ZeroStep);
+    }
+
+    let Some(axes) = axes else {
+        return Ok((starts, ends, steps));
+    };
+    if axes.len() != starts.len() {
+        return Err(SliceError::LengthMismatch("axes"));
+    }
+
+    // The bounds are given for every leading axis, those that aren't listed cover the whole axis.
+    let rank = match &node.inputs[0].ty {
+        ArgType::Tensor(tensor) => tensor.dim as i64,
+        _ => return Err(SliceError::NotTensor),
+    };
+    let axes = axes
+        .iter()
+        .map(|axis| {
+            let normalized = if *axis < 0 { axis + rank } else { *axis };
+            match (0..rank).contains(&normalized) {
+                true => Ok(normalized as usize),
+                false => Err(SliceError::AxisOutOfBounds { axis: *axis, rank }),
+            }
+        })
+        .collect::<Result<Vec<_>, _>>()?;
+    let num_axes = axes.iter().max().map_or(0, |axis| axis + 1);
+
+    let mut full_starts = vec![0; num_axes];
+    let mut full_ends = vec![i64::MAX; num_axes];
+    let mut full_steps = vec![1; num_axes];
+    for (i, axis) in axes.into_iter().enumerate() {
+        full_starts[axis] = starts[i];
+        full_ends[axis] = ends[i];
+        full_steps[axis] = steps[i];
+    }
+
+    Ok((full_starts, full_ends, full_steps))
 }
 
 pub fn transpose_config(curr: &Node) -> Vec<i64> {
@@ -1166,18 +1529,19 @@ pub fn transpose_config(curr: &Node) -> Vec<i64> {
 }
 
 pub fn squeeze_config(curr: &Node) -> Vec<i64> {
-    let axes = curr
-        .attrs
-        .iter()
-        .filter_map(|(key, value)| {
-            if key == "axes" {
-                Some(value.clone().into_i64s())
-            } else {
-                None
-            }
-        })
-        .next()
-        .unwrap_or_else(Vec::new);
+    // Since opset 13, the axes are given by the optional second input.
+    let axes = if curr.opset >= OpsetVersion(13) {
+        match curr.inputs.get(1).map(|input| &input.value) {
+            Some(Some(Data::Int64s(axes))) => axes.clone(),
+            Some(_) => panic!("Squeeze: axes must be a constant int64 tensor"),
+            None => Vec::new(),
+        }
+    } else {
+        curr.attrs
+            .get("axes")
+            .map(|axes| axes.clone().into_i64s())
+            .unwrap_or_default()
+    };
 
     match curr.inputs.first().unwrap().clone().ty {
         ArgType::Tensor(tensor) => tensor,
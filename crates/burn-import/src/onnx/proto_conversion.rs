@@ -5,11 +5,13 @@ use crate::onnx::ir::TensorType;
 use super::from_onnx::GraphData;
 use super::ir::Dim;
 use super::ir::{
-    ArgType, Argument, AttributeValue, Attributes, Data, ElementType, Node, NodeType, Tensor,
+    ArgType, Argument, AttributeValue, Attributes, Data, ElementType, Node, NodeType, OpsetVersion,
+    Tensor,
 };
 use super::protos::{
     attribute_proto::AttributeType, tensor_proto::DataType, tensor_shape_proto::dimension::Value,
-    type_proto, AttributeProto, NodeProto, TensorProto, TensorShapeProto, ValueInfoProto,
+    type_proto, AttributeProto, ModelProto, NodeProto, TensorProto, TensorShapeProto,
+    ValueInfoProto,
 };
 
 use bytemuck::cast_slice;
@@ -70,6 +72,11 @@ impl TryFrom<TensorProto> for Tensor {
                 assert!(!tensor.raw_data.is_empty());
                 Data::Bools(tensor.raw_data.iter().map(|x| *x != 0).collect())
             }),
+            // Strings are kept so that the nodes using them are reported as unsupported.
+            DataType::STRING => (
+                ElementType::String,
+                Data::Strings(to_string_vec(tensor.string_data)),
+            ),
             // TODO : Add more types
             _ => {
                 return Err(ParseError::VariantNotFound);
@@ -111,6 +118,7 @@ impl TryFrom<&type_proto::Tensor> for Tensor {
             DataType::INT64 => ElementType::Int64,
             DataType::DOUBLE => ElementType::Float64,
             DataType::BOOL => ElementType::Bool,
+            DataType::STRING => ElementType::String,
 
             // TODO : Add more types
             _ => {
@@ -180,7 +188,17 @@ pub fn convert_vec_attrs_proto(attrs: Vec<AttributeProto>) -> Attributes {
     result
 }
 
-pub fn convert_node_proto(node: &NodeProto, graph_data: &GraphData) -> Node {
+/// Returns the version of the default operator set imported by the model.
+pub fn convert_opset_version(model: &ModelProto) -> OpsetVersion {
+    model
+        .opset_import
+        .iter()
+        .find(|opset| opset.domain.is_empty() || opset.domain == "ai.onnx")
+        .map(|opset| OpsetVersion(opset.version))
+        .unwrap_or_default()
+}
+
+pub fn convert_node_proto(node: &NodeProto, graph_data: &GraphData, opset: OpsetVersion) -> Node {
     let name = node.name.clone();
 
     log::debug!("Converting ONNX node with type {:?}", node.op_type.as_str());
@@ -203,6 +221,7 @@ pub fn convert_node_proto(node: &NodeProto, graph_data: &GraphData) -> Node {
         inputs,
         outputs,
         attrs,
+        opset,
     }
 }
 
@@ -237,6 +256,7 @@ impl TryFrom<ValueInfoProto> for Argument {
             DataType::INT64 => ElementType::Int64,
             DataType::DOUBLE => ElementType::Float64,
             DataType::BOOL => ElementType::Bool,
+            DataType::STRING => ElementType::String,
             _ => {
                 return Err(ParseError::VariantNotFound);
             }
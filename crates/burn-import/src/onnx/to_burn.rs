@@ -9,7 +9,6 @@ use burn::{
     record::{FullPrecisionSettings, HalfPrecisionSettings, PrecisionSettings},
     tensor::{Element, TensorData},
 };
-use log::warn;
 
 use crate::{
     burn::{
@@ -26,7 +25,10 @@ use crate::{
             conv1d::Conv1dNode,
             conv2d::Conv2dNode,
             conv_transpose_2d::ConvTranspose2dNode,
+            cumsum::{CumSumAxis, CumSumNode},
+            depth_to_space::DepthToSpaceNode,
             dropout::DropoutNode,
+            einsum::EinsumNode,
             expand::ExpandNode,
             gather::GatherNode,
             gather_elements::GatherElementsNode,
@@ -37,15 +39,21 @@ use crate::{
             matmul::MatmulNode,
             max_pool1d::MaxPool1dNode,
             max_pool2d::MaxPool2dNode,
+            max_unpool2d::MaxUnpool2dNode,
+            nonzero::NonZeroNode,
+            pad::PadNode,
             prelu::PReluNode,
             random_normal::RandomNormalNode,
             random_uniform::RandomUniformNode,
             range::RangeNode,
             reshape::ReshapeNode,
             resize::{ResizeNode, ResizeOptions},
+            scatter_elements::ScatterElementsNode,
             slice::SliceNode,
+            space_to_depth::SpaceToDepthNode,
             squeeze::SqueezeNode,
             sum::SumNode,
+            trilu::{TriluDiagonal, TriluNode},
             unary::UnaryNode,
             unsqueeze::UnsqueezeNode,
         },
@@ -61,7 +69,7 @@ use crate::{
 };
 
 use super::{
-    from_onnx::parse_onnx,
+    from_onnx::{parse_onnx_with, OnnxGraphBuilder},
     ir::{self, ArgType, Argument, Data, ElementType, OnnxGraph},
     op_configuration::{
         avg_pool2d_config, clip_config, concat_config, dropout_config, reshape_config,
@@ -71,6 +79,21 @@ use super::{
 
 pub use crate::burn::graph::RecordType;
 
+/// The nodes and inputs of an ONNX graph that can't be converted to a Burn graph.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct UnsupportedOpsError {
+    /// One message per unsupported node or input, naming it.
+    pub errors: Vec<String>,
+}
+
+impl core::fmt::Display for UnsupportedOpsError {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        write!(f, "Unsupported ops:\n{}", self.errors.join("\n"))
+    }
+}
+
+impl std::error::Error for UnsupportedOpsError {}
+
 /// Generate code and states from `.onnx` files and save them to the `out_dir`.
 #[derive(Debug, Default)]
 pub struct ModelGen {
@@ -81,6 +104,7 @@ pub struct ModelGen {
     half_precision: bool,
     record_type: RecordType,
     embed_states: bool,
+    collapse_slices: bool,
 }
 
 impl ModelGen {
@@ -156,6 +180,18 @@ impl ModelGen {
         self
     }
 
+    /// Specify whether to fuse the slices taking a single index of an axis with the squeeze
+    /// removing that axis.
+    ///
+    /// # Arguments
+    ///
+    /// * `collapse_slices` - If true, `x[:, k]` is generated as a single slice collapsing the
+    ///   axis. Otherwise, the slice and the squeeze are generated as two nodes.
+    pub fn collapse_slices(&mut self, collapse_slices: bool) -> &mut Self {
+        self.collapse_slices = collapse_slices;
+        self
+    }
+
     /// Run code generation.
     fn run(&self, is_build_script: bool) {
         log::info!("Starting to convert ONNX to Burn");
@@ -196,7 +232,8 @@ impl ModelGen {
         log::debug!("Development mode: {:?}", self.development);
         log::debug!("Output file: {:?}", out_file);
 
-        let graph = parse_onnx(input.as_ref());
+        let builder = OnnxGraphBuilder::default().with_collapse_slices(self.collapse_slices);
+        let graph = parse_onnx_with(input.as_ref(), builder);
 
         if self.development {
             // export the graph
@@ -234,12 +271,51 @@ impl ModelGen {
 
 impl OnnxGraph {
     /// Converts ONNX graph to Burn graph.
+    ///
+    /// # Panics
+    ///
+    /// If the graph can't be converted, with every error reported by
+    /// [try_into_burn](Self::try_into_burn).
     pub fn into_burn<PS: PrecisionSettings + 'static>(self) -> BurnGraph<PS> {
+        self.try_into_burn()
+            .unwrap_or_else(|error| panic!("{error}"))
+    }
+
+    /// Converts ONNX graph to Burn graph, or returns every node and input that can't be
+    /// converted.
+    pub fn try_into_burn<PS: PrecisionSettings + 'static>(
+        self,
+    ) -> Result<BurnGraph<PS>, UnsupportedOpsError> {
         let mut graph = BurnGraph::<PS>::default();
+        let mut errors = vec![];
 
-        let mut unsupported_ops = vec![];
+        for input in self.inputs.iter().filter(|input| is_string(input)) {
+            errors.push(format!(
+                "input `{}` is a string tensor, which burn-import does not support",
+                input.name
+            ));
+        }
 
         for node in self.nodes {
+            // Nodes reading or producing string tensors are reported instead of being converted,
+            // since the numeric tensor kinds can't represent them.
+            if is_string_op(&node.node_type) {
+                errors.push(unsupported_node_error(&node));
+                continue;
+            }
+            if let Some(arg) = node
+                .inputs
+                .iter()
+                .chain(&node.outputs)
+                .find(|arg| is_string(arg))
+            {
+                errors.push(format!(
+                    "node `{}` uses the string tensor `{}`, which burn-import does not support",
+                    node.name, arg.name
+                ));
+                continue;
+            }
+
             match node.node_type {
                 NodeType::Add => graph.register(Self::add_conversion(node)),
                 NodeType::ArgMax => graph.register(Self::argmax_conversion(node)),
@@ -257,12 +333,19 @@ impl OnnxGraph {
                 NodeType::Max => graph.register(Self::max_conversion(node)),
                 NodeType::MaxPool1d => graph.register(Self::max_pool1d_conversion(node)),
                 NodeType::MaxPool2d => graph.register(Self::max_pool2d_conversion(node)),
+                NodeType::MaxUnpool => graph.register(Self::max_unpool2d_conversion(node)),
                 NodeType::PRelu => graph.register(Self::prelu_conversion::<PS>(node)),
+                NodeType::Pad => graph.register(Self::pad_conversion(node)),
                 NodeType::AveragePool1d => graph.register(Self::avg_pool_1d_conversion(node)),
                 NodeType::AveragePool2d => graph.register(Self::avg_pool_2d_conversion(node)),
                 NodeType::MatMul => graph.register(Self::matmul_conversion(node)),
                 NodeType::Neg => graph.register(Self::neg_conversion(node)),
                 NodeType::Not => graph.register(Self::not_conversion(node)),
+                NodeType::NonZero => graph.register(Self::nonzero_conversion(node)),
+                NodeType::And => graph.register(Self::and_conversion(node)),
+                NodeType::Mod => graph.register(Self::mod_conversion(node)),
+                NodeType::Or => graph.register(Self::or_conversion(node)),
+                NodeType::Xor => graph.register(Self::xor_conversion(node)),
                 NodeType::Greater => graph.register(Self::greater_conversion(node)),
                 NodeType::GreaterOrEqual => graph.register(Self::greater_or_equal_conversion(node)),
                 NodeType::Less => graph.register(Self::less_conversion(node)),
@@ -281,6 +364,10 @@ impl OnnxGraph {
                 NodeType::GatherElements => graph.register(Self::gather_elements_conversion(node)),
                 NodeType::Log => graph.register(Self::log_conversion(node)),
                 NodeType::LeakyRelu => graph.register(Self::leaky_relu_conversion(node)),
+                NodeType::HardSigmoid => graph.register(Self::hard_sigmoid_conversion(node)),
+                NodeType::HardSwish => graph.register(Self::hard_swish_conversion(node)),
+                NodeType::Mish => graph.register(Self::mish_conversion(node)),
+                NodeType::Softplus => graph.register(Self::softplus_conversion(node)),
                 NodeType::LogSoftmax => graph.register(Self::log_softmax_conversion(node)),
                 NodeType::Softmax => graph.register(Self::softmax_conversion(node)),
                 NodeType::Sqrt => graph.register(Self::sqrt_conversion(node)),
@@ -303,7 +390,10 @@ impl OnnxGraph {
                 NodeType::Transpose => graph.register(Self::transpose_conversion(node)),
                 NodeType::Concat => graph.register(Self::concat_conversion(node)),
                 NodeType::Cast => graph.register(Self::cast_conversion(node)),
+                NodeType::CumSum => graph.register(Self::cumsum_conversion(node)),
+                NodeType::DepthToSpace => graph.register(Self::depth_to_space_conversion(node)),
                 NodeType::Dropout => graph.register(Self::dropout_conversion(node)),
+                NodeType::Einsum => graph.register(Self::einsum_conversion(node)),
                 NodeType::GlobalAveragePool => {
                     graph.register(Self::global_avg_pool_conversion(node))
                 }
@@ -314,15 +404,20 @@ impl OnnxGraph {
                 NodeType::Unsqueeze => graph.register(Self::unsqueeze_conversion(node)),
                 NodeType::Where => graph.register(Self::where_conversion(node)),
                 NodeType::Sign => graph.register(Self::sign_conversion(node)),
+                NodeType::SpaceToDepth => graph.register(Self::space_to_depth_conversion(node)),
                 NodeType::Squeeze => graph.register(Self::squeeze_conversion(node)),
+                NodeType::Trilu => graph.register(Self::trilu_conversion(node)),
+                NodeType::ScatterElements => {
+                    graph.register(Self::scatter_elements_conversion(node))
+                }
                 NodeType::RandomUniform => graph.register(Self::random_uniform_conversion(node)),
                 NodeType::RandomNormal => graph.register(Self::random_normal_conversion(node)),
-                node_type => unsupported_ops.push(node_type),
+                _ => errors.push(unsupported_node_error(&node)),
             }
         }
 
-        if !unsupported_ops.is_empty() {
-            panic!("Unsupported ops: {:?}", unsupported_ops);
+        if !errors.is_empty() {
+            return Err(UnsupportedOpsError { errors });
         }
 
         // Get input and output names
@@ -340,7 +435,7 @@ impl OnnxGraph {
         // Register inputs and outputs with the graph
         graph.register_input_output(input_names, output_names);
 
-        graph
+        Ok(graph)
     }
 
     fn constant_conversion<PS: PrecisionSettings>(node: Node) -> ConstantNode {
@@ -412,11 +507,16 @@ impl OnnxGraph {
             .map(|val| val.clone().into_f32() as f64)
             .unwrap_or(0.0f64);
 
-        if node.attrs.contains_key("seed") {
-            warn!("seed attribute is not supported!");
-        }
+        let seed = node
+            .attrs
+            .get("seed")
+            .map(|val| seed_bits(val.clone().into_f32()));
+        let node = RandomUniformNode::new(output_type, low, high);
 
-        RandomUniformNode::new(output_type, low, high)
+        match seed {
+            Some(seed) => node.with_seed(seed),
+            None => node,
+        }
     }
 
     fn random_normal_conversion(node: Node) -> RandomNormalNode {
@@ -439,11 +539,16 @@ impl OnnxGraph {
             .map(|val| val.clone().into_f32() as f64)
             .unwrap_or(1.0f64);
 
-        if node.attrs.contains_key("seed") {
-            warn!("seed attribute is not supported!");
-        }
+        let seed = node
+            .attrs
+            .get("seed")
+            .map(|val| seed_bits(val.clone().into_f32()));
+        let node = RandomNormalNode::new(output_type, mean, scale);
 
-        RandomNormalNode::new(output_type, mean, scale)
+        match seed {
+            Some(seed) => node.with_seed(seed),
+            None => node,
+        }
     }
 
     fn add_conversion(node: Node) -> BinaryNode {
@@ -517,6 +622,35 @@ impl OnnxGraph {
         UnaryNode::leaky_relu(input, output, alpha)
     }
 
+    fn hard_sigmoid_conversion(node: Node) -> UnaryNode {
+        let input = node.inputs.first().unwrap().to_type();
+        let output = node.outputs.first().unwrap().to_type();
+        let (alpha, beta) = hard_sigmoid_config(&node);
+
+        UnaryNode::hard_sigmoid(input, output, alpha, beta)
+    }
+
+    fn hard_swish_conversion(node: Node) -> UnaryNode {
+        let input = node.inputs.first().unwrap().to_type();
+        let output = node.outputs.first().unwrap().to_type();
+
+        UnaryNode::hard_swish(input, output)
+    }
+
+    fn mish_conversion(node: Node) -> UnaryNode {
+        let input = node.inputs.first().unwrap().to_type();
+        let output = node.outputs.first().unwrap().to_type();
+
+        UnaryNode::mish(input, output)
+    }
+
+    fn softplus_conversion(node: Node) -> UnaryNode {
+        let input = node.inputs.first().unwrap().to_type();
+        let output = node.outputs.first().unwrap().to_type();
+
+        UnaryNode::softplus(input, output)
+    }
+
     fn relu_conversion(node: Node) -> UnaryNode {
         let input = node.inputs.first().unwrap().to_type();
         let output = node.outputs.first().unwrap().to_type();
@@ -528,7 +662,11 @@ impl OnnxGraph {
         let input = node.inputs.first().unwrap().to_type();
         let output = node.outputs.first().unwrap().to_type();
 
-        UnaryNode::gelu(input, output)
+        if gelu_config(&node) {
+            UnaryNode::gelu_tanh(input, output)
+        } else {
+            UnaryNode::gelu(input, output)
+        }
     }
 
     fn log_conversion(node: Node) -> UnaryNode {
@@ -548,8 +686,8 @@ impl OnnxGraph {
 
     fn gather_conversion(node: Node) -> GatherNode {
         let input = node.inputs.first().unwrap().to_tensor_type();
-        let index = node.inputs.get(1).unwrap().to_tensor_type();
-        let output = node.outputs.first().unwrap().to_tensor_type();
+        let index = node.inputs.get(1).unwrap().to_type();
+        let output = node.outputs.first().unwrap().to_type();
         let dim = gather_config(&node);
 
         GatherNode::new(input, index, output, dim)
@@ -713,9 +851,15 @@ impl OnnxGraph {
     fn slice_conversion(node: Node) -> SliceNode {
         let input = node.inputs.first().unwrap().to_tensor_type();
         let output = node.outputs.first().unwrap().to_tensor_type();
-        let (starts, ends) = slice_config(&node);
+        let (starts, ends, steps) = slice_config(&node).unwrap_or_else(|error| panic!("{error}"));
 
-        SliceNode::new(input, output, starts, ends)
+        let slice = SliceNode::new(input, output, starts, ends).with_steps(steps);
+
+        // Set when a following squeeze of the sliced axis was fused into the slice.
+        match node.attrs.contains_key("collapse_axis") {
+            true => slice.with_collapse_axis(),
+            false => slice,
+        }
     }
 
     fn sum_conversion(node: Node) -> SumNode {
@@ -809,6 +953,18 @@ impl OnnxGraph {
         DropoutNode::new(name, input, output, config)
     }
 
+    fn einsum_conversion(node: Node) -> EinsumNode {
+        let inputs = node
+            .inputs
+            .iter()
+            .map(|input| input.to_tensor_type())
+            .collect();
+        let output = node.outputs.first().unwrap().to_tensor_type();
+        let equation = einsum_config(&node);
+
+        EinsumNode::new(inputs, output, equation)
+    }
+
     fn batch_norm_conversion<PS: PrecisionSettings>(node: Node) -> BatchNormNode {
         let config = batch_norm_config(&node);
         let input = node.inputs.first().unwrap().to_tensor_type();
@@ -882,6 +1038,7 @@ impl OnnxGraph {
 
         let name = &node.name;
         Conv2dNode::new(name, input, output, weight, bias, config)
+            .with_pad_mode(conv2d_pad_mode(&node))
     }
 
     fn max_pool1d_conversion(node: Node) -> MaxPool1dNode {
@@ -902,6 +1059,15 @@ impl OnnxGraph {
         MaxPool2dNode::new(name, input, output, config)
     }
 
+    fn max_unpool2d_conversion(node: Node) -> MaxUnpool2dNode {
+        let input = node.inputs.first().unwrap().to_tensor_type();
+        let indices = node.inputs.get(1).unwrap().to_tensor_type();
+        let output = node.outputs.first().unwrap().to_tensor_type();
+        let config = max_unpool2d_config(&node);
+
+        MaxUnpool2dNode::new(input, indices, output, config)
+    }
+
     fn prelu_conversion<PS: PrecisionSettings>(node: Node) -> PReluNode {
         let input = node.inputs.first().unwrap().to_tensor_type();
         let output = node.outputs.first().unwrap().to_tensor_type();
@@ -986,6 +1152,45 @@ impl OnnxGraph {
         UnaryNode::not(input, output)
     }
 
+    fn nonzero_conversion(node: Node) -> NonZeroNode {
+        let input = node.inputs.first().unwrap().to_tensor_type();
+        let output = node.outputs.first().unwrap().to_tensor_type();
+
+        NonZeroNode::new(input, output)
+    }
+
+    fn mod_conversion(node: Node) -> BinaryNode {
+        let lhs = node.inputs.first().unwrap().to_type();
+        let rhs = node.inputs.get(1).unwrap().to_type();
+        let output = node.outputs.first().unwrap().to_type();
+
+        match mod_config(&node) {
+            true => BinaryNode::fmod(lhs, rhs, output),
+            false => BinaryNode::remainder(lhs, rhs, output),
+        }
+    }
+
+    fn and_conversion(node: Node) -> BinaryNode {
+        let lhs = node.inputs.first().unwrap().to_type();
+        let rhs = node.inputs.get(1).unwrap().to_type();
+        let output = node.outputs.first().unwrap().to_type();
+        BinaryNode::bool_and(lhs, rhs, output)
+    }
+
+    fn or_conversion(node: Node) -> BinaryNode {
+        let lhs = node.inputs.first().unwrap().to_type();
+        let rhs = node.inputs.get(1).unwrap().to_type();
+        let output = node.outputs.first().unwrap().to_type();
+        BinaryNode::bool_or(lhs, rhs, output)
+    }
+
+    fn xor_conversion(node: Node) -> BinaryNode {
+        let lhs = node.inputs.first().unwrap().to_type();
+        let rhs = node.inputs.get(1).unwrap().to_type();
+        let output = node.outputs.first().unwrap().to_type();
+        BinaryNode::bool_xor(lhs, rhs, output)
+    }
+
     fn greater_conversion(node: Node) -> BinaryNode {
         let lhs = node.inputs.first().unwrap().to_type();
         let rhs = node.inputs.get(1).unwrap().to_type();
@@ -1046,6 +1251,96 @@ impl OnnxGraph {
 
         SqueezeNode::new(input, output, axes)
     }
+
+    fn trilu_conversion(node: Node) -> TriluNode {
+        let input = node.inputs.first().unwrap().to_tensor_type();
+        let output = node.outputs.first().unwrap().to_tensor_type();
+        let upper = trilu_config(&node);
+
+        // The diagonal offset is an optional input, either constant or provided at runtime as a
+        // 0-d tensor, which is a scalar of the forward pass.
+        let diagonal = match node.inputs.get(1).filter(|arg| !arg.name.is_empty()) {
+            None => TriluDiagonal::Static(0),
+            Some(arg) => match (&arg.value, arg.to_type()) {
+                (Some(Data::Int64(k)), _) => TriluDiagonal::Static(*k),
+                (Some(Data::Int64s(k)), _) if k.len() == 1 => TriluDiagonal::Static(k[0]),
+                (Some(_), _) => panic!("Trilu: k must be a single int64 value"),
+                (None, Type::Scalar(k)) => TriluDiagonal::Runtime(k),
+                (None, _) => panic!("Trilu: k must be a 0-d tensor"),
+            },
+        };
+
+        TriluNode::new(input, output, upper, diagonal)
+    }
+
+    fn scatter_elements_conversion(node: Node) -> ScatterElementsNode {
+        let data = node.inputs.first().unwrap().to_tensor_type();
+        let indices = node.inputs.get(1).unwrap().to_tensor_type();
+        let updates = node.inputs.get(2).unwrap().to_tensor_type();
+        let output = node.outputs.first().unwrap().to_tensor_type();
+        let (axis, reduction) = scatter_elements_config(&node);
+
+        ScatterElementsNode::new(data, indices, updates, output, axis, reduction)
+    }
+
+    fn pad_conversion(node: Node) -> PadNode {
+        let input = node.inputs.first().unwrap().to_tensor_type();
+        let output = node.outputs.first().unwrap().to_tensor_type();
+        let (pads, mode, value) = pad_config(&node);
+
+        PadNode::new(input, output, pads, mode, value)
+    }
+
+    fn cumsum_conversion(node: Node) -> CumSumNode {
+        let input = node.inputs.first().unwrap().to_tensor_type();
+        let output = node.outputs.first().unwrap().to_tensor_type();
+        let (exclusive, reverse) = cumsum_config(&node);
+
+        // The axis is a required input, either constant or provided at runtime.
+        let arg = node
+            .inputs
+            .get(1)
+            .expect("CumSum: axis input must be present");
+        let axis = match &arg.value {
+            Some(Data::Int64(axis)) => Some(*axis),
+            Some(Data::Int32(axis)) => Some(*axis as i64),
+            Some(Data::Int64s(axis)) if axis.len() == 1 => Some(axis[0]),
+            Some(Data::Int32s(axis)) if axis.len() == 1 => Some(axis[0] as i64),
+            Some(_) => panic!("CumSum: axis must be a single integer value"),
+            None => None,
+        };
+
+        let axis = match (axis, arg.to_type()) {
+            (Some(axis), _) => {
+                let rank = input.dim as i64;
+                assert!(
+                    (-rank..rank).contains(&axis),
+                    "CumSum: axis {axis} is out of range for a tensor of rank {rank}"
+                );
+                CumSumAxis::Static(axis.rem_euclid(rank) as usize)
+            }
+            (None, Type::Scalar(axis)) => CumSumAxis::Runtime(axis),
+            (None, _) => panic!("CumSum: axis must be a scalar"),
+        };
+
+        CumSumNode::new(input, output, axis, exclusive, reverse)
+    }
+
+    fn depth_to_space_conversion(node: Node) -> DepthToSpaceNode {
+        let input = node.inputs.first().unwrap().to_tensor_type();
+        let output = node.outputs.first().unwrap().to_tensor_type();
+        let (blocksize, mode) = depth_to_space_config(&node);
+
+        DepthToSpaceNode::new(input, output, blocksize, mode)
+    }
+
+    fn space_to_depth_conversion(node: Node) -> SpaceToDepthNode {
+        let input = node.inputs.first().unwrap().to_tensor_type();
+        let output = node.outputs.first().unwrap().to_tensor_type();
+        let blocksize = space_to_depth_config(&node);
+
+        SpaceToDepthNode::new(input, output, blocksize)
+    }
 }
 
 /// Extract data from node states and convert it to `TensorData`.
@@ -1092,6 +1387,14 @@ fn serialize_data<E: Element>(data: Data, shape: Vec<usize>) -> TensorData {
     }
 }
 
+/// Converts the float seed of an ONNX random node to the seed of its RNG.
+///
+/// The bits of the float are used, so that negative and fractional seeds give their own sequence
+/// instead of being truncated or wrapped to an integer.
+fn seed_bits(seed: f32) -> u64 {
+    seed.to_bits() as u64
+}
+
 impl Argument {
     pub fn to_tensor_type(&self) -> TensorType {
         match &self.ty {
@@ -1140,6 +1443,37 @@ impl Argument {
     }
 }
 
+/// Whether the node type operates on string tensors, which have no burn equivalent.
+fn is_string_op(node_type: &NodeType) -> bool {
+    matches!(
+        node_type,
+        NodeType::StringNormalizer | NodeType::TfIdfVectorizer
+    )
+}
+
+/// Whether the argument holds strings, which have no Burn tensor kind.
+fn is_string(arg: &Argument) -> bool {
+    match &arg.ty {
+        ArgType::Tensor(tensor) => matches!(tensor.elem_type, ElementType::String),
+        ArgType::Scalar(elem_type) => matches!(elem_type, ElementType::String),
+        ArgType::Shape(_) => false,
+    }
+}
+
+/// Describes an unsupported node with its name and operator.
+fn unsupported_node_error(node: &Node) -> String {
+    let mut message = format!(
+        "node `{}` uses {} which burn-import does not support",
+        node.name, node.node_type
+    );
+
+    if is_string_op(&node.node_type) {
+        message.push_str(" (string operations can't be represented with numeric tensors)");
+    }
+
+    message
+}
+
 impl From<&ElementType> for ScalarKind {
     fn from(elem_type: &ElementType) -> Self {
         match elem_type {
@@ -1166,3 +1500,159 @@ impl From<ElementType> for TensorKind {
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::burn::node::slice::SliceError;
+    use crate::onnx::ir::{Argument, Attributes, OpsetVersion, TensorType as OnnxTensorType};
+
+    fn node(node_type: NodeType, name: &str) -> Node {
+        Node {
+            node_type,
+            name: name.to_string(),
+            inputs: vec![],
+            outputs: vec![],
+            attrs: Attributes::new(),
+            opset: OpsetVersion::default(),
+        }
+    }
+
+    #[test]
+    fn unsupported_string_op_error_names_the_node() {
+        let message = unsupported_node_error(&node(NodeType::TfIdfVectorizer, "tfidf1"));
+
+        assert_eq!(
+            message,
+            "node `tfidf1` uses TfIdfVectorizer which burn-import does not support \
+             (string operations can't be represented with numeric tensors)"
+        );
+    }
+
+    #[test]
+    #[should_panic(
+        expected = "node `tfidf1` uses TfIdfVectorizer which burn-import does not support"
+    )]
+    fn into_burn_reports_string_ops() {
+        let graph = OnnxGraph {
+            nodes: vec![
+                node(NodeType::StringNormalizer, "normalizer1"),
+                node(NodeType::TfIdfVectorizer, "tfidf1"),
+            ],
+            inputs: vec![],
+            outputs: vec![],
+        };
+
+        let _ = graph.into_burn::<FullPrecisionSettings>();
+    }
+
+    #[test]
+    #[should_panic(expected = "node `normalizer1` uses StringNormalizer")]
+    fn into_burn_reports_every_string_op() {
+        let graph = OnnxGraph {
+            nodes: vec![
+                node(NodeType::TfIdfVectorizer, "tfidf1"),
+                node(NodeType::StringNormalizer, "normalizer1"),
+            ],
+            inputs: vec![],
+            outputs: vec![],
+        };
+
+        let _ = graph.into_burn::<FullPrecisionSettings>();
+    }
+
+    fn tensor_input(name: &str, dim: usize, value: Option<Vec<i64>>) -> Argument {
+        Argument {
+            name: name.to_string(),
+            ty: ArgType::Tensor(OnnxTensorType {
+                elem_type: ElementType::Int64,
+                dim,
+                shape: None,
+            }),
+            passed: value.is_none(),
+            value: value.map(Data::Int64s),
+        }
+    }
+
+    #[test]
+    fn trilu_conversion_runtime_k_is_a_scalar() {
+        let mut trilu = node(NodeType::Trilu, "trilu1");
+        trilu.inputs = vec![tensor_input("input", 2, None), tensor_input("k", 0, None)];
+        trilu.outputs = vec![tensor_input("output", 2, None)];
+
+        let trilu = OnnxGraph::trilu_conversion(trilu);
+
+        assert!(matches!(
+            trilu.diagonal,
+            TriluDiagonal::Runtime(ScalarType { name, kind: ScalarKind::Int64 }) if name == "k"
+        ));
+    }
+
+    #[test]
+    fn seeds_are_neither_truncated_nor_wrapped() {
+        let seeds = [-1.0, -1.5, 1.0, 1.5, 0.0].map(seed_bits);
+
+        for (i, seed) in seeds.iter().enumerate() {
+            assert!(!seeds[i + 1..].contains(seed));
+        }
+        assert!(seeds.iter().all(|seed| *seed <= u32::MAX as u64));
+    }
+
+    #[test]
+    fn slice_conversion_honors_axes_input() {
+        // `x[:, ::-1]` on a rank 3 input.
+        let mut slice = node(NodeType::Slice, "slice1");
+        slice.inputs = vec![
+            tensor_input("input", 3, None),
+            tensor_input("starts", 1, Some(vec![-1])),
+            tensor_input("ends", 1, Some(vec![i64::MIN])),
+            tensor_input("axes", 1, Some(vec![-2])),
+            tensor_input("steps", 1, Some(vec![-1])),
+        ];
+        slice.outputs = vec![tensor_input("output", 3, None)];
+
+        let slice = OnnxGraph::slice_conversion(slice);
+
+        assert_eq!(slice.starts, vec![0, -1]);
+        assert_eq!(slice.ends, vec![i64::MAX, i64::MIN]);
+        assert_eq!(slice.steps, Some(vec![1, -1]));
+    }
+
+    #[test]
+    fn slice_config_reports_invalid_slices() {
+        let mut slice = node(NodeType::Slice, "slice1");
+        slice.inputs = vec![
+            tensor_input("input", 3, None),
+            tensor_input("starts", 1, Some(vec![0])),
+            tensor_input("ends", 1, Some(vec![2])),
+            tensor_input("axes", 1, Some(vec![3])),
+        ];
+
+        assert_eq!(
+            slice_config(&slice),
+            Err(SliceError::AxisOutOfBounds { axis: 3, rank: 3 })
+        );
+
+        slice.inputs.push(tensor_input("steps", 1, Some(vec![0])));
+        assert_eq!(slice_config(&slice), Err(SliceError::ZeroStep));
+
+        slice.inputs[2].value = None;
+        assert_eq!(slice_config(&slice), Err(SliceError::NotConstant("ends")));
+    }
+
+    #[test]
+    #[should_panic(expected = "Slice: step can't be zero")]
+    fn slice_conversion_panics_with_the_slice_error() {
+        let mut slice = node(NodeType::Slice, "slice1");
+        slice.inputs = vec![
+            tensor_input("input", 3, None),
+            tensor_input("starts", 1, Some(vec![0])),
+            tensor_input("ends", 1, Some(vec![2])),
+            tensor_input("axes", 1, Some(vec![0])),
+            tensor_input("steps", 1, Some(vec![0])),
+        ];
+        slice.outputs = vec![tensor_input("output", 3, None)];
+
+        let _ = OnnxGraph::slice_conversion(slice);
+    }
+}
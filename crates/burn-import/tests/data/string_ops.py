@@ -0,0 +1,48 @@
+#!/usr/bin/env python3
+
+# used to generate model: string_ops.onnx
+
+import onnx
+from onnx import TensorProto, helper
+
+
+def main():
+    normalizer = helper.make_node(
+        "StringNormalizer",
+        inputs=["text"],
+        outputs=["normalized"],
+        name="normalizer1",
+        case_change_action="LOWER",
+    )
+    tfidf = helper.make_node(
+        "TfIdfVectorizer",
+        inputs=["normalized"],
+        outputs=["features"],
+        name="tfidf1",
+        mode="TF",
+        min_gram_length=1,
+        max_gram_length=1,
+        max_skip_count=0,
+        ngram_counts=[0],
+        ngram_indexes=[0, 1],
+        pool_strings=["burn", "onnx"],
+    )
+    relu = helper.make_node("Relu", inputs=["features"], outputs=["output"], name="relu1")
+
+    graph = helper.make_graph(
+        [normalizer, tfidf, relu],
+        "string_ops",
+        [helper.make_tensor_value_info("text", TensorProto.STRING, [4])],
+        [helper.make_tensor_value_info("output", TensorProto.FLOAT, [2])],
+    )
+    model = helper.make_model(
+        graph, ir_version=8, opset_imports=[helper.make_opsetid("", 16)]
+    )
+    onnx.checker.check_model(model)
+
+    onnx.save(model, "string_ops.onnx")
+    print("Finished exporting model to string_ops.onnx")
+
+
+if __name__ == "__main__":
+    main()
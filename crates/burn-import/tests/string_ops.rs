@@ -0,0 +1,34 @@
+#![cfg(feature = "onnx")]
+
+use burn::record::FullPrecisionSettings;
+use burn_import::onnx::parse_onnx;
+
+/// `string_ops.onnx` lowercases a string input with `StringNormalizer`, counts two words with
+/// `TfIdfVectorizer` and applies `Relu` to the counts.
+#[test]
+fn string_model_reports_every_unsupported_node() {
+    let graph = parse_onnx("tests/data/string_ops.onnx".as_ref());
+
+    let error = graph
+        .try_into_burn::<FullPrecisionSettings>()
+        .expect_err("string operations should not be converted");
+
+    assert_eq!(
+        error.errors,
+        [
+            "input `input1` is a string tensor, which burn-import does not support",
+            "node `stringnormalizer1` uses StringNormalizer which burn-import does not support \
+             (string operations can't be represented with numeric tensors)",
+            "node `tfidfvectorizer1` uses TfIdfVectorizer which burn-import does not support \
+             (string operations can't be represented with numeric tensors)",
+        ]
+    );
+}
+
+#[test]
+#[should_panic(expected = "node `tfidfvectorizer1` uses TfIdfVectorizer")]
+fn string_model_into_burn_panics_with_every_error() {
+    let graph = parse_onnx("tests/data/string_ops.onnx".as_ref());
+
+    let _ = graph.into_burn::<FullPrecisionSettings>();
+}
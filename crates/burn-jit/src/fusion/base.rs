@@ -263,7 +263,7 @@ fn dynamic_inplace<R: JitRuntime>(
 
             let handle = &handles_inputs[pos];
 
-            if handle.handle.can_mut() && is_contiguous(&handle.strides) {
+            if handle.handle.can_mut() && is_contiguous(&desc.shape, &handle.strides) {
                 Some((pos, desc, input))
             } else {
                 None
@@ -318,7 +318,7 @@ fn dynamic_reading_strategy<R: JitRuntime>(
             continue;
         }
 
-        if is_contiguous(&handle.strides) {
+        if is_contiguous(&description_input.shape, &handle.strides) {
             settings
                 .reading_strategy
                 .push((input_id, ReadingStrategy::Plain));
@@ -327,10 +327,15 @@ fn dynamic_reading_strategy<R: JitRuntime>(
     settings
 }
 
-fn is_contiguous(strides: &[usize]) -> bool {
+fn is_contiguous(shape: &[usize], strides: &[usize]) -> bool {
     let mut current = 0;
 
-    for stride in strides.iter().rev() {
+    for (size, stride) in shape.iter().zip(strides.iter()).rev() {
+        // Expanded dimensions alias the same memory location.
+        if *stride == 0 && *size > 1 {
+            return false;
+        }
+
         if current > *stride {
             return false;
         }
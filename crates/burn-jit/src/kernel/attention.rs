@@ -0,0 +1,270 @@
+use crate::{
+    kernel::into_contiguous,
+    ops::{
+        expand,
+        numeric::{empty_device, zeros_device},
+    },
+    tensor::JitTensor,
+    FloatElement, JitRuntime,
+};
+use burn_cube::{calculate_cube_count_elemwise, frontend::TensorHandle, ir::CubeDim, prelude::*};
+use burn_tensor::Shape;
+
+/// Number of query rows handled by a cube, one per unit.
+const BLOCK_QUERIES: u32 = 32;
+/// Maximum number of keys staged in shared memory at once.
+const BLOCK_KEYS: usize = 32;
+/// Shared memory budget of the key and value tiles, the minimum guaranteed by WebGPU.
+const SHARED_MEMORY_SIZE: usize = 16384;
+
+/// Computes the attention output of a block of query rows with an online softmax.
+///
+/// The units of the cube load tiles of keys and values in shared memory together, then each unit
+/// visits the keys of the tile for its query row: the running maximum, the running sum of the
+/// exponentials and the output row are rescaled each time the maximum increases. The output row
+/// is used as the accumulator, so no intermediate tensor of the size of the scores is needed.
+///
+/// Masked keys (and the keys after the query with `causal`) are skipped. A query without any
+/// visible key gets a zero output row.
+#[cube(launch)]
+#[allow(clippy::too_many_arguments)]
+fn attention_kernel<F: Float>(
+    query: &Tensor<F>,
+    key: &Tensor<F>,
+    value: &Tensor<F>,
+    mask: &Tensor<UInt>,
+    output: &mut Tensor<F>,
+    scale: F32,
+    block_keys: Comptime<UInt>,
+    key_tile_size: Comptime<UInt>,
+    value_tile_size: Comptime<UInt>,
+    causal: Comptime<bool>,
+) {
+    let rank = query.rank();
+    let num_queries = query.shape(rank - UInt::new(2));
+    let num_keys = key.shape(rank - UInt::new(2));
+    let d_qk = query.shape(rank - UInt::new(1));
+    let d_v = value.shape(rank - UInt::new(1));
+    let block_keys = Comptime::runtime(block_keys);
+
+    let num_blocks = (num_queries + CUBE_DIM_X - UInt::new(1)) / CUBE_DIM_X;
+    let num_batches = output.len() / (num_queries * d_v);
+    let cube = CUBE_POS_Y * CUBE_COUNT_X + CUBE_POS_X;
+
+    // Every unit of a cube returns here or none does, so the barriers below are always reached.
+    if cube >= num_blocks * num_batches {
+        return;
+    }
+
+    let batch = cube / num_blocks;
+    let block_start = (cube % num_blocks) * CUBE_DIM_X;
+    let row = block_start + UNIT_POS_X;
+    let active = row < num_queries;
+
+    let query_offset = (batch * num_queries + row) * d_qk;
+    let key_offset = batch * num_keys * d_qk;
+    let value_offset = batch * num_keys * d_v;
+    let output_offset = (batch * num_queries + row) * d_v;
+    let scale = F::cast_from(scale);
+
+    // The mask is broadcast with null strides, so its offset is computed from the output index.
+    let mut mask_offset = row * mask.stride(rank - UInt::new(2));
+    for i in range(0u32, rank - UInt::new(2), Comptime::new(false)) {
+        mask_offset += output_offset / output.stride(i) % output.shape(i) * mask.stride(i);
+    }
+    let mask_stride = mask.stride(rank - UInt::new(1));
+
+    if active {
+        for j in range(0u32, d_v, Comptime::new(false)) {
+            output[output_offset + j] = F::new(0.);
+        }
+    }
+
+    // The causal mask is aligned on the first key: query `i` sees the keys `0..=i`, so the cube
+    // only loads the keys seen by its last query.
+    let mut end = num_keys;
+    let mut row_end = num_keys;
+    if Comptime::get(causal) {
+        end = UInt::min(UInt::min(block_start + CUBE_DIM_X, num_queries), num_keys);
+        row_end = UInt::min(row + UInt::new(1), num_keys);
+    }
+
+    let mut shared_key = SharedMemory::<F>::new(Comptime::get(key_tile_size));
+    let mut shared_value = SharedMemory::<F>::new(Comptime::get(value_tile_size));
+
+    let mut max = F::new(0.);
+    let mut sum = F::new(0.);
+    let mut count = UInt::new(0);
+
+    let num_tiles = (end + block_keys - UInt::new(1)) / block_keys;
+    for tile in range(0u32, num_tiles, Comptime::new(false)) {
+        let tile_start = tile * block_keys;
+        let tile_keys = UInt::min(block_keys, end - tile_start);
+
+        let key_elems = tile_keys * d_qk;
+        let num_loads = (key_elems + CUBE_DIM_X - UInt::new(1)) / CUBE_DIM_X;
+        for l in range(0u32, num_loads, Comptime::new(false)) {
+            let i = l * CUBE_DIM_X + UNIT_POS_X;
+            if i < key_elems {
+                shared_key[i] = key[key_offset + tile_start * d_qk + i];
+            }
+        }
+
+        let value_elems = tile_keys * d_v;
+        let num_loads = (value_elems + CUBE_DIM_X - UInt::new(1)) / CUBE_DIM_X;
+        for l in range(0u32, num_loads, Comptime::new(false)) {
+            let i = l * CUBE_DIM_X + UNIT_POS_X;
+            if i < value_elems {
+                shared_value[i] = value[value_offset + tile_start * d_v + i];
+            }
+        }
+
+        sync_units();
+
+        let mut visible_keys = UInt::new(0);
+        if active && row_end > tile_start {
+            visible_keys = UInt::min(tile_keys, row_end - tile_start);
+        }
+
+        for k in range(0u32, visible_keys, Comptime::new(false)) {
+            if mask[mask_offset + (tile_start + k) * mask_stride] == UInt::new(0) {
+                let mut score = F::new(0.);
+                for i in range(0u32, d_qk, Comptime::new(false)) {
+                    score += query[query_offset + i] * shared_key[k * d_qk + i];
+                }
+                score *= scale;
+
+                if count == UInt::new(0) {
+                    max = score;
+                }
+
+                // The exponentials are computed relative to the maximum for numerical stability.
+                let mut max_new = max;
+                if score > max_new {
+                    max_new = score;
+                }
+                let correction = F::exp(max - max_new);
+                let weight = F::exp(score - max_new);
+                sum = sum * correction + weight;
+
+                for j in range(0u32, d_v, Comptime::new(false)) {
+                    let index = output_offset + j;
+                    output[index] = output[index] * correction + weight * shared_value[k * d_v + j];
+                }
+
+                max = max_new;
+                count += UInt::new(1);
+            }
+        }
+
+        // The tiles are only overwritten once every unit is done with them.
+        sync_units();
+    }
+
+    if count > UInt::new(0) {
+        for j in range(0u32, d_v, Comptime::new(false)) {
+            output[output_offset + j] /= sum;
+        }
+    }
+}
+
+/// Computes the scaled dot-product attention `softmax(query @ key^T * scale) @ value` in a
+/// single kernel.
+///
+/// The tensors have the shape `[..., queries, d_qk]`, `[..., keys, d_qk]` and `[..., keys, d_v]`,
+/// with the same leading dimensions, and the output has the shape `[..., queries, d_v]`. The
+/// `mask` is broadcast to `[..., queries, keys]`, where `true` excludes the key from the
+/// attention of the query, as with [mask_fill](burn_tensor::Tensor::mask_fill) with negative
+/// infinity. The mask is read through broadcast strides and, with `causal`, query `i` only
+/// attends to the keys `0..=i`, so neither mask is materialized at the size of the scores.
+///
+/// The scores, the matmuls and the softmax are fused, so only the output is allocated and the
+/// attention takes a single launch instead of five. Each query row is handled by a unit visiting
+/// every key once, and the keys and the values are read from global memory once per block of
+/// queries, staged in shared memory tiles. As with the composed operations, a query with every
+/// key masked gets a zero output.
+///
+/// The shapes are validated before reaching the backend by
+/// [scaled_dot_product_attention](burn_tensor::module::scaled_dot_product_attention).
+///
+/// # Panics
+///
+/// If a single key and value don't fit in shared memory, see [attention_block_keys].
+pub fn scaled_dot_product_attention<R: JitRuntime, F: FloatElement, const D: usize>(
+    query: JitTensor<R, F, D>,
+    key: JitTensor<R, F, D>,
+    value: JitTensor<R, F, D>,
+    mask: Option<JitTensor<R, u32, D>>,
+    scale: f64,
+    causal: bool,
+) -> JitTensor<R, F, D> {
+    let num_queries = query.shape.dims[D - 2];
+    let num_keys = key.shape.dims[D - 2];
+    let block_keys = attention_block_keys::<F>(query.shape.dims[D - 1], value.shape.dims[D - 1]);
+    assert!(
+        block_keys > 0,
+        "The keys and the values are too large to be staged in shared memory"
+    );
+
+    let mut scores_dims = query.shape.dims;
+    scores_dims[D - 1] = num_keys;
+    let mut output_dims = query.shape.dims;
+    output_dims[D - 1] = value.shape.dims[D - 1];
+
+    let query = into_contiguous(query);
+    let key = into_contiguous(key);
+    let value = into_contiguous(value);
+
+    let output = empty_device::<R, F, D>(
+        query.client.clone(),
+        query.device.clone(),
+        Shape::new(output_dims),
+    );
+
+    let num_rows = output.shape.num_elements() / output_dims[D - 1].max(1);
+    if num_rows == 0 || output_dims[D - 1] == 0 || num_queries == 0 {
+        return output;
+    }
+
+    // Without a mask, a single unmasked element is broadcast to every score.
+    let mask = mask.unwrap_or_else(|| {
+        zeros_device(
+            query.client.clone(),
+            query.device.clone(),
+            Shape::new([1; D]),
+        )
+    });
+    let mask = expand(mask, Shape::new(scores_dims));
+
+    // The cubes are counted in a 2D grid, one per block of queries of each matrix.
+    let num_cubes = num_rows / num_queries * num_queries.div_ceil(BLOCK_QUERIES as usize);
+    let cube_count = calculate_cube_count_elemwise(num_cubes, 1);
+    let settings = KernelSettings::default().cube_dim(CubeDim::new(BLOCK_QUERIES, 1, 1));
+
+    attention_kernel_launch::<F::FloatPrimitive, R>(
+        query.client.clone(),
+        cube_count,
+        settings,
+        TensorHandle::new(&query.handle, &query.strides, &query.shape.dims),
+        TensorHandle::new(&key.handle, &key.strides, &key.shape.dims),
+        TensorHandle::new(&value.handle, &value.strides, &value.shape.dims),
+        TensorHandle::new(&mask.handle, &mask.strides, &mask.shape.dims),
+        TensorHandle::new(&output.handle, &output.strides, &output.shape.dims),
+        scale as f32,
+        UInt::new(block_keys as u32),
+        UInt::new((block_keys * query.shape.dims[D - 1]) as u32),
+        UInt::new((block_keys * output_dims[D - 1]) as u32),
+        causal,
+    );
+
+    output
+}
+
+/// Returns the number of keys staged at once in shared memory by the
+/// [attention kernel](scaled_dot_product_attention), with their values, or zero if a single key
+/// and value don't fit.
+pub fn attention_block_keys<F: FloatElement>(d_qk: usize, d_v: usize) -> usize {
+    let key_value_size = (d_qk + d_v).max(1) * core::mem::size_of::<F>();
+
+    usize::min(SHARED_MEMORY_SIZE / key_value_size, BLOCK_KEYS)
+}
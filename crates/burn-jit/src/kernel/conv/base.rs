@@ -0,0 +1,57 @@
+use burn_tensor::ops::ConvOptions;
+
+#[cfg(feature = "autotune")]
+use super::conv2d_autotune;
+use super::{can_use_winograd, conv2d_direct, conv2d_winograd, init_conv2d_output};
+use crate::{tensor::JitTensor, FloatElement, JitRuntime};
+
+/// The strategy to be used when launching a conv2d kernel.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum Conv2dStrategy {
+    /// The direct kernel, computing each output element from the input window.
+    Direct,
+    /// The Winograd F(2x2, 3x3) kernel when the convolution is supported, the direct kernel
+    /// otherwise.
+    Winograd,
+    #[cfg(feature = "autotune")]
+    /// Using autotune to chose between the Winograd and the direct kernels when the Winograd
+    /// kernel supports the convolution.
+    Autotune,
+}
+
+#[allow(clippy::derivable_impls)] // Necessary otherwise the feature flags dont' work.
+#[cfg(feature = "autotune")]
+impl Default for Conv2dStrategy {
+    fn default() -> Self {
+        Conv2dStrategy::Autotune
+    }
+}
+
+#[cfg(not(feature = "autotune"))]
+impl Default for Conv2dStrategy {
+    fn default() -> Self {
+        Conv2dStrategy::Direct
+    }
+}
+
+/// Launch a conv2d kernel using the given strategy.
+pub fn conv2d<R: JitRuntime, E: FloatElement>(
+    input: JitTensor<R, E, 4>,
+    weight: JitTensor<R, E, 4>,
+    bias: Option<JitTensor<R, E, 1>>,
+    options: ConvOptions<2>,
+    strategy: Conv2dStrategy,
+) -> JitTensor<R, E, 4> {
+    let output = init_conv2d_output(&input, &weight, &options);
+
+    if !can_use_winograd(&weight.shape, &options) {
+        return conv2d_direct(input, weight, bias, options, output);
+    }
+
+    match strategy {
+        Conv2dStrategy::Direct => conv2d_direct(input, weight, bias, options, output),
+        Conv2dStrategy::Winograd => conv2d_winograd(input, weight, bias, options, output),
+        #[cfg(feature = "autotune")]
+        Conv2dStrategy::Autotune => conv2d_autotune(input, weight, bias, options, output),
+    }
+}
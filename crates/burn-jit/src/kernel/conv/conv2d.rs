@@ -115,14 +115,12 @@ fn conv2d_kernel<F: Float>(
     output[ABSOLUTE_POS] = sum;
 }
 
-pub(crate) fn conv2d<R: JitRuntime, E: FloatElement>(
-    input: JitTensor<R, E, 4>,
-    weight: JitTensor<R, E, 4>,
-    bias: Option<JitTensor<R, E, 1>>,
-    options: ConvOptions<2>,
+/// Creates the output of a 2D convolution.
+pub(crate) fn init_conv2d_output<R: JitRuntime, E: FloatElement>(
+    input: &JitTensor<R, E, 4>,
+    weight: &JitTensor<R, E, 4>,
+    options: &ConvOptions<2>,
 ) -> JitTensor<R, E, 4> {
-    let input = into_contiguous(input);
-    let weight = into_contiguous(weight);
     let [batch_size, _, in_height, in_width] = input.shape.dims;
     let [out_channels, _, kernel_0, kernel_1] = weight.shape.dims;
 
@@ -141,13 +139,24 @@ pub(crate) fn conv2d<R: JitRuntime, E: FloatElement>(
         in_width,
     );
 
-    let shape_out = Shape::new([batch_size, out_channels, out_0, out_1]);
-
-    let output = empty_device(
+    empty_device(
         input.client.clone(),
         input.device.clone(),
-        shape_out.clone(),
-    );
+        Shape::new([batch_size, out_channels, out_0, out_1]),
+    )
+}
+
+/// Performs a 2D convolution with the direct kernel, writing in the given output.
+pub(crate) fn conv2d_direct<R: JitRuntime, E: FloatElement>(
+    input: JitTensor<R, E, 4>,
+    weight: JitTensor<R, E, 4>,
+    bias: Option<JitTensor<R, E, 1>>,
+    options: ConvOptions<2>,
+    output: JitTensor<R, E, 4>,
+) -> JitTensor<R, E, 4> {
+    let input = into_contiguous(input);
+    let weight = into_contiguous(weight);
+    let [_, _, kernel_0, kernel_1] = weight.shape.dims;
 
     let bias = match bias {
         Some(bias) => {
@@ -1,5 +1,11 @@
+mod base;
 mod conv2d;
 mod conv_transpose2d;
+mod tune;
+mod winograd;
 
+pub use base::*;
 pub(crate) use conv2d::*;
 pub(crate) use conv_transpose2d::*;
+pub use tune::*;
+pub(crate) use winograd::*;
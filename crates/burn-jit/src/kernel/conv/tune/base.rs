@@ -0,0 +1,173 @@
+use burn_compute::tune::{AutotuneOperation, AutotuneOperationSet};
+use burn_tensor::{ops::ConvOptions, ElementConversion};
+
+use crate::{
+    element::FloatElement,
+    kernel::{
+        conv::{conv2d_direct, conv2d_winograd},
+        prng::random_like_uniform,
+    },
+    ops::numeric::empty_device,
+    tensor::JitTensor,
+    tune_key::JitAutotuneKey,
+    JitRuntime,
+};
+
+use super::Conv2dAutotuneKey;
+
+/// Set of conv2d implementations available for autotune
+/// Autotune key is given by the closest upper power of 2 of the batch size, the channels and
+/// the spatial size of the input
+pub(crate) struct Conv2dAutotuneOperationSet<R: JitRuntime, E: FloatElement> {
+    key: JitAutotuneKey,
+    input: JitTensor<R, E, 4>,
+    weight: JitTensor<R, E, 4>,
+    bias: Option<JitTensor<R, E, 1>>,
+    options: ConvOptions<2>,
+    output: JitTensor<R, E, 4>,
+}
+
+impl<R: JitRuntime, E: FloatElement> Conv2dAutotuneOperationSet<R, E> {
+    fn new(
+        input: JitTensor<R, E, 4>,
+        weight: JitTensor<R, E, 4>,
+        bias: Option<JitTensor<R, E, 1>>,
+        options: ConvOptions<2>,
+        output: JitTensor<R, E, 4>,
+    ) -> Self {
+        Self {
+            key: JitAutotuneKey::Conv2d(Conv2dAutotuneKey::new(&input.shape, &weight.shape)),
+            input,
+            weight,
+            bias,
+            options,
+            output,
+        }
+    }
+}
+
+impl<R: JitRuntime, E: FloatElement> AutotuneOperationSet<JitAutotuneKey>
+    for Conv2dAutotuneOperationSet<R, E>
+{
+    fn key(&self) -> JitAutotuneKey {
+        self.key.clone()
+    }
+
+    fn autotunables(&self) -> Vec<Box<dyn AutotuneOperation>> {
+        let random_bounds: (E, E) = ((-10.0).elem::<E>(), (10.0).elem::<E>());
+        let input = random_like_uniform(&self.input, random_bounds.0, random_bounds.1);
+        let weight = random_like_uniform(&self.weight, random_bounds.0, random_bounds.1);
+        let bias = self
+            .bias
+            .as_ref()
+            .map(|bias| random_like_uniform(bias, random_bounds.0, random_bounds.1));
+
+        let output = empty_device(
+            self.output.client.clone(),
+            self.output.device.clone(),
+            self.output.shape.clone(),
+        );
+
+        vec![
+            Box::new(Conv2dDirect::new(
+                input.clone(),
+                weight.clone(),
+                bias.clone(),
+                self.options.clone(),
+                output.clone(),
+            )),
+            Box::new(Conv2dWinograd::new(
+                input,
+                weight,
+                bias,
+                self.options.clone(),
+                output,
+            )),
+        ]
+    }
+
+    fn fastest(self: Box<Self>, fastest_index: usize) -> Box<dyn AutotuneOperation> {
+        match fastest_index {
+            0 => Box::new(Conv2dDirect::new(
+                self.input,
+                self.weight,
+                self.bias,
+                self.options,
+                self.output,
+            )),
+            1 => Box::new(Conv2dWinograd::new(
+                self.input,
+                self.weight,
+                self.bias,
+                self.options,
+                self.output,
+            )),
+            _ => panic!("Fastest index is out of bound"),
+        }
+    }
+}
+
+/// Executes autotune on conv2d operations
+pub(crate) fn conv2d_autotune<R: JitRuntime, E: FloatElement>(
+    input: JitTensor<R, E, 4>,
+    weight: JitTensor<R, E, 4>,
+    bias: Option<JitTensor<R, E, 1>>,
+    options: ConvOptions<2>,
+    output: JitTensor<R, E, 4>,
+) -> JitTensor<R, E, 4> {
+    let client = input.client.clone();
+
+    let operation_set = Box::new(Conv2dAutotuneOperationSet::new(
+        input,
+        weight,
+        bias,
+        options,
+        output.clone(),
+    ));
+
+    client.autotune_execute(operation_set);
+
+    output
+}
+
+macro_rules! conv2d_tune_ops {
+    ($name:ident, $func:expr) => {
+        #[derive(new)]
+        pub(crate) struct $name<R: JitRuntime, E: FloatElement> {
+            input: JitTensor<R, E, 4>,
+            weight: JitTensor<R, E, 4>,
+            bias: Option<JitTensor<R, E, 1>>,
+            options: ConvOptions<2>,
+            output: JitTensor<R, E, 4>,
+        }
+
+        impl<R: JitRuntime, E: FloatElement> AutotuneOperation for $name<R, E> {
+            fn execute(self: Box<Self>) {
+                #[allow(clippy::redundant_closure_call)]
+                $func(
+                    self.input,
+                    self.weight,
+                    self.bias,
+                    self.options,
+                    self.output,
+                );
+            }
+
+            fn clone(&self) -> Box<dyn AutotuneOperation> {
+                Box::new(Self {
+                    input: self.input.clone(),
+                    weight: self.weight.clone(),
+                    bias: self.bias.clone(),
+                    options: self.options.clone(),
+                    output: self.output.clone(),
+                })
+            }
+        }
+    };
+}
+
+// Probably faster for few channels, where the transforms aren't amortized.
+conv2d_tune_ops!(Conv2dDirect, conv2d_direct);
+
+// Probably faster for many channels, with fewer multiplications per output.
+conv2d_tune_ops!(Conv2dWinograd, conv2d_winograd);
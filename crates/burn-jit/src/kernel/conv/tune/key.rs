@@ -0,0 +1,74 @@
+use crate::tune::anchor;
+use burn_tensor::Shape;
+use core::fmt::Debug;
+use serde::{Deserialize, Serialize};
+use std::{fmt::Display, hash::Hash};
+
+#[derive(Hash, Eq, PartialEq, Debug, Clone, Serialize, Deserialize)]
+/// Autotune key representative of conv2d versions
+pub struct Conv2dAutotuneKey {
+    anchored_batch: usize,
+    anchored_in_channels: usize,
+    anchored_out_channels: usize,
+    anchored_height: usize,
+    anchored_width: usize,
+}
+
+impl Display for Conv2dAutotuneKey {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        f.write_str(
+            format!(
+                "Conv2d - batch:{:?} in_channels:{:?} out_channels:{:?} height:{:?} width:{:?}",
+                self.anchored_batch,
+                self.anchored_in_channels,
+                self.anchored_out_channels,
+                self.anchored_height,
+                self.anchored_width
+            )
+            .as_str(),
+        )
+    }
+}
+
+impl Conv2dAutotuneKey {
+    /// Create a conv2d autotune key from the input and weight shapes
+    pub fn new(input_shape: &Shape<4>, weight_shape: &Shape<4>) -> Self {
+        let [batch_size, in_channels, height, width] = input_shape.dims;
+        let out_channels = weight_shape.dims[0];
+
+        Self {
+            anchored_batch: anchor(batch_size, Some(256)),
+            anchored_in_channels: anchor(in_channels, None),
+            anchored_out_channels: anchor(out_channels, None),
+            anchored_height: anchor(height, None),
+            anchored_width: anchor(width, None),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn conv2d_autotune_key_anchors_every_dim() {
+        let input_shape: Shape<4> = [3, 60, 224, 200].into();
+        let weight_shape: Shape<4> = [100, 60, 3, 3].into();
+        let key = Conv2dAutotuneKey::new(&input_shape, &weight_shape);
+
+        assert!(key.anchored_batch == 4);
+        assert!(key.anchored_in_channels == 64);
+        assert!(key.anchored_out_channels == 128);
+        assert!(key.anchored_height == 256);
+        assert!(key.anchored_width == 256);
+    }
+
+    #[test]
+    fn conv2d_autotune_key_large_batch() {
+        let input_shape: Shape<4> = [1000, 3, 32, 32].into();
+        let weight_shape: Shape<4> = [16, 3, 3, 3].into();
+        let key = Conv2dAutotuneKey::new(&input_shape, &weight_shape);
+
+        assert!(key.anchored_batch == 256);
+    }
+}
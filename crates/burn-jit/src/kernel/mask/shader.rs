@@ -12,7 +12,8 @@ pub(crate) trait MaskStrategy: Send + Sync + 'static {
         scope: &mut Scope,
         masked_value: Variable,
         value: Variable,
-        index: Variable,
+        layout: Variable,
+        position: Variable,
     ) -> Variable;
 
     fn value_info(value_item: Item) -> InputInfo;
@@ -26,7 +27,8 @@ impl MaskStrategy for MaskFill {
         scope: &mut Scope,
         masked_value: Variable,
         value: Variable,
-        _index: Variable,
+        _layout: Variable,
+        _position: Variable,
     ) -> Variable {
         cpa!(scope, masked_value = value);
         masked_value
@@ -51,8 +53,22 @@ impl MaskStrategy for MaskWhere {
         scope: &mut Scope,
         masked_value: Variable,
         value: Variable,
-        index: Variable,
+        layout: Variable,
+        position: Variable,
     ) -> Variable {
+        // The value tensor can be broadcasted, so it has its own index.
+        let index = scope.zero(Elem::UInt);
+
+        IndexOffsetGlobalWithLayout {
+            tensors: vec![value],
+            indexes: vec![index],
+            layout,
+            position,
+            dim_start: 0u32.into(),
+            dim_end: Variable::Rank,
+        }
+        .expand(scope);
+
         cpa!(scope, masked_value = value[index]);
         masked_value
     }
@@ -258,7 +274,7 @@ impl<EI: JitElement, EM: JitElement, M: MaskStrategy> MaskShader<EI, EM, M> {
         // Assign a value at the index
         let used_value = scope.create_local(output.item());
         cpa!(scope, if(masked).then(|scope| {
-            M::mask(scope, used_value, value, index_input );
+            M::mask(scope, used_value, value, output, id);
         }).else(|scope| {
             cpa!(scope, used_value = input[index_input]);
         }));
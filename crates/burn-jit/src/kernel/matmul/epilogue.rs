@@ -0,0 +1,80 @@
+use crate::{kernel::into_contiguous, tensor::JitTensor, FloatElement, JitRuntime};
+use burn_cube::{frontend::TensorHandle, CubeCountSettings, Execution};
+
+use super::{
+    init_matmul_output, tiling2d::check_bound_requirement, tiling2d::MatmulTiling2dEagerKernel,
+    tiling2d_launch_options, Tiling2dConfig,
+};
+
+/// Activation applied by the epilogue of a [fused matmul](matmul_activation).
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum MatmulActivation {
+    /// Rectified linear unit, `max(x, 0)`.
+    Relu,
+    /// Gaussian error linear unit, computed with the error function.
+    Gelu,
+}
+
+/// Matrix multiplication followed by the addition of a bias and an activation, fused in the
+/// epilogue of the [tiling 2d](super::matmul_tiling_2d) kernel.
+///
+/// The bias has the size of the last dimension of the output and is broadcast over its rows.
+/// The result is the same as the unfused `activation(lhs.matmul(rhs) + bias)`, saving the launch
+/// of the elementwise kernel and the global memory round trip of the intermediate result.
+pub fn matmul_activation<R: JitRuntime, E: FloatElement, const D: usize>(
+    lhs: JitTensor<R, E, D>,
+    rhs: JitTensor<R, E, D>,
+    bias: JitTensor<R, E, 1>,
+    activation: MatmulActivation,
+) -> JitTensor<R, E, D> {
+    lhs.assert_is_on_same_device(&rhs);
+    assert_eq!(
+        bias.shape.dims[0],
+        rhs.shape.dims[D - 1],
+        "The bias should have the size of the last dimension of the output"
+    );
+
+    let config = Tiling2dConfig::default();
+    let bounds_check_required = check_bound_requirement(&lhs.shape, &rhs.shape, &config);
+    let kernel = MatmulTiling2dEagerKernel::<R, E>::new(
+        config.clone(),
+        bounds_check_required,
+        Some(activation),
+    );
+
+    let out = init_matmul_output(&lhs, &rhs);
+    let client = lhs.client.clone();
+
+    let lhs = match lhs.batch_swapped_with_row_col() {
+        true => into_contiguous(lhs),
+        false => lhs,
+    };
+    let rhs = match rhs.batch_swapped_with_row_col() {
+        true => into_contiguous(rhs),
+        false => rhs,
+    };
+    let bias = into_contiguous(bias);
+
+    // The kernel tensors must share the same rank, so the bias is seen as a row of the output.
+    let mut bias_shape = [1; D];
+    bias_shape[D - 1] = bias.shape.dims[0];
+    let mut bias_strides = [bias.shape.dims[0]; D];
+    bias_strides[D - 1] = 1;
+
+    Execution::start(kernel, client)
+        .inputs(&[
+            TensorHandle::<R>::new(&lhs.handle, &lhs.strides, &lhs.shape.dims),
+            TensorHandle::new(&rhs.handle, &rhs.strides, &rhs.shape.dims),
+            TensorHandle::new(&bias.handle, &bias_strides, &bias_shape),
+        ])
+        .outputs(&[TensorHandle::new(
+            &out.handle,
+            &out.strides,
+            &out.shape.dims,
+        )])
+        .execute(CubeCountSettings::Custom(tiling2d_launch_options(
+            &out.shape, config,
+        )));
+
+    out
+}
@@ -1,4 +1,5 @@
 mod base;
+mod epilogue;
 mod simple;
 mod tiling2d;
 mod tiling2d_shader;
@@ -8,6 +9,7 @@ mod tune;
 pub mod utils;
 
 pub use base::*;
+pub use epilogue::*;
 pub use simple::*;
 pub use tune::*;
 pub use utils::*;
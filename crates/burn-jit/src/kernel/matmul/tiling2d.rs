@@ -17,14 +17,16 @@ use std::marker::PhantomData;
 use super::{
     padding::{crop, pad_round, PaddingOutput},
     shape_out, tiling2d_launch_options,
-    tiling2d_shader::MatmulTiling2dShader,
-    Tiling2dConfig,
+    tiling2d_shader::{MatmulTiling2dShader, Tiling2dEpilogue},
+    MatmulActivation, Tiling2dConfig,
 };
 
 #[derive(new, Debug)]
-struct MatmulTiling2dEagerKernel<R: JitRuntime, E: JitElement> {
+pub(super) struct MatmulTiling2dEagerKernel<R: JitRuntime, E: JitElement> {
     config: Tiling2dConfig,
     bounds_check_required: bool,
+    /// When set, a bias is read from a third input and added with the activation to the results.
+    epilogue: Option<MatmulActivation>,
     _runtime: PhantomData<R>,
     _elem: PhantomData<E>,
 }
@@ -45,25 +47,31 @@ impl<R: JitRuntime, E: JitElement> Kernel for MatmulTiling2dEagerKernel<R, E> {
 
         scope.write_global_custom(out);
 
+        let epilogue = self.epilogue.map(|activation| Tiling2dEpilogue {
+            bias: Variable::GlobalInputArray(2, item),
+            activation,
+        });
+
         MatmulTiling2dShader {
             variables: BinaryOperator { lhs, rhs, out },
             config: self.config.clone(),
             bounds_check_required: self.bounds_check_required,
+            epilogue,
         }
         .expand(&mut scope);
 
-        let lhs = InputInfo::Array {
-            item,
-            visibility: Visibility::Read,
-        };
-        let rhs = InputInfo::Array {
+        let input = InputInfo::Array {
             item,
             visibility: Visibility::Read,
         };
+        let mut inputs = vec![input.clone(), input.clone()];
+        if self.epilogue.is_some() {
+            inputs.push(input);
+        }
         let out = OutputInfo::Array { item };
 
         let info = KernelExpansion {
-            inputs: vec![lhs, rhs],
+            inputs,
             outputs: vec![out],
             scope,
         };
@@ -78,10 +86,11 @@ impl<R: JitRuntime, E: JitElement> Kernel for MatmulTiling2dEagerKernel<R, E> {
 
     fn id(&self) -> String {
         format!(
-            "{:?}config={:?}boundcheck={:?}",
+            "{:?}config={:?}boundcheck={:?}epilogue={:?}",
             core::any::TypeId::of::<Self>(),
             self.config,
-            self.bounds_check_required
+            self.bounds_check_required,
+            self.epilogue
         )
     }
 }
@@ -96,7 +105,8 @@ pub fn matmul_tiling_2d<R: JitRuntime, E: JitElement + Element, const D: usize>(
 ) -> JitTensor<R, E, D> {
     let bounds_check_required = check_bound_requirement(&lhs.shape, &rhs.shape, &config);
 
-    let kernel = MatmulTiling2dEagerKernel::<R, E>::new(config.clone(), bounds_check_required);
+    let kernel =
+        MatmulTiling2dEagerKernel::<R, E>::new(config.clone(), bounds_check_required, None);
     let client = lhs.client.clone();
 
     let lhs = match lhs.batch_swapped_with_row_col() {
@@ -132,7 +142,7 @@ pub fn matmul_tiling_2d_padded<R: JitRuntime, E: JitElement + Element, const D:
     out: JitTensor<R, E, D>,
     config: Tiling2dConfig,
 ) -> JitTensor<R, E, D> {
-    let kernel = MatmulTiling2dEagerKernel::<R, E>::new(config.clone(), false);
+    let kernel = MatmulTiling2dEagerKernel::<R, E>::new(config.clone(), false, None);
     let client = lhs.client.clone();
 
     // A tensor may need to be padded, in which case it will implicitly become contiguous
@@ -183,7 +193,7 @@ pub fn matmul_tiling_2d_padded<R: JitRuntime, E: JitElement + Element, const D:
     crop(rounded_output, out)
 }
 
-fn check_bound_requirement<const D: usize>(
+pub(super) fn check_bound_requirement<const D: usize>(
     lhs_shape: &Shape<D>,
     rhs_shape: &Shape<D>,
     config: &Tiling2dConfig,
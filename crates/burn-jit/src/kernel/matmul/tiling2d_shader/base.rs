@@ -4,12 +4,19 @@ use burn_cube::ir::{BinaryOperator, Scope, Synchronization, Variable};
 use crate::kernel::matmul::tiling2d_shader::{
     computation_loop, gather_shader_information, load_shared_memory, write_to_output,
 };
-use crate::kernel::matmul::Tiling2dConfig;
+use crate::kernel::matmul::{MatmulActivation, Tiling2dConfig};
 
 pub(crate) struct MatmulTiling2dShader {
     pub variables: BinaryOperator,
     pub config: Tiling2dConfig,
     pub bounds_check_required: bool,
+    pub epilogue: Option<Tiling2dEpilogue>,
+}
+
+/// Bias and activation applied to the results before they are written to the output.
+pub(crate) struct Tiling2dEpilogue {
+    pub bias: Variable,
+    pub activation: MatmulActivation,
 }
 
 pub(crate) struct Tiling2dState {
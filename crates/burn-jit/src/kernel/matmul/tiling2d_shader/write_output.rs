@@ -3,6 +3,8 @@ use burn_cube::{
     ir::{Elem, Scope, Variable},
 };
 
+use crate::kernel::matmul::MatmulActivation;
+
 use super::{MatmulTiling2dShader, Tiling2dState};
 
 #[allow(clippy::too_many_arguments)]
@@ -113,6 +115,32 @@ fn write_inner(
 
     cpa!(scope, result = results[results_position]);
 
+    if let Some(epilogue) = &shader.epilogue {
+        let bias = epilogue.bias;
+        let bias_value = scope.create_local(elem);
+        cpa!(scope, bias_value = bias[col_index]);
+        cpa!(scope, result += bias_value);
+
+        match epilogue.activation {
+            MatmulActivation::Relu => {
+                let zero = scope.create_with_value(0, elem);
+                cpa!(scope, result = max(result, zero));
+            }
+            MatmulActivation::Gelu => {
+                // 0.70710677 = 1 / sqrt(2)
+                let inv_sqrt_2 = scope.create_with_value(0.70710677, elem);
+                let one = scope.create_with_value(1, elem);
+                let half = scope.create_with_value(0.5, elem);
+                let erf = scope.create_local(elem);
+                cpa!(scope, erf = result * inv_sqrt_2);
+                cpa!(scope, erf = erf(erf));
+                cpa!(scope, erf += one);
+                cpa!(scope, result *= erf);
+                cpa!(scope, result *= half);
+            }
+        }
+    }
+
     cpa!(scope, row_index *= out_stride_row);
     cpa!(scope, col_index *= out_stride_col);
     cpa!(scope, output_position = row_index + col_index);
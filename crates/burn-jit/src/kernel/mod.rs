@@ -15,6 +15,8 @@ pub use unary::*;
 
 pub use burn_cube::{Kernel, SUBCUBE_DIM_APPROX};
 
+/// Attention kernels
+pub mod attention;
 /// Convolution kernels
 pub mod conv;
 /// Interpolation kernels
@@ -27,6 +29,15 @@ pub mod pool;
 pub mod prng;
 /// Reduction algorithms
 pub mod reduce;
+/// Segment max kernels
+pub mod scatter_max;
+/// Segment softmax kernels
+#[cfg(not(target_family = "wasm"))]
+pub mod segment_softmax;
+/// Top-k selection kernels
+pub mod topk;
+/// Transpose kernels
+pub mod transpose;
 
 pub(crate) use clamp::*;
 pub(crate) use comparison::*;
@@ -20,6 +20,8 @@ impl<E: JitElement> ReduceDimShared<E> for Argmax {
 
         let max = Variable::ConstantScalar(E::minimum_value().to_f64(), input_item.elem());
         cpa!(scope, value_shared_memory[write_position] = max);
+        let index = Variable::ConstantScalar(u32::MAX as f64, Elem::UInt);
+        cpa!(scope, index_shared_memory[write_position] = index);
         (value_shared_memory, index_shared_memory)
     }
 
@@ -33,8 +35,18 @@ impl<E: JitElement> ReduceDimShared<E> for Argmax {
         let current_value = scope.create_local(value.item());
         cpa!(scope, current_value = value_shared_memory[write_position]);
 
+        let current_index = scope.create_local(index.item());
+        cpa!(scope, current_index = index_shared_memory[write_position]);
+
+        // Ties resolve to the lowest index, matching the naive kernel and the other backends.
         let condition = scope.create_local(Elem::Bool);
+        let is_tie = scope.create_local(Elem::Bool);
+        let is_lower_index = scope.create_local(Elem::Bool);
         cpa!(scope, condition = value > current_value);
+        cpa!(scope, is_tie = value == current_value);
+        cpa!(scope, is_lower_index = index < current_index);
+        cpa!(scope, is_tie = is_tie && is_lower_index);
+        cpa!(scope, condition = condition || is_tie);
         cpa!(scope, if(condition).then(|scope| {
             cpa!(scope, value_shared_memory[write_position] = value);
             cpa!(scope, index_shared_memory[write_position] = index);
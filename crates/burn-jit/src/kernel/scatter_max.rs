@@ -0,0 +1,109 @@
+use crate::{
+    kernel::into_contiguous,
+    ops::{numeric::full_device, swap_dims},
+    tensor::JitTensor,
+    FloatElement, IntElement, JitRuntime,
+};
+use burn_cube::{
+    calculate_cube_count_elemwise, frontend::TensorHandle, prelude::*, SUBCUBE_DIM_APPROX,
+};
+use burn_tensor::{ElementConversion, Shape};
+
+/// Computes the maximum of each segment of one row in a single pass over its elements.
+///
+/// The outputs of a row are only written by the unit of the row, so they are updated in place
+/// without atomics. The positions are visited in increasing order, so a tied maximum keeps the
+/// lowest one. The outputs are filled beforehand with `-inf` and the sentinel position `size`.
+#[cube(launch)]
+fn scatter_max_kernel<F: Float, I: Int>(
+    input: &Tensor<F>,
+    indices: &Tensor<I>,
+    maxima: &mut Tensor<F>,
+    argmax: &mut Tensor<I>,
+) {
+    let last = input.rank() - UInt::new(1);
+    let size = input.shape(last);
+    let num_segments = maxima.shape(last);
+    let num_rows = maxima.len() / num_segments;
+
+    if ABSOLUTE_POS >= num_rows {
+        return;
+    }
+
+    let offset_input = ABSOLUTE_POS * size;
+    let offset_output = ABSOLUTE_POS * num_segments;
+
+    for j in range(0u32, size, Comptime::new(false)) {
+        let segment = indices[offset_input + j];
+
+        // Elements with a segment index outside of `0..num_segments` are ignored.
+        if segment >= I::new(0) && segment < I::cast_from(num_segments) {
+            let output = offset_output + UInt::cast_from(segment);
+            let value = input[offset_input + j];
+
+            if UInt::cast_from(argmax[output]) == size || value > maxima[output] {
+                maxima[output] = value;
+                argmax[output] = I::cast_from(j);
+            }
+        }
+    }
+}
+
+/// Computes the maximum of each segment along the given dimension, with the position of the
+/// maximum.
+///
+/// `indices` has the same shape as the tensor and gives the segment of each element along `dim`.
+/// Each row of the dimension is handled by one unit in a single pass over its elements, so the
+/// work is `O(n + s)` per row for `n` elements and `s` segments and the only allocations are the
+/// outputs. Empty segments get `-inf` with the size of the dimension as position, and elements
+/// with a segment index outside of `0..num_segments` are ignored.
+pub fn scatter_max<R: JitRuntime, F: FloatElement, I: IntElement, const D: usize>(
+    tensor: JitTensor<R, F, D>,
+    dim: usize,
+    indices: JitTensor<R, I, D>,
+    num_segments: usize,
+) -> (JitTensor<R, F, D>, JitTensor<R, I, D>) {
+    assert_eq!(
+        tensor.shape, indices.shape,
+        "The segment indices should have the shape of the tensor"
+    );
+
+    // Work on contiguous rows of the selected dimension.
+    let tensor = into_contiguous(swap_dims(tensor, dim, D - 1));
+    let indices = into_contiguous(swap_dims(indices, dim, D - 1));
+
+    let mut dims = tensor.shape.dims;
+    let size = dims[D - 1];
+    let num_rows = tensor.shape.num_elements() / size.max(1);
+    dims[D - 1] = num_segments;
+    let shape_out = Shape::new(dims);
+
+    let maxima = full_device::<R, F, D>(
+        tensor.client.clone(),
+        shape_out.clone(),
+        tensor.device.clone(),
+        f32::NEG_INFINITY.elem(),
+    );
+    let argmax = full_device::<R, I, D>(
+        tensor.client.clone(),
+        shape_out,
+        tensor.device.clone(),
+        (size as i64).elem(),
+    );
+
+    if size > 0 && num_segments > 0 && num_rows > 0 {
+        let cube_count = calculate_cube_count_elemwise(num_rows, SUBCUBE_DIM_APPROX);
+
+        scatter_max_kernel_launch::<F::FloatPrimitive, I::IntPrimitive, R>(
+            tensor.client.clone(),
+            cube_count,
+            KernelSettings::default(),
+            TensorHandle::new(&tensor.handle, &tensor.strides, &tensor.shape.dims),
+            TensorHandle::new(&indices.handle, &indices.strides, &indices.shape.dims),
+            TensorHandle::new(&maxima.handle, &maxima.strides, &maxima.shape.dims),
+            TensorHandle::new(&argmax.handle, &argmax.strides, &argmax.shape.dims),
+        );
+    }
+
+    (swap_dims(maxima, dim, D - 1), swap_dims(argmax, dim, D - 1))
+}
@@ -0,0 +1,153 @@
+use crate::{
+    kernel::into_contiguous,
+    ops::{numeric::zeros_device, swap_dims},
+    tensor::JitTensor,
+    FloatElement, IntElement, JitBackend, JitRuntime,
+};
+use burn_cube::{
+    calculate_cube_count_elemwise, frontend::TensorHandle, prelude::*, SUBCUBE_DIM_APPROX,
+};
+use burn_tensor::ops::IntTensorOps;
+
+/// Computes the softmax of one segment of a row, visiting only the elements of the segment.
+///
+/// The segment indices are sorted with the positions of their elements, so the elements of a
+/// segment are found with two binary searches over the sorted indices. The maximum and the sum of
+/// the exponentials are computed in a single pass over the elements with an online softmax: the
+/// sum is rescaled each time the maximum increases. A second pass writes the outputs, so each
+/// element is read twice by a single unit.
+#[cube(launch)]
+fn segment_softmax_kernel<F: Float, I: Int>(
+    input: &Tensor<F>,
+    sorted_ids: &Tensor<I>,
+    positions: &Tensor<I>,
+    output: &mut Tensor<F>,
+    num_segments: UInt,
+) {
+    let last = input.rank() - UInt::new(1);
+    let size = input.shape(last);
+    let num_rows = input.len() / size;
+
+    if ABSOLUTE_POS >= num_rows * num_segments {
+        return;
+    }
+
+    let row = ABSOLUTE_POS / num_segments;
+    let segment = ABSOLUTE_POS % num_segments;
+    let offset = row * size;
+
+    let start = lower_bound::<I>(sorted_ids, I::cast_from(segment));
+    let end = lower_bound::<I>(sorted_ids, I::cast_from(segment + UInt::new(1)));
+
+    // The maximum is subtracted before the exponentials for numerical stability.
+    let mut max = F::new(0.);
+    let mut sum = F::new(0.);
+
+    if start < end {
+        max = input[offset + UInt::cast_from(positions[start])];
+    }
+
+    for k in range(start, end, Comptime::new(false)) {
+        let value = input[offset + UInt::cast_from(positions[k])];
+
+        if value > max {
+            sum *= F::exp(max - value);
+            max = value;
+        }
+        sum += F::exp(value - max);
+    }
+
+    for k in range(start, end, Comptime::new(false)) {
+        let index = offset + UInt::cast_from(positions[k]);
+        output[index] = F::exp(input[index] - max) / sum;
+    }
+}
+
+/// Returns the position of the first sorted index that is not lower than the value.
+#[cube]
+fn lower_bound<I: Int>(sorted: &Tensor<I>, value: I) -> UInt {
+    let mut low = UInt::new(0);
+    let mut high = sorted.shape(sorted.rank() - UInt::new(1));
+
+    loop {
+        if low >= high {
+            break;
+        }
+
+        let mid = (low + high) / UInt::new(2);
+
+        if sorted[mid] < value {
+            low = mid + UInt::new(1);
+        } else {
+            high = mid;
+        }
+    }
+
+    low
+}
+
+/// Applies the softmax over the elements of each segment along the given dimension, the
+/// segments being unsorted.
+///
+/// `segment_ids` has the size of the dimension and gives the segment of each element, as used to
+/// normalize the attention scores of the incoming edges of each node in graph attention:
+///
+/// `output[i] = exp(input[i]) / sum(exp(input[j])) for every j where segment_ids[j] == segment_ids[i]`
+///
+/// The segment indices are sorted once with the positions of their elements, then the segment
+/// maximum, the exponentials, the segment sum and the division are fused in a single kernel with
+/// one unit per segment of each row, visiting only the elements of its segment. No intermediate
+/// tensor of the size of the input is allocated, and the work is `O(n + s * log(n))` per row for
+/// `n` elements and `s` segments. Elements with a segment index outside of `0..num_segments` are
+/// set to zero.
+pub fn segment_softmax<R: JitRuntime, F: FloatElement, I: IntElement, const D: usize>(
+    tensor: JitTensor<R, F, D>,
+    segment_ids: JitTensor<R, I, 1>,
+    num_segments: usize,
+    dim: usize,
+) -> JitTensor<R, F, D> {
+    let size = tensor.shape.dims[dim];
+    assert_eq!(
+        segment_ids.shape.dims[0], size,
+        "The segment indices should have the size of the dimension"
+    );
+
+    // Work on contiguous rows of the selected dimension, the ignored elements staying zeros.
+    let tensor = into_contiguous(swap_dims(tensor, dim, D - 1));
+    let output = zeros_device::<R, F, D>(
+        tensor.client.clone(),
+        tensor.device.clone(),
+        tensor.shape.clone(),
+    );
+
+    let num_rows = tensor.shape.num_elements() / size.max(1);
+    let num_units = num_rows * num_segments;
+
+    if size == 0 || num_units == 0 {
+        return swap_dims(output, dim, D - 1);
+    }
+
+    let (sorted_ids, positions) =
+        JitBackend::<R, F, I>::int_sort_with_indices(segment_ids, 0, false);
+
+    // The kernel tensors must share the same rank, so the segment indices are seen as a row.
+    let mut ids_shape = [1; D];
+    ids_shape[D - 1] = size;
+    let mut ids_strides = [size; D];
+    ids_strides[D - 1] = 1;
+
+    let cube_count = calculate_cube_count_elemwise(num_units, SUBCUBE_DIM_APPROX);
+
+    segment_softmax_kernel_launch::<F::FloatPrimitive, I::IntPrimitive, R>(
+        tensor.client.clone(),
+        cube_count,
+        KernelSettings::default(),
+        TensorHandle::new(&tensor.handle, &tensor.strides, &tensor.shape.dims),
+        TensorHandle::new(&sorted_ids.handle, &ids_strides, &ids_shape),
+        TensorHandle::new(&positions.handle, &ids_strides, &ids_shape),
+        TensorHandle::new(&output.handle, &output.strides, &output.shape.dims),
+        num_segments as u32,
+    );
+
+    swap_dims(output, dim, D - 1)
+}
@@ -0,0 +1,165 @@
+use crate::{
+    kernel::into_contiguous,
+    ops::{numeric::empty_device, swap_dims},
+    tensor::JitTensor,
+    FloatElement, IntElement, JitRuntime,
+};
+use burn_cube::{calculate_cube_count_elemwise, frontend::TensorHandle, ir::CubeDim, prelude::*};
+use burn_tensor::Shape;
+
+/// Number of units of the cube selecting the top-k elements of one row.
+const CUBE_SIZE: u32 = 256;
+
+/// Selects the `k` largest elements of one row of the last dimension by iterative max
+/// extraction: every step finds the largest element ordered after the previously selected one,
+/// so the row never has to be sorted.
+///
+/// Each row is handled by a cube. At every step, each unit scans a strided part of the row for
+/// its best candidate, then the candidates are reduced in shared memory by a tree of pairwise
+/// comparisons, so a step takes `size / CUBE_SIZE + log2(CUBE_SIZE)` iterations per unit.
+///
+/// Elements are ordered by descending value, then by ascending index, which keeps the lowest
+/// indices first when several elements are tied.
+#[cube(launch)]
+fn topk_kernel<F: Float, I: Int>(
+    input: &Tensor<F>,
+    values: &mut Tensor<F>,
+    indices: &mut Tensor<I>,
+    cube_size: Comptime<UInt>,
+) {
+    let last = input.rank() - UInt::new(1);
+    let size = input.shape(last);
+    let k = values.shape(last);
+    let row = CUBE_POS_Y * CUBE_COUNT_X + CUBE_POS_X;
+
+    // Every unit of a cube returns here or none does, so the barriers below are always reached.
+    if row * k >= values.len() {
+        return;
+    }
+
+    let cube_size_runtime = Comptime::runtime(cube_size);
+    let offset_input = row * size;
+    let offset_output = row * k;
+    let num_steps = (size + cube_size_runtime - UInt::new(1)) / cube_size_runtime;
+
+    let mut shared_values = SharedMemory::<F>::new(Comptime::get(cube_size));
+    let mut shared_indices = SharedMemory::<UInt>::new(Comptime::get(cube_size));
+
+    // The size is used as a sentinel for no selection.
+    let mut previous_value = F::new(0.);
+    let mut previous_index = size;
+
+    for j in range(0u32, k, Comptime::new(false)) {
+        let mut best_value = F::new(0.);
+        let mut best_index = size;
+
+        // The indices of a unit are increasing, so its first best element has the lowest index.
+        for step in range(0u32, num_steps, Comptime::new(false)) {
+            let i = step * cube_size_runtime + UNIT_POS_X;
+
+            if i < size {
+                let value = input[offset_input + i];
+
+                let after_previous = previous_index == size
+                    || value < previous_value
+                    || (value == previous_value && i > previous_index);
+                let better = best_index == size || value > best_value;
+
+                if after_previous && better {
+                    best_value = value;
+                    best_index = i;
+                }
+            }
+        }
+
+        shared_values[UNIT_POS_X] = best_value;
+        shared_indices[UNIT_POS_X] = best_index;
+        sync_units();
+
+        let mut stride = cube_size_runtime / UInt::new(2);
+
+        loop {
+            if stride == UInt::new(0) {
+                break;
+            }
+
+            if UNIT_POS_X < stride {
+                let value = shared_values[UNIT_POS_X];
+                let index = shared_indices[UNIT_POS_X];
+                let other_value = shared_values[UNIT_POS_X + stride];
+                let other_index = shared_indices[UNIT_POS_X + stride];
+
+                let better = other_index != size
+                    && (index == size
+                        || other_value > value
+                        || (other_value == value && other_index < index));
+
+                if better {
+                    shared_values[UNIT_POS_X] = other_value;
+                    shared_indices[UNIT_POS_X] = other_index;
+                }
+            }
+
+            // Every unit must reach the barrier, so only the first half of the units compare.
+            sync_units();
+            stride /= UInt::new(2);
+        }
+
+        previous_value = shared_values[0];
+        previous_index = shared_indices[0];
+
+        if UNIT_POS_X == UInt::new(0) {
+            values[offset_output + j] = previous_value;
+            indices[offset_output + j] = I::cast_from(previous_index);
+        }
+
+        // The shared memory is written again at the next step once every unit has read it.
+        sync_units();
+    }
+}
+
+/// Returns the `k` largest elements along the given dimension with their indices.
+///
+/// Each row is handled by a cube doing `k` passes over it, which is `O(n * k)` and avoids
+/// sorting the whole dimension when `k` is small. The units of the cube share every pass, so a
+/// row is scanned `CUBE_SIZE` elements at a time.
+pub fn topk_with_indices<R: JitRuntime, F: FloatElement, I: IntElement, const D: usize>(
+    tensor: JitTensor<R, F, D>,
+    k: usize,
+    dim: usize,
+) -> (JitTensor<R, F, D>, JitTensor<R, I, D>) {
+    // Work on contiguous rows of the selected dimension.
+    let tensor = into_contiguous(swap_dims(tensor, dim, D - 1));
+
+    let mut dims = tensor.shape.dims;
+    let num_rows = tensor.shape.num_elements() / dims[D - 1].max(1);
+    dims[D - 1] = k;
+    let shape_out = Shape::new(dims);
+
+    let values = empty_device::<R, F, D>(
+        tensor.client.clone(),
+        tensor.device.clone(),
+        shape_out.clone(),
+    );
+    let indices = empty_device::<R, I, D>(tensor.client.clone(), tensor.device.clone(), shape_out);
+
+    if k > 0 && num_rows > 0 {
+        let cube_count = calculate_cube_count_elemwise(num_rows, 1);
+        let settings = KernelSettings::default().cube_dim(CubeDim::new(CUBE_SIZE, 1, 1));
+
+        topk_kernel_launch::<F::FloatPrimitive, I::IntPrimitive, R>(
+            tensor.client.clone(),
+            cube_count,
+            settings,
+            TensorHandle::new(&tensor.handle, &tensor.strides, &tensor.shape.dims),
+            TensorHandle::new(&values.handle, &values.strides, &values.shape.dims),
+            TensorHandle::new(&indices.handle, &indices.strides, &indices.shape.dims),
+            UInt::new(CUBE_SIZE),
+        );
+    }
+
+    (
+        swap_dims(values, dim, D - 1),
+        swap_dims(indices, dim, D - 1),
+    )
+}
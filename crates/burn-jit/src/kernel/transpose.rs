@@ -0,0 +1,121 @@
+use crate::{
+    element::JitElement, kernel::into_contiguous, ops::numeric::empty_device, tensor::JitTensor,
+    JitRuntime,
+};
+use burn_cube::{calculate_cube_count_elemwise, frontend::TensorHandle, ir::CubeDim, prelude::*};
+use burn_tensor::Shape;
+
+/// Size of the square tiles, which is also the size of the cube in both dimensions.
+const TILE_SIZE: u32 = 16;
+
+/// Transposes one tile of one matrix of the batch through shared memory.
+///
+/// Each unit reads one element of a row of the tile and writes one element of a row of the
+/// transposed tile, so both the reads and the writes of neighbouring units are contiguous in
+/// global memory. The shared tile rows are padded with one element to avoid bank conflicts when
+/// reading its columns.
+///
+/// The cubes are laid out in a 2D grid, the tiles of every matrix of the batch one after the
+/// other, so that the number of cubes in each dimension stays within the launch limits.
+#[cube(launch)]
+fn transpose_last2_kernel<E: CubePrimitive>(
+    input: &Tensor<E>,
+    output: &mut Tensor<E>,
+    tile_size: Comptime<UInt>,
+    shared_size: Comptime<UInt>,
+) {
+    let rank = input.rank();
+    let rows = input.shape(rank - UInt::new(2));
+    let cols = input.shape(rank - UInt::new(1));
+    let tile_size = Comptime::runtime(tile_size);
+    let tile_stride = tile_size + UInt::new(1);
+
+    let tiles_x = (cols + tile_size - UInt::new(1)) / tile_size;
+    let tiles_y = (rows + tile_size - UInt::new(1)) / tile_size;
+    let tiles_per_matrix = tiles_x * tiles_y;
+    let cube = CUBE_POS_Y * CUBE_COUNT_X + CUBE_POS_X;
+
+    // Every unit of a cube returns here or none does, so the barrier below is always reached.
+    if cube >= tiles_per_matrix * (input.len() / (rows * cols)) {
+        return;
+    }
+
+    let batch_offset = cube / tiles_per_matrix * rows * cols;
+    let tile = cube % tiles_per_matrix;
+    let tile_row = tile / tiles_x;
+    let tile_col = tile % tiles_x;
+
+    // `tile_size` rows of `tile_size + 1` elements.
+    let mut shared = SharedMemory::<E>::new(Comptime::get(shared_size));
+
+    let row = tile_row * tile_size + UNIT_POS_Y;
+    let col = tile_col * tile_size + UNIT_POS_X;
+
+    if row < rows && col < cols {
+        shared[UNIT_POS_Y * tile_stride + UNIT_POS_X] = input[batch_offset + row * cols + col];
+    }
+
+    // Every unit must reach the barrier, so out of bounds units only skip the memory accesses.
+    sync_units();
+
+    let row_out = tile_col * tile_size + UNIT_POS_Y;
+    let col_out = tile_row * tile_size + UNIT_POS_X;
+
+    if row_out < cols && col_out < rows {
+        output[batch_offset + row_out * rows + col_out] =
+            shared[UNIT_POS_X * tile_stride + UNIT_POS_Y];
+    }
+}
+
+/// Transposes the last two dimensions of the tensor, returning a contiguous tensor.
+///
+/// This is equivalent to `into_contiguous(swap_dims(tensor, D - 2, D - 1))`, but the matrices
+/// are transposed by tiles staged in shared memory. The generic contiguous kernel computes the
+/// source index of each output element from the strides, which makes either its reads or its
+/// writes strided: only one element per memory transaction is used. With the tiles, both the
+/// reads and the writes are coalesced, so the kernel is bound by memory bandwidth and is
+/// expected to be several times faster than the generic path on large matrices, as used to
+/// transpose the keys in attention.
+pub fn transpose_last2<R: JitRuntime, E: JitElement, const D: usize>(
+    tensor: JitTensor<R, E, D>,
+) -> JitTensor<R, E, D> {
+    assert!(
+        D >= 2,
+        "Transposing the last two dimensions requires a rank of at least 2"
+    );
+
+    let tensor = into_contiguous(tensor);
+    let rows = tensor.shape.dims[D - 2];
+    let cols = tensor.shape.dims[D - 1];
+
+    let mut dims = tensor.shape.dims;
+    dims.swap(D - 2, D - 1);
+
+    let output = empty_device::<R, E, D>(
+        tensor.client.clone(),
+        tensor.device.clone(),
+        Shape::new(dims),
+    );
+
+    let num_elems = tensor.shape.num_elements();
+    if num_elems == 0 {
+        return output;
+    }
+
+    let tile_size = TILE_SIZE as usize;
+    let num_tiles = num_elems / (rows * cols) * rows.div_ceil(tile_size) * cols.div_ceil(tile_size);
+    let cube_count = calculate_cube_count_elemwise(num_tiles, 1);
+    let settings = KernelSettings::default().cube_dim(CubeDim::new(TILE_SIZE, TILE_SIZE, 1));
+
+    transpose_last2_kernel_launch::<E::Primitive, R>(
+        tensor.client.clone(),
+        cube_count,
+        settings,
+        TensorHandle::new(&tensor.handle, &tensor.strides, &tensor.shape.dims),
+        TensorHandle::new(&output.handle, &output.strides, &output.shape.dims),
+        UInt::new(TILE_SIZE),
+        UInt::new(TILE_SIZE * (TILE_SIZE + 1)),
+    );
+
+    output
+}
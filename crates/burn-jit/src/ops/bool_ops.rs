@@ -95,6 +95,10 @@ where
         tensor
     }
 
+    fn bool_transpose_last2<const D: usize>(tensor: BoolTensor<Self, D>) -> BoolTensor<Self, D> {
+        kernel::transpose::transpose_last2(tensor)
+    }
+
     fn bool_repeat<const D: usize>(
         tensor: BoolTensor<Self, D>,
         dim: usize,
@@ -158,6 +158,10 @@ where
         super::swap_dims(tensor, dim1, dim2)
     }
 
+    fn float_transpose_last2<const D: usize>(tensor: FloatTensor<Self, D>) -> FloatTensor<Self, D> {
+        kernel::transpose::transpose_last2(tensor)
+    }
+
     fn float_reshape<const D1: usize, const D2: usize>(
         tensor: FloatTensor<Self, D1>,
         shape: Shape<D2>,
@@ -311,6 +315,27 @@ where
         reduce::sum_dim(tensor, dim, Default::default())
     }
 
+    #[cfg(not(target_family = "wasm"))]
+    fn float_scatter_max<const D: usize>(
+        tensor: FloatTensor<Self, D>,
+        dim: usize,
+        indices: IntTensor<Self, D>,
+        num_segments: usize,
+    ) -> (FloatTensor<Self, D>, IntTensor<Self, D>) {
+        kernel::scatter_max::scatter_max(tensor, dim, indices, num_segments)
+    }
+
+    // The segment indices are sorted with a read back, which isn't available on wasm.
+    #[cfg(not(target_family = "wasm"))]
+    fn float_segment_softmax<const D: usize>(
+        tensor: FloatTensor<Self, D>,
+        segment_ids: IntTensor<Self, 1>,
+        num_segments: usize,
+        dim: usize,
+    ) -> FloatTensor<Self, D> {
+        kernel::segment_softmax::segment_softmax(tensor, segment_ids, num_segments, dim)
+    }
+
     fn float_mean_dim<const D: usize>(
         tensor: FloatTensor<Self, D>,
         dim: usize,
@@ -460,6 +485,30 @@ where
         reduce::argmax(tensor, dim, Default::default())
     }
 
+    // The fallback for a large `k` sorts with a read back, which isn't available on wasm.
+    #[cfg(not(target_family = "wasm"))]
+    fn float_topk_with_indices<const D: usize>(
+        tensor: FloatTensor<Self, D>,
+        k: usize,
+        dim: usize,
+    ) -> (FloatTensor<Self, D>, IntTensor<Self, D>) {
+        // The kernel does `k` passes over each row, each ending with a reduction synchronizing the
+        // units of the cube, so a full sort is faster for a large `k`.
+        if k <= 32 {
+            return kernel::topk::topk_with_indices(tensor, k, dim);
+        }
+
+        let mut ranges = tensor.shape.dims.map(|size| 0..size);
+        ranges[dim] = 0..k;
+
+        let (values, indices) = Self::float_sort_with_indices(tensor, dim, true);
+
+        (
+            kernel::slice(values, ranges.clone()),
+            kernel::slice(indices, ranges),
+        )
+    }
+
     fn float_argmin<const D: usize>(
         tensor: FloatTensor<Self, D>,
         dim: usize,
@@ -319,6 +319,10 @@ where
         tensor
     }
 
+    fn int_transpose_last2<const D: usize>(tensor: IntTensor<Self, D>) -> IntTensor<Self, D> {
+        kernel::transpose::transpose_last2(tensor)
+    }
+
     fn int_repeat<const D: usize>(
         tensor: IntTensor<Self, D>,
         dim: usize,
@@ -1,9 +1,9 @@
 use crate::{kernel, FloatElement, IntElement, JitBackend, JitRuntime};
 use burn_tensor::ops::{
-    ConvOptions, ConvTransposeOptions, InterpolateOptions, MaxPool2dBackward, MaxPool2dWithIndices,
-    ModuleOps,
+    attention::attention_composed, ConvOptions, ConvTransposeOptions, InterpolateOptions,
+    MaxPool2dBackward, MaxPool2dWithIndices, ModuleOps,
 };
-use burn_tensor::ops::{FloatTensor, IntTensor};
+use burn_tensor::ops::{BoolTensor, FloatTensor, IntTensor};
 
 impl<R, F, I> ModuleOps<Self> for JitBackend<R, F, I>
 where
@@ -17,7 +17,7 @@ where
         bias: Option<FloatTensor<Self, 1>>,
         options: ConvOptions<2>,
     ) -> FloatTensor<Self, 4> {
-        kernel::conv::conv2d(x, weight, bias, options)
+        kernel::conv::conv2d(x, weight, bias, options, Default::default())
     }
 
     fn conv_transpose2d(
@@ -29,6 +29,24 @@ where
         kernel::conv::conv_transpose2d(x, weight, bias, options)
     }
 
+    fn scaled_dot_product_attention<const D: usize>(
+        query: FloatTensor<Self, D>,
+        key: FloatTensor<Self, D>,
+        value: FloatTensor<Self, D>,
+        mask: Option<BoolTensor<Self, D>>,
+        scale: f64,
+        causal: bool,
+    ) -> FloatTensor<Self, D> {
+        let d_qk = query.shape.dims[D - 1];
+        let d_v = value.shape.dims[D - 1];
+
+        if kernel::attention::attention_block_keys::<F>(d_qk, d_v) == 0 {
+            return attention_composed::<Self, D>(query, key, value, mask, scale, causal);
+        }
+
+        kernel::attention::scaled_dot_product_attention(query, key, value, mask, scale, causal)
+    }
+
     fn avg_pool2d(
         x: FloatTensor<Self, 4>,
         kernel_size: [usize; 2],
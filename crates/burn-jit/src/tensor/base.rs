@@ -119,7 +119,7 @@ where
     }
 
     pub(crate) fn can_mut_broadcast(&self, rhs: &Self) -> bool {
-        if !self.handle.can_mut() {
+        if !self.can_mut() {
             return false;
         }
 
@@ -158,7 +158,13 @@ where
 
     /// Check if the tensor is safe to mutate.
     pub fn can_mut(&self) -> bool {
-        self.handle.can_mut()
+        self.handle.can_mut() && !self.is_expanded()
+    }
+
+    /// Check if several positions of the tensor share the same memory, i.e. a dimension was
+    /// expanded with a stride of zero, in which case writing the output in place is invalid.
+    fn is_expanded(&self) -> bool {
+        (0..D).any(|i| self.strides[i] == 0 && self.shape.dims[i] > 1)
     }
 
     /// Assert that both tensors are on the same device.
@@ -0,0 +1,172 @@
+#[burn_tensor_testgen::testgen(attention)]
+mod tests {
+    use super::*;
+    use burn_jit::kernel::attention::scaled_dot_product_attention;
+    use burn_tensor::{
+        activation::softmax, backend::Backend, Bool, Distribution, Tensor, TensorData,
+    };
+
+    #[test]
+    fn attention_should_match_composed() {
+        test_same_as_composed([2, 3, 7, 8], 11, 5, false, false);
+    }
+
+    #[test]
+    fn attention_should_match_composed_masked() {
+        test_same_as_composed([2, 2, 6, 4], 9, 4, true, false);
+    }
+
+    #[test]
+    fn attention_should_match_composed_causal() {
+        test_same_as_composed([1, 2, 9, 8], 9, 8, false, true);
+    }
+
+    #[test]
+    fn attention_should_match_composed_over_many_tiles() {
+        test_same_as_composed([1, 2, 70, 8], 100, 6, true, false);
+    }
+
+    #[test]
+    fn attention_should_match_composed_causal_over_many_blocks() {
+        test_same_as_composed([1, 1, 70, 4], 75, 4, true, true);
+    }
+
+    #[test]
+    fn attention_op_should_fall_back_when_keys_exceed_shared_memory() {
+        TestBackend::seed(0);
+        let device = Default::default();
+        let query = Tensor::<TestBackend, 3>::random([1, 3, 2100], Distribution::Default, &device);
+        let key = Tensor::<TestBackend, 3>::random([1, 4, 2100], Distribution::Default, &device);
+        let value = Tensor::<TestBackend, 3>::random([1, 4, 2100], Distribution::Default, &device);
+
+        let output = burn_tensor::module::scaled_dot_product_attention(
+            query.clone(),
+            key.clone(),
+            value.clone(),
+            None,
+            0.01,
+            true,
+        );
+
+        let causal_mask = Tensor::<TestBackend, 3, Bool>::tril_mask([1, 3, 4], 0, &device);
+        let scores = query
+            .matmul(key.swap_dims(1, 2))
+            .mul_scalar(0.01)
+            .mask_fill(causal_mask, f32::NEG_INFINITY);
+        let expected = softmax(scores, 2).matmul(value);
+
+        output
+            .into_data()
+            .assert_approx_eq(&expected.into_data(), 3);
+    }
+
+    #[test]
+    fn attention_should_be_stable_with_large_scores() {
+        let query = TestTensor::<3>::from([[[100.0, 0.0], [0.0, 100.0]]]);
+        let key = TestTensor::<3>::from([[[100.0, 0.0], [0.0, 100.0]]]);
+        let value = TestTensor::<3>::from([[[1.0, 2.0], [3.0, 4.0]]]);
+
+        let output = Tensor::<TestBackend, 3>::from_primitive(scaled_dot_product_attention(
+            query.into_primitive(),
+            key.into_primitive(),
+            value.into_primitive(),
+            None,
+            1.0,
+            false,
+        ));
+
+        // The scores differ by 10000, so each query only attends to its own key.
+        let expected = TensorData::from([[[1.0, 2.0], [3.0, 4.0]]]);
+        output.into_data().assert_approx_eq(&expected, 3);
+    }
+
+    #[test]
+    fn attention_should_output_zeros_for_fully_masked_rows() {
+        let query = TestTensor::<2>::from([[1.0, 0.0], [0.0, 1.0]]);
+        let key = TestTensor::<2>::from([[1.0, 0.0], [0.0, 1.0]]);
+        let value = TestTensor::<2>::from([[1.0, 2.0], [3.0, 4.0]]);
+        let mask = TestTensorBool::<2>::from([[false, true], [true, true]]);
+
+        let output = Tensor::<TestBackend, 2>::from_primitive(scaled_dot_product_attention(
+            query.into_primitive(),
+            key.into_primitive(),
+            value.into_primitive(),
+            Some(mask.into_primitive()),
+            1.0,
+            false,
+        ));
+
+        let expected = TensorData::from([[1.0, 2.0], [0.0, 0.0]]);
+        output.into_data().assert_approx_eq(&expected, 3);
+    }
+
+    fn test_same_as_composed(
+        shape: [usize; 4],
+        num_keys: usize,
+        d_v: usize,
+        masked: bool,
+        causal: bool,
+    ) {
+        TestBackend::seed(0);
+        let device = Default::default();
+        let [batch_size, heads, num_queries, d_qk] = shape;
+        let scale = 1.0 / (d_qk as f64).sqrt();
+
+        let query = Tensor::<TestBackend, 4>::random(shape, Distribution::Default, &device);
+        let key = Tensor::<TestBackend, 4>::random(
+            [batch_size, heads, num_keys, d_qk],
+            Distribution::Default,
+            &device,
+        );
+        let value = Tensor::<TestBackend, 4>::random(
+            [batch_size, heads, num_keys, d_v],
+            Distribution::Default,
+            &device,
+        );
+        // Broadcast over the batch and the heads, the first key is never masked.
+        let mask = masked.then(|| {
+            Tensor::<TestBackend, 2>::random(
+                [num_queries, num_keys],
+                Distribution::Default,
+                &device,
+            )
+            .slice_assign(
+                [0..num_queries, 0..1],
+                Tensor::zeros([num_queries, 1], &device),
+            )
+            .greater_elem(0.7)
+            .reshape([1, 1, num_queries, num_keys])
+        });
+
+        let output = Tensor::<TestBackend, 4>::from_primitive(scaled_dot_product_attention(
+            query.clone().into_primitive(),
+            key.clone().into_primitive(),
+            value.clone().into_primitive(),
+            mask.clone().map(|mask| mask.into_primitive()),
+            scale,
+            causal,
+        ));
+
+        // Composed path: scores, scale, mask, softmax and weighted sum of the values.
+        let mut scores = query.matmul(key.swap_dims(2, 3)).mul_scalar(scale);
+        let scores_shape = scores.shape();
+        if let Some(mask) = mask {
+            scores = scores.mask_fill(mask.expand(scores_shape.clone()), f32::NEG_INFINITY);
+        }
+        if causal {
+            let causal_mask =
+                Tensor::<TestBackend, 2, Bool>::tril_mask([num_queries, num_keys], 0, &device);
+            scores = scores.mask_fill(
+                causal_mask
+                    .reshape([1, 1, num_queries, num_keys])
+                    .expand(scores_shape.clone()),
+                f32::NEG_INFINITY,
+            );
+        }
+        let expected = softmax(scores, 3).matmul(value);
+
+        output
+            .into_data()
+            .assert_approx_eq(&expected.into_data(), 3);
+    }
+}
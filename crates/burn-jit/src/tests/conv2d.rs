@@ -1,6 +1,7 @@
 #[burn_tensor_testgen::testgen(conv2d)]
 mod tests {
     use super::*;
+    use burn_jit::kernel::conv::{conv2d, Conv2dStrategy};
     use burn_tensor::{module, Distribution, Tensor};
 
     #[test]
@@ -26,4 +27,91 @@ mod tests {
             .into_data()
             .assert_approx_eq(&output_ref.into_data(), 3);
     }
+
+    #[test]
+    fn conv2d_winograd_should_match_direct_conv() {
+        // 3x3 kernel with a stride and dilation of 1 uses the Winograd kernel.
+        test_same_as_ref([2, 5, 9, 8], [7, 5, 3, 3], [1, 1], Conv2dStrategy::Winograd);
+    }
+
+    #[test]
+    fn conv2d_winograd_should_match_direct_conv_many_channels_and_tiles() {
+        // The transformed inputs are shared by every output channel.
+        test_same_as_ref(
+            [2, 33, 20, 19],
+            [70, 33, 3, 3],
+            [1, 1],
+            Conv2dStrategy::Winograd,
+        );
+    }
+
+    #[test]
+    fn conv2d_direct_strategy_should_match_reference() {
+        test_same_as_ref([2, 5, 9, 8], [7, 5, 3, 3], [1, 1], Conv2dStrategy::Direct);
+    }
+
+    #[test]
+    fn conv2d_default_strategy_should_match_reference() {
+        test_same_as_ref(
+            [2, 8, 16, 16],
+            [16, 8, 3, 3],
+            [1, 1],
+            Conv2dStrategy::default(),
+        );
+    }
+
+    #[test]
+    fn conv2d_winograd_should_match_direct_conv_odd_output_no_padding() {
+        // Output of 5x6: the last row of tiles is partial.
+        test_same_as_ref([1, 3, 7, 8], [4, 3, 3, 3], [0, 0], Conv2dStrategy::Winograd);
+    }
+
+    #[test]
+    fn conv2d_winograd_should_match_direct_conv_asymmetric_padding() {
+        test_same_as_ref(
+            [3, 4, 6, 11],
+            [2, 4, 3, 3],
+            [2, 0],
+            Conv2dStrategy::Winograd,
+        );
+    }
+
+    fn test_same_as_ref(
+        shape_input: [usize; 4],
+        shape_weight: [usize; 4],
+        padding: [usize; 2],
+        strategy: Conv2dStrategy,
+    ) {
+        let test_device = Default::default();
+        let input =
+            Tensor::<TestBackend, 4>::random(shape_input, Distribution::Default, &test_device);
+        let weight =
+            Tensor::<TestBackend, 4>::random(shape_weight, Distribution::Default, &test_device);
+        let bias = Tensor::<TestBackend, 1>::random(
+            [shape_weight[0]],
+            Distribution::Default,
+            &test_device,
+        );
+        let ref_device = Default::default();
+
+        let input_ref = Tensor::<ReferenceBackend, 4>::from_data(input.to_data(), &ref_device);
+        let weight_ref = Tensor::<ReferenceBackend, 4>::from_data(weight.to_data(), &ref_device);
+        let bias_ref = Tensor::<ReferenceBackend, 1>::from_data(bias.to_data(), &ref_device);
+
+        let options = burn_tensor::ops::ConvOptions::new([1, 1], padding, [1, 1], 1);
+
+        let output = Tensor::<TestBackend, 4>::from_primitive(conv2d(
+            input.into_primitive(),
+            weight.into_primitive(),
+            Some(bias.into_primitive()),
+            options.clone(),
+            strategy,
+        ));
+        let output_ref = module::conv2d(input_ref, weight_ref, Some(bias_ref), options);
+
+        // The Winograd transforms introduce a small rounding error compared to the direct conv.
+        output
+            .into_data()
+            .assert_approx_eq(&output_ref.into_data(), 3);
+    }
 }
@@ -0,0 +1,52 @@
+#[burn_tensor_testgen::testgen(expand)]
+mod tests {
+    use super::*;
+    use burn_jit::tests::burn_fusion::Fusion;
+    use burn_tensor::{Tensor, TensorData};
+
+    type FusionBackend = Fusion<TestBackend>;
+
+    #[test]
+    fn expanded_lhs_should_not_be_written_in_place() {
+        // The expanded tensor owns its handle, so only its zero strides prevent an in-place add.
+        let expanded = TestTensor::<2>::from([[1.0, 2.0, 3.0]]).expand([3, 3]);
+        let rhs = TestTensor::<2>::from([[0.0, 0.0, 0.0], [10.0, 10.0, 10.0], [20.0, 20.0, 20.0]]);
+
+        let output = expanded.add(rhs);
+
+        output.into_data().assert_eq(
+            &TensorData::from([[1.0, 2.0, 3.0], [11.0, 12.0, 13.0], [21.0, 22.0, 23.0]]),
+            false,
+        );
+    }
+
+    #[test]
+    fn expanded_tensor_should_not_mutate_its_source() {
+        let source = TestTensor::<2>::from([[1.0], [2.0]]);
+
+        let output = source.clone().expand([2, 3]).add_scalar(1.0);
+
+        output
+            .into_data()
+            .assert_eq(&TensorData::from([[2.0, 2.0, 2.0], [3.0, 3.0, 3.0]]), false);
+        source
+            .into_data()
+            .assert_eq(&TensorData::from([[1.0], [2.0]]), false);
+    }
+
+    #[test]
+    fn fused_expanded_input_should_not_be_written_in_place() {
+        // Expanded on the last dimension, which has strides of a contiguous tensor otherwise.
+        let source = Tensor::<FusionBackend, 2>::from([[1.0], [2.0]]);
+        let expanded = source.clone().expand([2, 3]);
+
+        let output = expanded.add_scalar(1.0).mul_scalar(2.0);
+
+        output
+            .into_data()
+            .assert_eq(&TensorData::from([[4.0, 4.0, 4.0], [6.0, 6.0, 6.0]]), false);
+        source
+            .into_data()
+            .assert_eq(&TensorData::from([[1.0], [2.0]]), false);
+    }
+}
@@ -676,6 +676,72 @@ mod tests {
         }
     }
 
+    mod activation {
+        use super::*;
+        use burn_jit::kernel::matmul::{matmul_activation, MatmulActivation};
+        use burn_tensor::activation;
+
+        #[test]
+        fn relu_should_match_unfused() {
+            test_with_params(MatmulActivation::Relu, [2, 3, 9, 8], [2, 3, 8, 17]);
+        }
+
+        #[test]
+        fn gelu_should_match_unfused() {
+            test_with_params(MatmulActivation::Gelu, [2, 3, 9, 8], [2, 3, 8, 17]);
+        }
+
+        #[test]
+        fn gelu_should_match_unfused_with_bounds_check() {
+            test_with_params(MatmulActivation::Gelu, [1, 4, 7, 5], [1, 4, 5, 3]);
+        }
+
+        #[test]
+        fn relu_should_match_unfused_over_many_blocks() {
+            test_with_params(MatmulActivation::Relu, [1, 2, 130, 70], [1, 2, 70, 129]);
+        }
+
+        #[test]
+        fn relu_should_match_unfused_with_broadcasted_batches() {
+            test_with_params(MatmulActivation::Relu, [3, 1, 6, 4], [1, 2, 4, 6]);
+        }
+
+        fn test_with_params(
+            activation: MatmulActivation,
+            shape_lhs: [usize; 4],
+            shape_rhs: [usize; 4],
+        ) {
+            let distribution = burn_tensor::Distribution::Uniform(-1.0, 1.0);
+            let ref_tensor_device = Default::default();
+            let x = ReferenceTensor::random(shape_lhs, distribution, &ref_tensor_device);
+            let y = ReferenceTensor::random(shape_rhs, distribution, &ref_tensor_device);
+            let bias = Tensor::<ReferenceBackend, 1>::random(
+                [shape_rhs[3]],
+                distribution,
+                &ref_tensor_device,
+            );
+
+            let test_tensor_device = Default::default();
+            let x_jit = TestTensor::from_data(x.to_data(), &test_tensor_device);
+            let y_jit = TestTensor::from_data(y.to_data(), &test_tensor_device);
+            let bias_jit = Tensor::<TestBackend, 1>::from_data(bias.to_data(), &test_tensor_device);
+
+            let z_reference = x.matmul(y) + bias.unsqueeze();
+            let z_reference = match activation {
+                MatmulActivation::Relu => activation::relu(z_reference),
+                MatmulActivation::Gelu => activation::gelu(z_reference),
+            };
+            let z = Tensor::<TestBackend, 4>::from_primitive(matmul_activation(
+                x_jit.into_primitive(),
+                y_jit.into_primitive(),
+                bias_jit.into_primitive(),
+                activation,
+            ));
+
+            z_reference.into_data().assert_approx_eq(&z.into_data(), 3);
+        }
+    }
+
     fn same_as_reference<const D: usize, S>(strategy: MatmulStrategy, shape_lhs: S, shape_rhs: S)
     where
         S: Into<Shape<D>>,
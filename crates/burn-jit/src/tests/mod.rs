@@ -1,5 +1,6 @@
 #![allow(missing_docs)]
 
+mod attention;
 mod avg_pool2d;
 mod bernoulli;
 mod cast;
@@ -7,6 +8,7 @@ mod cat;
 mod clamp;
 mod conv2d;
 mod conv_transpose2d;
+mod expand;
 mod gather;
 mod mask_fill;
 mod mask_where;
@@ -17,10 +19,14 @@ mod normal;
 mod reduce;
 mod repeat;
 mod scatter;
+mod scatter_max;
+mod segment_softmax;
 mod select;
 mod select_assign;
 mod slice;
 mod slice_assign;
+mod topk;
+mod transpose;
 mod unary;
 mod uniform;
 
@@ -44,6 +50,7 @@ macro_rules! testgen_all {
                 burn_jit::testgen_conv2d!();
                 burn_jit::testgen_conv_transpose2d!();
 
+                burn_jit::testgen_expand!();
                 burn_jit::testgen_repeat!();
                 burn_jit::testgen_gather!();
                 burn_jit::testgen_scatter!();
@@ -70,6 +77,11 @@ macro_rules! testgen_all {
                 burn_jit::testgen_clamp!();
                 burn_jit::testgen_unary!();
                 burn_jit::testgen_matmul!();
+                burn_jit::testgen_topk!();
+                burn_jit::testgen_scatter_max!();
+                burn_jit::testgen_segment_softmax!();
+                burn_jit::testgen_transpose!();
+                burn_jit::testgen_attention!();
             }
         }
         mod jit_fusion {
@@ -0,0 +1,79 @@
+#[burn_tensor_testgen::testgen(scatter_max)]
+mod tests {
+    use super::*;
+    use burn_jit::kernel::scatter_max::scatter_max;
+    use burn_tensor::{backend::Backend, Distribution, Int, Tensor, TensorData};
+
+    #[test]
+    fn scatter_max_should_match_reference() {
+        test_same_as_reference([3, 64], 1, 5);
+    }
+
+    #[test]
+    fn scatter_max_should_match_reference_first_dim() {
+        test_same_as_reference([37, 4], 0, 6);
+    }
+
+    #[test]
+    fn scatter_max_should_match_reference_many_segments() {
+        // Segments of one to three elements, some of them empty.
+        test_same_as_reference([2, 3, 200], 2, 150);
+    }
+
+    #[test]
+    fn scatter_max_should_keep_lowest_position_and_ignore_out_of_range_segments() {
+        let tensor = TestTensor::<2>::from([[1.0, 4.0, 3.0, 4.0, 9.0]]);
+        let indices = TestTensorInt::<2>::from([[1, 0, 1, 0, -1]]);
+
+        let (maxima, argmax) = scatter_max(tensor.into_primitive(), 1, indices.into_primitive(), 3);
+        let maxima = Tensor::<TestBackend, 2>::from_primitive(maxima);
+        let argmax = Tensor::<TestBackend, 2, Int>::from_primitive(argmax);
+
+        maxima
+            .into_data()
+            .assert_eq(&TensorData::from([[4.0, 3.0, f32::NEG_INFINITY]]), false);
+        argmax
+            .into_data()
+            .assert_eq(&TensorData::from([[1, 2, 5]]), false);
+    }
+
+    fn test_same_as_reference<const D: usize>(shape: [usize; D], dim: usize, num_segments: usize) {
+        TestBackend::seed(0);
+        let size = shape[dim];
+        // Unsorted segments, shared by every row.
+        let segments = (0..size as i64)
+            .map(|i| (i * 7 + 3) % num_segments as i64)
+            .collect::<Vec<_>>();
+        let mut shape_ids = [1; D];
+        shape_ids[dim] = size;
+
+        let tensor = Tensor::<TestBackend, D>::random(
+            shape,
+            Distribution::Uniform(-4.0, 4.0),
+            &Default::default(),
+        );
+        let indices = Tensor::<TestBackend, 1, Int>::from_data(
+            TensorData::new(segments.clone(), [size]),
+            &Default::default(),
+        )
+        .reshape(shape_ids)
+        .expand(shape);
+
+        let (maxima, argmax) = tensor.clone().scatter_max(dim, indices, num_segments);
+
+        let tensor_ref =
+            Tensor::<ReferenceBackend, D>::from_data(tensor.into_data(), &Default::default());
+        let indices_ref = Tensor::<ReferenceBackend, 1, Int>::from_data(
+            TensorData::new(segments, [size]),
+            &Default::default(),
+        )
+        .reshape(shape_ids)
+        .expand(shape);
+        let (maxima_ref, argmax_ref) = tensor_ref.scatter_max(dim, indices_ref, num_segments);
+
+        maxima
+            .into_data()
+            .assert_approx_eq(&maxima_ref.into_data(), 3);
+        argmax.into_data().assert_eq(&argmax_ref.into_data(), false);
+    }
+}
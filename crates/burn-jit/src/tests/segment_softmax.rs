@@ -0,0 +1,101 @@
+#[burn_tensor_testgen::testgen(segment_softmax)]
+mod tests {
+    use super::*;
+    use burn_jit::kernel::segment_softmax::segment_softmax;
+    use burn_tensor::{backend::Backend, Distribution, Int, Tensor, TensorData};
+
+    #[test]
+    fn segment_softmax_should_match_unfused() {
+        test_same_as_unfused([3, 64], 1, 5);
+    }
+
+    #[test]
+    fn segment_softmax_should_match_unfused_first_dim() {
+        test_same_as_unfused([37, 4], 0, 6);
+    }
+
+    #[test]
+    fn segment_softmax_should_match_unfused_many_segments() {
+        // Segments of one to three elements, some of them empty.
+        test_same_as_unfused([2, 3, 200], 2, 150);
+    }
+
+    #[test]
+    fn segment_softmax_should_ignore_out_of_range_segments() {
+        let tensor = TestTensor::<2>::from([[1.0, 2.0, 3.0, 4.0]]);
+        let segment_ids = TestTensorInt::<1>::from([1, -1, 1, 5]);
+
+        let output = Tensor::<TestBackend, 2>::from_primitive(segment_softmax(
+            tensor.into_primitive(),
+            segment_ids.into_primitive(),
+            2,
+            1,
+        ));
+
+        // exp(1) / (exp(1) + exp(3)) and exp(3) / (exp(1) + exp(3)).
+        let expected = TensorData::from([[0.1192, 0.0, 0.8808, 0.0]]);
+        output.into_data().assert_approx_eq(&expected, 3);
+    }
+
+    fn test_same_as_unfused<const D: usize>(shape: [usize; D], dim: usize, num_segments: usize) {
+        TestBackend::seed(0);
+        let size = shape[dim];
+        // Unsorted segments.
+        let segments = (0..size as i64)
+            .map(|i| (i * 7 + 3) % num_segments as i64)
+            .collect::<Vec<_>>();
+
+        let tensor = Tensor::<TestBackend, D>::random(
+            shape,
+            Distribution::Uniform(-4.0, 4.0),
+            &Default::default(),
+        );
+        let segment_ids = Tensor::<TestBackend, 1, Int>::from_data(
+            TensorData::new(segments.clone(), [size]),
+            &Default::default(),
+        );
+
+        let output = tensor
+            .clone()
+            .segment_softmax(dim, segment_ids, num_segments);
+
+        // Unfused path: segment max, exponentials, segment sum and division.
+        let tensor_ref =
+            Tensor::<ReferenceBackend, D>::from_data(tensor.into_data(), &Default::default());
+        let segment_ids_ref = Tensor::<ReferenceBackend, 1, Int>::from_data(
+            TensorData::new(segments.clone(), [size]),
+            &Default::default(),
+        );
+        let expected = tensor_ref.segment_softmax(dim, segment_ids_ref, num_segments);
+
+        let output =
+            Tensor::<ReferenceBackend, D>::from_data(output.into_data(), &Default::default());
+        output
+            .clone()
+            .into_data()
+            .assert_approx_eq(&expected.into_data(), 3);
+
+        // The outputs of each non empty segment sum to 1.
+        let mut shape_ids = [1; D];
+        shape_ids[dim] = size;
+        let indices = Tensor::<ReferenceBackend, 1, Int>::from_data(
+            TensorData::new(segments, [size]),
+            &Default::default(),
+        )
+        .reshape(shape_ids)
+        .expand(shape);
+        let mut shape_segments = shape;
+        shape_segments[dim] = num_segments;
+        let sums = Tensor::<ReferenceBackend, D>::zeros(shape_segments, &Default::default())
+            .scatter(dim, indices, output);
+        let non_empty = sums.clone().greater_elem(0.0);
+
+        sums.clone().into_data().assert_approx_eq(
+            &sums
+                .ones_like()
+                .mask_fill(non_empty.bool_not(), 0.0)
+                .into_data(),
+            3,
+        );
+    }
+}
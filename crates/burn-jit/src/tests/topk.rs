@@ -0,0 +1,69 @@
+#[burn_tensor_testgen::testgen(topk)]
+mod tests {
+    use super::*;
+    use burn_tensor::{backend::Backend, Distribution, Int, Tensor};
+
+    #[test]
+    fn topk_should_match_reference_small_k_large_dim() {
+        test_same_as_ref([4, 1024], 1, 5);
+    }
+
+    #[test]
+    fn topk_should_match_reference_row_shorter_than_cube() {
+        test_same_as_ref([5, 100], 1, 20);
+    }
+
+    #[test]
+    fn topk_should_match_reference_first_dim() {
+        test_same_as_ref([777, 3, 2], 0, 8);
+    }
+
+    #[test]
+    fn topk_should_match_reference_with_ties() {
+        TestBackend::seed(0);
+        // Few distinct values, so that the k-th largest value is tied.
+        let tensor = Tensor::<TestBackend, 2>::random(
+            [6, 512],
+            Distribution::Uniform(0., 4.),
+            &Default::default(),
+        )
+        .int()
+        .float();
+        let tensor_ref =
+            Tensor::<ReferenceBackend, 2>::from_data(tensor.to_data(), &Default::default());
+
+        let (values, indices) = tensor.clone().topk_with_indices(10, 1);
+        let values_ref = tensor_ref.topk(10, 1);
+
+        values
+            .to_data()
+            .assert_approx_eq(&values_ref.into_data(), 3);
+        // Tied elements are kept in index order.
+        let sorted_indices = indices.clone().sort(1);
+        indices
+            .to_data()
+            .assert_eq(&sorted_indices.into_data(), false);
+        tensor
+            .gather(1, indices)
+            .into_data()
+            .assert_approx_eq(&values.into_data(), 3);
+    }
+
+    fn test_same_as_ref<const D: usize>(shape: [usize; D], dim: usize, k: usize) {
+        TestBackend::seed(0);
+        let tensor =
+            Tensor::<TestBackend, D>::random(shape, Distribution::Default, &Default::default());
+        let tensor_ref =
+            Tensor::<ReferenceBackend, D>::from_data(tensor.to_data(), &Default::default());
+
+        let (values, indices) = tensor.topk_with_indices(k, dim);
+        let (values_ref, indices_ref) = tensor_ref.topk_with_indices(k, dim);
+
+        values_ref
+            .into_data()
+            .assert_approx_eq(&values.into_data(), 3);
+        Tensor::<ReferenceBackend, D, Int>::from_data(indices.into_data(), &Default::default())
+            .into_data()
+            .assert_eq(&indices_ref.into_data(), false);
+    }
+}
@@ -0,0 +1,83 @@
+#[burn_tensor_testgen::testgen(transpose)]
+mod tests {
+    use super::*;
+    use burn_jit::kernel::transpose::transpose_last2;
+    use burn_tensor::{backend::Backend, Distribution, Int, Tensor};
+
+    #[test]
+    fn transpose_last2_should_match_generic_batched() {
+        test_same_as_generic([3, 37, 21]);
+    }
+
+    #[test]
+    fn transpose_last2_should_match_generic_tiles() {
+        test_same_as_generic([2, 2, 16, 48]);
+    }
+
+    #[test]
+    fn transpose_last2_should_match_generic_large_batch() {
+        // More matrices than the cubes allowed in a single launch dimension.
+        test_same_as_generic([70_000, 2, 3]);
+    }
+
+    #[test]
+    fn transpose_last2_op_should_match_generic_bool() {
+        let tensor = Tensor::<TestBackend, 1, Int>::arange(0..2 * 19 * 20, &Default::default())
+            .reshape([2, 19, 20])
+            .remainder_scalar(3)
+            .equal_elem(0);
+
+        let output = tensor.clone().transpose_last2();
+
+        output
+            .into_data()
+            .assert_eq(&tensor.swap_dims(1, 2).into_data(), true);
+    }
+
+    #[test]
+    fn transpose_last2_should_match_generic_int() {
+        let tensor = Tensor::<TestBackend, 1, Int>::arange(0..3 * 17 * 5, &Default::default())
+            .reshape([3, 17, 5]);
+
+        let output = Tensor::<TestBackend, 3, Int>::from_primitive(transpose_last2(
+            tensor.clone().into_primitive(),
+        ));
+
+        output
+            .into_data()
+            .assert_eq(&tensor.swap_dims(1, 2).into_data(), true);
+    }
+
+    #[test]
+    fn transpose_last2_should_match_generic_strided_input() {
+        TestBackend::seed(0);
+        let tensor = Tensor::<TestBackend, 3>::random(
+            [19, 4, 33],
+            Distribution::Default,
+            &Default::default(),
+        )
+        .swap_dims(0, 1);
+
+        let output = Tensor::<TestBackend, 3>::from_primitive(transpose_last2(
+            tensor.clone().into_primitive(),
+        ));
+
+        output
+            .into_data()
+            .assert_eq(&tensor.swap_dims(1, 2).into_data(), true);
+    }
+
+    fn test_same_as_generic<const D: usize>(shape: [usize; D]) {
+        TestBackend::seed(0);
+        let tensor =
+            Tensor::<TestBackend, D>::random(shape, Distribution::Default, &Default::default());
+
+        let output = Tensor::<TestBackend, D>::from_primitive(transpose_last2(
+            tensor.clone().into_primitive(),
+        ));
+        // Generic path: permute the strides, then copy with the contiguous kernel.
+        let expected = tensor.swap_dims(D - 2, D - 1).into_data();
+
+        output.into_data().assert_eq(&expected, true);
+    }
+}
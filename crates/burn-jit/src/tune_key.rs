@@ -1,4 +1,6 @@
-use crate::kernel::{matmul::MatmulAutotuneKey, reduce::ReduceAutotuneKey};
+use crate::kernel::{
+    conv::Conv2dAutotuneKey, matmul::MatmulAutotuneKey, reduce::ReduceAutotuneKey,
+};
 use burn_compute::tune::AutotuneKey;
 use serde::{Deserialize, Serialize};
 use std::fmt::Display;
@@ -13,6 +15,8 @@ pub enum JitAutotuneKey {
     Matmul(MatmulAutotuneKey),
     /// Key for reduce dim operations
     ReduceDim(ReduceAutotuneKey),
+    /// Key for conv2d operation
+    Conv2d(Conv2dAutotuneKey),
     #[cfg(any(feature = "fusion", test))]
     /// Key for fused element wise operations.
     FusionElemWise(FusionElemWiseAutotuneKey),
@@ -23,6 +27,7 @@ impl Display for JitAutotuneKey {
         match self {
             JitAutotuneKey::Matmul(matmul_key) => std::fmt::Display::fmt(&matmul_key, f),
             JitAutotuneKey::ReduceDim(reduce_key) => std::fmt::Display::fmt(&reduce_key, f),
+            JitAutotuneKey::Conv2d(conv2d_key) => std::fmt::Display::fmt(&conv2d_key, f),
             #[cfg(any(feature = "fusion", test))]
             JitAutotuneKey::FusionElemWise(reduce_key) => std::fmt::Display::fmt(&reduce_key, f),
         }
@@ -291,6 +291,13 @@ where
         }
     }
 
+    pub fn cumsum<const D: usize>(tensor: NdArrayTensor<E, D>, dim: usize) -> NdArrayTensor<E, D> {
+        let mut array = tensor.array.into_owned();
+        array.accumulate_axis_inplace(Axis(dim), |previous, current| *current += *previous);
+
+        NdArrayTensor::new(array.into_shared())
+    }
+
     pub fn prod_dim<const D: usize>(
         tensor: NdArrayTensor<E, D>,
         dim: usize,
@@ -418,15 +425,13 @@ where
         mask: NdArrayTensor<bool, D>,
         value: E,
     ) -> NdArrayTensor<E, D> {
-        let mask_mul = mask.array.mapv(|x| match x {
-            true => 0.elem(),
-            false => 1.elem(),
-        });
-        let mask_add = mask.array.mapv(|x| match x {
-            true => value,
-            false => 0.elem(),
+        // Assign the value instead of multiplying by zero, so that masked NaN values are replaced.
+        let mut array = tensor.array;
+        array.zip_mut_with(&mask.array, |x, mask| {
+            if *mask {
+                *x = value;
+            }
         });
-        let array = (tensor.array * mask_mul) + mask_add;
 
         NdArrayTensor::new(array)
     }
@@ -657,4 +662,24 @@ mod tests {
             expected_array.array.into_iter().collect::<Vec<_>>(),
         );
     }
+
+    #[test]
+    fn should_write_kv_cache_buffers_in_place() {
+        use crate::NdArray;
+        use burn_tensor::{KvCache, Tensor};
+
+        let device = Default::default();
+        let mut cache = KvCache::<NdArray<f32>, 2>::new([4, 2], [4, 2], 0, &device);
+        let data_ptr = |tensor: Tensor<NdArray<f32>, 2>| tensor.into_primitive().array.as_ptr();
+
+        let (keys, values) =
+            cache.append(Tensor::ones([1, 2], &device), Tensor::ones([1, 2], &device));
+        let (keys_ptr, values_ptr) = (data_ptr(keys), data_ptr(values));
+
+        let (keys, values) =
+            cache.append(Tensor::ones([2, 2], &device), Tensor::ones([2, 2], &device));
+
+        assert_eq!(data_ptr(keys), keys_ptr);
+        assert_eq!(data_ptr(values), values_ptr);
+    }
 }
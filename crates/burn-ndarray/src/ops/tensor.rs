@@ -1,7 +1,8 @@
 // Language
 use alloc::vec::Vec;
+use core::ffi::c_void;
 use core::ops::Range;
-use ndarray::IntoDimension;
+use ndarray::{Array, IntoDimension, IxDyn};
 
 // Current crate
 use super::{matmul::matmul, NdArrayMathOps, NdArrayOps};
@@ -11,6 +12,7 @@ use crate::{NdArrayDevice, SEED};
 
 // Workspace crates
 use burn_common::rand::get_seeded_rng;
+use burn_tensor::dlpack::{DLDevice, DLPackTensor};
 use burn_tensor::{backend::Backend, ops::FloatTensorOps, ElementConversion, Shape, TensorData};
 use burn_tensor::{Distribution, Reader};
 
@@ -58,6 +60,44 @@ impl<E: FloatNdArrayElement> FloatTensorOps<Self> for NdArray<E> {
         Reader::Concrete(TensorData::new(values, shape))
     }
 
+    fn float_to_dlpack<const D: usize>(tensor: NdArrayTensor<E, D>) -> Reader<DLPackTensor> {
+        // The buffer is only shared when no other tensor can see it, since the consumer may write
+        // into it. Clones and views of non standard layouts are exported as a copy.
+        let array = match tensor.array.try_into_owned_nocopy() {
+            Ok(array) if array.is_standard_layout() => array,
+            Ok(array) => array.as_standard_layout().into_owned(),
+            Err(array) => array.as_standard_layout().into_owned(),
+        };
+        let data = array.as_ptr() as *mut c_void;
+        let shape = array.shape().to_vec();
+        let strides = array.strides().to_vec();
+
+        // SAFETY: the array keeps its buffer alive, and its shape and strides describe it.
+        let tensor = unsafe {
+            DLPackTensor::from_owner(
+                array,
+                data,
+                DLDevice::CPU,
+                E::dtype(),
+                &shape,
+                Some(&strides),
+            )
+        };
+
+        Reader::Concrete(tensor)
+    }
+
+    fn float_from_dlpack<const D: usize>(
+        tensor: DLPackTensor,
+        _device: &NdArrayDevice,
+    ) -> NdArrayTensor<E, D> {
+        // A tensor exported by this backend gets its array back, sharing the same buffer.
+        match tensor.into_owner::<Array<E, IxDyn>>() {
+            Ok(array) => NdArrayTensor::new(array.into_shared()),
+            Err(tensor) => NdArrayTensor::from_data(tensor.to_data()),
+        }
+    }
+
     fn float_device<const D: usize>(_tensor: &NdArrayTensor<E, D>) -> NdArrayDevice {
         NdArrayDevice::Cpu
     }
@@ -339,6 +379,13 @@ impl<E: FloatNdArrayElement> FloatTensorOps<Self> for NdArray<E> {
         NdArrayMathOps::sum_dim(tensor, dim)
     }
 
+    fn float_cumsum<const D: usize>(
+        tensor: NdArrayTensor<E, D>,
+        dim: usize,
+    ) -> NdArrayTensor<E, D> {
+        NdArrayMathOps::cumsum(tensor, dim)
+    }
+
     fn float_argmax<const D: usize>(
         tensor: NdArrayTensor<E, D>,
         dim: usize,
@@ -503,3 +550,86 @@ impl<E: FloatNdArrayElement> FloatTensorOps<Self> for NdArray<E> {
         NdArrayOps::expand(tensor, shape)
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use burn_tensor::Tensor;
+
+    #[test]
+    fn dlpack_round_trip_should_share_the_buffer() {
+        let device = NdArrayDevice::Cpu;
+        let tensor =
+            Tensor::<NdArray<f32>, 2>::from_floats([[1.0, 2.0, 3.0], [4.0, 5.0, 6.0]], &device);
+        let buffer = tensor.clone().into_primitive().array.as_ptr();
+
+        let dlpack = tensor.to_dlpack();
+
+        assert_eq!(dlpack.data_ptr(), buffer as *const c_void);
+        assert_eq!(dlpack.device(), DLDevice::CPU);
+        assert_eq!(dlpack.shape(), [2, 3]);
+        assert_eq!(dlpack.strides(), [3, 1]);
+
+        let tensor = Tensor::<NdArray<f32>, 2>::from_dlpack(dlpack, &device);
+
+        assert_eq!(tensor.clone().into_primitive().array.as_ptr(), buffer);
+        tensor
+            .into_data()
+            .assert_eq(&TensorData::from([[1.0, 2.0, 3.0], [4.0, 5.0, 6.0]]), false);
+    }
+
+    #[test]
+    fn dlpack_export_should_not_alias_cloned_tensors() {
+        let device = NdArrayDevice::Cpu;
+        let tensor = Tensor::<NdArray<f32>, 1>::from_floats([1.0, 2.0, 3.0], &device);
+        let clone = tensor.clone();
+        let buffer = clone.clone().into_primitive().array.as_ptr();
+
+        let dlpack = tensor.to_dlpack();
+
+        assert_ne!(dlpack.data_ptr(), buffer as *const c_void);
+
+        // SAFETY: the exported buffer holds three contiguous `f32` values owned by `dlpack`.
+        unsafe { *(dlpack.data_ptr() as *mut f32) = 10.0 };
+
+        clone
+            .into_data()
+            .assert_eq(&TensorData::from([1.0, 2.0, 3.0]), false);
+    }
+
+    #[test]
+    fn dlpack_export_should_copy_transposed_tensors() {
+        let device = NdArrayDevice::Cpu;
+        let tensor =
+            Tensor::<NdArray<f32>, 2>::from_floats([[1.0, 2.0, 3.0], [4.0, 5.0, 6.0]], &device)
+                .swap_dims(0, 1);
+
+        let dlpack = tensor.to_dlpack();
+
+        assert_eq!(dlpack.shape(), [3, 2]);
+        assert_eq!(dlpack.strides(), [2, 1]);
+
+        Tensor::<NdArray<f32>, 2>::from_dlpack(dlpack, &device)
+            .into_data()
+            .assert_eq(
+                &TensorData::from([[1.0, 4.0], [2.0, 5.0], [3.0, 6.0]]),
+                false,
+            );
+    }
+
+    #[test]
+    fn dlpack_import_should_copy_foreign_tensors() {
+        let dlpack = DLPackTensor::from_vec(vec![1.0f32, 2.0, 3.0], &[3]);
+        let buffer = dlpack.data_ptr();
+
+        let tensor = Tensor::<NdArray<f32>, 1>::from_dlpack(dlpack, &NdArrayDevice::Cpu);
+
+        assert_ne!(
+            tensor.clone().into_primitive().array.as_ptr() as *const c_void,
+            buffer
+        );
+        tensor
+            .into_data()
+            .assert_eq(&TensorData::from([1.0, 2.0, 3.0]), false);
+    }
+}
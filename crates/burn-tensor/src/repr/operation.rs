@@ -996,6 +996,7 @@ pub enum InterpolateModeDescription {
 #[allow(missing_docs)]
 pub struct InterpolateOptionsDescription {
     pub mode: InterpolateModeDescription,
+    pub antialias: bool,
 }
 
 #[derive(Clone, Debug, Hash, PartialEq, Serialize, Deserialize)]
@@ -1021,6 +1022,7 @@ impl From<InterpolateOptionsDescription> for InterpolateOptions {
     fn from(val: InterpolateOptionsDescription) -> Self {
         Self {
             mode: val.mode.into(),
+            antialias: val.antialias,
         }
     }
 }
@@ -1039,6 +1041,7 @@ impl From<InterpolateOptions> for InterpolateOptionsDescription {
     fn from(val: InterpolateOptions) -> Self {
         Self {
             mode: val.mode.into(),
+            antialias: val.antialias,
         }
     }
 }
@@ -23,11 +23,50 @@ pub fn leaky_relu<const D: usize, B: Backend>(
     ))
 }
 
+/// Applies the hard tanh function.
+///
+/// `hard_tanh(x) = min_val` for `x < min_val`, `max_val` for `x > max_val` and `x` otherwise.
+pub fn hard_tanh<const D: usize, B: Backend>(
+    tensor: Tensor<B, D>,
+    min_val: f64,
+    max_val: f64,
+) -> Tensor<B, D> {
+    tensor.clamp(min_val, max_val)
+}
+
+/// Applies the hard sigmoid function, following the ONNX definition.
+///
+/// `hard_sigmoid(x) = max(0, min(1, alpha * x + beta))`
+///
+/// The ONNX defaults are `alpha = 0.2` and `beta = 0.5`.
+pub fn hard_sigmoid<const D: usize, B: Backend>(
+    tensor: Tensor<B, D>,
+    alpha: f64,
+    beta: f64,
+) -> Tensor<B, D> {
+    tensor.mul_scalar(alpha).add_scalar(beta).clamp(0.0, 1.0)
+}
+
+/// Applies the hard swish function as described in the paper [Searching for MobileNetV3](https://arxiv.org/abs/1905.02244).
+///
+/// `hard_swish(x) = x * max(0, min(1, x / 6 + 0.5))`
+pub fn hard_swish<const D: usize, B: Backend>(tensor: Tensor<B, D>) -> Tensor<B, D> {
+    tensor.clone().mul(hard_sigmoid(tensor, 1.0 / 6.0, 0.5))
+}
+
 /// Applies the Gaussian Error Linear Units function as described in the paper [Gaussian Error Linear Units (GELUs)](https://arxiv.org/pdf/1606.08415v3.pdf).
 pub fn gelu<const D: usize, B: Backend>(tensor: Tensor<B, D>) -> Tensor<B, D> {
     Tensor::from_primitive(B::gelu(tensor.primitive))
 }
 
+/// Applies the tanh approximation of the Gaussian Error Linear Units function, as used by
+/// PyTorch's `gelu(approximate="tanh")` and the ONNX `Gelu` operator.
+///
+/// `gelu_tanh(x) = 0.5 * x * (1 + tanh(sqrt(2 / pi) * (x + 0.044715 * x^3)))`
+pub fn gelu_tanh<const D: usize, B: Backend>(tensor: Tensor<B, D>) -> Tensor<B, D> {
+    Tensor::from_primitive(B::gelu_tanh(tensor.primitive))
+}
+
 /// Applies Parametric ReLu activation function as described in the paper [Delving Deep into Rectifiers: Surpassing Human-Level Performance on ImageNet Classification](https://arxiv.org/pdf/1502.01852).
 /// ` PReLu(x) = max(0,x) + \alpha * min(0,x)`
 /// tensor is assumed to be of shape \[batch_size, channels, ...\]
@@ -78,8 +117,14 @@ pub fn softmax<const D: usize, B: Backend>(tensor: Tensor<B, D>, dim: usize) ->
 /// Applies the softplus function
 ///
 /// `softplus(x_i) = log(1 + exp(\beta x_i)) / \beta`
+///
+/// # Notes
+///
+/// The function is computed as `(max(\beta x_i, 0) + log(1 + exp(-|\beta x_i|))) / \beta`, which
+/// is equivalent but never evaluates `exp` on a large positive value, so it doesn't overflow.
 pub fn softplus<const D: usize, B: Backend>(tensor: Tensor<B, D>, beta: f64) -> Tensor<B, D> {
-    let tensor = (tensor.mul_scalar(beta).exp() + 1).log();
+    let tensor = tensor.mul_scalar(beta);
+    let tensor = tensor.clone().clamp_min(0.0) + tensor.abs().neg().exp().log1p();
     tensor.div_scalar(beta)
 }
 
@@ -139,7 +184,7 @@ pub fn silu<const D: usize, B: Backend>(tensor: Tensor<B, D>) -> Tensor<B, D> {
 ///
 /// `mish(x_i) = x_i \times tanh(softplus(x_i))`
 pub fn mish<const D: usize, B: Backend>(tensor: Tensor<B, D>) -> Tensor<B, D> {
-    tensor.clone().mul(softplus(tensor, 1.0).tanh())
+    Tensor::from_primitive(B::mish(tensor.primitive))
 }
 
 /// Applies the tanh function
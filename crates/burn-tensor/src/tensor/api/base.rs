@@ -1,13 +1,12 @@
 #![allow(clippy::single_range_in_vec_init)]
 
+use alloc::vec;
 use alloc::vec::Vec;
 
 #[cfg(any(feature = "wasm-sync", not(target_family = "wasm")))]
 use alloc::format;
 #[cfg(any(feature = "wasm-sync", not(target_family = "wasm")))]
 use alloc::string::String;
-#[cfg(any(feature = "wasm-sync", not(target_family = "wasm")))]
-use alloc::vec;
 
 use burn_common::{reader::Reader, stub::Mutex};
 use core::iter::repeat;
@@ -94,6 +93,8 @@ where
     /// - If the tensor contains more than one `-1` in the shape.
     /// - If the tensor contains values that are not positive (other than -1).
     /// - If the shape does not match the number of elements of the original shape.
+    /// - If the `-1` dimension can't be inferred, the number of elements of the original shape
+    ///   not being divisible by the product of the other dimensions.
     ///
     /// # Example
     /// ```rust
@@ -128,6 +129,22 @@ where
         Tensor::new(K::transpose(self.primitive))
     }
 
+    /// Transposes the last two dimensions of the tensor into a new contiguous tensor.
+    ///
+    /// The result is the same as [transpose](Tensor::transpose), which only swaps the strides of
+    /// the last two dimensions, but the elements are copied in the transposed order. Backends may
+    /// use a dedicated kernel reading and writing memory in tiles, which is faster than the
+    /// generic copy of a strided tensor when the transposed tensor is read many times, as the
+    /// keys in attention.
+    ///
+    /// # Panics
+    ///
+    /// If the tensor has a rank lower than 2.
+    pub fn transpose_last2(self) -> Tensor<B, D, K> {
+        check!(TensorCheck::transpose_last2::<D>());
+        Tensor::new(K::transpose_last2(self.primitive))
+    }
+
     /// Swaps two dimensions of a tensor.
     ///
     /// # Arguments
@@ -675,6 +692,18 @@ where
         Self::new(K::to_device(self.primitive, device))
     }
 
+    /// Starts moving the tensor to the given device, returning a handle to
+    /// [wait](TensorTransfer::wait) on.
+    ///
+    /// No backend overlaps the transfer with other work yet: the transfer is performed right away,
+    /// which behaves like [to_device](Tensor::to_device). Backends may override it to transfer the
+    /// tensor in the background.
+    pub fn to_device_async(self, device: &B::Device) -> TensorTransfer<B, D, K> {
+        TensorTransfer {
+            reader: K::to_device_async(self.primitive, device),
+        }
+    }
+
     #[cfg(all(not(feature = "wasm-sync"), target_family = "wasm"))]
     /// Returns the data of the current tensor.
     pub async fn into_data(self) -> TensorData {
@@ -699,6 +728,28 @@ where
         Self::into_data(self.clone())
     }
 
+    #[cfg(any(feature = "wasm-sync", not(target_family = "wasm")))]
+    /// Returns an iterator over the data of the current tensor, in chunks of at most
+    /// `chunk_elems` elements.
+    ///
+    /// Each chunk is read from the device when the iterator advances, so the host memory used is
+    /// bounded by the chunk size, e.g. when writing a large tensor to disk. The chunks are
+    /// one-dimensional and their concatenation gives the flattened data of the tensor.
+    ///
+    /// # Panics
+    ///
+    /// If `chunk_elems` is zero.
+    pub fn to_data_chunks(&self, chunk_elems: usize) -> DataChunks<B, K> {
+        check!(TensorCheck::to_data_chunks(chunk_elems));
+
+        let num_elements = self.shape().num_elements();
+        DataChunks {
+            tensor: self.clone().reshape([num_elements]),
+            start: 0,
+            chunk_elems,
+        }
+    }
+
     /// Create a tensor from the given data on the given device.
     pub fn from_data<T>(data: T, device: &B::Device) -> Self
     where
@@ -760,6 +811,41 @@ where
         Tensor::<B, D2, K>::cat(tensors, dim)
     }
 
+    /// Interleaves the elements of two tensors along the given dimension, alternating between
+    /// the elements of `self` and `other`:
+    ///
+    /// `output[2 * i] = self[i]` and `output[2 * i + 1] = other[i] // dim = 0`
+    ///
+    /// The two tensors are concatenated along the dimension and the two halves are then
+    /// interleaved with a reshape.
+    ///
+    /// # Panics
+    ///
+    /// - If the dimension is greater than the number of dimensions of the tensors.
+    /// - If the tensors don't have the same shape, including along the interleaved dimension.
+    ///
+    /// # Returns
+    ///
+    /// A tensor with the size of the given dimension doubled.
+    pub fn interleave(self, other: Self, dim: usize) -> Self {
+        check!(TensorCheck::interleave::<D>(
+            dim,
+            &self.shape(),
+            &other.shape()
+        ));
+
+        let mut shape = self.shape();
+        let size = shape.dims[dim];
+        let before = shape.dims[..dim].iter().product::<usize>();
+        let after = shape.dims[dim + 1..].iter().product::<usize>();
+        shape.dims[dim] = 2 * size;
+
+        Tensor::cat(vec![self, other], dim)
+            .reshape([before, 2, size, after])
+            .swap_dims(1, 2)
+            .reshape(shape)
+    }
+
     /// Iterate over slices of tensors alongside a given dimension.
     ///
     /// # Panics
@@ -794,7 +880,8 @@ where
     /// May return less chunks than requested if the tensor size is not divisible by the number of chunks.
     ///
     /// When the given dimension is evenly divisible by the number of chunks, the chunks will be of equal size.
-    /// Otherwise all chunks will be of equal size except for the last one.
+    /// Otherwise all chunks have `ceil(dim_size / chunks)` elements except for the last one, which
+    /// absorbs the remainder. When `chunks` exceeds the dimension size, chunks of size 1 are returned.
     ///
     /// # Panics
     ///
@@ -870,6 +957,45 @@ where
         K::all_dim(self.primitive, dim)
     }
 
+    /// Tests if no element in the `tensor` evaluates to True.
+    ///
+    /// # Arguments
+    ///
+    /// * `tensor` - The tensor to test. All input tensor types (Float, Int, Bool) are supported.
+    ///
+    /// # Returns
+    ///
+    /// A boolean tensor `Tensor<B, 1, Bool>` with a single element, True if no element in the input tensor
+    /// evaluates to True, False otherwise.
+    ///
+    /// # Notes
+    ///
+    /// This is the negation of [any](Tensor::any): a tensor with only False (zero) elements returns True,
+    /// and so does an empty tensor, since it vacuously has no True element.
+    pub fn none(self) -> Tensor<B, 1, Bool> {
+        K::none(self.primitive)
+    }
+
+    /// Tests if no element in the `tensor` evaluates to True along a given dimension `dim`.
+    ///
+    /// # Arguments
+    ///
+    /// * `tensor` - The tensor to test. All input tensor types (Float, Int, Bool) are supported.
+    /// * `dim` - The axis along which to test.
+    ///
+    /// # Returns
+    ///
+    /// A boolean tensor `Tensor<B, D, Bool>` with the same size as input `tensor`, except in the `dim` axis
+    /// where the size is 1. The elem in the `dim` axis is True if no element along this dim in the input
+    /// evaluates to True, False otherwise.
+    ///
+    /// # Notes
+    ///
+    /// This is the negation of [any_dim](Tensor::any_dim), so an empty `dim` axis returns True.
+    pub fn none_dim(self, dim: usize) -> Tensor<B, D, Bool> {
+        K::none_dim(self.primitive, dim)
+    }
+
     /// Convert the tensor into a scalar.
     ///
     /// # Panics
@@ -918,6 +1044,59 @@ where
     }
 }
 
+/// Handle to a tensor being moved to another device, given by
+/// [to_device_async](Tensor::to_device_async).
+pub struct TensorTransfer<B, const D: usize, K>
+where
+    B: Backend,
+    K: BasicOps<B>,
+{
+    reader: Reader<K::Primitive<D>>,
+}
+
+impl<B: Backend, const D: usize, K: BasicOps<B>> TensorTransfer<B, D, K> {
+    #[cfg(all(not(feature = "wasm-sync"), target_family = "wasm"))]
+    /// Waits for the transfer to complete and returns the tensor on the target device.
+    pub async fn wait(self) -> Tensor<B, D, K> {
+        Tensor::new(self.reader.read().await)
+    }
+
+    #[cfg(any(feature = "wasm-sync", not(target_family = "wasm")))]
+    /// Waits for the transfer to complete and returns the tensor on the target device.
+    pub fn wait(self) -> Tensor<B, D, K> {
+        Tensor::new(self.reader.read())
+    }
+}
+
+/// Iterator given by [to_data_chunks](Tensor::to_data_chunks).
+pub struct DataChunks<B, K>
+where
+    B: Backend,
+    K: BasicOps<B>,
+{
+    tensor: Tensor<B, 1, K>,
+    start: usize,
+    chunk_elems: usize,
+}
+
+#[cfg(any(feature = "wasm-sync", not(target_family = "wasm")))]
+impl<B: Backend, K: BasicOps<B>> Iterator for DataChunks<B, K> {
+    type Item = TensorData;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let num_elements = self.tensor.dims()[0];
+        if self.start >= num_elements {
+            return None;
+        }
+
+        let end = usize::min(self.start + self.chunk_elems, num_elements);
+        let chunk = self.tensor.clone().slice([self.start..end]).into_data();
+        self.start = end;
+
+        Some(chunk)
+    }
+}
+
 /// Iterator given by (Tensor::iter_dim).
 pub struct DimIter<B, const D: usize, K>
 where
@@ -1300,6 +1479,27 @@ pub trait BasicOps<B: Backend>: TensorKind<B> {
     /// The transposed tensor.
     fn transpose<const D: usize>(tensor: Self::Primitive<D>) -> Self::Primitive<D>;
 
+    /// Transposes the last two dimensions of a tensor into a new contiguous tensor.
+    ///
+    /// # Arguments
+    ///
+    /// * `tensor` - The tensor to transpose.
+    ///
+    /// # Returns
+    ///
+    /// The transposed tensor.
+    ///
+    /// # Remarks
+    ///
+    /// This is a low-level function used internally by the library to call different backend functions
+    /// with static dispatch. It is not designed for direct usage by users, and not recommended to import
+    /// or use this function directly.
+    ///
+    /// For transposing the last two dimensions of a tensor, users should prefer the
+    /// [Tensor::transpose_last2](Tensor::transpose_last2) function, which is more high-level and
+    /// designed for public use.
+    fn transpose_last2<const D: usize>(tensor: Self::Primitive<D>) -> Self::Primitive<D>;
+
     /// Swaps two dimensions of a tensor.
     ///
     /// # Arguments
@@ -1435,6 +1635,31 @@ pub trait BasicOps<B: Backend>: TensorKind<B> {
         device: &B::Device,
     ) -> Self::Primitive<D>;
 
+    /// Starts moving a tensor to the given device.
+    ///
+    /// # Arguments
+    ///
+    /// * `tensor` - The tensor.
+    /// * `device` - The device on which the tensor will be moved.
+    ///
+    /// # Returns
+    ///
+    /// A reader of the tensor on the given device.
+    ///
+    /// # Remarks
+    ///
+    /// This is a low-level function used internally by the library to call different backend functions
+    /// with static dispatch. It is not designed for direct usage by users, and not recommended to import
+    /// or use this function directly.
+    ///
+    /// For moving a tensor to a device asynchronously, users should prefer the
+    /// [Tensor::to_device_async](Tensor::to_device_async) function, which is more high-level and
+    /// designed for public use.
+    fn to_device_async<const D: usize>(
+        tensor: Self::Primitive<D>,
+        device: &B::Device,
+    ) -> Reader<Self::Primitive<D>>;
+
     /// Extracts the data from the tensor.
     ///
     /// # Arguments
@@ -1651,6 +1876,44 @@ pub trait BasicOps<B: Backend>: TensorKind<B> {
     /// which is more high-level and designed for public use.
     fn all_dim<const D: usize>(tensor: Self::Primitive<D>, dim: usize) -> Tensor<B, D, Bool>;
 
+    /// Tests if no element in the `tensor` evaluates to True.
+    ///
+    /// # Arguments
+    ///
+    /// * `tensor` - The tensor to test.
+    ///
+    /// # Returns
+    ///
+    /// A boolean tensor with a single element, True if no element in the input tensor evaluates to True, False otherwise.
+    ///
+    /// # Remarks
+    ///
+    /// This is a low-level function used internally by the library to call different backend functions
+    /// with static dispatch. It is not designed for direct usage by users, and not recommended to import
+    /// or use this function directly. Users should prefer the [Tensor::none](Tensor::none) function,
+    /// which is more high-level and designed for public use.
+    fn none<const D: usize>(tensor: Self::Primitive<D>) -> Tensor<B, 1, Bool>;
+
+    /// Tests if no element in the `tensor` evaluates to True along a given dimension `dim`.
+    ///
+    /// # Arguments
+    ///
+    /// * `tensor` - The tensor to test.
+    /// * `dim` - The axis along which to test.
+    ///
+    /// # Returns
+    ///
+    /// A boolean tensor with the same size as input `tensor`, except in the `dim` axis where the size is 1.
+    /// Returns True if no element in the input tensor along the given dimension evaluates to True, False otherwise.
+    ///
+    /// # Remarks
+    ///
+    /// This is a low-level function used internally by the library to call different backend functions
+    /// with static dispatch. It is not designed for direct usage by users, and not recommended to import
+    /// or use this function directly. Users should prefer the [Tensor::none_dim](Tensor::none_dim) function,
+    /// which is more high-level and designed for public use.
+    fn none_dim<const D: usize>(tensor: Self::Primitive<D>, dim: usize) -> Tensor<B, D, Bool>;
+
     /// Broadcasts the given tensor to the specified shape.
     ///
     /// # Arguments
@@ -1689,6 +1952,10 @@ impl<B: Backend> BasicOps<B> for Float {
         B::float_transpose(tensor)
     }
 
+    fn transpose_last2<const D: usize>(tensor: Self::Primitive<D>) -> Self::Primitive<D> {
+        B::float_transpose_last2(tensor)
+    }
+
     fn swap_dims<const D: usize>(
         tensor: Self::Primitive<D>,
         dim1: usize,
@@ -1724,6 +1991,13 @@ impl<B: Backend> BasicOps<B> for Float {
         B::float_to_device(tensor, device)
     }
 
+    fn to_device_async<const D: usize>(
+        tensor: Self::Primitive<D>,
+        device: &<B as Backend>::Device,
+    ) -> Reader<Self::Primitive<D>> {
+        B::float_to_device_async(tensor, device)
+    }
+
     fn into_data<const D: usize>(tensor: Self::Primitive<D>) -> Reader<TensorData> {
         B::float_into_data(tensor)
     }
@@ -1774,6 +2048,14 @@ impl<B: Backend> BasicOps<B> for Float {
         Tensor::new(B::float_all_dim(tensor, dim))
     }
 
+    fn none<const D: usize>(tensor: Self::Primitive<D>) -> Tensor<B, 1, Bool> {
+        Tensor::new(B::float_none(tensor))
+    }
+
+    fn none_dim<const D: usize>(tensor: Self::Primitive<D>, dim: usize) -> Tensor<B, D, Bool> {
+        Tensor::new(B::float_none_dim(tensor, dim))
+    }
+
     fn permute<const D: usize>(tensor: Self::Primitive<D>, axes: [usize; D]) -> Self::Primitive<D> {
         B::float_permute(tensor, axes)
     }
@@ -1811,6 +2093,10 @@ impl<B: Backend> BasicOps<B> for Int {
         B::int_transpose(tensor)
     }
 
+    fn transpose_last2<const D: usize>(tensor: Self::Primitive<D>) -> Self::Primitive<D> {
+        B::int_transpose_last2(tensor)
+    }
+
     fn swap_dims<const D: usize>(
         tensor: Self::Primitive<D>,
         dim1: usize,
@@ -1846,6 +2132,13 @@ impl<B: Backend> BasicOps<B> for Int {
         B::int_to_device(tensor, device)
     }
 
+    fn to_device_async<const D: usize>(
+        tensor: Self::Primitive<D>,
+        device: &<B as Backend>::Device,
+    ) -> Reader<Self::Primitive<D>> {
+        B::int_to_device_async(tensor, device)
+    }
+
     fn into_data<const D: usize>(tensor: Self::Primitive<D>) -> Reader<TensorData> {
         B::int_into_data(tensor)
     }
@@ -1896,6 +2189,14 @@ impl<B: Backend> BasicOps<B> for Int {
         Tensor::new(B::int_all_dim(tensor, dim))
     }
 
+    fn none<const D: usize>(tensor: Self::Primitive<D>) -> Tensor<B, 1, Bool> {
+        Tensor::new(B::int_none(tensor))
+    }
+
+    fn none_dim<const D: usize>(tensor: Self::Primitive<D>, dim: usize) -> Tensor<B, D, Bool> {
+        Tensor::new(B::int_none_dim(tensor, dim))
+    }
+
     fn permute<const D: usize>(tensor: Self::Primitive<D>, axes: [usize; D]) -> Self::Primitive<D> {
         B::int_permute(tensor, axes)
     }
@@ -1933,6 +2234,10 @@ impl<B: Backend> BasicOps<B> for Bool {
         B::bool_transpose(tensor)
     }
 
+    fn transpose_last2<const D: usize>(tensor: Self::Primitive<D>) -> Self::Primitive<D> {
+        B::bool_transpose_last2(tensor)
+    }
+
     fn swap_dims<const D: usize>(
         tensor: Self::Primitive<D>,
         dim1: usize,
@@ -1968,6 +2273,13 @@ impl<B: Backend> BasicOps<B> for Bool {
         B::bool_to_device(tensor, device)
     }
 
+    fn to_device_async<const D: usize>(
+        tensor: Self::Primitive<D>,
+        device: &<B as Backend>::Device,
+    ) -> Reader<Self::Primitive<D>> {
+        B::bool_to_device_async(tensor, device)
+    }
+
     fn into_data<const D: usize>(tensor: Self::Primitive<D>) -> Reader<TensorData> {
         B::bool_into_data(tensor)
     }
@@ -2018,6 +2330,14 @@ impl<B: Backend> BasicOps<B> for Bool {
         Tensor::new(B::bool_all_dim(tensor, dim))
     }
 
+    fn none<const D: usize>(tensor: Self::Primitive<D>) -> Tensor<B, 1, Bool> {
+        Tensor::new(B::bool_none(tensor))
+    }
+
+    fn none_dim<const D: usize>(tensor: Self::Primitive<D>, dim: usize) -> Tensor<B, D, Bool> {
+        Tensor::new(B::bool_none_dim(tensor, dim))
+    }
+
     fn permute<const D: usize>(tensor: Self::Primitive<D>, axes: [usize; D]) -> Self::Primitive<D> {
         B::bool_permute(tensor, axes)
     }
@@ -2153,31 +2473,26 @@ impl<const D2: usize> ReshapeArgs<D2> for [i32; D2] {
 
         // Handle the case where the dimension is inferred (via -1)
         if let Some(index) = infer_index {
-            // Handle the case where the dimension is inferred
-            let mut product = 1;
-            for (i, &s) in new_shape.iter().enumerate() {
-                if i != index {
-                    product *= s;
-                }
-            }
-            let product_current = tensor.shape().num_elements() as i32;
+            check!(TensorCheck::reshape_args_inferred(
+                &tensor.shape(),
+                &new_shape
+            ));
 
-            new_shape[index] = product_current / product;
+            let product = new_shape
+                .iter()
+                .filter(|&&s| s != -1)
+                .map(|&s| s as usize)
+                .product::<usize>();
 
-            // Check if the reshape is valid
-            if product_current % product != 0 {
-                panic!(
-                    "Cannot reshape tensor of shape {:?} to shape {:?}",
-                    tensor.shape(),
-                    new_shape
-                );
-            }
+            new_shape[index] = (tensor.shape().num_elements() / product) as i32;
         };
 
         // Convert each element to usize
-        let new_shape: [usize; D2] = new_shape.map(|x| x as usize);
+        let new_shape = Shape::from(new_shape.map(|x| x as usize));
 
-        Shape::from(new_shape)
+        check!(TensorCheck::reshape_args_usize(&tensor.shape(), &new_shape));
+
+        new_shape
     }
 }
 
@@ -1,4 +1,4 @@
-use crate::{backend::Backend, Bool, Int, Shape, Tensor, TensorData};
+use crate::{backend::Backend, check, check::TensorCheck, Bool, Int, Shape, Tensor, TensorData};
 use alloc::vec::Vec;
 
 #[cfg(all(not(feature = "wasm-sync"), target_family = "wasm"))]
@@ -40,6 +40,30 @@ where
         Tensor::new(B::bool_not(self.primitive))
     }
 
+    /// Performs the logical and operation with another boolean tensor, element-wise.
+    ///
+    /// The two tensors are broadcasted along the dimensions of size one.
+    pub fn bool_and(self, other: Self) -> Self {
+        check!(TensorCheck::binary_ops_ew("BoolAnd", &self, &other));
+        Tensor::new(B::bool_and(self.primitive, other.primitive))
+    }
+
+    /// Performs the logical or operation with another boolean tensor, element-wise.
+    ///
+    /// The two tensors are broadcasted along the dimensions of size one.
+    pub fn bool_or(self, other: Self) -> Self {
+        check!(TensorCheck::binary_ops_ew("BoolOr", &self, &other));
+        Tensor::new(B::bool_or(self.primitive, other.primitive))
+    }
+
+    /// Performs the logical exclusive or operation with another boolean tensor, element-wise.
+    ///
+    /// The two tensors are broadcasted along the dimensions of size one.
+    pub fn bool_xor(self, other: Self) -> Self {
+        check!(TensorCheck::binary_ops_ew("BoolXor", &self, &other));
+        Tensor::new(B::bool_xor(self.primitive, other.primitive))
+    }
+
     /// Compute the indices of the elements that are non-zero.
     ///
     /// # Returns
@@ -105,6 +129,10 @@ where
         let height = shape.dims[D - 2];
         let width = shape.dims[D - 1];
 
+        // Offsets past the matrix bounds select all or none of the elements, clamping them keeps
+        // the index arithmetic within the integer element range.
+        let offset = offset.clamp(-(height as i64), width as i64);
+
         // Generate row and column index tensors.
         let row_indices: Tensor<B, 1, Int> = Tensor::arange(0..height as i64, device);
         let col_indices: Tensor<B, 1, Int> = Tensor::arange(0..width as i64, device);
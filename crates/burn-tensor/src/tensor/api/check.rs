@@ -1,4 +1,4 @@
-use crate::{backend::Backend, BasicOps, Shape, Tensor};
+use crate::{backend::Backend, ops::PadMode, BasicOps, Shape, Tensor};
 use alloc::format;
 use alloc::string::{String, ToString};
 use alloc::vec;
@@ -49,6 +49,28 @@ impl TensorCheck {
             .binary_ops_ew_shape(ops, &lhs.shape(), &rhs.shape())
     }
 
+    pub(crate) fn interleave<const D: usize>(
+        dim: usize,
+        shape_lhs: &Shape<D>,
+        shape_rhs: &Shape<D>,
+    ) -> Self {
+        let mut check = Self::dim_ops::<D>("Interleave", dim);
+
+        if shape_lhs != shape_rhs {
+            check = check.register(
+                "Interleave",
+                TensorError::new("The tensors to interleave should have the same shape.").details(
+                    format!(
+                        "Lhs tensor shape {:?}, rhs tensor shape {:?}.",
+                        shape_lhs.dims, shape_rhs.dims
+                    ),
+                ),
+            );
+        }
+
+        check
+    }
+
     pub(crate) fn into_scalar<const D: usize>(shape: &Shape<D>) -> Self {
         let mut check = Self::Ok;
 
@@ -80,6 +102,20 @@ impl TensorCheck {
         check
     }
 
+    pub(crate) fn transpose_last2<const D: usize>() -> Self {
+        let mut check = Self::Ok;
+
+        if D < 2 {
+            check = check.register(
+                "Transpose Last 2",
+                TensorError::new("The tensor should have at least two dimensions.")
+                    .details(format!("Tensor rank: '{D}'.")),
+            );
+        }
+
+        check
+    }
+
     pub(crate) fn from_data<const D: usize>(dims: &[usize]) -> Self {
         let mut check = Self::Ok;
 
@@ -94,6 +130,19 @@ impl TensorCheck {
         check
     }
 
+    pub(crate) fn to_data_chunks(chunk_elems: usize) -> Self {
+        let mut check = Self::Ok;
+
+        if chunk_elems == 0 {
+            check = check.register(
+                "ToDataChunks",
+                TensorError::new("The number of elements per chunk must be greater than 0"),
+            );
+        }
+
+        check
+    }
+
     pub(crate) fn narrow<B: Backend, const D: usize, K: BasicOps<B>>(
         tensor: &Tensor<B, D, K>,
         dim: usize,
@@ -153,8 +202,44 @@ impl TensorCheck {
                      tensor.",
                 )
                 .details(format!(
-                    "Current shape: {:?}, target shape: {:?}.",
-                    original.dims, target.dims
+                    "Current shape: {:?} ({} elements), target shape: {:?} ({} elements).",
+                    original.dims,
+                    original.num_elements(),
+                    target.dims,
+                    target.num_elements()
+                )),
+            );
+        }
+
+        check
+    }
+
+    /// Checks that the dimension inferred from the `-1` of the target shape is a whole number,
+    /// the other dimensions being already resolved.
+    pub(crate) fn reshape_args_inferred<const D1: usize, const D2: usize>(
+        original: &Shape<D1>,
+        target: &[i32; D2],
+    ) -> Self {
+        let mut check = Self::Ok;
+
+        let num_elements = original.num_elements();
+        let product = target
+            .iter()
+            .filter(|&&dim| dim != -1)
+            .map(|&dim| dim as usize)
+            .product::<usize>();
+
+        if num_elements.checked_rem(product) != Some(0) {
+            check = check.register(
+                "Reshape",
+                TensorError::new(
+                    "The -1 dimension can't be inferred, the number of elements of the current \
+                     tensor isn't divisible by the product of the other dimensions.",
+                )
+                .details(format!(
+                    "Current shape: {:?} ({} elements), target shape: {:?} (product of the other \
+                     dimensions: {}).",
+                    original.dims, num_elements, target, product
                 )),
             );
         }
@@ -575,16 +660,16 @@ impl TensorCheck {
 
         let shape_reference = tensors.first().unwrap().shape();
 
-        for tensor in tensors {
+        for (index, tensor) in tensors.iter().enumerate() {
             let shape = tensor.shape();
 
             if shape_reference != shape {
                 return check.register(
                     "Stack",
                     TensorError::new("Can't stack tensors with different shapes").details(format!(
-                        "Provided dimension ({}), tensors shapes: {:?}",
-                        dim,
-                        tensors.iter().map(Tensor::shape).collect::<Vec<_>>()
+                        "The tensor at index ({}) has shape {:?} while the first tensor has \
+                         shape {:?}.",
+                        index, shape.dims, shape_reference.dims
                     )),
                 );
             }
@@ -824,6 +909,158 @@ impl TensorCheck {
         check
     }
 
+    pub(crate) fn histogram_bins(shape_edges: &Shape<1>) -> Self {
+        let mut check = Self::Ok;
+
+        if shape_edges.dims[0] < 2 {
+            check = check.register(
+                "HistogramBins",
+                TensorError::new("The edges tensor should contain at least two values.")
+                    .details(format!("Got ({}) edges.", shape_edges.dims[0])),
+            );
+        }
+
+        check
+    }
+
+    pub(crate) fn gradient<const D: usize>(
+        dim: usize,
+        shape: &Shape<D>,
+        shape_coordinates: Option<&Shape<1>>,
+    ) -> Self {
+        let ops = "Gradient";
+        let mut check = Self::Ok;
+
+        if dim >= D {
+            return check.register(
+                ops,
+                TensorError::new(format!(
+                    "Can't compute the gradient along dimension {dim}, the tensor has {D} \
+                     dimensions."
+                )),
+            );
+        }
+
+        if shape.dims[dim] < 2 {
+            check = check.register(
+                ops,
+                TensorError::new(
+                    "The dimension should contain at least two values to compute its gradient.",
+                )
+                .details(format!(
+                    "Dimension {dim} has a size of {}.",
+                    shape.dims[dim]
+                )),
+            );
+        }
+
+        if let Some(shape_coordinates) = shape_coordinates {
+            if shape_coordinates.dims[0] != shape.dims[dim] {
+                check = check.register(
+                    ops,
+                    TensorError::new(
+                        "The coordinates should have the same size as the dimension of the \
+                         gradient.",
+                    )
+                    .details(format!(
+                        "Dimension {dim} has a size of {}, got ({}) coordinates.",
+                        shape.dims[dim], shape_coordinates.dims[0]
+                    )),
+                );
+            }
+        }
+
+        check
+    }
+
+    pub(crate) fn top_p_mask<const D: usize>(dim: usize, p: f64) -> Self {
+        let ops = "TopPMask";
+        let mut check = Self::Ok;
+
+        if dim >= D {
+            check = check.register(
+                ops,
+                TensorError::new(format!(
+                    "Can't filter a tensor with ({D}) dimensions on axis ({dim})"
+                )),
+            );
+        }
+
+        if !(p > 0.0 && p <= 1.0) {
+            check = check.register(
+                ops,
+                TensorError::new("The cumulative probability threshold should be in (0, 1].")
+                    .details(format!("Got p = {p}.")),
+            );
+        }
+
+        check
+    }
+
+    pub(crate) fn scatter_max<const D: usize>(
+        dim: usize,
+        shape: &Shape<D>,
+        shape_indices: &Shape<D>,
+    ) -> Self {
+        let ops = "ScatterMax";
+        let mut check = Self::Ok;
+
+        if dim >= D {
+            check = check.register(
+                ops,
+                TensorError::new(format!(
+                    "Can't reduce segments of a tensor with ({D}) dimensions on axis ({dim})"
+                )),
+            );
+        }
+
+        if shape != shape_indices {
+            check = check.register(
+                ops,
+                TensorError::new(
+                    "Indices tensor shape should be the same as the tensor shape.".to_string(),
+                )
+                .details(format!(
+                    "The shape differs: {:?} != {:?}",
+                    shape.dims, shape_indices.dims
+                )),
+            );
+        }
+
+        check
+    }
+
+    pub(crate) fn segment_softmax<const D: usize>(
+        dim: usize,
+        shape: &Shape<D>,
+        shape_ids: &Shape<1>,
+    ) -> Self {
+        let ops = "SegmentSoftmax";
+        let mut check = Self::Ok;
+
+        if dim >= D {
+            check = check.register(
+                ops,
+                TensorError::new(format!(
+                    "Can't normalize segments of a tensor with ({D}) dimensions on axis ({dim})"
+                )),
+            );
+        } else if shape.dims[dim] != shape_ids.dims[0] {
+            check = check.register(
+                ops,
+                TensorError::new(
+                    "Segment indices should have the size of the dimension.".to_string(),
+                )
+                .details(format!(
+                    "The sizes differ: {} != {}",
+                    shape.dims[dim], shape_ids.dims[0]
+                )),
+            );
+        }
+
+        check
+    }
+
     pub(crate) fn select<const D: usize>(dim: usize) -> Self {
         Self::check_select_basic::<D>(Self::Ok, "select", dim)
     }
@@ -832,6 +1069,116 @@ impl TensorCheck {
         Self::check_select_basic::<D>(Self::Ok, "select_assign", dim)
     }
 
+    pub(crate) fn index_put<const D: usize>(indices_lengths: &[usize]) -> Self {
+        let mut check = Self::Ok;
+
+        if indices_lengths.len() != D {
+            check = check.register(
+                "IndexPut",
+                TensorError::new("One index tensor per dimension is required.").details(format!(
+                    "Got ({}) index tensors for a tensor with ({D}) dimensions.",
+                    indices_lengths.len()
+                )),
+            );
+        }
+
+        if indices_lengths
+            .windows(2)
+            .any(|lengths| lengths[0] != lengths[1])
+        {
+            check = check.register(
+                "IndexPut",
+                TensorError::new("All index tensors must have the same length.")
+                    .details(format!("Index tensors lengths: {indices_lengths:?}.")),
+            );
+        }
+
+        check
+    }
+
+    pub(crate) fn dynamic_partition<const D: usize>(
+        shape: &Shape<D>,
+        shape_partitions: &Shape<1>,
+    ) -> Self {
+        let mut check = Self::Ok;
+
+        if shape.dims[0] != shape_partitions.dims[0] {
+            check = check.register(
+                "DynamicPartition",
+                TensorError::new("The partitions should have the size of the first dimension.")
+                    .details(format!(
+                        "Tensor shape {:?}, got ({}) partitions.",
+                        shape.dims, shape_partitions.dims[0]
+                    )),
+            );
+        }
+
+        check
+    }
+
+    pub(crate) fn dynamic_stitch<const D: usize>(
+        indices_lengths: &[usize],
+        shapes: &[Option<Shape<D>>],
+    ) -> Self {
+        let ops = "DynamicStitch";
+        let mut check = Self::Ok;
+
+        if indices_lengths.len() != shapes.len() {
+            return check.register(
+                ops,
+                TensorError::new("One index tensor per data tensor is required.").details(format!(
+                    "Got ({}) index tensors for ({}) data tensors.",
+                    indices_lengths.len(),
+                    shapes.len()
+                )),
+            );
+        }
+
+        let Some(first) = shapes.iter().flatten().next() else {
+            return check.register(
+                ops,
+                TensorError::new("At least one data tensor is required."),
+            );
+        };
+
+        for (i, (length, shape)) in indices_lengths.iter().zip(shapes).enumerate() {
+            let num_rows = shape.as_ref().map_or(0, |shape| shape.dims[0]);
+
+            if *length != num_rows {
+                check = check.register(
+                    ops,
+                    TensorError::new(
+                        "Each index tensor should have the size of the first dimension of its \
+                         data tensor.",
+                    )
+                    .details(format!(
+                        "Data tensor ({i}) has ({num_rows}) rows, got ({length}) indices."
+                    )),
+                );
+            }
+
+            if let Some(shape) = shape {
+                if shape.dims[1..] != first.dims[1..] {
+                    check = check.register(
+                        ops,
+                        TensorError::new(
+                            "The data tensors should have the same shape except for the first \
+                             dimension.",
+                        )
+                        .details(format!(
+                            "Expected a shape {:?} for the other dimensions, data tensor ({i}) \
+                             has a shape {:?}.",
+                            &first.dims[1..],
+                            shape.dims
+                        )),
+                    );
+                }
+            }
+        }
+
+        check
+    }
+
     fn check_select_basic<const D: usize>(mut check: Self, ops: &str, dim: usize) -> Self {
         if dim > D {
             check = check.register(
@@ -939,6 +1286,293 @@ impl TensorCheck {
         check
     }
 
+    pub(crate) fn max_unpool2d(
+        x_dims: &[usize; 4],
+        indices_dims: &[usize; 4],
+        default_size: [usize; 2],
+        stride: [usize; 2],
+        output_size: Option<[usize; 2]>,
+    ) -> Self {
+        let mut check = Self::Ok;
+
+        if x_dims != indices_dims {
+            check = check.register(
+                "MaxUnpool2d",
+                TensorError::new("The input and indices tensors must have the same shape.")
+                    .details(format!(
+                        "Input shape {x_dims:?}, indices shape {indices_dims:?}."
+                    )),
+            );
+        }
+
+        if let Some(output_size) = output_size {
+            for i in 0..2 {
+                let min = default_size[i];
+                let max = default_size[i] + stride[i];
+
+                if output_size[i] < min || output_size[i] >= max {
+                    check = check.register(
+                        "MaxUnpool2d",
+                        TensorError::new("Invalid output size.").details(format!(
+                            "The output size {output_size:?} must be within [{min}, {max}) \
+                             on spatial dimension {i}."
+                        )),
+                    );
+                }
+            }
+        }
+
+        check
+    }
+
+    /// Checks that the affine matrices match the batch size of the requested grid.
+    pub(crate) fn affine_grid(theta_dims: &[usize; 3], size: &[usize; 4]) -> Self {
+        let mut check = Self::Ok;
+
+        if theta_dims != &[size[0], 2, 3] {
+            check = check.register(
+                "AffineGrid",
+                TensorError::new("The affine matrices must have a shape of [batch_size, 2, 3].")
+                    .details(format!("Theta shape {theta_dims:?}, output size {size:?}.")),
+            );
+        }
+
+        check
+    }
+
+    /// Checks that the query, the key, the value and the mask of an attention have compatible
+    /// shapes, the mask being broadcast to the shape of the scores `[..., queries, keys]`.
+    pub(crate) fn scaled_dot_product_attention<const D: usize>(
+        query: &Shape<D>,
+        key: &Shape<D>,
+        value: &Shape<D>,
+        mask: Option<&Shape<D>>,
+    ) -> Self {
+        let mut check = Self::Ok;
+
+        if D < 2 {
+            return check.register(
+                "Attention",
+                TensorError::new(
+                    "The tensors must have at least 2 dimensions: [..., seq, features].",
+                )
+                .details(format!("Got tensors with {D} dimensions.")),
+            );
+        }
+
+        let [query, key, value] = [&query.dims, &key.dims, &value.dims];
+
+        if query[..D - 2] != key[..D - 2] {
+            check = check.register(
+                "Attention",
+                TensorError::new("The query and the key must have the same leading dimensions.")
+                    .details(format!("Query shape {query:?}, key shape {key:?}.")),
+            );
+        }
+
+        if key[..D - 1] != value[..D - 1] {
+            check = check.register(
+                "Attention",
+                TensorError::new("The key and the value must have the same number of keys.")
+                    .details(format!("Key shape {key:?}, value shape {value:?}.")),
+            );
+        }
+
+        if query[D - 1] != key[D - 1] {
+            check = check.register(
+                "Attention",
+                TensorError::new("The query and the key must have the same feature size.")
+                    .details(format!("Query shape {query:?}, key shape {key:?}.")),
+            );
+        }
+
+        if let Some(mask) = mask {
+            let mut scores = *query;
+            scores[D - 1] = key[D - 2];
+
+            let broadcastable = mask
+                .dims
+                .iter()
+                .zip(scores.iter())
+                .all(|(mask, scores)| *mask == *scores || *mask == 1);
+
+            if !broadcastable {
+                check = check.register(
+                    "Attention",
+                    TensorError::new(
+                        "The mask must be broadcastable to the shape of the scores [..., queries, keys].",
+                    )
+                    .details(format!(
+                        "Mask shape {:?}, scores shape {scores:?}.",
+                        mask.dims
+                    )),
+                );
+            }
+        }
+
+        check
+    }
+
+    pub(crate) fn batch_norm_stats<const D: usize>() -> Self {
+        let mut check = Self::Ok;
+
+        if D < 2 {
+            check = check.register(
+                "BatchNormStats",
+                TensorError::new(
+                    "The input must have at least 2 dimensions: [batch_size, channels, ...].",
+                )
+                .details(format!("Got a tensor with {D} dimensions.")),
+            );
+        }
+
+        check
+    }
+
+    /// Checks that the input is large enough to be padded with values taken from itself.
+    pub(crate) fn pad_mode<const D: usize>(
+        pad_mode: PadMode,
+        shape: &Shape<D>,
+        dims: &[usize],
+        padding: &[usize],
+    ) -> Self {
+        let mut check = Self::Ok;
+
+        for (dim, padding) in dims.iter().zip(padding) {
+            let size = shape.dims[*dim];
+            let requirement = match pad_mode {
+                PadMode::Reflect if *padding >= size => {
+                    Some("must be smaller than the padded dimension")
+                }
+                PadMode::Replicate if *padding > 0 && size == 0 => {
+                    Some("needs a non-empty padded dimension")
+                }
+                // Zero padding never reads the input.
+                _ => None,
+            };
+
+            if let Some(requirement) = requirement {
+                check = check.register(
+                    "Pad",
+                    TensorError::new(format!(
+                        "Can't apply {pad_mode:?} padding of ({padding}) on axis ({dim}) of size ({size})"
+                    ))
+                    .details(format!("{pad_mode:?} padding {requirement}.")),
+                );
+            }
+        }
+
+        check
+    }
+
+    /// Checks that the sequence dimension of a key/value cache exists and that the key and the
+    /// value buffers have the same capacity.
+    pub(crate) fn kv_cache_new<const D: usize>(
+        dim: usize,
+        key_shape: &Shape<D>,
+        value_shape: &Shape<D>,
+    ) -> Self {
+        let check = Self::dim_ops::<D>("KvCache", dim);
+
+        if let Self::Failed(_) = check {
+            return check;
+        }
+
+        let (key_capacity, value_capacity) = (key_shape.dims[dim], value_shape.dims[dim]);
+
+        if key_capacity != value_capacity {
+            return check.register(
+                "KvCache",
+                TensorError::new("The keys and the values should have the same capacity.").details(
+                    format!(
+                        "Sequence dimension: '{dim}', key capacity: '{key_capacity}', value \
+                         capacity: '{value_capacity}'."
+                    ),
+                ),
+            );
+        }
+
+        check
+    }
+
+    /// Checks that the positions appended to a key/value cache match and fit in its capacity.
+    pub(crate) fn kv_cache_append(
+        num_keys: usize,
+        num_values: usize,
+        len: usize,
+        capacity: usize,
+    ) -> Self {
+        let mut check = Self::Ok;
+
+        if num_keys != num_values {
+            check = check.register(
+                "KvCache Append",
+                TensorError::new(
+                    "The keys and the values should have the same number of positions.",
+                )
+                .details(format!(
+                    "Key positions: '{num_keys}', value positions: '{num_values}'."
+                )),
+            );
+        }
+
+        if len + num_keys > capacity {
+            check = check.register(
+                "KvCache Append",
+                TensorError::new("The new positions exceed the capacity of the cache.").details(
+                    format!(
+                        "Cached positions: '{len}', new positions: '{num_keys}', capacity: \
+                         '{capacity}'."
+                    ),
+                ),
+            );
+        }
+
+        check
+    }
+
+    /// Checks the dimension of argmax and argmin.
+    pub(crate) fn arg_dim<const D: usize>(ops: &str, dim: usize, shape: &Shape<D>) -> Self {
+        let mut check = Self::Ok;
+
+        if dim >= D {
+            check = check.register(
+                ops,
+                TensorError::new(format!(
+                    "Can't compute the index of a tensor with ({D}) dimensions on axis ({dim})"
+                )),
+            );
+        } else if shape.dims[dim] == 0 {
+            check = check.register(
+                ops,
+                TensorError::new(format!(
+                    "Can't compute the index over axis ({dim}) since it is empty"
+                ))
+                .details(format!("Tensor shape {:?}.", shape.dims)),
+            );
+        }
+
+        check
+    }
+
+    pub(crate) fn keepdim<const D: usize, const D2: usize>(ops: &str, keepdim: bool) -> Self {
+        let mut check = Self::Ok;
+        let expected = if keepdim { D } else { D - 1 };
+
+        if D2 != expected {
+            check = check.register(
+                ops,
+                TensorError::new(format!(
+                    "The output rank ({D2}) doesn't match the reduction of a tensor with ({D}) \
+                     dimensions with keepdim set to {keepdim}"
+                ))
+                .details(format!("Expected an output rank of {expected}.")),
+            );
+        }
+
+        check
+    }
+
     pub(crate) fn sort_dim<const D: usize>(ops: &str, dim: usize) -> Self {
         let mut check = Self::Ok;
 
@@ -954,6 +1588,29 @@ impl TensorCheck {
         check
     }
 
+    pub(crate) fn topk<const D: usize>(dim: usize, k: usize, shape: &Shape<D>) -> Self {
+        let mut check = Self::Ok;
+
+        if dim >= D {
+            check = check.register(
+                "Topk",
+                TensorError::new(format!(
+                    "Can't select the top-k elements of a tensor with ({D}) dimensions on axis ({dim})"
+                )),
+            );
+        } else if k > shape.dims[dim] {
+            check = check.register(
+                "Topk",
+                TensorError::new(format!(
+                    "Can't select the top ({k}) elements of axis ({dim}) of size ({})",
+                    shape.dims[dim]
+                )),
+            );
+        }
+
+        check
+    }
+
     /// The goal is to minimize the cost of checks when there are no error, but it's way less
     /// important when an error occurred, crafting a comprehensive error message is more important
     /// than optimizing string manipulation.
@@ -972,6 +1629,21 @@ impl TensorCheck {
         })
     }
 
+    pub(crate) fn mask_where<const D: usize>(
+        shape: &Shape<D>,
+        mask: &Shape<D>,
+        value: &Shape<D>,
+    ) -> Self {
+        Self::Ok
+            .binary_ops_ew_shape("Mask Where", shape, mask)
+            .binary_ops_ew_shape("Mask Where", shape, value)
+            .binary_ops_ew_shape("Mask Where", mask, value)
+    }
+
+    pub(crate) fn mask_fill<const D: usize>(shape: &Shape<D>, mask: &Shape<D>) -> Self {
+        Self::Ok.binary_ops_ew_shape("Mask Fill", shape, mask)
+    }
+
     /// Checks if shapes are compatible for element wise operations supporting broadcasting.
     pub(crate) fn binary_ops_ew_shape<const D: usize>(
         self,
@@ -1248,4 +1920,89 @@ mod tests {
             &vec![0, 1, 2]
         ));
     }
+
+    #[test]
+    fn attention_mask_broadcast() {
+        check!(TensorCheck::scaled_dot_product_attention(
+            &Shape::new([2, 4, 3, 8]),
+            &Shape::new([2, 4, 5, 8]),
+            &Shape::new([2, 4, 5, 6]),
+            Some(&Shape::new([2, 1, 1, 5]))
+        ));
+    }
+
+    #[test]
+    #[should_panic]
+    fn attention_mask_invalid_shape() {
+        check!(TensorCheck::scaled_dot_product_attention(
+            &Shape::new([2, 4, 3, 8]),
+            &Shape::new([2, 4, 5, 8]),
+            &Shape::new([2, 4, 5, 6]),
+            Some(&Shape::new([2, 4, 3, 3]))
+        ));
+    }
+
+    #[test]
+    #[should_panic(expected = "Reflect padding must be smaller than the padded dimension.")]
+    fn pad_mode_reflect_larger_than_dimension() {
+        check!(TensorCheck::pad_mode(
+            PadMode::Reflect,
+            &Shape::new([2, 3]),
+            &[1],
+            &[3]
+        ));
+    }
+
+    #[test]
+    #[should_panic(expected = "Replicate padding needs a non-empty padded dimension.")]
+    fn pad_mode_replicate_empty_dimension() {
+        check!(TensorCheck::pad_mode(
+            PadMode::Replicate,
+            &Shape::new([2, 0]),
+            &[1],
+            &[1]
+        ));
+    }
+
+    #[test]
+    fn pad_mode_valid_padding() {
+        check!(TensorCheck::pad_mode(
+            PadMode::Reflect,
+            &Shape::new([2, 3]),
+            &[0, 1],
+            &[1, 2]
+        ));
+        check!(TensorCheck::pad_mode(
+            PadMode::Replicate,
+            &Shape::new([2, 0]),
+            &[0, 1],
+            &[4, 0]
+        ));
+    }
+
+    #[test]
+    #[should_panic(expected = "The keys and the values should have the same capacity.")]
+    fn kv_cache_different_capacities() {
+        check!(TensorCheck::kv_cache_new(
+            1,
+            &Shape::new([2, 4, 8]),
+            &Shape::new([2, 3, 8])
+        ));
+    }
+
+    #[test]
+    #[should_panic(expected = "Given dimension is higher than the tensor rank.")]
+    fn kv_cache_dim_out_of_bounds() {
+        check!(TensorCheck::kv_cache_new(
+            3,
+            &Shape::new([2, 4, 8]),
+            &Shape::new([2, 4, 8])
+        ));
+    }
+
+    #[test]
+    #[should_panic(expected = "The new positions exceed the capacity of the cache.")]
+    fn kv_cache_append_exceeds_capacity() {
+        check!(TensorCheck::kv_cache_append(2, 2, 3, 4));
+    }
 }
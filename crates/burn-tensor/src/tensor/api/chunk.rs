@@ -26,43 +26,14 @@ pub fn chunk<B: Backend, const D: usize, K: TensorKind<B> + BasicOps<B>>(
     dim: usize,
 ) -> Vec<K::Primitive<D>> {
     let size = K::shape(&tensor).dims[dim];
-    if size < chunks {
-        return (0..size)
-            .map(|i| narrow::<B, D, K>(tensor.clone(), dim, i, 1))
-            .collect();
-    }
+    // Same as PyTorch: the chunk size is `ceil(size / chunks)` and the last chunk takes the
+    // remainder, so fewer chunks than requested may be returned.
+    let chunk_size = size.div_ceil(chunks);
 
     let mut tensors = Vec::with_capacity(chunks);
-    let mut sum_chunk_size = 0;
-    if size % chunks == 0 {
-        let chunk_size = size / chunks;
-        for _ in 0..chunks {
-            tensors.push(narrow::<B, D, K>(
-                tensor.clone(),
-                dim,
-                sum_chunk_size,
-                chunk_size,
-            ));
-            sum_chunk_size += chunk_size;
-        }
-    } else {
-        let chunk_size = (size / chunks) + 1; // assumes not divisible
-        for _ in 0..chunks - 1 {
-            tensors.push(narrow::<B, D, K>(
-                tensor.clone(),
-                dim,
-                sum_chunk_size,
-                chunk_size,
-            ));
-            sum_chunk_size += chunk_size;
-        }
-        let remainder = size % chunk_size;
-        tensors.push(narrow::<B, D, K>(
-            tensor.clone(),
-            dim,
-            sum_chunk_size,
-            remainder,
-        ));
+    for start in (0..size).step_by(chunk_size.max(1)) {
+        let length = usize::min(chunk_size, size - start);
+        tensors.push(narrow::<B, D, K>(tensor.clone(), dim, start, length));
     }
 
     tensors
@@ -1,12 +1,14 @@
+use alloc::vec;
 use alloc::vec::Vec;
 use core::convert::TryInto;
 
 use crate::check;
 use crate::check::TensorCheck;
+use crate::dlpack::DLPackTensor;
 use crate::ops::FullPrecisionBackend;
 use crate::tensor::backend::Backend;
 use crate::tensor::stats;
-use crate::tensor::{Distribution, Shape, TensorData};
+use crate::tensor::{Distribution, ElementConversion, Shape, TensorData};
 use crate::Int;
 use crate::Tensor;
 
@@ -88,6 +90,55 @@ where
         Self::new(B::float_tanh(self.primitive))
     }
 
+    /// Applies element wise the floating-point remainder operation.
+    ///
+    /// Unlike [remainder](Tensor::remainder), the result has the same sign as the dividend,
+    /// which matches the C `fmod` function.
+    ///
+    /// `y = x1 - trunc(x1 / x2) * x2`
+    pub fn fmod(self, other: Self) -> Self {
+        let sign = self.clone().sign();
+        let lhs = self.abs();
+        let rhs = other.abs();
+        let quotient = lhs.clone().div(rhs.clone());
+        // The quotient of absolute values is positive, so truncating it removes its fractional
+        // part. Staying in float avoids overflowing the integer range on large quotients and
+        // keeps the NaN of a zero divisor.
+        let quotient = quotient.clone().sub(quotient.remainder_scalar(1.0));
+
+        lhs.sub(quotient.mul(rhs)).mul(sign)
+    }
+
+    /// Applies element wise the remainder operation with another tensor.
+    ///
+    /// Unlike [fmod](Tensor::fmod), the result has the same sign as the divisor, like
+    /// [remainder_scalar](Tensor::remainder_scalar) and the Python `%` operator.
+    ///
+    /// `y = x1 - floor(x1 / x2) * x2`
+    pub fn remainder(self, other: Self) -> Self {
+        let remainder = self.fmod(other.clone());
+        // The truncated remainder of the opposite sign is one divisor away from the floored one.
+        let wrap = remainder
+            .clone()
+            .not_equal_elem(0.0)
+            .bool_and(remainder.clone().sign().not_equal(other.clone().sign()));
+
+        remainder.clone().mask_where(wrap, remainder.add(other))
+    }
+
+    /// Applies element wise the floating-point remainder operation with a scalar.
+    ///
+    /// Unlike [remainder_scalar](Tensor::remainder_scalar), the result has the same sign as the
+    /// dividend, which matches the C `fmod` function.
+    ///
+    /// `y = x - trunc(x / s) * s`
+    pub fn fmod_scalar<E: ElementConversion>(self, other: E) -> Self {
+        let sign = self.clone().sign();
+        let divisor = other.elem::<f64>().abs();
+
+        self.abs().remainder_scalar(divisor).mul(sign)
+    }
+
     /// Create a tensor from floats (f32) on a given device.
     ///
     /// # Example
@@ -182,7 +233,46 @@ where
         Self::new(B::float_matmul(self.primitive, other.primitive))
     }
 
+    /// Applies the batched matrix multiplication operation, optionally transposing the last two
+    /// dimensions of each operand.
+    ///
+    /// `C = op(A) op(B)` where `op(X)` is `X^T` when the corresponding flag is set.
+    ///
+    /// # Arguments
+    ///
+    /// * `other` - The right-hand side operand.
+    /// * `transpose_lhs` - Whether to transpose the last two dimensions of `self`.
+    /// * `transpose_rhs` - Whether to transpose the last two dimensions of `other`.
+    ///
+    /// # Notes
+    ///
+    /// The transposition only swaps the strides of the last two dimensions. On the JIT backends,
+    /// the tiling 2D kernel reads those strides directly, so no permuted copy is materialized;
+    /// other kernels, such as the padded variant that autotune may select, still copy the
+    /// operands.
+    ///
+    /// # Panics
+    ///
+    /// If the two (possibly transposed) tensors don't have a compatible shape.
+    pub fn bmm(self, other: Self, transpose_lhs: bool, transpose_rhs: bool) -> Self {
+        let lhs = if transpose_lhs {
+            self.transpose()
+        } else {
+            self
+        };
+        let rhs = if transpose_rhs {
+            other.transpose()
+        } else {
+            other
+        };
+
+        lhs.matmul(rhs)
+    }
+
     /// Calculate the variance along the given dimension.
+    ///
+    /// The mean is subtracted before squaring (two-pass), which keeps the result accurate for data
+    /// with a large mean compared to its spread.
     pub fn var(self, dim: usize) -> Self {
         stats::var(self, dim)
     }
@@ -206,6 +296,384 @@ where
         (var, mean)
     }
 
+    /// Keeps the `k` largest elements along the given dimension and sets the others to `-inf`,
+    /// e.g. to restrict attention logits to the top-k keys.
+    ///
+    /// See [topk_mask_fill](Tensor::topk_mask_fill) for the tie handling.
+    #[cfg(any(feature = "wasm-sync", not(target_family = "wasm")))]
+    pub fn topk_mask(self, k: usize, dim: usize) -> Self {
+        self.topk_mask_fill(k, dim, f32::NEG_INFINITY)
+    }
+
+    /// Keeps the `k` largest elements along the given dimension and sets the others to `value`.
+    ///
+    /// Exactly `k` elements are kept along `dim`: when several elements are tied with the `k`-th
+    /// largest value, the ones with the lowest indices are kept.
+    #[cfg(any(feature = "wasm-sync", not(target_family = "wasm")))]
+    pub fn topk_mask_fill<E: ElementConversion>(self, k: usize, dim: usize, value: E) -> Self {
+        check!(TensorCheck::sort_dim::<D>("Topk_mask", dim));
+
+        let size = self.dims()[dim];
+        if k >= size {
+            return self;
+        }
+        if k == 0 {
+            return Tensor::full(self.shape(), value, &self.device());
+        }
+
+        // Work on rows of the selected dimension.
+        let x = self.swap_dims(dim, D - 1);
+        let shape = x.shape();
+        let rows = shape.num_elements() / size;
+        let x = x.reshape([rows, size]);
+
+        let threshold = x
+            .clone()
+            .topk(k, 1)
+            .narrow(1, k - 1, 1)
+            .expand([rows, size]);
+        let greater = x.clone().greater(threshold.clone());
+        let tied = x.clone().equal(threshold);
+
+        // Number of tied elements that can still be kept once the greater ones are.
+        let slots = greater
+            .clone()
+            .int()
+            .sum_dim(1)
+            .neg()
+            .add_scalar(k as i64)
+            .expand([rows, size]);
+        // Number of tied elements up to each position, so that the lowest indices are kept first.
+        let tied_count = tied.clone().int().cumsum(1);
+
+        let keep = tied
+            .bool_and(tied_count.lower_equal(slots))
+            .bool_or(greater);
+
+        x.mask_fill(keep.bool_not(), value)
+            .reshape(shape)
+            .swap_dims(dim, D - 1)
+    }
+
+    /// Applies nucleus (top-p) filtering to the probabilities along the given dimension.
+    ///
+    /// Keeps the smallest set of most probable elements whose cumulative probability exceeds `p`
+    /// and sets the others to zero, without renormalizing the kept probabilities. The most
+    /// probable element is always kept, even when its probability alone exceeds `p`.
+    ///
+    /// When several elements are tied at the boundary of the set, the order of the kept ones
+    /// follows the (unstable) [descending sort](Tensor::sort_descending_with_indices).
+    #[cfg(any(feature = "wasm-sync", not(target_family = "wasm")))]
+    pub fn top_p_mask(self, p: f64, dim: usize) -> Self {
+        check!(TensorCheck::top_p_mask::<D>(dim, p));
+
+        let (sorted, indices) = self.clone().sort_descending_with_indices(dim);
+        let mut shape = sorted.dims();
+        let size = shape[dim];
+        shape[dim] = 1;
+
+        // The cumulative probability of the elements ranked before each one is the inclusive sum
+        // shifted by one position, which avoids subtracting each probability from its sum.
+        let zeros = Tensor::zeros(shape, &sorted.device());
+        let ranked_before = Tensor::cat(vec![zeros, sorted.cumsum(dim)], dim).narrow(dim, 0, size);
+
+        // An element is kept when the elements ranked before it don't exceed `p` yet, which is
+        // always the case for the first one.
+        let keep = ranked_before.lower_equal_elem(p).float();
+        let keep = self.zeros_like().scatter(dim, indices, keep);
+
+        self.mul(keep)
+    }
+
+    /// Computes the maximum of each segment along the given dimension, with the position of the
+    /// maximum in the input.
+    ///
+    /// `indices` has the same shape as the tensor and gives the segment of each element along
+    /// `dim`. The outputs have the shape of the tensor with `dim` resized to `num_segments`:
+    ///
+    /// `maxima[i, s] = max(input[i, j]) for every j where indices[i, j] == s // dim = 1`
+    ///
+    /// The returned indices are the positions along `dim` of each maximum, the lowest position
+    /// being returned when the maximum is tied, e.g. to route the gradient in the backward pass
+    /// of a segment max pooling.
+    ///
+    /// # Notes
+    ///
+    /// Empty segments produce `-inf` with the sentinel index `size`, the size of the input along
+    /// `dim`. Elements with a segment index outside of `0..num_segments` are ignored.
+    ///
+    /// The maxima are computed with
+    /// [float_scatter_max](crate::ops::FloatTensorOps::float_scatter_max). Backends without their
+    /// own implementation fall back to a synchronous read back of the tensors to the host, which
+    /// blocks until the device has computed them.
+    #[cfg(any(feature = "wasm-sync", not(target_family = "wasm")))]
+    pub fn scatter_max(
+        self,
+        dim: usize,
+        indices: Tensor<B, D, Int>,
+        num_segments: usize,
+    ) -> (Self, Tensor<B, D, Int>) {
+        check!(TensorCheck::scatter_max::<D>(
+            dim,
+            &self.shape(),
+            &indices.shape()
+        ));
+
+        let size = self.dims()[dim];
+        let (maxima, argmax) =
+            B::float_scatter_max(self.primitive.clone(), dim, indices.primitive, num_segments);
+        let argmax = Tensor::<B, D, Int>::new(argmax);
+
+        if size == 0 {
+            return (Tensor::new(maxima), argmax);
+        }
+
+        // The maxima are gathered back from their positions so that the gradient flows to the
+        // maximum of each segment, the empty segments keeping their sentinel.
+        let empty = argmax.clone().equal_elem(size as i64);
+        let maxima = self
+            .gather(dim, argmax.clone().clamp_max(size as i64 - 1))
+            .mask_fill(empty, f32::NEG_INFINITY);
+
+        (maxima, argmax)
+    }
+
+    /// Applies the softmax over the elements of each segment along the given dimension, the
+    /// segments being unsorted.
+    ///
+    /// `segment_ids` has the size of the dimension and gives the segment of each element, as used
+    /// to normalize the attention scores of the incoming edges of each node in graph attention:
+    ///
+    /// `output[i] = exp(input[i]) / sum(exp(input[j])) for every j where segment_ids[j] == segment_ids[i]`
+    ///
+    /// The maximum of each segment is subtracted before the exponentials for numerical
+    /// stability. Elements with a segment index outside of `0..num_segments` are set to zero.
+    #[cfg(any(feature = "wasm-sync", not(target_family = "wasm")))]
+    pub fn segment_softmax(
+        self,
+        dim: usize,
+        segment_ids: Tensor<B, 1, Int>,
+        num_segments: usize,
+    ) -> Self {
+        check!(TensorCheck::segment_softmax::<D>(
+            dim,
+            &self.shape(),
+            &segment_ids.shape()
+        ));
+
+        Self::new(B::float_segment_softmax(
+            self.primitive,
+            segment_ids.primitive,
+            num_segments,
+            dim,
+        ))
+    }
+
+    /// Counts the elements of the tensor falling in each bin delimited by the given edges.
+    ///
+    /// `edges` must be sorted in increasing order and contain at least two values, `n` edges
+    /// defining `n - 1` bins that don't need to have the same width. Each bin includes its left
+    /// edge and excludes its right one, except the last bin which includes both, so that:
+    ///
+    /// `counts[i] = count(edges[i] <= x < edges[i + 1])`
+    ///
+    /// # Notes
+    ///
+    /// Values outside of the `[edges[0], edges[n - 1]]` range, as well as `NaN`, are dropped and
+    /// not counted in any bin.
+    ///
+    /// Each value is located with a binary search over the edges, which takes
+    /// `log2(num_edges)` gathers of the edges for all the elements at once.
+    pub fn histogram_bins(self, edges: Tensor<B, 1>) -> Tensor<B, 1, Int> {
+        check!(TensorCheck::histogram_bins(&edges.shape()));
+
+        let device = self.device();
+        let num_edges = edges.dims()[0];
+        let num_bins = num_edges - 1;
+        let num_elements = self.shape().num_elements();
+
+        let values = self.reshape([num_elements]);
+        let last_edge = edges.clone().narrow(0, num_bins, 1).expand([num_elements]);
+
+        // Sorted search: the bin of a value is the number of edges lower or equal to it, minus
+        // one. The number of edges is searched in `low..high`, which is halved at each step.
+        let mut low = Tensor::<B, 1, Int>::zeros([num_elements], &device);
+        let mut high = Tensor::<B, 1, Int>::full([num_elements], num_edges as i64, &device);
+
+        for _ in 0..usize::BITS - num_edges.leading_zeros() {
+            let searching = low.clone().lower(high.clone());
+            let middle = low.clone().add(high.clone()).div_scalar(2);
+            let edge = edges
+                .clone()
+                .gather(0, middle.clone().clamp_max(num_bins as i64));
+            let above = values
+                .clone()
+                .greater_equal(edge)
+                .bool_and(searching.clone());
+            let below = above.clone().bool_not().bool_and(searching);
+
+            low = low.mask_where(above, middle.clone().add_scalar(1));
+            high = high.mask_where(below, middle);
+        }
+        let bins = low.sub_scalar(1);
+
+        // Values equal to the last edge are clamped to the last bin, while the ones outside of
+        // the range are sent to an extra bin that is discarded.
+        let dropped = bins
+            .clone()
+            .lower_elem(0)
+            .bool_or(values.greater(last_edge));
+        let bins = bins
+            .clamp_max(num_bins as i64 - 1)
+            .mask_fill(dropped, num_bins as i64);
+
+        Tensor::<B, 1, Int>::zeros([num_bins + 1], &device)
+            .scatter(0, bins, Tensor::<B, 1, Int>::ones([num_elements], &device))
+            .narrow(0, 0, num_bins)
+    }
+
+    /// Estimates the derivative of the tensor along the given dimension, the values being
+    /// sampled with a uniform `spacing`.
+    ///
+    /// The output has the same shape as the input. The derivative is estimated with central
+    /// differences in the interior and with one-sided differences at the boundaries:
+    ///
+    /// - `out[0] = (x[1] - x[0]) / spacing`
+    /// - `out[i] = (x[i + 1] - x[i - 1]) / (2 * spacing)`
+    /// - `out[n - 1] = (x[n - 1] - x[n - 2]) / spacing`
+    ///
+    /// See [gradient_with_coordinates](Tensor::gradient_with_coordinates) for non-uniform
+    /// spacing.
+    pub fn gradient(self, dim: usize, spacing: f64) -> Self {
+        check!(TensorCheck::gradient::<D>(dim, &self.shape(), None));
+
+        let size = self.dims()[dim];
+        let delta = |start: usize, length: usize, step: usize| {
+            self.clone()
+                .narrow(dim, start + step, length)
+                .sub(self.clone().narrow(dim, start, length))
+        };
+
+        let first = delta(0, 1, 1).div_scalar(spacing);
+        let last = delta(size - 2, 1, 1).div_scalar(spacing);
+
+        if size == 2 {
+            return Tensor::cat(vec![first, last], dim);
+        }
+
+        let interior = delta(0, size - 2, 2).div_scalar(2.0 * spacing);
+
+        Tensor::cat(vec![first, interior, last], dim)
+    }
+
+    /// Estimates the derivative of the tensor along the given dimension, the values being
+    /// sampled at the given `coordinates`.
+    ///
+    /// The coordinates must be strictly monotonic and have the size of the dimension. As with
+    /// [gradient](Tensor::gradient), the output has the same shape as the input, with one-sided
+    /// differences at the boundaries. In the interior, the second order accurate central
+    /// difference for non-uniform spacing is used, with `hd = c[i] - c[i - 1]` and
+    /// `hs = c[i + 1] - c[i]`:
+    ///
+    /// `out[i] = (hd² x[i + 1] + (hs² - hd²) x[i] - hs² x[i - 1]) / (hd hs (hd + hs))`
+    pub fn gradient_with_coordinates(self, dim: usize, coordinates: Tensor<B, 1>) -> Self {
+        check!(TensorCheck::gradient::<D>(
+            dim,
+            &self.shape(),
+            Some(&coordinates.shape())
+        ));
+
+        let shape = self.shape();
+        let size = shape.dims[dim];
+
+        // Spacings are broadcast over the other dimensions.
+        let spacings = |start: usize, length: usize| {
+            let mut shape_spacings = [1; D];
+            shape_spacings[dim] = length;
+            let mut shape_expand = shape.clone();
+            shape_expand.dims[dim] = length;
+
+            coordinates
+                .clone()
+                .narrow(0, start + 1, length)
+                .sub(coordinates.clone().narrow(0, start, length))
+                .reshape(shape_spacings)
+                .expand(shape_expand)
+        };
+        let values = |start: usize, length: usize| self.clone().narrow(dim, start, length);
+
+        let first = values(1, 1).sub(values(0, 1)).div(spacings(0, 1));
+        let last = values(size - 1, 1)
+            .sub(values(size - 2, 1))
+            .div(spacings(size - 2, 1));
+
+        if size == 2 {
+            return Tensor::cat(vec![first, last], dim);
+        }
+
+        let hd = spacings(0, size - 2);
+        let hs = spacings(1, size - 2);
+        let hd2 = hd.clone().powf_scalar(2.0);
+        let hs2 = hs.clone().powf_scalar(2.0);
+
+        let interior = hd2
+            .clone()
+            .mul(values(2, size - 2))
+            .add(hs2.clone().sub(hd2).mul(values(1, size - 2)))
+            .sub(hs2.mul(values(0, size - 2)))
+            .div(hd.clone().mul(hs.clone()).mul(hd.add(hs)));
+
+        Tensor::cat(vec![first, interior, last], dim)
+    }
+
+    #[cfg(all(not(feature = "wasm-sync"), target_family = "wasm"))]
+    /// Exports the tensor as a [DLPack tensor](DLPackTensor), to share it with other frameworks.
+    pub async fn to_dlpack(self) -> DLPackTensor {
+        B::float_to_dlpack(self.primitive).read().await
+    }
+
+    #[cfg(any(feature = "wasm-sync", not(target_family = "wasm")))]
+    /// Exports the tensor as a [DLPack tensor](DLPackTensor), to share it with other frameworks.
+    ///
+    /// The ndarray backend shares the buffer of a tensor that no other tensor uses, and copies it
+    /// otherwise. The other backends, including the GPU ones, export a copy in host memory.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use burn_tensor::backend::Backend;
+    /// use burn_tensor::Tensor;
+    ///
+    /// fn example<B: Backend>() {
+    ///     let device = B::Device::default();
+    ///     let tensor = Tensor::<B, 2>::from_floats([[1.0, 2.0], [3.0, 4.0]], &device);
+    ///
+    ///     let dlpack = tensor.to_dlpack();
+    ///     assert_eq!(dlpack.shape(), [2, 2]);
+    ///
+    ///     let tensor = Tensor::<B, 2>::from_dlpack(dlpack, &device);
+    /// }
+    /// ```
+    pub fn to_dlpack(self) -> DLPackTensor {
+        B::float_to_dlpack(self.primitive).read()
+    }
+
+    /// Imports a [DLPack tensor](DLPackTensor) shared by another framework.
+    ///
+    /// Backends able to share a buffer with the DLPack tensor import it without copying, the
+    /// others copy its values from host memory.
+    ///
+    /// # Panics
+    ///
+    /// - If the DLPack tensor doesn't have `D` dimensions.
+    /// - If the backend can't read the DLPack tensor from its device, or doesn't support its
+    ///   element type.
+    pub fn from_dlpack(tensor: DLPackTensor, device: &B::Device) -> Self {
+        check!(TensorCheck::from_data::<D>(&tensor.shape()));
+
+        Self::new(B::float_from_dlpack(tensor, device))
+    }
+
     /// Returns a tensor with full precision based on the selected backend.
     pub fn into_full_precision(self) -> Tensor<FullPrecisionBackend<B>, D> {
         Tensor::new(B::float_into_full_precision(self.primitive))
@@ -350,3 +818,41 @@ where
         )
     }
 }
+
+impl<B> Tensor<B, 3>
+where
+    B: Backend,
+{
+    /// Creates a `[height, width, 2]` grid holding the `(y, x)` coordinates of each position, as
+    /// used to build 2D positional encodings.
+    ///
+    /// # Arguments
+    ///
+    /// * `height` - The number of rows of the grid.
+    /// * `width` - The number of columns of the grid.
+    /// * `normalized` - If true, the coordinates are mapped to `[-1, 1]` with `-1` and `1` at the
+    ///   first and last rows/columns (corners aligned), a dimension of size 1 being mapped to `0`.
+    ///   Otherwise, the coordinates are the pixel indices.
+    /// * `device` - The device on which the tensor will be allocated.
+    pub fn coord_grid(height: usize, width: usize, normalized: bool, device: &B::Device) -> Self {
+        let grid = Tensor::<B, 2, Int>::cartesian_grid([height, width], device).float();
+
+        if !normalized {
+            return grid;
+        }
+
+        let scale = |size: usize| match size {
+            0 | 1 => 0.0,
+            _ => 2.0 / (size - 1) as f32,
+        };
+        let offset = |size: usize| match size {
+            0 | 1 => 0.0,
+            _ => -1.0,
+        };
+        let scale = Tensor::<B, 1>::from_floats([scale(height), scale(width)], device);
+        let offset = Tensor::<B, 1>::from_floats([offset(height), offset(width)], device);
+
+        grid.mul(scale.reshape([1, 1, 2]).expand([height, width, 2]))
+            .add(offset.reshape([1, 1, 2]).expand([height, width, 2]))
+    }
+}
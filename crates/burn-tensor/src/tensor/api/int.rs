@@ -28,6 +28,23 @@ where
     pub fn arange_step(range: Range<i64>, step: usize, device: &B::Device) -> Self {
         Tensor::new(B::int_arange_step(range, step, device))
     }
+
+    /// Creates a `[num_indices, num_classes]` float tensor where each row is the one-hot encoding
+    /// of the corresponding index.
+    ///
+    /// The ones are scattered directly into a zero tensor, so no dense intermediate comparing each
+    /// index with every class is allocated: the peak memory is the output itself.
+    ///
+    /// Indices must be lower than `num_classes`.
+    pub fn one_hot(self, num_classes: usize) -> Tensor<B, 2> {
+        let [num_indices] = self.dims();
+        let device = self.device();
+
+        let indices = self.reshape([num_indices, 1]);
+        let ones = Tensor::ones([num_indices, 1], &device);
+
+        Tensor::zeros([num_indices, num_classes], &device).scatter(1, indices, ones)
+    }
 }
 
 impl<const D: usize, B> Tensor<B, D, Int>
@@ -71,6 +88,24 @@ where
         Tensor::new(B::int_into_float(self.primitive))
     }
 
+    /// Applies element wise the remainder operation with another tensor.
+    ///
+    /// The result has the same sign as the divisor, like
+    /// [remainder_scalar](Tensor::remainder_scalar) and the Python `%` operator.
+    ///
+    /// `y = x1 - floor(x1 / x2) * x2`
+    pub fn remainder(self, other: Self) -> Self {
+        // The integer division truncates toward zero, so the remainder has the sign of the
+        // dividend and is one divisor away from the floored one when the signs differ.
+        let remainder = self.clone().sub(self.div(other.clone()).mul(other.clone()));
+        let wrap = remainder
+            .clone()
+            .not_equal_elem(0)
+            .bool_and(remainder.clone().sign().not_equal(other.clone().sign()));
+
+        remainder.clone().mask_where(wrap, remainder.add(other))
+    }
+
     /// Generates a cartesian grid for the given tensor shape on the specified device.
     /// The generated tensor is of dimension `D2 = D + 1`, where each element at dimension D contains the cartesian grid coordinates for that element.
     ///
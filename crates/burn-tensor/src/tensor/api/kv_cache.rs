@@ -0,0 +1,177 @@
+use crate::{backend::Backend, check, check::TensorCheck, Shape, Tensor};
+
+/// A key/value cache for autoregressive decoding, preallocated to a maximum sequence length.
+///
+/// The keys and the values are written into buffers allocated once with the full capacity, so
+/// appending the tensors of a decoding step is a [slice_assign](Tensor::slice_assign) of the new
+/// positions instead of a [cat](Tensor::cat) of the whole cache, which would reallocate and copy
+/// every cached position at each step.
+///
+/// # Examples
+///
+/// ```rust
+/// use burn_tensor::backend::Backend;
+/// use burn_tensor::{KvCache, Tensor};
+///
+/// fn example<B: Backend>() {
+///     let device = Default::default();
+///     // Batch of 1, 2 heads, up to 16 positions of 8 features.
+///     let mut cache = KvCache::<B, 4>::new([1, 2, 16, 8], [1, 2, 16, 8], 2, &device);
+///
+///     let key = Tensor::<B, 4>::ones([1, 2, 1, 8], &device);
+///     let value = Tensor::<B, 4>::ones([1, 2, 1, 8], &device);
+///     let (keys, values) = cache.append(key, value);
+///     assert_eq!(keys.dims(), [1, 2, 1, 8]);
+///     assert_eq!(values.dims(), [1, 2, 1, 8]);
+/// }
+/// ```
+#[derive(Clone, Debug)]
+pub struct KvCache<B: Backend, const D: usize> {
+    /// The key buffer, only taken out of the cache while it is written.
+    keys: Option<Tensor<B, D>>,
+    /// The value buffer, only taken out of the cache while it is written.
+    values: Option<Tensor<B, D>>,
+    dim: usize,
+    len: usize,
+}
+
+impl<B: Backend, const D: usize> KvCache<B, D> {
+    /// Creates an empty cache with buffers of the given shapes.
+    ///
+    /// # Arguments
+    ///
+    /// * `key_shape` - The shape of the key buffer.
+    /// * `value_shape` - The shape of the value buffer.
+    /// * `dim` - The sequence dimension, whose size in the shapes is the capacity of the cache.
+    /// * `device` - The device where the buffers are allocated.
+    ///
+    /// # Panics
+    ///
+    /// - If the dimension is greater than the number of dimensions of the tensors.
+    /// - If the key and the value shapes have different sizes on the sequence dimension.
+    pub fn new<S: Into<Shape<D>>>(
+        key_shape: S,
+        value_shape: S,
+        dim: usize,
+        device: &B::Device,
+    ) -> Self {
+        let key_shape = key_shape.into();
+        let value_shape = value_shape.into();
+
+        check!(TensorCheck::kv_cache_new(dim, &key_shape, &value_shape));
+
+        Self {
+            keys: Some(Tensor::zeros(key_shape, device)),
+            values: Some(Tensor::zeros(value_shape, device)),
+            dim,
+            len: 0,
+        }
+    }
+
+    /// Writes the keys and the values of the new positions after the cached ones, returning all
+    /// the valid keys and values of the cache.
+    ///
+    /// # Arguments
+    ///
+    /// * `keys` - The new keys, with the shape of the key buffer except on the sequence dimension.
+    /// * `values` - The new values, with the shape of the value buffer except on the sequence
+    ///   dimension.
+    ///
+    /// # Panics
+    ///
+    /// - If the keys and the values have a different number of positions.
+    /// - If the new positions exceed the capacity of the cache.
+    ///
+    /// # Returns
+    ///
+    /// The keys and the values of the positions `0..len`, including the new ones.
+    pub fn append(
+        &mut self,
+        keys: Tensor<B, D>,
+        values: Tensor<B, D>,
+    ) -> (Tensor<B, D>, Tensor<B, D>) {
+        let num_new = keys.dims()[self.dim];
+
+        check!(TensorCheck::kv_cache_append(
+            num_new,
+            values.dims()[self.dim],
+            self.len,
+            self.capacity()
+        ));
+
+        let start = self.len;
+        let end = start + num_new;
+
+        // The buffers are moved out of the cache so that they are not shared when written, which
+        // would copy them instead of updating them in place.
+        let key_buffer = self
+            .keys
+            .take()
+            .expect("The key buffer should be allocated");
+        let value_buffer = self
+            .values
+            .take()
+            .expect("The value buffer should be allocated");
+
+        self.keys = Some(write_positions(key_buffer, keys, self.dim, start, end));
+        self.values = Some(write_positions(value_buffer, values, self.dim, start, end));
+        self.len = end;
+
+        (self.keys(), self.values())
+    }
+
+    /// Returns the valid keys of the cache.
+    pub fn keys(&self) -> Tensor<B, D> {
+        self.key_buffer().clone().narrow(self.dim, 0, self.len)
+    }
+
+    /// Returns the valid values of the cache.
+    pub fn values(&self) -> Tensor<B, D> {
+        self.value_buffer().clone().narrow(self.dim, 0, self.len)
+    }
+
+    /// Empties the cache, keeping its buffers for the next sequence.
+    pub fn reset(&mut self) {
+        self.len = 0;
+    }
+
+    /// Returns the number of cached positions.
+    pub fn len(&self) -> usize {
+        self.len
+    }
+
+    /// Returns true if no position is cached.
+    pub fn is_empty(&self) -> bool {
+        self.len == 0
+    }
+
+    /// Returns the maximum number of positions of the cache.
+    pub fn capacity(&self) -> usize {
+        self.key_buffer().dims()[self.dim]
+    }
+
+    fn key_buffer(&self) -> &Tensor<B, D> {
+        self.keys
+            .as_ref()
+            .expect("The key buffer should be allocated")
+    }
+
+    fn value_buffer(&self) -> &Tensor<B, D> {
+        self.values
+            .as_ref()
+            .expect("The value buffer should be allocated")
+    }
+}
+
+fn write_positions<B: Backend, const D: usize>(
+    buffer: Tensor<B, D>,
+    tensor: Tensor<B, D>,
+    dim: usize,
+    start: usize,
+    end: usize,
+) -> Tensor<B, D> {
+    let dims = buffer.dims();
+    let ranges: [_; D] = core::array::from_fn(|i| if i == dim { start..end } else { 0..dims[i] });
+
+    buffer.slice_assign(ranges, tensor)
+}
@@ -9,6 +9,7 @@ mod chunk;
 mod float;
 mod int;
 mod kind;
+mod kv_cache;
 mod narrow;
 mod numeric;
 mod sort;
@@ -19,6 +20,7 @@ pub use base::*;
 pub use cartesian_grid::cartesian_grid;
 pub use chunk::chunk;
 pub use kind::*;
+pub use kv_cache::KvCache;
 pub use narrow::narrow;
 pub use numeric::*;
 pub use sort::{argsort, sort, sort_with_indices};
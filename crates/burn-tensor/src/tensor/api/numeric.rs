@@ -1,10 +1,12 @@
+use alloc::collections::BTreeSet;
+use alloc::vec;
 use alloc::vec::Vec;
 
 use crate::alloc::borrow::ToOwned;
 
 use crate::{
     backend::Backend, check, check::TensorCheck, BasicOps, Bool, Distribution, Element,
-    ElementConversion, Float, Int, Shape, Tensor, TensorKind,
+    ElementConversion, Float, Int, Shape, Tensor, TensorData, TensorKind,
 };
 
 impl<B, const D: usize, K> Tensor<B, D, K>
@@ -141,6 +143,44 @@ where
         Self::new(K::sum_dim(self.primitive, dim))
     }
 
+    /// Aggregate the elements selected by `mask` along the given *dimension* or *axis*
+    /// with the sum operation, returning the reduced values along with the number of
+    /// contributing elements.
+    ///
+    /// Elements where the mask is `true` contribute to the reduction. When no element
+    /// contributes, the sum is zero and the count is zero. Dividing the sum by the count
+    /// gives the masked mean.
+    ///
+    /// # Notes
+    ///
+    /// To skip NaN values, use `tensor.clone().equal(tensor)` as the mask.
+    pub fn sum_dim_masked(self, dim: usize, mask: Tensor<B, D, Bool>) -> (Self, Tensor<B, D, Int>) {
+        check!(TensorCheck::aggregate_dim::<D>("SumMasked", dim));
+        check!(TensorCheck::mask_fill(&self.shape(), &mask.shape()));
+
+        let shape = broadcast_shape(&[self.shape(), mask.shape()]);
+        let tensor = broadcast_to(self, &shape);
+        let mask = broadcast_to(mask, &shape);
+
+        let (sum, count) = K::sum_dim_masked(tensor.primitive, dim, mask);
+
+        (Self::new(sum), count)
+    }
+
+    /// Computes the cumulative sum of the elements along the given *dimension* or *axis*.
+    ///
+    /// Each output element is the sum of the input elements up to and including its position
+    /// along `dim`.
+    ///
+    /// # Notes
+    ///
+    /// Backends without a dedicated scan compute the partial sums by doubling their span, which
+    /// takes `log2(size)` additions and allocations of the whole tensor.
+    pub fn cumsum(self, dim: usize) -> Self {
+        check!(TensorCheck::aggregate_dim::<D>("CumSum", dim));
+        Self::new(K::cumsum(self.primitive, dim))
+    }
+
     /// Aggregate all elements along the given *dimension* or *axis*
     /// in the tensor with the product operation.
     pub fn prod(self) -> Tensor<B, 1, K> {
@@ -228,16 +268,43 @@ where
     ///
     /// This is similar to [mask_fill](Tensor::mask_fill), however the value is a tensor instead of
     /// a scalar.
+    ///
+    /// # Notes
+    ///
+    /// The tensor, the mask and the value are broadcasted together, so any of their dimensions
+    /// can be of size 1.
     pub fn mask_where(self, mask: Tensor<B, D, Bool>, value: Self) -> Self {
-        Self::new(K::mask_where(self.primitive, mask, value.primitive))
+        check!(TensorCheck::mask_where(
+            &self.shape(),
+            &mask.shape(),
+            &value.shape()
+        ));
+
+        let shape = broadcast_shape(&[self.shape(), mask.shape(), value.shape()]);
+        let tensor = broadcast_to(self, &shape);
+        let mask = broadcast_to(mask, &shape);
+        let value = broadcast_to(value, &shape);
+
+        Self::new(K::mask_where(tensor.primitive, mask, value.primitive))
     }
 
     /// Update the given tensor with the value where the mask is true.
     ///
     /// This is similar to [mask_where](Tensor::mask_where), however the value is a scalar instead of
     /// a tensor.
+    ///
+    /// # Notes
+    ///
+    /// The tensor and the mask are broadcasted together, so any of their dimensions can be of
+    /// size 1.
     pub fn mask_fill<E: ElementConversion>(self, mask: Tensor<B, D, Bool>, value: E) -> Self {
-        Self::new(K::mask_fill(self.primitive, mask, value.elem()))
+        check!(TensorCheck::mask_fill(&self.shape(), &mask.shape()));
+
+        let shape = broadcast_shape(&[self.shape(), mask.shape()]);
+        let tensor = broadcast_to(self, &shape);
+        let mask = broadcast_to(mask, &shape);
+
+        Self::new(K::mask_fill(tensor.primitive, mask, value.elem()))
     }
 
     /// Gather tensor elements corresponding to the given indices from the specified dim.
@@ -324,8 +391,180 @@ where
         ))
     }
 
+    /// Assign the values at the positions given by one index tensor per dimension.
+    ///
+    /// Example using a 2D tensor:
+    ///
+    /// `input[indices[0][i], indices[1][i]] = values[i]; // accumulate = false`
+    /// `input[indices[0][i], indices[1][i]] += values[i]; // accumulate = true`
+    ///
+    /// # Notes
+    ///
+    /// The values are broadcasted to the number of indexed positions, so a single value can be
+    /// assigned to all of them. When `accumulate` is false and the same position is indexed more
+    /// than once, the last value is kept. Finding the duplicated positions reads the indices back
+    /// from the device in that case.
+    #[cfg(any(feature = "wasm-sync", not(target_family = "wasm")))]
+    pub fn index_put(
+        self,
+        indices: &[Tensor<B, 1, Int>],
+        values: Tensor<B, 1, K>,
+        accumulate: bool,
+    ) -> Self {
+        check!(TensorCheck::index_put::<D>(
+            &indices
+                .iter()
+                .map(|index| index.dims()[0])
+                .collect::<Vec<_>>()
+        ));
+
+        let shape = self.shape();
+        let device = self.device();
+        let num_elements = shape.num_elements();
+        let num_indices = indices[0].dims()[0];
+
+        // Convert the per-dimension indices to indices into the flattened tensor.
+        let mut stride = num_elements;
+        let mut flat_indices = Tensor::<B, 1, Int>::zeros([num_indices], &device);
+        for (dim, index) in indices.iter().enumerate() {
+            stride /= shape.dims[dim];
+            flat_indices = flat_indices.add(index.clone().mul_scalar(stride as i64));
+        }
+
+        let mut values = values.expand([num_indices]);
+        let mut tensor = self.reshape([num_elements]);
+
+        if !accumulate {
+            // The scatter sums the values written to the same position, so only the last value
+            // of each position is kept.
+            let mut positions = BTreeSet::new();
+            let mut overwritten = vec![false; num_indices];
+            let flat: Vec<i64> = flat_indices.clone().into_data().iter().collect();
+            for (i, position) in flat.into_iter().enumerate().rev() {
+                overwritten[i] = !positions.insert(position);
+            }
+            let overwritten = Tensor::<B, 1, Bool>::from_data(
+                TensorData::new(overwritten, [num_indices]),
+                &device,
+            );
+            values = values.mask_fill(overwritten, 0);
+
+            let mask = Tensor::<B, 1, Int>::zeros([num_elements], &device)
+                .scatter(
+                    0,
+                    flat_indices.clone(),
+                    Tensor::ones([num_indices], &device),
+                )
+                .greater_elem(0);
+            tensor = tensor.mask_fill(mask, 0);
+        }
+
+        tensor.scatter(0, flat_indices, values).reshape(shape)
+    }
+
+    /// Splits the rows of the tensor (along the first dimension) into `num_partitions` tensors,
+    /// `partitions` giving the partition of each row.
+    ///
+    /// The rows of each partition keep their order, so that the inverse is obtained with
+    /// [dynamic_stitch](Tensor::dynamic_stitch) and the row indices of each partition:
+    ///
+    /// `output[p] = input[i] for every i where partitions[i] == p`
+    ///
+    /// # Notes
+    ///
+    /// The size of each partition depends on the values of `partitions`, which are read back from
+    /// the device once. A partition without any row is `None`, since tensors with a dimension of
+    /// size zero aren't supported. Rows with a partition outside of `0..num_partitions` are
+    /// dropped.
+    #[cfg(any(feature = "wasm-sync", not(target_family = "wasm")))]
+    pub fn dynamic_partition(
+        self,
+        partitions: Tensor<B, 1, Int>,
+        num_partitions: usize,
+    ) -> Vec<Option<Self>> {
+        check!(TensorCheck::dynamic_partition::<D>(
+            &self.shape(),
+            &partitions.shape()
+        ));
+
+        let device = self.device();
+        let mut rows = vec![Vec::new(); num_partitions];
+
+        for (row, partition) in partitions.into_data().iter::<i64>().enumerate() {
+            if let Some(rows) = usize::try_from(partition)
+                .ok()
+                .and_then(|partition| rows.get_mut(partition))
+            {
+                rows.push(row as i64);
+            }
+        }
+
+        rows.into_iter()
+            .map(|rows| {
+                let num_rows = rows.len();
+                (num_rows > 0).then(|| {
+                    let indices = Tensor::from_data(TensorData::new(rows, [num_rows]), &device);
+                    self.clone().select(0, indices)
+                })
+            })
+            .collect()
+    }
+
+    /// Reassembles the rows of the given tensors into a single tensor, the row `j` of `data[i]`
+    /// being written to the row `indices[i][j]` of the output.
+    ///
+    /// This is the inverse of [dynamic_partition](Tensor::dynamic_partition) when `indices` are
+    /// the row indices of each partition, `None` standing for a partition without any row:
+    ///
+    /// `output[indices[i][j]] = data[i][j]`
+    ///
+    /// # Notes
+    ///
+    /// The output has as many rows as there are indices in total, which should cover each row
+    /// exactly once. The rows are assigned with [select_assign](Tensor::select_assign), so a row
+    /// indexed more than once receives the sum of its values.
+    pub fn dynamic_stitch(
+        indices: Vec<Option<Tensor<B, 1, Int>>>,
+        data: Vec<Option<Self>>,
+    ) -> Self {
+        check!(TensorCheck::dynamic_stitch::<D>(
+            &indices
+                .iter()
+                .map(|index| index.as_ref().map_or(0, |index| index.dims()[0]))
+                .collect::<Vec<_>>(),
+            &data
+                .iter()
+                .map(|tensor| tensor.as_ref().map(|tensor| tensor.shape()))
+                .collect::<Vec<_>>()
+        ));
+
+        let pairs = indices
+            .into_iter()
+            .zip(data)
+            .filter_map(|(index, tensor)| index.zip(tensor))
+            .collect::<Vec<_>>();
+
+        let mut dims = pairs[0].1.dims();
+        dims[0] = pairs.iter().map(|(index, _)| index.dims()[0]).sum();
+        let mut output = Self::zeros(dims, &pairs[0].1.device());
+
+        for (index, tensor) in pairs {
+            output = output.select_assign(0, index, tensor);
+        }
+
+        output
+    }
+
     /// Applies the argmax function along the given dimension and returns an integer tensor.
     ///
+    /// The reduced dimension is kept with a size of 1, use [squeeze](Tensor::squeeze) to remove
+    /// it. When the maximum value appears more than once, the index of the first occurrence is
+    /// returned.
+    ///
+    /// # Panics
+    ///
+    /// If the dimension is out of bounds or has a size of zero.
+    ///
     /// # Example
     ///
     /// ```rust
@@ -341,9 +580,40 @@ where
     /// }
     /// ```
     pub fn argmax(self, dim: usize) -> Tensor<B, D, Int> {
+        check!(TensorCheck::arg_dim("Argmax", dim, &self.shape()));
         Tensor::new(K::argmax(self.primitive, dim))
     }
 
+    /// Find the indices of the maximum values along the given dimension, keeping the reduced
+    /// dimension with a size of 1 when `keepdim` is true and removing it otherwise, as with
+    /// `argmax(dim, keepdim)` in PyTorch.
+    ///
+    /// The output rank `D2` is `D` when `keepdim` is true and `D - 1` otherwise.
+    ///
+    /// # Panics
+    ///
+    /// If the dimension is out of bounds or has a size of zero, or if `D2` doesn't match
+    /// `keepdim`.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use burn_tensor::backend::Backend;
+    /// use burn_tensor::{Int, Tensor, Shape};
+    ///
+    /// fn example<B: Backend>() {
+    ///     let device = Default::default();
+    ///     let tensor = Tensor::<B, 3>::ones(Shape::new([2, 3, 3]), &device);
+    ///     let indices: Tensor<B, 2, Int> = tensor.argmax_keepdim(1, false);
+    ///     println!("{:?}", indices.shape());
+    ///     // Shape { dims: [2, 3] }
+    /// }
+    /// ```
+    pub fn argmax_keepdim<const D2: usize>(self, dim: usize, keepdim: bool) -> Tensor<B, D2, Int> {
+        check!(TensorCheck::keepdim::<D, D2>("Argmax", keepdim));
+        reduced_dim(self.argmax(dim), dim, keepdim)
+    }
+
     /// Find the maximum value.
     pub fn max(self) -> Tensor<B, 1, K> {
         Tensor::new(K::max(self.primitive))
@@ -387,6 +657,14 @@ where
 
     /// Applies the argmin function along the given dimension and returns an integer tensor.
     ///
+    /// The reduced dimension is kept with a size of 1, use [squeeze](Tensor::squeeze) to remove
+    /// it. When the minimum value appears more than once, the index of the first occurrence is
+    /// returned.
+    ///
+    /// # Panics
+    ///
+    /// If the dimension is out of bounds or has a size of zero.
+    ///
     /// # Example
     ///
     /// ```rust
@@ -402,9 +680,40 @@ where
     /// }
     /// ```
     pub fn argmin(self, dim: usize) -> Tensor<B, D, Int> {
+        check!(TensorCheck::arg_dim("Argmin", dim, &self.shape()));
         Tensor::new(K::argmin(self.primitive, dim))
     }
 
+    /// Find the indices of the minimum values along the given dimension, keeping the reduced
+    /// dimension with a size of 1 when `keepdim` is true and removing it otherwise, as with
+    /// `argmin(dim, keepdim)` in PyTorch.
+    ///
+    /// The output rank `D2` is `D` when `keepdim` is true and `D - 1` otherwise.
+    ///
+    /// # Panics
+    ///
+    /// If the dimension is out of bounds or has a size of zero, or if `D2` doesn't match
+    /// `keepdim`.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use burn_tensor::backend::Backend;
+    /// use burn_tensor::{Int, Tensor, Shape};
+    ///
+    /// fn example<B: Backend>() {
+    ///     let device = Default::default();
+    ///     let tensor = Tensor::<B, 3>::ones(Shape::new([2, 3, 3]), &device);
+    ///     let indices: Tensor<B, 2, Int> = tensor.argmin_keepdim(1, false);
+    ///     println!("{:?}", indices.shape());
+    ///     // Shape { dims: [2, 3] }
+    /// }
+    /// ```
+    pub fn argmin_keepdim<const D2: usize>(self, dim: usize, keepdim: bool) -> Tensor<B, D2, Int> {
+        check!(TensorCheck::keepdim::<D, D2>("Argmin", keepdim));
+        reduced_dim(self.argmin(dim), dim, keepdim)
+    }
+
     /// Find the minimum value.
     pub fn min(self) -> Tensor<B, 1, K> {
         Tensor::new(K::min(self.primitive))
@@ -496,6 +805,10 @@ where
     /// Returns the upper triangular part of a matrix (2-D tensor) or batch of matrices input,
     /// the other elements of the result tensor out are set to 0.
     ///
+    /// The `diagonal` offset is a runtime value, so it can be computed on the fly (e.g. from a
+    /// sliding attention window). Positive values move the diagonal above the main one and negative
+    /// values below it; offsets beyond the matrix size keep or zero every element.
+    ///
     /// # Example
     /// ```rust
     /// use burn_tensor::backend::Backend;
@@ -533,6 +846,9 @@ where
     /// Returns the lower triangular part of a matrix (2-D tensor) or batch of matrices input,
     /// the other elements of the result tensor out are set to 0.
     ///
+    /// Like [triu](Tensor::triu), `diagonal` is a runtime offset: a negative value also zeroes the
+    /// first sub-diagonals, and an offset beyond the matrix size keeps or zeroes every element.
+    ///
     /// # Example
     /// ```rust
     /// use burn_tensor::backend::Backend;
@@ -730,20 +1046,16 @@ where
     /// Returns the `k` largest elements of the given input tensor along a given dimension.
     #[cfg(any(feature = "wasm-sync", not(target_family = "wasm")))]
     pub fn topk(self, k: usize, dim: usize) -> Tensor<B, D, K> {
-        let k_indices = Tensor::arange(0..k as i64, &self.device());
-        self.sort_descending(dim).select(dim, k_indices)
+        self.topk_with_indices(k, dim).0
     }
 
     /// Returns the `k` largest elements of the given input tensor along a given dimension.
     /// Also returns the indices.
     #[cfg(any(feature = "wasm-sync", not(target_family = "wasm")))]
     pub fn topk_with_indices(self, k: usize, dim: usize) -> (Tensor<B, D, K>, Tensor<B, D, Int>) {
-        let k_indices = Tensor::arange(0..k as i64, &self.device());
-        let (values, indices) = self.sort_descending_with_indices(dim);
-        (
-            values.select(dim, k_indices.clone()),
-            indices.select(dim, k_indices),
-        )
+        check!(TensorCheck::topk::<D>(dim, k, &self.shape()));
+        let (values, indices) = K::topk_with_indices(self.primitive, k, dim);
+        (Tensor::new(values), Tensor::new(indices))
     }
 
     /// Pad the tensor with the given value on the last two dimensions.
@@ -788,6 +1100,63 @@ where
         // Assign the original tensor data to the appropriate slice of the padded tensor
         padded_tensor.slice_assign(ranges, self)
     }
+
+    /// Shifts the elements of the tensor along the given dimension, filling the vacated
+    /// positions with a constant value.
+    ///
+    /// Unlike a circular roll, the elements shifted past the end of the dimension are discarded.
+    ///
+    /// # Arguments
+    ///
+    /// * `dim` - The dimension along which to shift the elements.
+    /// * `amount` - The number of positions to shift. A positive amount shifts the elements
+    ///   towards the end of the dimension, a negative amount towards the start.
+    /// * `fill` - The value written to the vacated positions.
+    ///
+    /// # Returns
+    ///
+    /// A new tensor with the same shape as the input. When the absolute shift amount is greater
+    /// than or equal to the size of the dimension, every element is set to the fill value.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use burn_tensor::backend::Backend;
+    /// use burn_tensor::Tensor;
+    ///
+    /// fn example<B: Backend>() {
+    ///     let device = Default::default();
+    ///     let tensor = Tensor::<B, 1>::from_floats([1.0, 2.0, 3.0, 4.0], &device);
+    ///     let shifted = tensor.shift(0, 1, 0.0);
+    ///     println!("{shifted}");
+    ///     // [0.0, 1.0, 2.0, 3.0]
+    /// }
+    /// ```
+    pub fn shift<E: ElementConversion>(self, dim: usize, amount: i64, fill: E) -> Self {
+        check!(TensorCheck::dim_ops::<D>("Shift", dim));
+
+        let dims = self.dims();
+        let size = dims[dim];
+        let output = Tensor::full(dims, fill, &self.device());
+
+        let offset = amount.unsigned_abs() as usize;
+        if offset >= size {
+            return output;
+        }
+
+        let mut ranges_input = dims.map(|dim| 0..dim);
+        let mut ranges_output = ranges_input.clone();
+
+        if amount >= 0 {
+            ranges_input[dim] = 0..size - offset;
+            ranges_output[dim] = offset..size;
+        } else {
+            ranges_input[dim] = offset..size;
+            ranges_output[dim] = 0..size - offset;
+        }
+
+        output.slice_assign(ranges_output, self.slice(ranges_input))
+    }
 }
 
 impl<B, K> Tensor<B, 2, K>
@@ -809,6 +1178,50 @@ where
     }
 }
 
+/// Returns the shape all the given shapes broadcast to.
+fn broadcast_shape<const D: usize>(shapes: &[Shape<D>]) -> Shape<D> {
+    let mut dims = [1; D];
+
+    for shape in shapes {
+        for (dim, size) in dims.iter_mut().zip(shape.dims.iter()) {
+            *dim = usize::max(*dim, *size);
+        }
+    }
+
+    Shape::new(dims)
+}
+
+/// Expands the tensor to the given shape, only when its shape differs.
+fn broadcast_to<B: Backend, const D: usize, K: BasicOps<B>>(
+    tensor: Tensor<B, D, K>,
+    shape: &Shape<D>,
+) -> Tensor<B, D, K> {
+    if &tensor.shape() == shape {
+        return tensor;
+    }
+
+    tensor.expand(shape.clone())
+}
+
+/// Reshapes the output of a reduction keeping the reduced dimension, removing that dimension
+/// unless `keepdim` is true.
+fn reduced_dim<B: Backend, const D: usize, const D2: usize, K: BasicOps<B>>(
+    tensor: Tensor<B, D, K>,
+    dim: usize,
+    keepdim: bool,
+) -> Tensor<B, D2, K> {
+    let dims = tensor.dims();
+    let dims: [usize; D2] = core::array::from_fn(|i| {
+        if keepdim || i < dim {
+            dims[i]
+        } else {
+            dims[i + 1]
+        }
+    });
+
+    tensor.reshape(dims)
+}
+
 /// Trait that list all operations that can be applied on all numerical tensors.
 ///
 /// # Warnings
@@ -1172,6 +1585,55 @@ where
     /// which is more high-level and designed for public use.
     fn sum_dim<const D: usize>(tensor: Self::Primitive<D>, dim: usize) -> Self::Primitive<D>;
 
+    /// Sums the elements of the tensor selected by a mask along a dimension.
+    ///
+    /// # Arguments
+    ///
+    /// * `tensor` - The tensor to sum.
+    /// * `dim` - The dimension along which to sum.
+    /// * `mask` - The elements to sum, with the same shape as the tensor.
+    ///
+    /// # Returns
+    ///
+    /// The sum of the selected elements along the specified dimension, and their number.
+    ///
+    /// # Remarks
+    ///
+    /// This is a low-level function used internally by the library to call different backend functions
+    /// with static dispatch. It is not designed for direct usage by users, and not recommended to import
+    /// or use this function directly.
+    ///
+    /// For summing the elements selected by a mask along a dimension, users should prefer the
+    /// [Tensor::sum_dim_masked](Tensor::sum_dim_masked) function, which is more high-level and
+    /// designed for public use.
+    fn sum_dim_masked<const D: usize>(
+        tensor: Self::Primitive<D>,
+        dim: usize,
+        mask: Tensor<B, D, Bool>,
+    ) -> (Self::Primitive<D>, Tensor<B, D, Int>);
+
+    /// Computes the cumulative sum of the elements of the tensor along a dimension.
+    ///
+    /// # Arguments
+    ///
+    /// * `tensor` - The tensor to accumulate.
+    /// * `dim` - The dimension along which to accumulate.
+    ///
+    /// # Returns
+    ///
+    /// A tensor with the same shape where each element is the sum of the elements up to and
+    /// including its position along the dimension.
+    ///
+    /// # Remarks
+    ///
+    /// This is a low-level function used internally by the library to call different backend functions
+    /// with static dispatch. It is not designed for direct usage by users, and not recommended to import
+    /// or use this function directly.
+    ///
+    /// For the cumulative sum of a tensor along a dimension, users should prefer the [Tensor::cumsum](Tensor::cumsum) function,
+    /// which is more high-level and designed for public use.
+    fn cumsum<const D: usize>(tensor: Self::Primitive<D>, dim: usize) -> Self::Primitive<D>;
+
     /// Computes the product of all the elements of the tensor.
     ///
     /// # Arguments
@@ -2093,6 +2555,23 @@ where
         dim: usize,
         descending: bool,
     ) -> <Int as TensorKind<B>>::Primitive<D>;
+
+    /// Returns the `k` largest elements of the input `tensor` along a given dimension, with their
+    /// indices.
+    ///
+    /// # Remarks
+    /// This is a low-level function used internally by the library to call different backend functions
+    /// with static dispatch. It is not designed for direct usage by users, and not recommended to import
+    /// or use this function directly.
+    ///
+    /// Users should prefer the [Tensor::topk_with_indices](Tensor::topk_with_indices) function,
+    /// which is more high-level and designed for public use.
+    #[cfg(any(feature = "wasm-sync", not(target_family = "wasm")))]
+    fn topk_with_indices<const D: usize>(
+        tensor: Self::Primitive<D>,
+        k: usize,
+        dim: usize,
+    ) -> (Self::Primitive<D>, <Int as TensorKind<B>>::Primitive<D>);
 }
 
 impl<B: Backend> Numeric<B> for Int {
@@ -2175,6 +2654,19 @@ impl<B: Backend> Numeric<B> for Int {
         B::int_sum_dim(tensor, dim)
     }
 
+    fn sum_dim_masked<const D: usize>(
+        tensor: Self::Primitive<D>,
+        dim: usize,
+        mask: Tensor<B, D, Bool>,
+    ) -> (Self::Primitive<D>, Tensor<B, D, Int>) {
+        let (sum, count) = B::int_sum_dim_masked(tensor, dim, mask.primitive);
+        (sum, Tensor::new(count))
+    }
+
+    fn cumsum<const D: usize>(tensor: Self::Primitive<D>, dim: usize) -> Self::Primitive<D> {
+        B::int_cumsum(tensor, dim)
+    }
+
     fn prod<const D: usize>(tensor: Self::Primitive<D>) -> Self::Primitive<1> {
         B::int_prod(tensor)
     }
@@ -2437,6 +2929,23 @@ impl<B: Backend> Numeric<B> for Int {
     ) -> <Int as TensorKind<B>>::Primitive<D> {
         B::int_argsort(tensor, dim, descending)
     }
+
+    #[cfg(any(feature = "wasm-sync", not(target_family = "wasm")))]
+    fn topk_with_indices<const D: usize>(
+        tensor: Self::Primitive<D>,
+        k: usize,
+        dim: usize,
+    ) -> (Self::Primitive<D>, <Int as TensorKind<B>>::Primitive<D>) {
+        let mut ranges = B::int_shape(&tensor).dims.map(|size| 0..size);
+        ranges[dim] = 0..k;
+
+        let (values, indices) = B::int_sort_with_indices(tensor, dim, true);
+
+        (
+            B::int_slice(values, ranges.clone()),
+            B::int_slice(indices, ranges),
+        )
+    }
 }
 
 impl<B: Backend> Numeric<B> for Float {
@@ -2520,6 +3029,19 @@ impl<B: Backend> Numeric<B> for Float {
         B::float_sum_dim(tensor, dim)
     }
 
+    fn sum_dim_masked<const D: usize>(
+        tensor: Self::Primitive<D>,
+        dim: usize,
+        mask: Tensor<B, D, Bool>,
+    ) -> (Self::Primitive<D>, Tensor<B, D, Int>) {
+        let (sum, count) = B::float_sum_dim_masked(tensor, dim, mask.primitive);
+        (sum, Tensor::new(count))
+    }
+
+    fn cumsum<const D: usize>(tensor: Self::Primitive<D>, dim: usize) -> Self::Primitive<D> {
+        B::float_cumsum(tensor, dim)
+    }
+
     fn prod<const D: usize>(tensor: Self::Primitive<D>) -> Self::Primitive<1> {
         B::float_prod(tensor)
     }
@@ -2784,6 +3306,15 @@ impl<B: Backend> Numeric<B> for Float {
     ) -> <Int as TensorKind<B>>::Primitive<D> {
         B::float_argsort(tensor, dim, descending)
     }
+
+    #[cfg(any(feature = "wasm-sync", not(target_family = "wasm")))]
+    fn topk_with_indices<const D: usize>(
+        tensor: Self::Primitive<D>,
+        k: usize,
+        dim: usize,
+    ) -> (Self::Primitive<D>, <Int as TensorKind<B>>::Primitive<D>) {
+        B::float_topk_with_indices(tensor, k, dim)
+    }
 }
 
 impl<B, const D: usize, K> core::ops::Add<Self> for Tensor<B, D, K>
@@ -48,6 +48,15 @@ impl TensorData {
         }
     }
 
+    /// Creates a new tensor data structure from the bytes of its values.
+    pub(crate) fn from_bytes<S: Into<Vec<usize>>>(value: Vec<u8>, shape: S, dtype: DType) -> Self {
+        Self {
+            value,
+            shape: shape.into(),
+            dtype,
+        }
+    }
+
     /// Returns the immutable slice view of the tensor data.
     pub fn as_slice<E: Element>(&self) -> Result<&[E], DataError> {
         if E::dtype() == self.dtype {
@@ -218,6 +227,34 @@ impl TensorData {
         }
     }
 
+    /// Reinterprets the bytes of the data as another element type of the same size, without
+    /// converting the values (e.g. the bit patterns of `f32` values read as `i32`).
+    ///
+    /// Unlike [convert](Self::convert), the values are not preserved, only their bytes.
+    ///
+    /// # Errors
+    ///
+    /// If the element sizes differ, or if the bytes aren't valid values of the target type
+    /// (e.g. a byte other than 0 or 1 reinterpreted as a `bool`).
+    pub fn bitcast(self, dtype: DType) -> Result<Self, DataError> {
+        if dtype.size() != self.dtype.size() {
+            return Err(DataError::TypeMismatch(format!(
+                "Can't bitcast {:?} to {:?}, the element sizes differ ({} != {} bytes)",
+                self.dtype,
+                dtype,
+                self.dtype.size(),
+                dtype.size()
+            )));
+        }
+
+        if dtype == DType::Bool {
+            bytemuck::checked::try_cast_slice::<u8, bool>(&self.value)
+                .map_err(DataError::CastError)?;
+        }
+
+        Ok(Self { dtype, ..self })
+    }
+
     /// Returns the data as a slice of bytes.
     pub fn as_bytes(&self) -> &[u8] {
         self.value.as_slice()
@@ -932,4 +969,39 @@ mod tests {
 
         data1.assert_approx_eq(&data2, 2);
     }
+
+    #[test]
+    fn should_bitcast_f32_to_i32() {
+        let values = [1.0f32, -2.5, 0.0, f32::INFINITY];
+        let data = TensorData::from(values).bitcast(DType::I32).unwrap();
+
+        assert_eq!(data.dtype, DType::I32);
+        assert_eq!(
+            data.to_vec::<i32>().unwrap(),
+            values.map(|v| v.to_bits() as i32).to_vec()
+        );
+
+        let data = data.bitcast(DType::F32).unwrap();
+        assert_eq!(data.to_vec::<f32>().unwrap(), values.to_vec());
+    }
+
+    #[test]
+    fn should_not_bitcast_different_sizes() {
+        let data = TensorData::from([1.0f32, 2.0]);
+
+        assert!(matches!(
+            data.bitcast(DType::I64),
+            Err(DataError::TypeMismatch(_))
+        ));
+    }
+
+    #[test]
+    fn should_not_bitcast_invalid_bool() {
+        let data = TensorData::from([0u8, 1, 2]);
+
+        assert!(matches!(
+            data.bitcast(DType::Bool),
+            Err(DataError::CastError(_))
+        ));
+    }
 }
@@ -0,0 +1,534 @@
+//! Interop through [DLPack](https://dmlc.github.io/dlpack/latest/), the in-memory tensor
+//! structure shared by frameworks such as PyTorch and JAX.
+//!
+//! The structures follow the C ABI of `dlpack.h`, so a binding can wrap the pointer returned by
+//! [into_raw](crate::dlpack::DLPackTensor::into_raw) in a `dltensor` capsule, and hand the
+//! pointer of a capsule it consumes to [from_raw](crate::dlpack::DLPackTensor::from_raw).
+
+use alloc::boxed::Box;
+use alloc::vec;
+use alloc::vec::Vec;
+use core::any::Any;
+use core::ffi::c_void;
+use core::ptr::NonNull;
+
+use crate::{DType, Element, TensorData};
+
+/// The type of the device holding the buffer of a DLPack tensor.
+#[repr(transparent)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct DLDeviceType(pub i32);
+
+impl DLDeviceType {
+    /// Host memory.
+    pub const CPU: Self = Self(1);
+    /// CUDA device memory.
+    pub const CUDA: Self = Self(2);
+    /// CUDA pinned host memory.
+    pub const CUDA_HOST: Self = Self(3);
+    /// OpenCL device memory.
+    pub const OPENCL: Self = Self(4);
+    /// Vulkan buffer.
+    pub const VULKAN: Self = Self(7);
+    /// Metal buffer.
+    pub const METAL: Self = Self(8);
+    /// ROCm device memory.
+    pub const ROCM: Self = Self(10);
+    /// WebGPU buffer.
+    pub const WEBGPU: Self = Self(15);
+}
+
+/// The device holding the buffer of a DLPack tensor.
+#[repr(C)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct DLDevice {
+    /// The type of the device.
+    pub device_type: DLDeviceType,
+    /// The index of the device among the devices of its type.
+    pub device_id: i32,
+}
+
+impl DLDevice {
+    /// The host.
+    pub const CPU: Self = Self {
+        device_type: DLDeviceType::CPU,
+        device_id: 0,
+    };
+}
+
+/// The element type of a DLPack tensor.
+#[repr(C)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct DLDataType {
+    /// The kind of number, one of the associated constants.
+    pub code: u8,
+    /// The number of bits of an element.
+    pub bits: u8,
+    /// The number of lanes of an element, `1` for scalar elements.
+    pub lanes: u16,
+}
+
+impl DLDataType {
+    /// Signed integers.
+    pub const INT: u8 = 0;
+    /// Unsigned integers.
+    pub const UINT: u8 = 1;
+    /// IEEE floating point numbers.
+    pub const FLOAT: u8 = 2;
+    /// Brain floating point numbers.
+    pub const BFLOAT: u8 = 4;
+    /// Booleans, stored on 8 bits.
+    pub const BOOL: u8 = 6;
+}
+
+impl From<DType> for DLDataType {
+    fn from(dtype: DType) -> Self {
+        let code = match dtype {
+            DType::F64 | DType::F32 | DType::F16 => Self::FLOAT,
+            DType::BF16 => Self::BFLOAT,
+            DType::I64 | DType::I32 | DType::I16 | DType::I8 => Self::INT,
+            DType::U64 | DType::U32 | DType::U8 => Self::UINT,
+            DType::Bool => Self::BOOL,
+        };
+
+        Self {
+            code,
+            bits: (dtype.size() * 8) as u8,
+            lanes: 1,
+        }
+    }
+}
+
+impl TryFrom<DLDataType> for DType {
+    type Error = DLDataType;
+
+    fn try_from(dtype: DLDataType) -> Result<Self, Self::Error> {
+        let converted = match (dtype.code, dtype.bits, dtype.lanes) {
+            (DLDataType::FLOAT, 64, 1) => DType::F64,
+            (DLDataType::FLOAT, 32, 1) => DType::F32,
+            (DLDataType::FLOAT, 16, 1) => DType::F16,
+            (DLDataType::BFLOAT, 16, 1) => DType::BF16,
+            (DLDataType::INT, 64, 1) => DType::I64,
+            (DLDataType::INT, 32, 1) => DType::I32,
+            (DLDataType::INT, 16, 1) => DType::I16,
+            (DLDataType::INT, 8, 1) => DType::I8,
+            (DLDataType::UINT, 64, 1) => DType::U64,
+            (DLDataType::UINT, 32, 1) => DType::U32,
+            (DLDataType::UINT, 8, 1) => DType::U8,
+            (DLDataType::BOOL, 8, 1) => DType::Bool,
+            _ => return Err(dtype),
+        };
+
+        Ok(converted)
+    }
+}
+
+/// The description of a DLPack tensor, without its ownership.
+#[repr(C)]
+#[derive(Debug)]
+pub struct DLTensor {
+    /// The buffer, on the device.
+    pub data: *mut c_void,
+    /// The device holding the buffer.
+    pub device: DLDevice,
+    /// The number of dimensions.
+    pub ndim: i32,
+    /// The element type.
+    pub dtype: DLDataType,
+    /// The size of each dimension.
+    pub shape: *mut i64,
+    /// The stride of each dimension in elements, or null for a contiguous row-major tensor.
+    pub strides: *mut i64,
+    /// The offset in bytes of the first element in the buffer.
+    pub byte_offset: u64,
+}
+
+/// A DLPack tensor with the context releasing it.
+#[repr(C)]
+#[derive(Debug)]
+pub struct DLManagedTensor {
+    /// The tensor.
+    pub dl_tensor: DLTensor,
+    /// The context of the producer, used by the deleter.
+    pub manager_ctx: *mut c_void,
+    /// Releases the tensor, called once by its consumer.
+    pub deleter: Option<unsafe extern "C" fn(*mut DLManagedTensor)>,
+}
+
+/// A managed tensor exported by Burn, keeping its buffer alive through its owner.
+///
+/// The managed tensor is the first field, so pointers to both are the same.
+#[repr(C)]
+struct Exported {
+    managed: DLManagedTensor,
+    owner: Box<dyn Any + Send>,
+    shape: Vec<i64>,
+    strides: Vec<i64>,
+}
+
+unsafe extern "C" fn delete_exported(managed: *mut DLManagedTensor) {
+    // SAFETY: the deleter is only set on managed tensors allocated as part of `Exported`.
+    drop(unsafe { Box::from_raw(managed as *mut Exported) });
+}
+
+/// A DLPack tensor, released with the deleter of its producer when dropped.
+///
+/// # Examples
+///
+/// ```rust
+/// use burn_tensor::dlpack::{DLDevice, DLPackTensor};
+///
+/// let tensor = DLPackTensor::from_vec(vec![1.0f32, 2.0, 3.0, 4.0], &[2, 2]);
+///
+/// assert_eq!(tensor.device(), DLDevice::CPU);
+/// assert_eq!(tensor.shape(), [2, 2]);
+/// assert_eq!(tensor.strides(), [2, 1]);
+/// ```
+#[derive(Debug)]
+pub struct DLPackTensor {
+    managed: NonNull<DLManagedTensor>,
+}
+
+impl DLPackTensor {
+    /// Exports the values of a contiguous row-major tensor, in host memory.
+    ///
+    /// # Panics
+    ///
+    /// If the number of values doesn't match the shape.
+    pub fn from_vec<E: Element>(mut values: Vec<E>, shape: &[usize]) -> Self {
+        assert_eq!(
+            values.len(),
+            shape.iter().product::<usize>(),
+            "The number of values should match the shape"
+        );
+        let data = values.as_mut_ptr() as *mut c_void;
+
+        // SAFETY: the buffer of the vector holds the values and doesn't move with the vector.
+        unsafe { Self::from_owner(values, data, DLDevice::CPU, E::dtype(), shape, None) }
+    }
+
+    /// Exports a buffer kept alive by its owner, which is dropped when the consumer releases the
+    /// tensor.
+    ///
+    /// # Arguments
+    ///
+    /// * `owner` - The value owning the buffer.
+    /// * `data` - The first element of the tensor, on the device.
+    /// * `device` - The device holding the buffer.
+    /// * `dtype` - The element type.
+    /// * `shape` - The size of each dimension.
+    /// * `strides` - The stride of each dimension in elements, or `None` for a contiguous
+    ///   row-major tensor.
+    ///
+    /// # Safety
+    ///
+    /// `data` must point to the elements described by the shape, the strides and the element
+    /// type on the device, and stay valid while `owner` is alive.
+    pub unsafe fn from_owner<T: Any + Send>(
+        owner: T,
+        data: *mut c_void,
+        device: DLDevice,
+        dtype: DType,
+        shape: &[usize],
+        strides: Option<&[isize]>,
+    ) -> Self {
+        let mut shape = shape.iter().map(|size| *size as i64).collect::<Vec<_>>();
+        let mut strides: Vec<i64> = strides
+            .map(|strides| strides.iter().map(|stride| *stride as i64).collect())
+            .unwrap_or_default();
+        let strides_ptr = match strides.is_empty() {
+            true => core::ptr::null_mut(),
+            false => strides.as_mut_ptr(),
+        };
+
+        let exported = Box::new(Exported {
+            managed: DLManagedTensor {
+                dl_tensor: DLTensor {
+                    data,
+                    device,
+                    ndim: shape.len() as i32,
+                    dtype: dtype.into(),
+                    shape: shape.as_mut_ptr(),
+                    strides: strides_ptr,
+                    byte_offset: 0,
+                },
+                manager_ctx: core::ptr::null_mut(),
+                deleter: Some(delete_exported),
+            },
+            owner: Box::new(owner),
+            shape,
+            strides,
+        });
+
+        let managed = Box::into_raw(exported) as *mut DLManagedTensor;
+        // The context is the exported tensor itself, which the deleter releases.
+        // SAFETY: the pointer comes from a box.
+        unsafe { (*managed).manager_ctx = managed as *mut c_void };
+
+        Self {
+            // SAFETY: the pointer comes from a box.
+            managed: unsafe { NonNull::new_unchecked(managed) },
+        }
+    }
+
+    /// Takes the ownership of a managed tensor, usually from a `dltensor` capsule.
+    ///
+    /// # Safety
+    ///
+    /// `managed` must point to a valid managed tensor that isn't used or released elsewhere.
+    ///
+    /// # Panics
+    ///
+    /// If the pointer is null.
+    pub unsafe fn from_raw(managed: *mut DLManagedTensor) -> Self {
+        Self {
+            managed: NonNull::new(managed).expect("The managed tensor should not be null"),
+        }
+    }
+
+    /// Gives up the ownership of the managed tensor, which should be released by calling its
+    /// deleter.
+    pub fn into_raw(self) -> *mut DLManagedTensor {
+        let managed = self.managed.as_ptr();
+        core::mem::forget(self);
+
+        managed
+    }
+
+    /// Returns the description of the tensor.
+    pub fn as_dl_tensor(&self) -> &DLTensor {
+        // SAFETY: the managed tensor is valid until it is released.
+        unsafe { &self.managed.as_ref().dl_tensor }
+    }
+
+    /// Returns the device holding the buffer.
+    pub fn device(&self) -> DLDevice {
+        self.as_dl_tensor().device
+    }
+
+    /// Returns the element type.
+    pub fn dtype(&self) -> DLDataType {
+        self.as_dl_tensor().dtype
+    }
+
+    /// Returns the size of each dimension.
+    pub fn shape(&self) -> Vec<usize> {
+        self.dims(self.as_dl_tensor().shape)
+            .iter()
+            .map(|size| *size as usize)
+            .collect()
+    }
+
+    /// Returns the stride of each dimension in elements.
+    pub fn strides(&self) -> Vec<isize> {
+        let tensor = self.as_dl_tensor();
+
+        if !tensor.strides.is_null() {
+            return self
+                .dims(tensor.strides)
+                .iter()
+                .map(|stride| *stride as isize)
+                .collect();
+        }
+
+        // Contiguous row-major strides.
+        let shape = self.shape();
+        let mut strides = vec![1; shape.len()];
+        for i in (0..shape.len().saturating_sub(1)).rev() {
+            strides[i] = strides[i + 1] * shape[i + 1] as isize;
+        }
+
+        strides
+    }
+
+    /// Returns the first element of the tensor, on its device.
+    pub fn data_ptr(&self) -> *const c_void {
+        let tensor = self.as_dl_tensor();
+
+        (tensor.data as *const u8).wrapping_add(tensor.byte_offset as usize) as *const c_void
+    }
+
+    /// Takes back the owner of a tensor exported with [from_owner](Self::from_owner), or returns
+    /// the tensor if it was exported elsewhere or with another owner type.
+    pub fn into_owner<T: Any>(self) -> Result<T, Self> {
+        // SAFETY: the managed tensor is valid until it is released.
+        let managed = unsafe { self.managed.as_ref() };
+        // Function pointers are compared by address, a tensor exported elsewhere never has this
+        // deleter.
+        let is_exported = managed.deleter.map(|deleter| deleter as *const ())
+            == Some(delete_exported as *const ())
+            && managed.manager_ctx == self.managed.as_ptr() as *mut c_void;
+
+        if !is_exported {
+            return Err(self);
+        }
+
+        // SAFETY: tensors released by `delete_exported` are allocated as part of `Exported`.
+        let is_owner = unsafe {
+            (*(self.managed.as_ptr() as *const Exported))
+                .owner
+                .is::<T>()
+        };
+        if !is_owner {
+            return Err(self);
+        }
+
+        // SAFETY: same as above, and the tensor isn't released when dropped since it is forgotten.
+        let exported = unsafe { Box::from_raw(self.into_raw() as *mut Exported) };
+        let owner = exported
+            .owner
+            .downcast::<T>()
+            .expect("The owner type was checked");
+
+        Ok(*owner)
+    }
+
+    /// Copies the tensor into a contiguous [TensorData].
+    ///
+    /// # Panics
+    ///
+    /// If the tensor isn't in host memory, or if its element type isn't supported.
+    pub fn to_data(&self) -> TensorData {
+        let device = self.device();
+        assert!(
+            matches!(
+                device.device_type,
+                DLDeviceType::CPU | DLDeviceType::CUDA_HOST
+            ),
+            "Can't read a DLPack tensor on {device:?} from the host"
+        );
+        let dtype = DType::try_from(self.dtype())
+            .unwrap_or_else(|dtype| panic!("Unsupported DLPack data type {dtype:?}"));
+
+        let shape = self.shape();
+        let strides = self.strides();
+        let elem_size = dtype.size();
+        let num_elements = shape.iter().product::<usize>();
+        let data = self.data_ptr() as *const u8;
+
+        let mut bytes = Vec::with_capacity(num_elements * elem_size);
+        let mut index = vec![0; shape.len()];
+
+        for _ in 0..num_elements {
+            let offset = index
+                .iter()
+                .zip(&strides)
+                .map(|(i, stride)| *i as isize * stride)
+                .sum::<isize>();
+
+            // SAFETY: the producer guarantees that every index of the shape is in the buffer.
+            let elem = unsafe {
+                core::slice::from_raw_parts(data.offset(offset * elem_size as isize), elem_size)
+            };
+            bytes.extend_from_slice(elem);
+
+            // Next index in row-major order.
+            for dim in (0..shape.len()).rev() {
+                index[dim] += 1;
+                if index[dim] < shape[dim] {
+                    break;
+                }
+                index[dim] = 0;
+            }
+        }
+
+        TensorData::from_bytes(bytes, shape, dtype)
+    }
+
+    fn dims(&self, values: *const i64) -> &[i64] {
+        let ndim = self.as_dl_tensor().ndim as usize;
+
+        match ndim {
+            0 => &[],
+            // SAFETY: the shape and the strides have one value per dimension.
+            _ => unsafe { core::slice::from_raw_parts(values, ndim) },
+        }
+    }
+}
+
+impl Drop for DLPackTensor {
+    fn drop(&mut self) {
+        // SAFETY: the managed tensor is owned, so it is released once.
+        unsafe {
+            if let Some(deleter) = self.managed.as_ref().deleter {
+                deleter(self.managed.as_ptr());
+            }
+        }
+    }
+}
+
+// SAFETY: the owners of the exported buffers are `Send`, and tensors taken from a capsule are
+// only released once, by their owner.
+unsafe impl Send for DLPackTensor {}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn dtype_should_round_trip() {
+        let dtypes = [
+            DType::F64,
+            DType::F32,
+            DType::F16,
+            DType::BF16,
+            DType::I64,
+            DType::I32,
+            DType::I16,
+            DType::I8,
+            DType::U64,
+            DType::U32,
+            DType::U8,
+            DType::Bool,
+        ];
+
+        for dtype in dtypes {
+            assert_eq!(DType::try_from(DLDataType::from(dtype)), Ok(dtype));
+        }
+    }
+
+    #[test]
+    fn vector_lanes_should_not_be_supported() {
+        let dtype = DLDataType {
+            code: DLDataType::FLOAT,
+            bits: 32,
+            lanes: 4,
+        };
+
+        assert_eq!(DType::try_from(dtype), Err(dtype));
+    }
+
+    #[test]
+    fn strided_tensor_should_be_copied_in_row_major_order() {
+        let mut values = vec![1i32, 2, 3, 4, 5, 6];
+        let data = values.as_mut_ptr() as *mut c_void;
+        // The transpose of the 2x3 row-major values.
+        let tensor = unsafe {
+            DLPackTensor::from_owner(
+                values,
+                data,
+                DLDevice::CPU,
+                DType::I32,
+                &[3, 2],
+                Some(&[1, 3]),
+            )
+        };
+
+        let data = tensor.to_data();
+
+        assert_eq!(data.shape, [3, 2]);
+        assert_eq!(data.to_vec::<i32>().unwrap(), [1, 4, 2, 5, 3, 6]);
+    }
+
+    #[test]
+    fn owner_should_be_taken_back() {
+        let tensor = DLPackTensor::from_vec(vec![1.0f32, 2.0], &[2]);
+        let data = tensor.data_ptr();
+
+        let tensor = tensor.into_owner::<Vec<f64>>().unwrap_err();
+        let values = tensor.into_owner::<Vec<f32>>().unwrap();
+
+        assert_eq!(values.as_ptr() as *const c_void, data);
+        assert_eq!(values, [1.0, 2.0]);
+    }
+}
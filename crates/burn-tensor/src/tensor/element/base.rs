@@ -257,3 +257,15 @@ pub enum DType {
     U8,
     Bool,
 }
+
+impl DType {
+    /// Returns the size of an element of the data type, in bytes.
+    pub fn size(&self) -> usize {
+        match self {
+            DType::F64 | DType::I64 | DType::U64 => core::mem::size_of::<u64>(),
+            DType::F32 | DType::I32 | DType::U32 => core::mem::size_of::<u32>(),
+            DType::F16 | DType::BF16 | DType::I16 => core::mem::size_of::<u16>(),
+            DType::I8 | DType::U8 | DType::Bool => core::mem::size_of::<u8>(),
+        }
+    }
+}
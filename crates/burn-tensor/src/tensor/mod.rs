@@ -21,6 +21,9 @@ pub mod backend;
 /// The container module.
 pub mod container;
 
+/// The DLPack interop module.
+pub mod dlpack;
+
 /// The loss module.
 pub mod loss;
 
@@ -1,7 +1,15 @@
+use alloc::vec;
+use alloc::vec::Vec;
+
 use crate::{
     backend::Backend,
-    ops::{ConvOptions, ConvTransposeOptions, InterpolateOptions, UnfoldOptions},
-    Int, Tensor,
+    check,
+    check::TensorCheck,
+    ops::{
+        ConvOptions, ConvTransposeOptions, InterpolateMode, InterpolateOptions, PadMode,
+        UnfoldOptions,
+    },
+    Bool, Distribution, Int, Tensor,
 };
 
 /// Applies the [embedding module](crate::ops::ModuleOps::embedding).
@@ -12,6 +20,31 @@ where
     Tensor::new(B::embedding(weights.primitive, indices.primitive))
 }
 
+/// Applies the [embedding module](crate::ops::ModuleOps::embedding), returning zeros for every
+/// position looking up the `padding_idx` row.
+///
+/// Since the padding positions are masked after the lookup, they don't contribute any gradient to
+/// the `padding_idx` row of the weights.
+pub fn embedding_with_padding<B>(
+    weights: Tensor<B, 2>,
+    indices: Tensor<B, 2, Int>,
+    padding_idx: usize,
+) -> Tensor<B, 3>
+where
+    B: Backend,
+{
+    let [batch_size, seq_length] = indices.dims();
+    let [_, d_model] = weights.dims();
+
+    let mask = indices
+        .clone()
+        .equal_elem(padding_idx as i64)
+        .unsqueeze_dim::<3>(2)
+        .expand([batch_size, seq_length, d_model]);
+
+    embedding(weights, indices).mask_fill(mask, 0.0)
+}
+
 /// Applies a [1D convolution](crate::ops::ModuleOps::conv2d).
 pub fn conv1d<B>(
     x: Tensor<B, 3>,
@@ -48,6 +81,159 @@ where
     ))
 }
 
+/// Applies a [2D convolution](crate::ops::ModuleOps::conv2d) on the input padded with the given
+/// [mode](PadMode).
+///
+/// The amount of padding on each side is taken from `options.padding`, so [PadMode::Zeros] is the
+/// same as [conv2d].
+pub fn conv2d_with_pad_mode<B>(
+    x: Tensor<B, 4>,
+    weight: Tensor<B, 4>,
+    bias: Option<Tensor<B, 1>>,
+    options: ConvOptions<2>,
+    pad_mode: PadMode,
+) -> Tensor<B, 4>
+where
+    B: Backend,
+{
+    if pad_mode == PadMode::Zeros {
+        return conv2d(x, weight, bias, options);
+    }
+
+    let [pad_height, pad_width] = options.padding;
+    check!(TensorCheck::pad_mode(
+        pad_mode,
+        &x.shape(),
+        &[2, 3],
+        &[pad_height, pad_width]
+    ));
+
+    let x = pad_dim(x, 2, pad_height, pad_height, pad_mode);
+    let x = pad_dim(x, 3, pad_width, pad_width, pad_mode);
+    let options = ConvOptions::new(options.stride, [0, 0], options.dilation, options.groups);
+
+    conv2d(x, weight, bias, options)
+}
+
+/// Pads the input with the given [mode](PadMode).
+///
+/// The `padding` holds the `(before, after)` amount of padding of each dimension, so any
+/// dimension can be padded, unlike [Tensor::pad] which only pads the last two with a constant.
+///
+/// # Panics
+///
+/// With [PadMode::Reflect], the padding of a dimension must be lower than its size, and with
+/// [PadMode::Replicate], a padded dimension can't be empty.
+pub fn pad_with_mode<B, const D: usize>(
+    x: Tensor<B, D>,
+    padding: [(usize, usize); D],
+    pad_mode: PadMode,
+) -> Tensor<B, D>
+where
+    B: Backend,
+{
+    let dims = (0..D).collect::<Vec<_>>();
+    let max_padding = padding
+        .iter()
+        .map(|(before, after)| usize::max(*before, *after))
+        .collect::<Vec<_>>();
+    check!(TensorCheck::pad_mode(
+        pad_mode,
+        &x.shape(),
+        &dims,
+        &max_padding
+    ));
+
+    padding
+        .iter()
+        .enumerate()
+        .fold(x, |x, (dim, (before, after))| {
+            pad_dim(x, dim, *before, *after, pad_mode)
+        })
+}
+
+/// Pads the last dimension of the input by replicating its edges.
+///
+/// # Arguments
+///
+/// * `x` - The input tensor of shape `[batch_size, channels, length]`.
+/// * `padding` - The `[left, right]` padding.
+///
+/// # Notes
+///
+/// The padding can be larger than the length, the edge values being repeated as many times as
+/// needed. An empty length can't be padded and panics.
+pub fn replication_pad1d<B>(x: Tensor<B, 3>, padding: [usize; 2]) -> Tensor<B, 3>
+where
+    B: Backend,
+{
+    let [left, right] = padding;
+
+    pad_with_mode(x, [(0, 0), (0, 0), (left, right)], PadMode::Replicate)
+}
+
+/// Pads the last three dimensions of the input by replicating their edges.
+///
+/// # Arguments
+///
+/// * `x` - The input tensor of shape `[batch_size, channels, depth, height, width]`.
+/// * `padding` - The `[left, right, top, bottom, front, back]` padding, the width being padded
+///   by `left` and `right`, the height by `top` and `bottom`, and the depth by `front` and `back`.
+///
+/// # Notes
+///
+/// As with [replication_pad1d], the padding can be larger than the padded dimensions, while
+/// padding an empty dimension panics.
+pub fn replication_pad3d<B>(x: Tensor<B, 5>, padding: [usize; 6]) -> Tensor<B, 5>
+where
+    B: Backend,
+{
+    let [left, right, top, bottom, front, back] = padding;
+
+    pad_with_mode(
+        x,
+        [(0, 0), (0, 0), (front, back), (top, bottom), (left, right)],
+        PadMode::Replicate,
+    )
+}
+
+/// Pads both sides of a dimension with zeros or with values taken from the tensor itself.
+fn pad_dim<B: Backend, const D: usize>(
+    x: Tensor<B, D>,
+    dim: usize,
+    before: usize,
+    after: usize,
+    pad_mode: PadMode,
+) -> Tensor<B, D> {
+    if before == 0 && after == 0 {
+        return x;
+    }
+
+    let size = x.dims()[dim];
+    let side = |offset: usize, padding: usize| match pad_mode {
+        PadMode::Zeros => {
+            let mut shape = x.dims();
+            shape[dim] = padding;
+            Tensor::zeros(shape, &x.device())
+        }
+        PadMode::Reflect => x.clone().narrow(dim, offset, padding).flip([dim as isize]),
+        PadMode::Replicate => x.clone().narrow(dim, offset, 1).repeat(dim, padding),
+    };
+
+    let before = (before > 0).then(|| side(usize::from(pad_mode == PadMode::Reflect), before));
+    let after = (after > 0).then(|| {
+        let offset = match pad_mode {
+            PadMode::Zeros => 0,
+            PadMode::Reflect => size - after - 1,
+            PadMode::Replicate => size - 1,
+        };
+        side(offset, after)
+    });
+
+    let tensors = before.into_iter().chain([x]).chain(after).collect();
+    Tensor::cat(tensors, dim)
+}
+
 /// Applies a [1D transposed convolution](crate::ops::ModuleOps::conv_transpose1d).
 pub fn conv_transpose1d<B>(
     x: Tensor<B, 3>,
@@ -92,6 +278,45 @@ where
     Tensor::new(B::unfold4d(x.primitive, kernel_size, options))
 }
 
+/// Applies the [scaled dot-product attention](crate::ops::ModuleOps::scaled_dot_product_attention).
+///
+/// With a `mask`, `true` excludes the key from the attention of the query, and with `causal`,
+/// query `i` only attends to the keys `0..=i`. A query without any visible key gets a zero output.
+///
+/// # Panics
+///
+/// - If the tensors have a rank lower than 2.
+/// - If the query, the key and the value don't have the same leading dimensions, or the key and
+///   the value a different number of keys, or the query and the key a different feature size.
+/// - If the mask can't be broadcast to the shape of the scores `[..., queries, keys]`.
+pub fn scaled_dot_product_attention<B, const D: usize>(
+    query: Tensor<B, D>,
+    key: Tensor<B, D>,
+    value: Tensor<B, D>,
+    mask: Option<Tensor<B, D, Bool>>,
+    scale: f64,
+    causal: bool,
+) -> Tensor<B, D>
+where
+    B: Backend,
+{
+    check!(TensorCheck::scaled_dot_product_attention(
+        &query.shape(),
+        &key.shape(),
+        &value.shape(),
+        mask.as_ref().map(|mask| mask.shape()).as_ref(),
+    ));
+
+    Tensor::new(B::scaled_dot_product_attention(
+        query.primitive,
+        key.primitive,
+        value.primitive,
+        mask.map(|mask| mask.primitive),
+        scale,
+        causal,
+    ))
+}
+
 /// Applies a [1D max pooling](crate::ops::ModuleOps::max_pool1d).
 pub fn max_pool1d<B>(
     x: Tensor<B, 3>,
@@ -189,6 +414,9 @@ where
 }
 
 /// Applies a [2D max pooling with indices](crate::ops::ModuleOps::max_pool2d_with_indices).
+///
+/// Returns the pooled values along with the flat spatial indices (`h * width + w`) of the maxima.
+/// Ties within a window resolve to the lowest index.
 pub fn max_pool2d_with_indices<B>(
     x: Tensor<B, 4>,
     kernel_size: [usize; 2],
@@ -204,6 +432,104 @@ where
     (Tensor::new(output.output), Tensor::new(output.indices))
 }
 
+/// Applies a 2D max unpooling, the partial inverse of
+/// [2D max pooling with indices](max_pool2d_with_indices).
+///
+/// Every value of `x` is written back to the flat spatial position (`h * width + w`) given by
+/// `indices`, while all other positions are filled with zeros.
+///
+/// When `output_size` is `None`, the spatial output size is
+/// `(input - 1) * stride - 2 * padding + kernel_size`. Since a strided pooling maps several input
+/// sizes to the same output size, `output_size` can be provided to recover the original size; it
+/// must lie within `[default, default + stride)` for each dimension.
+pub fn max_unpool2d<B>(
+    x: Tensor<B, 4>,
+    indices: Tensor<B, 4, Int>,
+    kernel_size: [usize; 2],
+    stride: [usize; 2],
+    padding: [usize; 2],
+    output_size: Option<[usize; 2]>,
+) -> Tensor<B, 4>
+where
+    B: Backend,
+{
+    let [batch_size, channels, height_in, width_in] = x.dims();
+    let default_size = [
+        ((height_in - 1) * stride[0] + kernel_size[0]).saturating_sub(2 * padding[0]),
+        ((width_in - 1) * stride[1] + kernel_size[1]).saturating_sub(2 * padding[1]),
+    ];
+
+    check!(TensorCheck::max_unpool2d(
+        &x.dims(),
+        &indices.dims(),
+        default_size,
+        stride,
+        output_size
+    ));
+
+    let [height_out, width_out] = output_size.unwrap_or(default_size);
+    let device = x.device();
+
+    let x = x.reshape([batch_size, channels, height_in * width_in]);
+    let indices = indices.reshape([batch_size, channels, height_in * width_in]);
+    let shape = [batch_size, channels, height_out * width_out];
+
+    // Overlapping windows can select the same position more than once, the scattered values are
+    // summed so we divide by the number of hits to write the value only once.
+    let counts = Tensor::<B, 3>::zeros(shape, &device).scatter(2, indices.clone(), x.ones_like());
+    let output = Tensor::<B, 3>::zeros(shape, &device)
+        .scatter(2, indices, x)
+        .div(counts.clamp_min(1.0));
+
+    output.reshape([batch_size, channels, height_out, width_out])
+}
+
+/// Generates a 2D sampling grid from a batch of affine matrices, as used by spatial transformer
+/// networks.
+///
+/// # Arguments
+///
+/// * `theta` - The affine matrices of shape `[batch_size, 2, 3]`.
+/// * `size` - The `[batch_size, channels, height, width]` size of the output image.
+/// * `align_corners` - If true, `-1` and `1` refer to the centers of the corner pixels.
+///   Otherwise, they refer to the outer edges of the corner pixels.
+///
+/// # Returns
+///
+/// The grid of shape `[batch_size, height, width, 2]` holding the normalized `(x, y)` sampling
+/// coordinates of each output position, following the PyTorch `affine_grid` convention.
+pub fn affine_grid<B>(theta: Tensor<B, 3>, size: [usize; 4], align_corners: bool) -> Tensor<B, 4>
+where
+    B: Backend,
+{
+    check!(TensorCheck::affine_grid(&theta.dims(), &size));
+
+    let [batch_size, _, height, width] = size;
+    let device = theta.device();
+
+    // Without aligned corners, the pixel centers are shrunk by `(n - 1) / n` toward the origin.
+    let scale = |size: usize| {
+        if align_corners || size == 0 {
+            1.0
+        } else {
+            (size - 1) as f32 / size as f32
+        }
+    };
+    let scale = Tensor::<B, 1>::from_floats([scale(width), scale(height)], &device);
+
+    // Base grid holding the homogeneous `(x, y, 1)` coordinates of each position.
+    let base = Tensor::<B, 3>::coord_grid(height, width, true, &device)
+        .flip([2])
+        .reshape([height * width, 2])
+        .mul(scale.reshape([1, 2]).expand([height * width, 2]));
+    let base = Tensor::cat(vec![base, Tensor::ones([height * width, 1], &device)], 1);
+
+    base.unsqueeze::<3>()
+        .expand([batch_size, height * width, 3])
+        .matmul(theta.swap_dims(1, 2))
+        .reshape([batch_size, height, width, 2])
+}
+
 /// Applies a [2D adaptive avg pooling](crate::ops::ModuleOps::adaptive_avg_pool2d).
 pub fn adaptive_avg_pool2d<B>(x: Tensor<B, 4>, output_size: [usize; 2]) -> Tensor<B, 4>
 where
@@ -221,6 +547,11 @@ where
 }
 
 /// Applies a [2D interpolation](crate::ops::ModuleOps::interpolate).
+///
+/// When [antialias](InterpolateOptions::antialias) is enabled with the bilinear or bicubic mode,
+/// the downscaled dimensions are first reduced with an area filter
+/// ([adaptive average pooling](adaptive_avg_pool2d)), so every input value contributes to the
+/// output instead of only the sampled ones.
 pub fn interpolate<B>(
     x: Tensor<B, 4>,
     output_size: [usize; 2],
@@ -229,5 +560,196 @@ pub fn interpolate<B>(
 where
     B: Backend,
 {
+    let [_, _, height, width] = x.dims();
+    let [out_height, out_width] = output_size;
+
+    let downscale = out_height < height || out_width < width;
+    let filtered = !matches!(options.mode, InterpolateMode::Nearest);
+
+    if options.antialias && filtered && downscale {
+        let size = [out_height.min(height), out_width.min(width)];
+        let x = adaptive_avg_pool2d(x, size);
+
+        if size == output_size {
+            return x;
+        }
+
+        return Tensor::new(B::interpolate(x.primitive, output_size, options));
+    }
+
     Tensor::new(B::interpolate(x.primitive, output_size, options))
 }
+
+/// Applies stochastic depth, dropping whole samples of the batch (first dimension) with the
+/// probability `prob` during training, as described in the paper
+/// [Deep Networks with Stochastic Depth](https://arxiv.org/abs/1603.09382).
+///
+/// Unlike elementwise dropout, all the values of a sample are kept or dropped together, which is
+/// used to skip entire residual branches. The kept samples are scaled by `1 / (1 - prob)`, and the
+/// dropped ones are set to exactly zero, even when they contain non-finite values.
+///
+/// The input is returned unchanged when `training` is false or `prob` is zero.
+pub fn drop_path<B, const D: usize>(x: Tensor<B, D>, prob: f64, training: bool) -> Tensor<B, D>
+where
+    B: Backend,
+{
+    if !training || prob == 0.0 {
+        return x;
+    }
+
+    let shape = x.shape();
+    let mut shape_mask = [1; D];
+    shape_mask[0] = shape.dims[0];
+
+    let prob_keep = 1.0 - prob;
+    let dropped =
+        Tensor::<B, D>::random(shape_mask, Distribution::Bernoulli(prob_keep), &x.device())
+            .equal_elem(0.0)
+            .expand(shape);
+
+    let x = x.mask_fill(dropped, 0.0);
+
+    if prob_keep == 0.0 {
+        // Every sample is dropped, there is nothing to scale.
+        return x;
+    }
+
+    x.div_scalar(prob_keep)
+}
+
+/// Computes the batch statistics of a [batch normalization](https://arxiv.org/abs/1502.03167)
+/// layer during training.
+///
+/// The input has the shape `[batch_size, channels, ...]`, and the statistics are computed per
+/// channel over all the other dimensions.
+///
+/// # Returns
+///
+/// The `(mean, variance)` tuple, each of shape `[channels]`. The variance is biased, meaning it
+/// is normalized by the number of reduced elements, as done in the forward pass of the layer.
+pub fn batch_norm_stats<B, const D: usize>(x: Tensor<B, D>) -> (Tensor<B, 1>, Tensor<B, 1>)
+where
+    B: Backend,
+{
+    check!(TensorCheck::batch_norm_stats::<D>());
+
+    let dims = x.dims();
+    let channels = dims[1];
+    let flatten_size = dims.iter().product::<usize>() / usize::max(channels, 1);
+
+    let mut shape_unsqueeze = [1; D];
+    shape_unsqueeze[1] = channels;
+
+    // Move the channels first so every row holds the values of a single channel.
+    let mean = x
+        .clone()
+        .swap_dims(0, 1)
+        .reshape([channels, flatten_size])
+        .mean_dim(1)
+        .reshape([channels]);
+
+    let var = x
+        .sub(mean.clone().reshape(shape_unsqueeze))
+        .powf_scalar(2.0)
+        .swap_dims(0, 1)
+        .reshape([channels, flatten_size])
+        .mean_dim(1)
+        .reshape([channels]);
+
+    (mean, var)
+}
+
+/// Computes the batch statistics of a [batch normalization](batch_norm_stats) layer over the
+/// positions selected by `mask`, e.g. to exclude the padding of a batch of sequences.
+///
+/// The mask is `true` for the valid positions and must be broadcastable to the input shape
+/// `[batch_size, channels, ...]`, such as `[batch_size, 1, length]` for padded sequences.
+///
+/// # Returns
+///
+/// The `(mean, variance)` tuple, each of shape `[channels]`, the variance being biased. A channel
+/// without any valid position has no statistics, so it falls back to a mean of 0 and a variance
+/// of 1, which leaves its values unchanged apart from the epsilon, the scale and the shift.
+pub fn masked_batch_norm_stats<B, const D: usize>(
+    x: Tensor<B, D>,
+    mask: Tensor<B, D, Bool>,
+) -> (Tensor<B, 1>, Tensor<B, 1>)
+where
+    B: Backend,
+{
+    check!(TensorCheck::batch_norm_stats::<D>());
+
+    let dims = x.dims();
+    let channels = dims[1];
+    let flatten_size = dims.iter().product::<usize>() / usize::max(channels, 1);
+
+    // Move the channels first so every row holds the values of a single channel.
+    let rows = |tensor: Tensor<B, D>| tensor.swap_dims(0, 1).reshape([channels, flatten_size]);
+    let x = rows(x);
+    let mask = mask
+        .expand(dims)
+        .swap_dims(0, 1)
+        .reshape([channels, flatten_size]);
+
+    let (sum, count) = x.clone().sum_dim_masked(1, mask.clone());
+    let empty = count.clone().equal_elem(0);
+    let count = count.float().clamp_min(1.0);
+
+    // The sums of an empty channel are zero, giving a zero mean and variance before the fallback.
+    let mean = sum.div(count.clone());
+    let (sum_squares, _) = x.sub(mean.clone()).powf_scalar(2.0).sum_dim_masked(1, mask);
+    let var = sum_squares.div(count).mask_fill(empty, 1.0);
+
+    (mean.reshape([channels]), var.reshape([channels]))
+}
+
+/// Applies a [batch normalization](https://arxiv.org/abs/1502.03167) whose statistics are
+/// computed over the positions selected by `mask` only.
+///
+/// See [masked_batch_norm_stats] for the mask and the fallback statistics of a channel without
+/// any valid position. Every position is normalized, including the masked-out ones, whose values
+/// are expected to be ignored by the following layers.
+///
+/// `y = (x - mean) / sqrt(var + epsilon) * gamma + beta`
+pub fn masked_batch_norm<B, const D: usize>(
+    x: Tensor<B, D>,
+    mask: Tensor<B, D, Bool>,
+    gamma: Tensor<B, 1>,
+    beta: Tensor<B, 1>,
+    epsilon: f64,
+) -> Tensor<B, D>
+where
+    B: Backend,
+{
+    let (mean, var) = masked_batch_norm_stats(x.clone(), mask);
+
+    let mut shape_unsqueeze = [1; D];
+    shape_unsqueeze[1] = x.dims()[1];
+    let unsqueeze = |tensor: Tensor<B, 1>| tensor.reshape(shape_unsqueeze);
+
+    x.sub(unsqueeze(mean))
+        .div(unsqueeze(var).add_scalar(epsilon).sqrt())
+        .mul(unsqueeze(gamma))
+        .add(unsqueeze(beta))
+}
+
+/// Updates a running statistic with the statistic of the current batch, using an exponential
+/// moving average.
+///
+/// `running = (1 - momentum) * running + momentum * batch_stat`
+///
+/// The batch statistic is detached from the autodiff graph, the running statistics being state
+/// that isn't optimized.
+pub fn running_stats_update<B, const D: usize>(
+    running: Tensor<B, D>,
+    batch_stat: Tensor<B, D>,
+    momentum: f64,
+) -> Tensor<B, D>
+where
+    B: Backend,
+{
+    running
+        .mul_scalar(1.0 - momentum)
+        .add(batch_stat.detach().mul_scalar(momentum))
+        .detach()
+}
@@ -79,6 +79,35 @@ pub trait ActivationOps<B: Backend> {
 
         B::float_div_scalar(x, 2i32.elem())
     }
+
+    /// Applies the Gelu activation function using the tanh approximation.
+    ///
+    /// `gelu_tanh(x) = 0.5 * x * (1 + tanh(sqrt(2 / pi) * (x + 0.044715 * x^3)))`
+    ///
+    /// # Arguments
+    ///
+    /// * `tensor` - The tensor.
+    ///
+    /// # Returns
+    ///
+    /// The output tensor.
+    fn gelu_tanh<const D: usize>(tensor: FloatTensor<B, D>) -> FloatTensor<B, D> {
+        let x2 = B::float_mul(tensor.clone(), tensor.clone());
+        let x3 = B::float_mul(x2, tensor.clone());
+        let x = B::float_mul_scalar(x3, 0.044715.elem());
+        let x = B::float_add(tensor.clone(), x);
+        // sqrt(2 / pi), written with constants since `sqrt` isn't available without std.
+        let x = B::float_mul_scalar(
+            x,
+            (core::f64::consts::FRAC_2_SQRT_PI * core::f64::consts::FRAC_1_SQRT_2).elem(),
+        );
+        let x = B::float_tanh(x);
+        let x = B::float_add_scalar(x, 1i32.elem());
+        let x = B::float_mul(tensor, x);
+
+        B::float_div_scalar(x, 2i32.elem())
+    }
+
     /// Applies the PReLu activation function.
     /// # Arguments
     /// * `tensor` - The input tensor
@@ -275,4 +304,58 @@ pub trait ActivationOps<B: Backend> {
             ),
         )
     }
+
+    /// Applies the Mish activation function.
+    ///
+    /// `mish(x) = x * tanh(softplus(x))`
+    ///
+    /// # Arguments
+    ///
+    /// * `tensor` - The tensor.
+    ///
+    /// # Returns
+    ///
+    /// The output tensor.
+    fn mish<const D: usize>(tensor: FloatTensor<B, D>) -> FloatTensor<B, D> {
+        let softplus = softplus::<B, D>(tensor.clone());
+
+        B::float_mul(tensor, B::float_tanh(softplus))
+    }
+
+    /// Applies the Mish activation function backward.
+    ///
+    /// # Arguments
+    ///
+    /// * `x` - The input tensor.
+    /// * `grad` - The gradient.
+    ///
+    /// # Returns
+    ///
+    /// The output gradient.
+    fn mish_backward<const D: usize>(
+        x: FloatTensor<B, D>,
+        grad: FloatTensor<B, D>,
+    ) -> FloatTensor<B, D> {
+        // d/dx mish(x) = tanh(softplus(x)) + x * (1 - tanh(softplus(x))^2) * sigmoid(x)
+        //
+        // For large negative inputs, softplus(x) underflows to zero and sigmoid(x) to zero, so
+        // the derivative goes to zero instead of evaluating `exp(-x)`, which would overflow.
+        let tanh = B::float_tanh(softplus::<B, D>(x.clone()));
+        let tanh_derive = B::float_add_scalar(
+            B::float_neg(B::float_mul(tanh.clone(), tanh.clone())),
+            1.0.elem(),
+        );
+        let value = B::float_mul(x.clone(), B::float_mul(tanh_derive, B::sigmoid(x)));
+
+        B::float_mul(grad, B::float_add(tanh, value))
+    }
+}
+
+/// Computes `log(1 + exp(x))` as `max(x, 0) + log(1 + exp(-|x|))`, which never evaluates `exp` on
+/// a large positive value, so it doesn't overflow.
+fn softplus<B: Backend, const D: usize>(tensor: FloatTensor<B, D>) -> FloatTensor<B, D> {
+    let positive = B::float_clamp_min(tensor.clone(), 0.elem());
+    let tail = B::float_log1p(B::float_exp(B::float_neg(B::float_abs(tensor))));
+
+    B::float_add(positive, tail)
 }
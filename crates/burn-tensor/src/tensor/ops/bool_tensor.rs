@@ -114,6 +114,17 @@ pub trait BoolTensorOps<B: Backend> {
         device: &Device<B>,
     ) -> BoolTensor<B, D>;
 
+    /// Starts moving the tensor to the given device, returning a reader of the moved tensor.
+    ///
+    /// The default implementation moves the tensor synchronously with
+    /// [bool_to_device](BoolTensorOps::bool_to_device).
+    fn bool_to_device_async<const D: usize>(
+        tensor: BoolTensor<B, D>,
+        device: &Device<B>,
+    ) -> Reader<BoolTensor<B, D>> {
+        Reader::Concrete(B::bool_to_device(tensor, device))
+    }
+
     /// Reshapes the tensor.
     ///
     /// # Arguments
@@ -248,6 +259,51 @@ pub trait BoolTensorOps<B: Backend> {
     /// The tensor with the result of the negation.
     fn bool_not<const D: usize>(tensor: BoolTensor<B, D>) -> BoolTensor<B, D>;
 
+    /// Executes the logical and (`&&`) operation on two boolean tensors.
+    ///
+    /// # Arguments
+    ///
+    /// * `lhs` - The left hand side tensor.
+    /// * `rhs` - The right hand side tensor.
+    ///
+    /// # Returns
+    ///
+    /// The tensor with the result of the logical and.
+    fn bool_and<const D: usize>(lhs: BoolTensor<B, D>, rhs: BoolTensor<B, D>) -> BoolTensor<B, D> {
+        let product = B::int_mul(B::bool_into_int(lhs), B::bool_into_int(rhs));
+        B::int_equal_elem(product, 1.elem())
+    }
+
+    /// Executes the logical or (`||`) operation on two boolean tensors.
+    ///
+    /// # Arguments
+    ///
+    /// * `lhs` - The left hand side tensor.
+    /// * `rhs` - The right hand side tensor.
+    ///
+    /// # Returns
+    ///
+    /// The tensor with the result of the logical or.
+    fn bool_or<const D: usize>(lhs: BoolTensor<B, D>, rhs: BoolTensor<B, D>) -> BoolTensor<B, D> {
+        let sum = B::int_add(B::bool_into_int(lhs), B::bool_into_int(rhs));
+        B::int_greater_elem(sum, 0.elem())
+    }
+
+    /// Executes the logical exclusive or operation on two boolean tensors.
+    ///
+    /// # Arguments
+    ///
+    /// * `lhs` - The left hand side tensor.
+    /// * `rhs` - The right hand side tensor.
+    ///
+    /// # Returns
+    ///
+    /// The tensor with the result of the logical exclusive or.
+    fn bool_xor<const D: usize>(lhs: BoolTensor<B, D>, rhs: BoolTensor<B, D>) -> BoolTensor<B, D> {
+        let sum = B::int_add(B::bool_into_int(lhs), B::bool_into_int(rhs));
+        B::int_equal_elem(sum, 1.elem())
+    }
+
     /// Transposes a bool tensor.
     ///
     /// # Arguments
@@ -261,6 +317,22 @@ pub trait BoolTensorOps<B: Backend> {
         Self::bool_swap_dims(tensor, D - 2, D - 1)
     }
 
+    /// Transposes the last two dimensions of a bool tensor into a new contiguous tensor.
+    ///
+    /// The default implementation is the same as [transpose](Self::bool_transpose), backends
+    /// may copy the elements in the transposed order with a dedicated kernel.
+    ///
+    /// # Arguments
+    ///
+    /// * `tensor` - The tensor to transpose.
+    ///
+    /// # Returns
+    ///
+    /// The transposed tensor.
+    fn bool_transpose_last2<const D: usize>(tensor: BoolTensor<B, D>) -> BoolTensor<B, D> {
+        Self::bool_transpose(tensor)
+    }
+
     /// Swaps two dimensions of a bool tensor.
     ///
     /// # Arguments
@@ -410,6 +482,38 @@ pub trait BoolTensorOps<B: Backend> {
         B::int_equal_elem(sum, (num_elems as i32).elem())
     }
 
+    /// Tests if no element in the boolean `tensor` evaluates to True.
+    ///
+    /// # Arguments
+    ///
+    /// * `tensor` - The tensor to test.
+    ///
+    /// # Returns
+    ///
+    /// A boolean tensor `Tensor<B, 1, Bool>` with a single element, True if no element in the input tensor
+    /// evaluates to True, False otherwise.
+    fn bool_none<const D: usize>(tensor: BoolTensor<B, D>) -> BoolTensor<B, 1> {
+        let sum = B::int_sum(B::bool_into_int(tensor));
+        B::int_equal_elem(sum, 0.elem())
+    }
+
+    /// Tests if no element in the boolean `tensor` evaluates to True along a given dimension `dim`.
+    ///
+    /// # Arguments
+    ///
+    /// * `tensor` - The tensor to test.
+    /// * `dim` - The axis along which to test.
+    ///
+    /// # Returns
+    ///
+    /// A boolean tensor `Tensor<B, D, Bool>` with the same size as input `tensor`, except in the `dim` axis
+    /// where the size is 1. The elem in the `dim` axis is True if no element along this dim in the input
+    /// evaluates to True, False otherwise.
+    fn bool_none_dim<const D: usize>(tensor: BoolTensor<B, D>, dim: usize) -> BoolTensor<B, D> {
+        let sum = B::int_sum_dim(B::bool_into_int(tensor), dim);
+        B::int_equal_elem(sum, 0.elem())
+    }
+
     /// Compute the indices of the elements that are non-zero, grouped by element.
     ///
     /// # Arguments
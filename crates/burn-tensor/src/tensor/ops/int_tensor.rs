@@ -1,4 +1,5 @@
 use super::cat::cat_with_slice_assign;
+use super::cumsum::cumsum_with_doubling;
 use super::repeat::repeat_with_slice_assign;
 use super::{BoolTensor, Device, FloatTensor, IntElem, IntTensor};
 use crate::cast::ToElement;
@@ -91,6 +92,17 @@ pub trait IntTensorOps<B: Backend> {
         device: &Device<B>,
     ) -> IntTensor<B, D>;
 
+    /// Starts moving the tensor to the given device, returning a reader of the moved tensor.
+    ///
+    /// The default implementation moves the tensor synchronously with
+    /// [int_to_device](IntTensorOps::int_to_device).
+    fn int_to_device_async<const D: usize>(
+        tensor: IntTensor<B, D>,
+        device: &Device<B>,
+    ) -> Reader<IntTensor<B, D>> {
+        Reader::Concrete(B::int_to_device(tensor, device))
+    }
+
     /// Reshapes the tensor.
     ///
     /// # Arguments
@@ -764,6 +776,54 @@ pub trait IntTensorOps<B: Backend> {
     /// The sum of all elements in the tensor along the dimension.
     fn int_sum_dim<const D: usize>(tensor: IntTensor<B, D>, dim: usize) -> IntTensor<B, D>;
 
+    /// Sums the elements selected by a mask along a dimension, with the number of selected
+    /// elements.
+    ///
+    /// # Arguments
+    ///
+    /// * `tensor` - The tensor to sum.
+    /// * `dim` - The dimension to sum along.
+    /// * `mask` - The elements to sum, with the same shape as `tensor`.
+    ///
+    /// # Returns
+    ///
+    /// The sum of the selected elements along the dimension and their number.
+    ///
+    /// # Remarks
+    ///
+    /// The default implementation fills the other elements with zeros before summing, and sums
+    /// the mask separately. Backends should override it to reduce both in a single pass.
+    fn int_sum_dim_masked<const D: usize>(
+        tensor: IntTensor<B, D>,
+        dim: usize,
+        mask: BoolTensor<B, D>,
+    ) -> (IntTensor<B, D>, IntTensor<B, D>) {
+        let count = B::int_sum_dim(B::bool_into_int(mask.clone()), dim);
+        let tensor = B::int_mask_fill(tensor, B::bool_not(mask), 0.elem());
+
+        (B::int_sum_dim(tensor, dim), count)
+    }
+
+    /// Computes the cumulative sum of the elements of the tensor along a dimension.
+    ///
+    /// # Arguments
+    ///
+    /// * `tensor` - The tensor to accumulate.
+    /// * `dim` - The dimension to accumulate along.
+    ///
+    /// # Returns
+    ///
+    /// A tensor with the same shape as `tensor` where each element is the sum of the elements of
+    /// `tensor` up to and including its position along `dim`.
+    ///
+    /// # Remarks
+    ///
+    /// The default implementation doubles the span of the partial sums, which allocates
+    /// `log2(size)` tensors of the shape of `tensor`. Backends with a scan should override it.
+    fn int_cumsum<const D: usize>(tensor: IntTensor<B, D>, dim: usize) -> IntTensor<B, D> {
+        cumsum_with_doubling::<B, D, Int>(Tensor::from_primitive(tensor), dim).into_primitive()
+    }
+
     /// Computes the product of all elements in the tensor.
     ///
     /// # Arguments
@@ -964,6 +1024,22 @@ pub trait IntTensorOps<B: Backend> {
         Self::int_swap_dims(tensor, D - 2, D - 1)
     }
 
+    /// Transposes the last two dimensions of a int tensor into a new contiguous tensor.
+    ///
+    /// The default implementation is the same as [transpose](Self::int_transpose), backends
+    /// may copy the elements in the transposed order with a dedicated kernel.
+    ///
+    /// # Arguments
+    ///
+    /// * `tensor` - The tensor to transpose.
+    ///
+    /// # Returns
+    ///
+    /// The transposed tensor.
+    fn int_transpose_last2<const D: usize>(tensor: IntTensor<B, D>) -> IntTensor<B, D> {
+        Self::int_transpose(tensor)
+    }
+
     /// Swaps two dimensions of an int tensor.
     ///
     /// # Arguments
@@ -1203,6 +1279,40 @@ pub trait IntTensorOps<B: Backend> {
         B::int_equal_elem(sum, (num_elems as i32).elem())
     }
 
+    /// Tests if no element in the int `tensor` evaluates to True.
+    ///
+    /// # Arguments
+    ///
+    /// * `tensor` - The tensor to test.
+    ///
+    /// # Returns
+    ///
+    /// A boolean tensor `Tensor<B, 1, Bool>` with a single element, True if no element in the input tensor
+    /// evaluates to True, False otherwise.
+    fn int_none<const D: usize>(tensor: IntTensor<B, D>) -> BoolTensor<B, 1> {
+        let bool_tensor = B::int_not_equal_elem(tensor, 0.elem());
+        let sum = B::int_sum(B::bool_into_int(bool_tensor));
+        B::int_equal_elem(sum, 0.elem())
+    }
+
+    /// Tests if no element in the int `tensor` evaluates to True along a given dimension `dim`.
+    ///
+    /// # Arguments
+    ///
+    /// * `tensor` - The tensor to test.
+    /// * `dim` - The axis along which to test.
+    ///
+    /// # Returns
+    ///
+    /// A boolean tensor `Tensor<B, D, Bool>` with the same size as input `tensor`, except in the `dim` axis
+    /// where the size is 1. The elem in the `dim` axis is True if no element along this dim in the input
+    /// evaluates to True, False otherwise.
+    fn int_none_dim<const D: usize>(tensor: IntTensor<B, D>, dim: usize) -> BoolTensor<B, D> {
+        let bool_tensor = B::int_not_equal_elem(tensor, 0.elem());
+        let sum = B::int_sum_dim(B::bool_into_int(bool_tensor), dim);
+        B::int_equal_elem(sum, 0.elem())
+    }
+
     /// Returns the signs of the int `tensor`.
     ///
     /// # Arguments
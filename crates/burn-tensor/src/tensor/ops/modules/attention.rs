@@ -0,0 +1,60 @@
+use crate::{
+    backend::Backend,
+    ops::{BoolTensor, FloatTensor},
+    Bool, Tensor,
+};
+
+/// Computes the scaled dot-product attention with the tensor operations: the scores are
+/// materialized, masked with negative infinity and normalized with a softmax before the matmul
+/// with the values.
+///
+/// The softmax is computed relative to the maximum of the visible scores, and a query without any
+/// visible key gets a zero output row instead of `NaN`. This is the default implementation of
+/// [scaled_dot_product_attention](crate::ops::ModuleOps::scaled_dot_product_attention).
+pub fn attention_composed<B: Backend, const D: usize>(
+    query: FloatTensor<B, D>,
+    key: FloatTensor<B, D>,
+    value: FloatTensor<B, D>,
+    mask: Option<BoolTensor<B, D>>,
+    scale: f64,
+    causal: bool,
+) -> FloatTensor<B, D> {
+    let query = Tensor::<B, D>::new(query);
+    let key = Tensor::<B, D>::new(key);
+    let value = Tensor::<B, D>::new(value);
+    let device = query.device();
+
+    let scores = query.matmul(key.swap_dims(D - 2, D - 1)).mul_scalar(scale);
+    let shape = scores.shape();
+
+    let mut masked = mask.map(|mask| Tensor::<B, D, Bool>::new(mask).expand(shape.clone()));
+    if causal {
+        // Query `i` sees the keys `0..=i`, so the keys after the diagonal are masked.
+        let mut dims = [1; D];
+        dims[D - 2] = shape.dims[D - 2];
+        dims[D - 1] = shape.dims[D - 1];
+        let causal = Tensor::<B, D, Bool>::tril_mask(dims, 0, &device).expand(shape.clone());
+
+        masked = Some(match masked {
+            Some(mask) => mask.bool_or(causal),
+            None => causal,
+        });
+    }
+
+    let scores = match masked {
+        Some(mask) => scores.mask_fill(mask, f32::NEG_INFINITY),
+        None => scores,
+    };
+
+    // Without any visible key the maximum is negative infinity, which is replaced so that the
+    // exponentials are zeros rather than `NaN`.
+    let max = scores.clone().detach().max_dim(D - 1);
+    let max = max
+        .clone()
+        .mask_fill(max.equal_elem(f32::NEG_INFINITY), 0.0);
+    let weights = scores.sub(max).exp();
+    let sum = weights.clone().sum_dim(D - 1);
+    let sum = sum.clone().mask_fill(sum.equal_elem(0.0), 1.0);
+
+    weights.div(sum).matmul(value).into_primitive()
+}
@@ -1,7 +1,7 @@
-use super::{conv, pool, unfold::unfold4d_using_conv2d};
+use super::{attention::attention_composed, conv, pool, unfold::unfold4d_using_conv2d};
 use crate::{
     backend::Backend,
-    ops::{FloatTensor, IntTensor},
+    ops::{BoolTensor, FloatTensor, IntTensor},
     Shape,
 };
 
@@ -81,6 +81,18 @@ pub struct ConvOptions<const N: usize> {
     pub groups: usize,
 }
 
+/// How the input is padded before a convolution.
+#[derive(Debug, Clone, Copy, Default, Hash, PartialEq, Eq)]
+pub enum PadMode {
+    /// Pads with zeros.
+    #[default]
+    Zeros,
+    /// Pads with the reflection of the input, excluding the edge (e.g. `[c, b | a, b, c | b, a]`).
+    Reflect,
+    /// Pads by repeating the edge of the input (e.g. `[a, a | a, b, c | c, c]`).
+    Replicate,
+}
+
 /// Transposed convolution options.
 #[derive(new, Debug, Clone, Hash, PartialEq, Eq)]
 pub struct ConvTransposeOptions<const N: usize> {
@@ -135,6 +147,13 @@ pub enum InterpolateMode {
 pub struct InterpolateOptions {
     /// Algorithm used for upsampling.
     pub mode: InterpolateMode,
+    /// Applies an area filter before downscaling with the bilinear or bicubic mode, which
+    /// prevents aliasing artifacts. It has no effect when upscaling or with the nearest mode.
+    ///
+    /// The filter is applied by [interpolate](crate::module::interpolate), backends don't have
+    /// to handle it.
+    #[new(default)]
+    pub antialias: bool,
 }
 
 /// Gradient computed during the backward pass for each tensor used by [interpolate](ModuleOps::interpolate).
@@ -305,6 +324,38 @@ pub trait ModuleOps<B: Backend> {
         unfold4d_using_conv2d::<B>(x, kernel_size, options)
     }
 
+    /// Scaled dot-product attention, `softmax(query @ key^T * scale) @ value`.
+    ///
+    /// The default implementation composes the tensor operations, materializing the scores.
+    /// Backends may fuse them in a single kernel.
+    ///
+    /// # Arguments
+    ///
+    /// * `query` - The queries.
+    /// * `key` - The keys.
+    /// * `value` - The values.
+    /// * `mask` - Broadcast to the scores, `true` excludes the key from the attention of the query.
+    /// * `scale` - The factor of the scores, usually `1 / sqrt(d_qk)`.
+    /// * `causal` - If query `i` only attends to the keys `0..=i`.
+    ///
+    /// # Shapes
+    ///
+    /// query: `[..., queries, d_qk]`,
+    /// key: `[..., keys, d_qk]`,
+    /// value: `[..., keys, d_v]`,
+    /// mask: broadcastable to `[..., queries, keys]`,
+    /// returns: `[..., queries, d_v]`.
+    fn scaled_dot_product_attention<const D: usize>(
+        query: FloatTensor<B, D>,
+        key: FloatTensor<B, D>,
+        value: FloatTensor<B, D>,
+        mask: Option<BoolTensor<B, D>>,
+        scale: f64,
+        causal: bool,
+    ) -> FloatTensor<B, D> {
+        attention_composed::<B, D>(query, key, value, mask, scale, causal)
+    }
+
     /// One dimensional avg pooling.
     ///
     /// # Shapes
@@ -449,6 +500,10 @@ pub trait ModuleOps<B: Backend> {
 
     /// Two dimensional max pooling with indices.
     ///
+    /// The indices are flattened over the spatial dimensions of the input, i.e. the position
+    /// `(h, w)` maps to `h * width + w`. When several elements of a window hold the maximum
+    /// value, the lowest index is returned.
+    ///
     /// # Shapes
     ///
     /// x: [batch_size, channels, height, width],
@@ -0,0 +1,25 @@
+use crate::{backend::Backend, Numeric, Tensor};
+use alloc::vec;
+
+/// Computes the cumulative sum along a dimension by doubling the span of the partial sums, which
+/// takes `log2(size)` additions and allocations of the whole tensor.
+pub(crate) fn cumsum_with_doubling<B: Backend, const D: usize, K: Numeric<B>>(
+    tensor: Tensor<B, D, K>,
+    dim: usize,
+) -> Tensor<B, D, K> {
+    let size = tensor.dims()[dim];
+    let mut sums = tensor;
+    let mut span = 1;
+
+    while span < size {
+        let head = sums.clone().narrow(dim, 0, span);
+        let tail =
+            sums.clone()
+                .narrow(dim, span, size - span)
+                .add(sums.narrow(dim, 0, size - span));
+        sums = Tensor::cat(vec![head, tail], dim);
+        span *= 2;
+    }
+
+    sums
+}
@@ -1,10 +1,20 @@
+/// Module with attention operations.
+pub mod attention;
 /// Module with convolution operations.
 pub mod conv;
 
 /// Module with cat operation
 pub(crate) mod cat;
+/// Module with cumsum operation
+pub(crate) mod cumsum;
 /// Module with repeat operation
 pub(crate) mod repeat;
+/// Module with scatter max operation
+#[cfg(any(feature = "wasm-sync", not(target_family = "wasm")))]
+pub(crate) mod scatter_max;
+/// Module with segment softmax operation
+#[cfg(any(feature = "wasm-sync", not(target_family = "wasm")))]
+pub(crate) mod segment_softmax;
 /// Module with unfold operations.
 pub(crate) mod unfold;
 
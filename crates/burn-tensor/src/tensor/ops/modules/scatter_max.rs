@@ -0,0 +1,77 @@
+use crate::{
+    backend::Backend,
+    ops::{FloatElem, FloatTensor, IntElem, IntTensor},
+    ElementConversion, TensorData,
+};
+use alloc::{vec, vec::Vec};
+
+/// Computes the maximum of each segment along a dimension with its position, in a single pass
+/// over the elements read from the device.
+///
+/// Empty segments get `-inf` with the sentinel index `size`, the size of the input along `dim`,
+/// and elements with a segment index outside of `0..num_segments` are ignored. The outputs are
+/// the only allocations with a size depending on `num_segments`.
+///
+/// # Remarks
+///
+/// This is a synchronous fallback, used only when the backend doesn't implement the operation:
+/// both tensors are read back to the host and the outputs are uploaded again. Backends are
+/// expected to override it with a kernel on the device, and the function isn't meant to be used
+/// directly.
+pub(crate) fn scatter_max_with_reader<B: Backend, const D: usize>(
+    tensor: FloatTensor<B, D>,
+    dim: usize,
+    indices: IntTensor<B, D>,
+    num_segments: usize,
+) -> (FloatTensor<B, D>, IntTensor<B, D>) {
+    let device = B::float_device(&tensor);
+    let mut dims = B::float_shape(&tensor).dims;
+    let values = B::float_into_data(tensor).read();
+    let indices = B::int_into_data(indices).read();
+
+    let size = dims[dim];
+    let outer = dims[..dim].iter().product::<usize>();
+    let inner = dims[dim + 1..].iter().product::<usize>();
+
+    let values = values.iter::<FloatElem<B>>().collect::<Vec<_>>();
+    let indices = indices.iter::<i64>().collect::<Vec<_>>();
+
+    let num_outputs = outer * num_segments * inner;
+    let mut maxima = vec![f64::NEG_INFINITY.elem::<FloatElem<B>>(); num_outputs];
+    let mut argmax = vec![size as i64; num_outputs];
+
+    for o in 0..outer {
+        // The positions are visited in increasing order, so a tied maximum keeps the lowest one.
+        for j in 0..size {
+            for i in 0..inner {
+                let index = (o * size + j) * inner + i;
+                let segment = indices[index];
+
+                if segment < 0 || segment >= num_segments as i64 {
+                    continue;
+                }
+
+                let value = values[index];
+                let output = (o * num_segments + segment as usize) * inner + i;
+
+                if argmax[output] == size as i64
+                    || value.elem::<f64>() > maxima[output].elem::<f64>()
+                {
+                    maxima[output] = value;
+                    argmax[output] = j as i64;
+                }
+            }
+        }
+    }
+
+    dims[dim] = num_segments;
+    let argmax = argmax
+        .into_iter()
+        .map(|index| index.elem::<IntElem<B>>())
+        .collect::<Vec<_>>();
+
+    (
+        B::float_from_data(TensorData::new(maxima, dims), &device),
+        B::int_from_data(TensorData::new(argmax, dims), &device),
+    )
+}
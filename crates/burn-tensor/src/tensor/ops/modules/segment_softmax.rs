@@ -0,0 +1,45 @@
+use crate::{backend::Backend, Int, Tensor};
+
+/// Computes the softmax of each segment with the segment max and a sum scatter: the maxima are
+/// gathered back to subtract them before the exponentials, and the sums to divide them.
+///
+/// The maxima are computed with [scatter_max](Tensor::scatter_max). Elements with a segment index
+/// outside of `0..num_segments` are set to zero.
+pub(crate) fn segment_softmax_with_scatter<B: Backend, const D: usize>(
+    tensor: Tensor<B, D>,
+    segment_ids: Tensor<B, 1, Int>,
+    num_segments: usize,
+    dim: usize,
+) -> Tensor<B, D> {
+    let shape = tensor.shape();
+
+    // Without segments, every element is ignored.
+    if num_segments == 0 {
+        return tensor.zeros_like();
+    }
+
+    let mut ids_shape = [1; D];
+    ids_shape[dim] = shape.dims[dim];
+    let ids = segment_ids.reshape(ids_shape).expand(shape.clone());
+    let ignored = ids
+        .clone()
+        .lower_elem(0)
+        .bool_or(ids.clone().greater_equal_elem(num_segments as i64));
+
+    let (maxima, _) = tensor.clone().scatter_max(dim, ids.clone(), num_segments);
+
+    // The ignored elements are moved to the first segment to keep the indices valid, their
+    // exponentials being zeroed before they are summed.
+    let indices = ids.mask_fill(ignored.clone(), 0);
+    let exp = tensor
+        .sub(maxima.gather(dim, indices.clone()))
+        .exp()
+        .mask_fill(ignored.clone(), 0.0);
+
+    let mut sums_shape = shape;
+    sums_shape.dims[dim] = num_segments;
+    let sums =
+        Tensor::<B, D>::zeros(sums_shape, &exp.device()).scatter(dim, indices.clone(), exp.clone());
+
+    exp.div(sums.gather(dim, indices)).mask_fill(ignored, 0.0)
+}
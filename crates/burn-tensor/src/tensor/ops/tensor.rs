@@ -1,7 +1,9 @@
 use super::cat::cat_with_slice_assign;
+use super::cumsum::cumsum_with_doubling;
 use super::repeat::repeat_with_slice_assign;
 use super::{BoolTensor, Device, FloatElem, FloatTensor, FullPrecisionBackend, IntElem, IntTensor};
 use crate::backend::BackendBridge;
+use crate::dlpack::DLPackTensor;
 use crate::tensor::cast::ToElement;
 use crate::Tensor;
 use crate::{backend::Backend, tensor::Shape, Distribution, ElementConversion, Float, TensorData};
@@ -10,6 +12,8 @@ use alloc::vec::Vec;
 use burn_common::reader::Reader;
 use core::ops::Range;
 
+#[cfg(any(feature = "wasm-sync", not(target_family = "wasm")))]
+use super::{scatter_max::scatter_max_with_reader, segment_softmax::segment_softmax_with_scatter};
 #[cfg(any(feature = "wasm-sync", not(target_family = "wasm")))]
 use crate::{argsort, sort, sort_with_indices};
 
@@ -152,6 +156,78 @@ pub trait FloatTensorOps<B: Backend> {
         device: &Device<B>,
     ) -> FloatTensor<B, D>;
 
+    /// Starts moving the tensor to the given device.
+    ///
+    /// # Arguments
+    ///
+    /// * `tensor` - The tensor.
+    /// * `device` - The device to move the tensor to.
+    ///
+    /// # Returns
+    ///
+    /// A reader of the tensor on the given device, read once the transfer is complete.
+    ///
+    /// # Remarks
+    ///
+    /// The default implementation moves the tensor synchronously with
+    /// [float_to_device](FloatTensorOps::float_to_device). Backends able to overlap the transfer
+    /// with other work should override it.
+    fn float_to_device_async<const D: usize>(
+        tensor: FloatTensor<B, D>,
+        device: &Device<B>,
+    ) -> Reader<FloatTensor<B, D>> {
+        Reader::Concrete(B::float_to_device(tensor, device))
+    }
+
+    /// Exports the tensor as a DLPack tensor.
+    ///
+    /// # Arguments
+    ///
+    /// * `tensor` - The tensor.
+    ///
+    /// # Returns
+    ///
+    /// A reader of the DLPack tensor, with the shape, the strides and the element type of the
+    /// tensor.
+    ///
+    /// # Remarks
+    ///
+    /// The default implementation reads the tensor back to export a copy in host memory. Backends
+    /// able to share their buffers should override it.
+    fn float_to_dlpack<const D: usize>(tensor: FloatTensor<B, D>) -> Reader<DLPackTensor> {
+        B::float_into_data(tensor).map(|data| {
+            let shape = data.shape.clone();
+            let values = data
+                .convert::<FloatElem<B>>()
+                .to_vec::<FloatElem<B>>()
+                .expect("The values should have been converted to the float element type");
+
+            DLPackTensor::from_vec(values, &shape)
+        })
+    }
+
+    /// Imports a DLPack tensor.
+    ///
+    /// # Arguments
+    ///
+    /// * `tensor` - The DLPack tensor.
+    /// * `device` - The device to create the tensor on.
+    ///
+    /// # Returns
+    ///
+    /// The tensor with the values of the DLPack tensor.
+    ///
+    /// # Remarks
+    ///
+    /// The default implementation copies the values from host memory, so the DLPack tensor must
+    /// be on the CPU. Backends able to share a buffer with the DLPack tensor should override it.
+    fn float_from_dlpack<const D: usize>(
+        tensor: DLPackTensor,
+        device: &Device<B>,
+    ) -> FloatTensor<B, D> {
+        B::float_from_data(tensor.to_data().convert::<FloatElem<B>>(), device)
+    }
+
     /// Converts float tensor to int tensor.
     ///
     /// # Arguments
@@ -414,6 +490,22 @@ pub trait FloatTensorOps<B: Backend> {
         Self::float_swap_dims(tensor, D - 2, D - 1)
     }
 
+    /// Transposes the last two dimensions of a tensor into a new contiguous tensor.
+    ///
+    /// The default implementation is the same as [transpose](Self::float_transpose), backends
+    /// may copy the elements in the transposed order with a dedicated kernel.
+    ///
+    /// # Arguments
+    ///
+    /// * `tensor` - The tensor to transpose.
+    ///
+    /// # Returns
+    ///
+    /// The transposed tensor.
+    fn float_transpose_last2<const D: usize>(tensor: FloatTensor<B, D>) -> FloatTensor<B, D> {
+        Self::float_transpose(tensor)
+    }
+
     /// Swaps two dimensions of a tensor.
     ///
     /// # Arguments
@@ -839,6 +931,118 @@ pub trait FloatTensorOps<B: Backend> {
     /// A tensor with the sum of all elements in `tensor` along `dim`.
     fn float_sum_dim<const D: usize>(tensor: FloatTensor<B, D>, dim: usize) -> FloatTensor<B, D>;
 
+    /// Sum of the elements selected by a mask along a dimension, with the number of selected
+    /// elements.
+    ///
+    /// # Arguments
+    ///
+    /// * `tensor` - The tensor to sum.
+    /// * `dim` - The dimension along which to sum.
+    /// * `mask` - The elements to sum, with the same shape as `tensor`.
+    ///
+    /// # Returns
+    ///
+    /// The sum of the selected elements along `dim` and their number.
+    ///
+    /// # Remarks
+    ///
+    /// The default implementation fills the other elements with zeros before summing, and sums
+    /// the mask separately. Backends should override it to reduce both in a single pass.
+    fn float_sum_dim_masked<const D: usize>(
+        tensor: FloatTensor<B, D>,
+        dim: usize,
+        mask: BoolTensor<B, D>,
+    ) -> (FloatTensor<B, D>, IntTensor<B, D>) {
+        let count = B::int_sum_dim(B::bool_into_int(mask.clone()), dim);
+        let tensor = B::float_mask_fill(tensor, B::bool_not(mask), 0.elem());
+
+        (B::float_sum_dim(tensor, dim), count)
+    }
+
+    /// Cumulative sum of the elements of a tensor along a dimension.
+    ///
+    /// # Arguments
+    ///
+    /// * `tensor` - The tensor to accumulate.
+    /// * `dim` - The dimension along which to accumulate.
+    ///
+    /// # Returns
+    ///
+    /// A tensor with the same shape as `tensor` where each element is the sum of the elements of
+    /// `tensor` up to and including its position along `dim`.
+    ///
+    /// # Remarks
+    ///
+    /// The default implementation doubles the span of the partial sums, which allocates
+    /// `log2(size)` tensors of the shape of `tensor`. Backends with a scan should override it.
+    fn float_cumsum<const D: usize>(tensor: FloatTensor<B, D>, dim: usize) -> FloatTensor<B, D> {
+        cumsum_with_doubling::<B, D, Float>(Tensor::from_primitive(tensor), dim).into_primitive()
+    }
+
+    /// Maximum of each segment along a dimension, with the position of the maximum.
+    ///
+    /// # Arguments
+    ///
+    /// * `tensor` - The input tensor.
+    /// * `dim` - The dimension along which the segments are defined.
+    /// * `indices` - The segment of each element, with the same shape as `tensor`.
+    /// * `num_segments` - The number of segments.
+    ///
+    /// # Returns
+    ///
+    /// The maxima and their positions along `dim`, with the shape of `tensor` where `dim` is
+    /// resized to `num_segments`. Empty segments get `-inf` with the size of `tensor` along `dim`
+    /// as position, and the lowest position is returned for a tied maximum.
+    ///
+    /// # Remarks
+    ///
+    /// The default implementation is a synchronous host fallback, reading the tensors back to
+    /// compute the maxima. Backends should override it to keep them on the device.
+    #[cfg(any(feature = "wasm-sync", not(target_family = "wasm")))]
+    fn float_scatter_max<const D: usize>(
+        tensor: FloatTensor<B, D>,
+        dim: usize,
+        indices: IntTensor<B, D>,
+        num_segments: usize,
+    ) -> (FloatTensor<B, D>, IntTensor<B, D>) {
+        scatter_max_with_reader::<B, D>(tensor, dim, indices, num_segments)
+    }
+
+    /// Softmax of the elements of each segment along a dimension, the segments being unsorted.
+    ///
+    /// # Arguments
+    ///
+    /// * `tensor` - The tensor to normalize.
+    /// * `segment_ids` - The segment of each element along `dim`, shared by every other index.
+    /// * `num_segments` - The number of segments.
+    /// * `dim` - The dimension along which the segments are defined.
+    ///
+    /// # Returns
+    ///
+    /// A tensor with the same shape as `tensor`, where the elements of each segment sum to one and
+    /// the elements with a segment index outside of `0..num_segments` are zeros.
+    ///
+    /// # Remarks
+    ///
+    /// The default implementation composes a [segment max](FloatTensorOps::float_scatter_max),
+    /// which reads the tensors back unless the backend overrides it, with a sum scatter. Backends
+    /// should override it to normalize the segments on the device in a single kernel.
+    #[cfg(any(feature = "wasm-sync", not(target_family = "wasm")))]
+    fn float_segment_softmax<const D: usize>(
+        tensor: FloatTensor<B, D>,
+        segment_ids: IntTensor<B, 1>,
+        num_segments: usize,
+        dim: usize,
+    ) -> FloatTensor<B, D> {
+        segment_softmax_with_scatter::<B, D>(
+            Tensor::from_primitive(tensor),
+            Tensor::from_primitive(segment_ids),
+            num_segments,
+            dim,
+        )
+        .into_primitive()
+    }
+
     /// Product of all elements in a tensor.
     ///
     /// # Arguments
@@ -1351,6 +1555,40 @@ pub trait FloatTensorOps<B: Backend> {
         B::float_equal_elem(sum, (num_elems as f32).elem())
     }
 
+    /// Tests if no element in the float `tensor` evaluates to True.
+    ///
+    /// # Arguments
+    ///
+    /// * `tensor` - The tensor to test.
+    ///
+    /// # Returns
+    ///
+    /// A boolean tensor `Tensor<B, 1, Bool>` with a single element, True if no element in the input tensor
+    /// evaluates to True, False otherwise.
+    fn float_none<const D: usize>(tensor: FloatTensor<B, D>) -> BoolTensor<B, 1> {
+        let bool_tensor = B::float_not_equal_elem(tensor, 0.0f32.elem());
+        let sum = B::float_sum(B::bool_into_float(bool_tensor));
+        B::float_equal_elem(sum, 0.0f32.elem())
+    }
+
+    /// Tests if no element in the float `tensor` evaluates to True along a given dimension `dim`.
+    ///
+    /// # Arguments
+    ///
+    /// * `tensor` - The tensor to test.
+    /// * `dim` - The axis along which to test.
+    ///
+    /// # Returns
+    ///
+    /// A boolean tensor `Tensor<B, D, Bool>` with the same size as input `tensor`, except in the `dim` axis
+    /// where the size is 1. The elem in the `dim` axis is True if no element along this dim in the input
+    /// evaluates to True, False otherwise.
+    fn float_none_dim<const D: usize>(tensor: FloatTensor<B, D>, dim: usize) -> BoolTensor<B, D> {
+        let bool_tensor = B::float_not_equal_elem(tensor, 0.0f32.elem());
+        let sum = B::float_sum_dim(B::bool_into_float(bool_tensor), dim);
+        B::float_equal_elem(sum, 0.0f32.elem())
+    }
+
     /// Returns the signs of the float `tensor`.
     ///
     /// # Arguments
@@ -1442,4 +1680,35 @@ pub trait FloatTensorOps<B: Backend> {
     ) -> IntTensor<B, D> {
         argsort::<B, D, Float>(tensor, dim, descending)
     }
+
+    /// Returns the `k` largest elements of the input `tensor` along a given dimension, with their
+    /// indices.
+    ///
+    /// The elements are returned in descending order.
+    ///
+    /// # Arguments
+    ///
+    /// * `tensor` - The input tensor.
+    /// * `k` - The number of elements to keep.
+    /// * `dim` - The axis along which to select the elements.
+    ///
+    /// # Returns
+    ///
+    /// The `k` largest elements and their indices in the input tensor.
+    #[cfg(any(feature = "wasm-sync", not(target_family = "wasm")))]
+    fn float_topk_with_indices<const D: usize>(
+        tensor: FloatTensor<B, D>,
+        k: usize,
+        dim: usize,
+    ) -> (FloatTensor<B, D>, IntTensor<B, D>) {
+        let mut ranges = B::float_shape(&tensor).dims.map(|size| 0..size);
+        ranges[dim] = 0..k;
+
+        let (values, indices) = B::float_sort_with_indices(tensor, dim, true);
+
+        (
+            B::float_slice(values, ranges.clone()),
+            B::int_slice(indices, ranges),
+        )
+    }
 }
@@ -16,4 +16,22 @@ mod tests {
         output.into_data().assert_approx_eq(&expected, 2); // Low precision to allow approximation
                                                            // implementation using tanh
     }
+
+    #[test]
+    fn test_gelu_tanh() {
+        let tensor = TestTensor::<1>::from([-2.0, -1.0, 0.0, 1.0, 2.0]);
+        let output = activation::gelu_tanh(tensor);
+        let expected = TensorData::from([-0.0454, -0.1588, 0.0, 0.8412, 1.9546]);
+
+        output.into_data().assert_approx_eq(&expected, 3);
+    }
+
+    #[test]
+    fn test_gelu_tanh_large_inputs() {
+        let tensor = TestTensor::<1>::from([-100.0, -20.0, 20.0, 100.0]);
+        let output = activation::gelu_tanh(tensor);
+        let expected = TensorData::from([0.0, 0.0, 20.0, 100.0]);
+
+        output.into_data().assert_approx_eq(&expected, 3);
+    }
 }
@@ -0,0 +1,27 @@
+#[burn_tensor_testgen::testgen(hard_sigmoid)]
+mod tests {
+    use super::*;
+    use burn_tensor::{activation, Tensor, TensorData};
+
+    #[test]
+    fn test_hard_sigmoid() {
+        // Breakpoints of the default ONNX parameters are at -2.5 and 2.5.
+        let tensor = TestTensor::<2>::from([[-3.0, -2.5, -1.0], [0.0, 2.5, 3.0]]);
+
+        let output = activation::hard_sigmoid(tensor, 0.2, 0.5);
+        let expected = TensorData::from([[0.0, 0.0, 0.3], [0.5, 1.0, 1.0]]);
+
+        output.into_data().assert_approx_eq(&expected, 4);
+    }
+
+    #[test]
+    fn test_hard_sigmoid_alpha_beta() {
+        // Breakpoints are at -beta / alpha = -1 and (1 - beta) / alpha = 3.
+        let tensor = TestTensor::<2>::from([[-2.0, -1.0, 0.0], [1.0, 3.0, 4.0]]);
+
+        let output = activation::hard_sigmoid(tensor, 0.25, 0.25);
+        let expected = TensorData::from([[0.0, 0.0, 0.25], [0.5, 1.0, 1.0]]);
+
+        output.into_data().assert_approx_eq(&expected, 4);
+    }
+}
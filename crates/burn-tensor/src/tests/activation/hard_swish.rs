@@ -0,0 +1,16 @@
+#[burn_tensor_testgen::testgen(hard_swish)]
+mod tests {
+    use super::*;
+    use burn_tensor::{activation, Tensor, TensorData};
+
+    #[test]
+    fn test_hard_swish() {
+        // Breakpoints are at -3 and 3.
+        let tensor = TestTensor::<2>::from([[-4.0, -3.0, -1.0], [1.0, 3.0, 4.0]]);
+
+        let output = activation::hard_swish(tensor);
+        let expected = TensorData::from([[0.0, 0.0, -0.3333], [0.6667, 3.0, 4.0]]);
+
+        output.into_data().assert_approx_eq(&expected, 3);
+    }
+}
@@ -0,0 +1,15 @@
+#[burn_tensor_testgen::testgen(hard_tanh)]
+mod tests {
+    use super::*;
+    use burn_tensor::{activation, Tensor, TensorData};
+
+    #[test]
+    fn test_hard_tanh() {
+        let tensor = TestTensor::<2>::from([[-2.0, -1.0, -0.5], [0.5, 1.0, 2.0]]);
+
+        let output = activation::hard_tanh(tensor, -1.0, 1.0);
+        let expected = TensorData::from([[-1.0, -1.0, -0.5], [0.5, 1.0, 1.0]]);
+
+        output.into_data().assert_approx_eq(&expected, 4);
+    }
+}
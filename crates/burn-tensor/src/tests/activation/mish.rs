@@ -13,4 +13,24 @@ mod tests {
 
         output.into_data().assert_approx_eq(&expected, 4);
     }
+
+    #[test]
+    fn test_mish_large_inputs() {
+        let tensor = TestTensor::<1>::from([-100.0, -20.0, 20.0, 100.0]);
+
+        let output = activation::mish(tensor);
+        let expected = TensorData::from([0.0, 0.0, 20.0, 100.0]);
+
+        output.into_data().assert_approx_eq(&expected, 4);
+    }
+
+    #[test]
+    fn test_mish_zero_and_one() {
+        let tensor = TestTensor::<1>::from([0.0, 1.0]);
+
+        let output = activation::mish(tensor);
+        let expected = TensorData::from([0.0, 0.8651]);
+
+        output.into_data().assert_approx_eq(&expected, 4);
+    }
 }
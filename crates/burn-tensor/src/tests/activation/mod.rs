@@ -1,4 +1,7 @@
 pub(crate) mod gelu;
+pub(crate) mod hard_sigmoid;
+pub(crate) mod hard_swish;
+pub(crate) mod hard_tanh;
 pub(crate) mod leaky_relu;
 pub(crate) mod log_sigmoid;
 pub(crate) mod mish;
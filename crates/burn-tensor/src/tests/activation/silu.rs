@@ -12,4 +12,14 @@ mod tests {
 
         output.into_data().assert_approx_eq(&expected, 4);
     }
+
+    #[test]
+    fn test_silu_large_inputs() {
+        let tensor = TestTensor::<1>::from([-100.0, -20.0, 20.0, 100.0]);
+
+        let output = activation::silu(tensor);
+        let expected = TensorData::from([0.0, 0.0, 20.0, 100.0]);
+
+        output.into_data().assert_approx_eq(&expected, 4);
+    }
 }
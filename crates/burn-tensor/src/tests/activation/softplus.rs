@@ -20,4 +20,20 @@ mod tests {
 
         output.into_data().assert_approx_eq(&expected, 4);
     }
+
+    #[test]
+    fn test_softplus_large_inputs() {
+        // exp(100) overflows, a naive implementation would return inf.
+        let tensor = TestTensor::<1>::from([-100.0, -20.0, 20.0, 100.0]);
+
+        let output = activation::softplus(tensor.clone(), 1.0);
+        let expected = TensorData::from([0.0, 2.0612e-9, 20.0, 100.0]);
+
+        output.into_data().assert_approx_eq(&expected, 4);
+
+        let output = activation::softplus(tensor, 2.0);
+        let expected = TensorData::from([0.0, 0.0, 20.0, 100.0]);
+
+        output.into_data().assert_approx_eq(&expected, 4);
+    }
 }
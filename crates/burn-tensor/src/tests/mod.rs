@@ -10,6 +10,9 @@ macro_rules! testgen_all {
     () => {
         // test activation
         burn_tensor::testgen_gelu!();
+        burn_tensor::testgen_hard_sigmoid!();
+        burn_tensor::testgen_hard_swish!();
+        burn_tensor::testgen_hard_tanh!();
         burn_tensor::testgen_mish!();
         burn_tensor::testgen_relu!();
         burn_tensor::testgen_leaky_relu!();
@@ -27,8 +30,11 @@ macro_rules! testgen_all {
         burn_tensor::testgen_module_conv_transpose1d!();
         burn_tensor::testgen_module_conv_transpose2d!();
         burn_tensor::testgen_module_unfold4d!();
+        burn_tensor::testgen_module_attention!();
         burn_tensor::testgen_module_max_pool1d!();
         burn_tensor::testgen_module_max_pool2d!();
+        burn_tensor::testgen_module_max_unpool2d!();
+        burn_tensor::testgen_module_affine_grid!();
         burn_tensor::testgen_module_avg_pool1d!();
         burn_tensor::testgen_module_avg_pool2d!();
         burn_tensor::testgen_module_adaptive_avg_pool1d!();
@@ -36,6 +42,9 @@ macro_rules! testgen_all {
         burn_tensor::testgen_module_nearest_interpolate!();
         burn_tensor::testgen_module_bilinear_interpolate!();
         burn_tensor::testgen_module_bicubic_interpolate!();
+        burn_tensor::testgen_module_batch_norm_stats!();
+        burn_tensor::testgen_module_pad_with_mode!();
+        burn_tensor::testgen_module_replication_pad!();
 
         // test ops
         burn_tensor::testgen_add!();
@@ -48,16 +57,26 @@ macro_rules! testgen_all {
         burn_tensor::testgen_chunk!();
         burn_tensor::testgen_clamp!();
         burn_tensor::testgen_close!();
+        burn_tensor::testgen_coord_grid!();
         burn_tensor::testgen_cos!();
         burn_tensor::testgen_create_like!();
+        burn_tensor::testgen_cumsum!();
+        burn_tensor::testgen_data_chunks!();
         burn_tensor::testgen_div!();
+        burn_tensor::testgen_dlpack!();
+        burn_tensor::testgen_dynamic_partition!();
         burn_tensor::testgen_erf!();
         burn_tensor::testgen_exp!();
         burn_tensor::testgen_flatten!();
         burn_tensor::testgen_full!();
         burn_tensor::testgen_gather_scatter!();
+        burn_tensor::testgen_gradient!();
+        burn_tensor::testgen_histogram!();
+        burn_tensor::testgen_index_put!();
         burn_tensor::testgen_init!();
+        burn_tensor::testgen_interleave!();
         burn_tensor::testgen_iter_dim!();
+        burn_tensor::testgen_kv_cache!();
         burn_tensor::testgen_log!();
         burn_tensor::testgen_log1p!();
         burn_tensor::testgen_map_comparison!();
@@ -73,7 +92,9 @@ macro_rules! testgen_all {
         burn_tensor::testgen_recip!();
         burn_tensor::testgen_repeat!();
         burn_tensor::testgen_reshape!();
+        burn_tensor::testgen_segment_softmax!();
         burn_tensor::testgen_select!();
+        burn_tensor::testgen_shift!();
         burn_tensor::testgen_sin!();
         burn_tensor::testgen_slice!();
         burn_tensor::testgen_stack!();
@@ -82,14 +103,17 @@ macro_rules! testgen_all {
         burn_tensor::testgen_squeeze!();
         burn_tensor::testgen_sub!();
         burn_tensor::testgen_tanh!();
+        burn_tensor::testgen_transfer!();
         burn_tensor::testgen_transpose!();
         burn_tensor::testgen_tri!();
         burn_tensor::testgen_powf!();
         burn_tensor::testgen_any!();
         burn_tensor::testgen_all_op!();
+        burn_tensor::testgen_none_op!();
         burn_tensor::testgen_permute!();
         burn_tensor::testgen_movedim!();
         burn_tensor::testgen_flip!();
+        burn_tensor::testgen_fmod!();
         burn_tensor::testgen_bool!();
         burn_tensor::testgen_argwhere_nonzero!();
         burn_tensor::testgen_sign!();
@@ -0,0 +1,56 @@
+#[burn_tensor_testgen::testgen(module_affine_grid)]
+mod tests {
+    use super::*;
+    use burn_tensor::module::affine_grid;
+    use burn_tensor::{Tensor, TensorData};
+
+    #[test]
+    fn test_affine_grid_identity_align_corners() {
+        let theta = TestTensor::<3>::from([[[1.0, 0.0, 0.0], [0.0, 1.0, 0.0]]]);
+
+        let grid = affine_grid(theta, [1, 1, 2, 3], true);
+
+        let expected = TensorData::from([[
+            [[-1.0, -1.0], [0.0, -1.0], [1.0, -1.0]],
+            [[-1.0, 1.0], [0.0, 1.0], [1.0, 1.0]],
+        ]]);
+        grid.into_data().assert_approx_eq(&expected, 4);
+    }
+
+    #[test]
+    fn test_affine_grid_identity() {
+        let theta = TestTensor::<3>::from([[[1.0, 0.0, 0.0], [0.0, 1.0, 0.0]]]);
+
+        let grid = affine_grid(theta, [1, 1, 2, 3], false);
+
+        // Pixel centers: (2i + 1) / n - 1.
+        let expected = TensorData::from([[
+            [[-0.6667, -0.5], [0.0, -0.5], [0.6667, -0.5]],
+            [[-0.6667, 0.5], [0.0, 0.5], [0.6667, 0.5]],
+        ]]);
+        grid.into_data().assert_approx_eq(&expected, 4);
+    }
+
+    #[test]
+    fn test_affine_grid_batch() {
+        // Identity, then a scale of 2 on x with a translation of 0.5 on y.
+        let theta = TestTensor::<3>::from([
+            [[1.0, 0.0, 0.0], [0.0, 1.0, 0.0]],
+            [[2.0, 0.0, 0.0], [0.0, 1.0, 0.5]],
+        ]);
+
+        let grid = affine_grid(theta, [2, 3, 1, 2], true);
+
+        // A dimension of size 1 is mapped to the center.
+        let expected = TensorData::from([[[[-1.0, 0.0], [1.0, 0.0]]], [[[-2.0, 0.5], [2.0, 0.5]]]]);
+        grid.into_data().assert_approx_eq(&expected, 4);
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_affine_grid_invalid_theta() {
+        let theta = TestTensor::<3>::from([[[1.0, 0.0], [0.0, 1.0]]]);
+
+        let _grid = affine_grid(theta, [1, 1, 2, 2], true);
+    }
+}
@@ -0,0 +1,57 @@
+#[burn_tensor_testgen::testgen(module_attention)]
+mod tests {
+    use super::*;
+    use burn_tensor::module::scaled_dot_product_attention;
+    use burn_tensor::TensorData;
+
+    #[test]
+    fn test_attention() {
+        let query = TestTensor::<2>::from([[1.0, 0.0], [0.0, 1.0]]);
+        let key = TestTensor::<2>::from([[1.0, 0.0], [0.0, 1.0]]);
+        let value = TestTensor::<2>::from([[1.0, 2.0], [3.0, 4.0]]);
+
+        let output = scaled_dot_product_attention(query, key, value, None, 1.0, false);
+
+        // softmax([1, 0]) = [0.7311, 0.2689]
+        let expected = TensorData::from([[1.5379, 2.5379], [2.4621, 3.4621]]);
+        output.into_data().assert_approx_eq(&expected, 3);
+    }
+
+    #[test]
+    fn test_attention_causal() {
+        let query = TestTensor::<3>::from([[[1.0, 0.0], [0.0, 1.0]]]);
+        let key = TestTensor::<3>::from([[[1.0, 0.0], [0.0, 1.0]]]);
+        let value = TestTensor::<3>::from([[[1.0, 2.0], [3.0, 4.0]]]);
+
+        let output = scaled_dot_product_attention(query, key, value, None, 1.0, true);
+
+        let expected = TensorData::from([[[1.0, 2.0], [2.4621, 3.4621]]]);
+        output.into_data().assert_approx_eq(&expected, 3);
+    }
+
+    #[test]
+    fn test_attention_fully_masked_rows_are_zeros() {
+        let query = TestTensor::<2>::from([[1.0, 0.0], [0.0, 1.0]]);
+        let key = TestTensor::<2>::from([[1.0, 0.0], [0.0, 1.0]]);
+        let value = TestTensor::<2>::from([[1.0, 2.0], [3.0, 4.0]]);
+        let mask = TestTensorBool::<2>::from([[false, true], [true, true]]);
+
+        let output = scaled_dot_product_attention(query, key, value, Some(mask), 1.0, false);
+
+        let expected = TensorData::from([[1.0, 2.0], [0.0, 0.0]]);
+        output.into_data().assert_approx_eq(&expected, 3);
+    }
+
+    #[test]
+    fn test_attention_stable_with_large_scores() {
+        let query = TestTensor::<2>::from([[100.0, 0.0], [0.0, 100.0]]);
+        let key = TestTensor::<2>::from([[100.0, 0.0], [0.0, 100.0]]);
+        let value = TestTensor::<2>::from([[1.0, 2.0], [3.0, 4.0]]);
+
+        let output = scaled_dot_product_attention(query, key, value, None, 1.0, false);
+
+        // The scores differ by 10000, so each query only attends to its own key.
+        let expected = TensorData::from([[1.0, 2.0], [3.0, 4.0]]);
+        output.into_data().assert_approx_eq(&expected, 3);
+    }
+}
@@ -0,0 +1,104 @@
+#[burn_tensor_testgen::testgen(module_batch_norm_stats)]
+mod tests {
+    use super::*;
+    use burn_tensor::module::{
+        batch_norm_stats, masked_batch_norm, masked_batch_norm_stats, running_stats_update,
+    };
+    use burn_tensor::{Tensor, TensorData};
+
+    #[test]
+    fn test_batch_norm_stats_reduces_non_channel_dims() {
+        // Shape [batch_size = 2, channels = 2, length = 2].
+        let x = TestTensor::<3>::from([[[1.0, 3.0], [10.0, 10.0]], [[5.0, 7.0], [20.0, 40.0]]]);
+
+        let (mean, var) = batch_norm_stats(x);
+
+        // Channel 0 holds [1, 3, 5, 7], channel 1 holds [10, 10, 20, 40].
+        mean.into_data()
+            .assert_approx_eq(&TensorData::from([4.0, 20.0]), 4);
+        var.into_data()
+            .assert_approx_eq(&TensorData::from([5.0, 150.0]), 4);
+    }
+
+    #[test]
+    fn test_batch_norm_stats_4d() {
+        // Shape [batch_size = 1, channels = 3, height = 1, width = 2].
+        let x = TestTensor::<4>::from([[[[1.0, 2.0]], [[-4.0, 4.0]], [[0.5, 0.5]]]]);
+
+        let (mean, var) = batch_norm_stats(x);
+
+        mean.into_data()
+            .assert_approx_eq(&TensorData::from([1.5, 0.0, 0.5]), 4);
+        var.into_data()
+            .assert_approx_eq(&TensorData::from([0.25, 16.0, 0.0]), 4);
+    }
+
+    #[test]
+    fn test_running_stats_update() {
+        let running = TestTensor::<1>::from([1.0, 0.0, -2.0]);
+        let batch_stat = TestTensor::<1>::from([3.0, 10.0, 2.0]);
+
+        let running = running_stats_update(running, batch_stat, 0.1);
+
+        // (1 - 0.1) * running + 0.1 * batch_stat
+        running
+            .into_data()
+            .assert_approx_eq(&TensorData::from([1.2, 1.0, -1.6]), 4);
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_batch_norm_stats_requires_channels() {
+        let x = TestTensor::<1>::from([1.0, 2.0]);
+
+        let _stats = batch_norm_stats(x);
+    }
+
+    #[test]
+    fn test_masked_batch_norm_excludes_padding() {
+        // Shape [batch_size = 2, channels = 2, length = 3], the last position of the second
+        // sequence being padding.
+        let x = TestTensor::<3>::from([
+            [[1.0, 2.0, 3.0], [4.0, 5.0, 6.0]],
+            [[7.0, 8.0, 100.0], [-1.0, 0.0, -100.0]],
+        ]);
+        let mask = TestTensorBool::<3>::from([[[true, true, true]], [[true, true, false]]]);
+        let gamma = TestTensor::<1>::from([2.0, 0.5]);
+        let beta = TestTensor::<1>::from([0.1, -0.1]);
+
+        let output = masked_batch_norm(x.clone(), mask, gamma.clone(), beta.clone(), 1e-5);
+
+        // Same statistics as the batch norm of the valid positions only.
+        let valid = Tensor::cat(
+            vec![
+                x.clone().narrow(0, 0, 1),
+                x.clone().narrow(0, 1, 1).narrow(2, 0, 2),
+            ],
+            2,
+        );
+        let (mean, var) = batch_norm_stats(valid);
+        let expected = x
+            .sub(mean.reshape([1, 2, 1]))
+            .div(var.add_scalar(1e-5).sqrt().reshape([1, 2, 1]))
+            .mul(gamma.reshape([1, 2, 1]))
+            .add(beta.reshape([1, 2, 1]));
+
+        output
+            .into_data()
+            .assert_approx_eq(&expected.into_data(), 3);
+    }
+
+    #[test]
+    fn test_masked_batch_norm_stats_channel_without_valid_position() {
+        let x = TestTensor::<3>::from([[[1.0, 3.0], [10.0, 20.0]]]);
+        let mask = TestTensorBool::<3>::from([[[true, true], [false, false]]]);
+
+        let (mean, var) = masked_batch_norm_stats(x, mask);
+
+        // The second channel falls back to a zero mean and a unit variance.
+        mean.into_data()
+            .assert_approx_eq(&TensorData::from([2.0, 0.0]), 4);
+        var.into_data()
+            .assert_approx_eq(&TensorData::from([1.0, 1.0]), 4);
+    }
+}
@@ -3,7 +3,7 @@ mod tests {
     use super::*;
     use burn_tensor::module::interpolate;
     use burn_tensor::ops::{InterpolateMode, InterpolateOptions};
-    use burn_tensor::Shape;
+    use burn_tensor::{ElementConversion, Shape};
 
     #[test]
     fn test_upsample_interpolation() {
@@ -85,6 +85,57 @@ mod tests {
         ]]]));
     }
 
+    #[test]
+    fn test_downsample_antialias_high_frequency() {
+        let device = Default::default();
+        // Checkerboard pattern, the highest frequency an 8x8 image can hold.
+        let x = TestTensorInt::arange(0..8, &device).reshape([8, 1])
+            + TestTensorInt::arange(0..8, &device).reshape([1, 8]);
+        let x = x.remainder_scalar(2).float().reshape([1, 1, 8, 8]);
+
+        let options = InterpolateOptions::new(InterpolateMode::Bilinear);
+        let aliased = interpolate(x.clone(), [4, 4], options.clone());
+        let antialiased = interpolate(
+            x,
+            [4, 4],
+            InterpolateOptions {
+                antialias: true,
+                ..options
+            },
+        );
+
+        // Every output value averages a 2x2 block of the pattern.
+        antialiased.to_data().assert_approx_eq(
+            &TestTensor::<4>::full([1, 1, 4, 4], 0.5, &device).into_data(),
+            3,
+        );
+
+        let energy = |y: TestTensor<4>| {
+            let centered = y.clone() - y.mean().reshape([1, 1, 1, 1]);
+            centered.powf_scalar(2.0).sum().into_scalar().elem::<f32>()
+        };
+        assert!(energy(antialiased) < energy(aliased));
+    }
+
+    #[test]
+    fn test_antialias_only_filters_downscaled_dims() {
+        let device = Default::default();
+        let x = TestTensorInt::arange(0..8, &device)
+            .reshape([1, 1, 4, 2])
+            .float();
+
+        let mut options = InterpolateOptions::new(InterpolateMode::Bilinear);
+        options.antialias = true;
+        let output = interpolate(x, [2, 4], options);
+
+        // The height is averaged by pairs of rows, the width is interpolated.
+        let expected =
+            TestTensor::<4>::from([[[[1.0, 1.3333, 1.6667, 2.0], [5.0, 5.3333, 5.6667, 6.0]]]]);
+        output
+            .into_data()
+            .assert_approx_eq(&expected.into_data(), 3);
+    }
+
     struct InterpolateTestCase {
         batch_size: usize,
         channels: usize,
@@ -1,8 +1,8 @@
 #[burn_tensor_testgen::testgen(module_conv2d)]
 mod tests {
     use super::*;
-    use burn_tensor::module::conv2d;
-    use burn_tensor::ops::ConvOptions;
+    use burn_tensor::module::{conv2d, conv2d_with_pad_mode};
+    use burn_tensor::ops::{ConvOptions, PadMode};
     use burn_tensor::{Shape, Tensor};
 
     #[test]
@@ -104,6 +104,78 @@ mod tests {
         ]));
     }
 
+    #[test]
+    fn test_conv2d_reflect_padding() {
+        let padded = TestTensor::from([[[
+            [4., 3., 4., 5., 4.],
+            [1., 0., 1., 2., 1.],
+            [4., 3., 4., 5., 4.],
+            [7., 6., 7., 8., 7.],
+            [4., 3., 4., 5., 4.],
+        ]]]);
+
+        assert_pad_mode(PadMode::Reflect, padded);
+    }
+
+    #[test]
+    fn test_conv2d_replicate_padding() {
+        let padded = TestTensor::from([[[
+            [0., 0., 1., 2., 2.],
+            [0., 0., 1., 2., 2.],
+            [3., 3., 4., 5., 5.],
+            [6., 6., 7., 8., 8.],
+            [6., 6., 7., 8., 8.],
+        ]]]);
+
+        assert_pad_mode(PadMode::Replicate, padded);
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_conv2d_reflect_padding_too_large() {
+        let device = Default::default();
+        let x = TestTensor::<4>::ones([1, 1, 2, 2], &device);
+        let weight = TestTensor::<4>::ones([1, 1, 3, 3], &device);
+
+        conv2d_with_pad_mode(
+            x,
+            weight,
+            None,
+            ConvOptions::new([1, 1], [2, 2], [1, 1], 1),
+            PadMode::Reflect,
+        );
+    }
+
+    /// Compares the padding mode against a zero-padded convolution of the explicitly padded input.
+    fn assert_pad_mode(pad_mode: PadMode, padded: TestTensor<4>) {
+        let device = Default::default();
+        let x = TestTensorInt::<1>::arange(0..9, &device)
+            .reshape([1, 1, 3, 3])
+            .float();
+        let weight = TestTensorInt::<1>::arange(0..18, &device)
+            .reshape([2, 1, 3, 3])
+            .float();
+        let bias = TestTensor::<1>::from([1., -1.]);
+
+        let output = conv2d_with_pad_mode(
+            x,
+            weight.clone(),
+            Some(bias.clone()),
+            ConvOptions::new([1, 1], [1, 1], [1, 1], 1),
+            pad_mode,
+        );
+        let expected = conv2d(
+            padded,
+            weight,
+            Some(bias),
+            ConvOptions::new([1, 1], [0, 0], [1, 1], 1),
+        );
+
+        output
+            .into_data()
+            .assert_approx_eq(&expected.into_data(), 3);
+    }
+
     struct Conv2dTestCase {
         batch_size: usize,
         channels_in: usize,
@@ -1,7 +1,11 @@
 #[burn_tensor_testgen::testgen(module_forward)]
 mod tests {
     use super::*;
-    use burn_tensor::{backend::Backend, module::embedding, Int, Tensor, TensorData};
+    use burn_tensor::{
+        backend::Backend,
+        module::{drop_path, embedding, embedding_with_padding},
+        Int, Tensor, TensorData,
+    };
 
     #[test]
     fn test_embedding_forward() {
@@ -18,4 +22,65 @@ mod tests {
 
         output.into_data().assert_eq(&expected, false);
     }
+
+    #[test]
+    fn test_embedding_with_padding_forward() {
+        let weights = TensorData::from([[0.0, 1.0, 2.0], [3.0, 4.0, 5.0], [6.0, 7.0, 8.0]]);
+        let indices = TensorData::from([[0, 1], [2, 1]]);
+        let weights = Tensor::<TestBackend, 2>::from(weights);
+        let indices = Tensor::<TestBackend, 2, Int>::from(indices);
+
+        let output = embedding_with_padding(weights, indices, 1);
+        let expected = TensorData::from([
+            [[0.0, 1.0, 2.0], [0.0, 0.0, 0.0]],
+            [[6.0, 7.0, 8.0], [0.0, 0.0, 0.0]],
+        ]);
+
+        output.into_data().assert_eq(&expected, false);
+    }
+
+    #[test]
+    fn test_drop_path_drops_whole_samples() {
+        TestBackend::seed(42);
+        let x = TestTensor::<3>::ones([32, 3, 4], &Default::default());
+
+        let output = drop_path(x, 0.5, true);
+
+        // Each sample is either dropped or kept and scaled by 1 / (1 - 0.5).
+        let data = output.into_data().to_vec::<f32>().unwrap();
+        let mut num_kept = 0;
+        for sample in data.chunks(12) {
+            let value = sample[0];
+            assert!(value == 0.0 || value == 2.0, "Unexpected value {value}");
+            assert!(
+                sample.iter().all(|v| *v == value),
+                "Sample partially dropped"
+            );
+            if value == 2.0 {
+                num_kept += 1;
+            }
+        }
+        assert!(num_kept > 0 && num_kept < 32);
+    }
+
+    #[test]
+    fn test_drop_path_is_deterministic_with_seed() {
+        let x = TestTensor::<2>::ones([16, 5], &Default::default());
+
+        TestBackend::seed(7);
+        let first = drop_path(x.clone(), 0.3, true);
+        TestBackend::seed(7);
+        let second = drop_path(x, 0.3, true);
+
+        first.into_data().assert_eq(&second.into_data(), true);
+    }
+
+    #[test]
+    fn test_drop_path_inference_is_identity() {
+        let x = TestTensor::<2>::from([[1.0, -2.0], [3.0, 4.0]]);
+
+        let output = drop_path(x.clone(), 0.9, false);
+
+        output.into_data().assert_eq(&x.into_data(), true);
+    }
 }
@@ -227,6 +227,7 @@ mod tests {
         y.to_data().assert_approx_eq(&output.into_data(), 3);
     }
 
+    #[test]
     fn test_max_pool2d_with_indices() {
         let batch_size = 1;
         let channels_in = 1;
@@ -319,4 +320,34 @@ mod tests {
         y.to_data().assert_approx_eq(&output.into_data(), 3);
         output_indices.into_data().assert_eq(&indices, false);
     }
+
+    #[test]
+    fn test_max_pool2d_with_indices_ties_select_lowest_index() {
+        let x = TestTensor::from([[[
+            [1.0, 3.0, 3.0, 0.0],
+            [3.0, 2.0, 5.0, 5.0],
+            [4.0, 4.0, 0.0, 1.0],
+            [4.0, 4.0, 1.0, 0.0],
+        ]]]);
+        let indices = TensorData::from([[[[1, 6], [8, 11]]]]);
+        let y = TensorData::from([[[[3.0, 5.0], [4.0, 1.0]]]]);
+
+        let (output, output_indices) =
+            max_pool2d_with_indices(x.clone(), [2, 2], [2, 2], [0, 0], [1, 1]);
+
+        output.clone().into_data().assert_approx_eq(&y, 3);
+        output_indices
+            .clone()
+            .into_data()
+            .assert_eq(&indices, false);
+
+        // The indices must point to the maxima in the flattened input.
+        let gathered = x
+            .reshape([1, 1, 16])
+            .gather(2, output_indices.reshape([1, 1, 4]))
+            .reshape([1, 1, 2, 2]);
+        gathered
+            .into_data()
+            .assert_approx_eq(&output.into_data(), 3);
+    }
 }
@@ -0,0 +1,74 @@
+#[burn_tensor_testgen::testgen(module_max_unpool2d)]
+mod tests {
+    use super::*;
+    use burn_tensor::module::{max_pool2d_with_indices, max_unpool2d};
+    use burn_tensor::{Tensor, TensorData};
+
+    #[test]
+    fn test_max_unpool2d_round_trip() {
+        let x = TestTensor::from([[[
+            [0.2479, 0.6386, 0.3166, 0.5742],
+            [0.7065, 0.1940, 0.6305, 0.8959],
+            [0.5416, 0.8602, 0.8129, 0.1662],
+            [0.3358, 0.3059, 0.8293, 0.0990],
+        ]]]);
+
+        let (output, indices) = max_pool2d_with_indices(x, [2, 2], [2, 2], [0, 0], [1, 1]);
+        let unpooled = max_unpool2d(output, indices, [2, 2], [2, 2], [0, 0], None);
+
+        let expected = TensorData::from([[[
+            [0.0, 0.0, 0.0, 0.0],
+            [0.7065, 0.0, 0.0, 0.8959],
+            [0.0, 0.8602, 0.0, 0.0],
+            [0.0, 0.0, 0.8293, 0.0],
+        ]]]);
+        unpooled.into_data().assert_eq(&expected, false);
+    }
+
+    #[test]
+    fn test_max_unpool2d_overlapping_windows() {
+        let x = TestTensor::from([[[[1.0, 2.0, 3.0], [4.0, 9.0, 5.0], [6.0, 7.0, 8.0]]]]);
+
+        // Every window selects the center position.
+        let (output, indices) = max_pool2d_with_indices(x, [2, 2], [1, 1], [0, 0], [1, 1]);
+        let unpooled = max_unpool2d(output, indices, [2, 2], [1, 1], [0, 0], None);
+
+        let expected = TensorData::from([[[[0.0, 0.0, 0.0], [0.0, 9.0, 0.0], [0.0, 0.0, 0.0]]]]);
+        unpooled.into_data().assert_eq(&expected, false);
+    }
+
+    #[test]
+    fn test_max_unpool2d_with_output_size() {
+        let x = TestTensor::from([[[
+            [1.0, 2.0, 3.0, 4.0, 5.0],
+            [6.0, 7.0, 8.0, 9.0, 10.0],
+            [11.0, 12.0, 13.0, 14.0, 15.0],
+            [16.0, 17.0, 18.0, 19.0, 20.0],
+            [21.0, 22.0, 23.0, 24.0, 25.0],
+        ]]]);
+
+        let (output, indices) = max_pool2d_with_indices(x, [2, 2], [2, 2], [0, 0], [1, 1]);
+        // Without the output size, the unpooled size would be 4x4 while the flat indices refer to
+        // the original 5x5 input.
+        let unpooled = max_unpool2d(output, indices, [2, 2], [2, 2], [0, 0], Some([5, 5]));
+
+        let expected = TensorData::from([[[
+            [0.0, 0.0, 0.0, 0.0, 0.0],
+            [0.0, 7.0, 0.0, 9.0, 0.0],
+            [0.0, 0.0, 0.0, 0.0, 0.0],
+            [0.0, 17.0, 0.0, 19.0, 0.0],
+            [0.0, 0.0, 0.0, 0.0, 0.0],
+        ]]]);
+        unpooled.into_data().assert_eq(&expected, false);
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_max_unpool2d_invalid_output_size() {
+        let x = TestTensor::from([[[[1.0, 2.0], [3.0, 4.0]]]]);
+        let indices = TestTensorInt::from([[[[0, 1], [2, 3]]]]);
+
+        // Valid output sizes are within [4, 6).
+        let _ = max_unpool2d(x, indices, [2, 2], [2, 2], [0, 0], Some([6, 6]));
+    }
+}
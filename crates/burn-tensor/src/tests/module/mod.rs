@@ -1,7 +1,10 @@
 mod adaptive_avgpool1d;
 mod adaptive_avgpool2d;
+mod affine_grid;
+mod attention;
 mod avgpool1d;
 mod avgpool2d;
+mod batch_norm_stats;
 mod bicubic_interpolate;
 mod bilinear_interpolate;
 mod conv1d;
@@ -11,5 +14,8 @@ mod conv_transpose2d;
 mod forward;
 mod maxpool1d;
 mod maxpool2d;
+mod maxunpool2d;
 mod nearest_interpolate;
+mod pad_with_mode;
+mod replication_pad;
 mod unfold4d;
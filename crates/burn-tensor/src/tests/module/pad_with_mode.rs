@@ -0,0 +1,49 @@
+#[burn_tensor_testgen::testgen(module_pad_with_mode)]
+mod tests {
+    use super::*;
+    use burn_tensor::module::pad_with_mode;
+    use burn_tensor::ops::PadMode;
+    use burn_tensor::TensorData;
+
+    #[test]
+    fn test_pad_with_mode_reflect() {
+        let x = TestTensor::<3>::from([[[1.0, 2.0, 3.0, 4.0]]]);
+
+        let output = pad_with_mode(x, [(0, 0), (0, 0), (2, 1)], PadMode::Reflect);
+
+        let expected = TensorData::from([[[3.0, 2.0, 1.0, 2.0, 3.0, 4.0, 3.0]]]);
+        output.into_data().assert_approx_eq(&expected, 4);
+    }
+
+    #[test]
+    fn test_pad_with_mode_replicate() {
+        let x = TestTensor::<3>::from([[[1.0, 2.0], [3.0, 4.0]]]);
+
+        let output = pad_with_mode(x, [(0, 0), (1, 0), (0, 2)], PadMode::Replicate);
+
+        let expected = TensorData::from([[
+            [1.0, 2.0, 2.0, 2.0],
+            [1.0, 2.0, 2.0, 2.0],
+            [3.0, 4.0, 4.0, 4.0],
+        ]]);
+        output.into_data().assert_approx_eq(&expected, 4);
+    }
+
+    #[test]
+    fn test_pad_with_mode_zeros() {
+        let x = TestTensor::<2>::from([[1.0, 2.0]]);
+
+        let output = pad_with_mode(x, [(1, 0), (0, 1)], PadMode::Zeros);
+
+        let expected = TensorData::from([[0.0, 0.0, 0.0], [1.0, 2.0, 0.0]]);
+        output.into_data().assert_approx_eq(&expected, 4);
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_pad_with_mode_reflect_larger_than_input() {
+        let x = TestTensor::<2>::from([[1.0, 2.0]]);
+
+        let _output = pad_with_mode(x, [(0, 0), (2, 0)], PadMode::Reflect);
+    }
+}
@@ -0,0 +1,52 @@
+#[burn_tensor_testgen::testgen(module_replication_pad)]
+mod tests {
+    use super::*;
+    use burn_tensor::module::{replication_pad1d, replication_pad3d};
+    use burn_tensor::TensorData;
+
+    #[test]
+    fn test_replication_pad1d() {
+        let x = TestTensor::<3>::from([[[1.0, 2.0, 3.0], [4.0, 5.0, 6.0]]]);
+
+        let output = replication_pad1d(x, [2, 1]);
+
+        let expected = TensorData::from([[
+            [1.0, 1.0, 1.0, 2.0, 3.0, 3.0],
+            [4.0, 4.0, 4.0, 5.0, 6.0, 6.0],
+        ]]);
+        output.into_data().assert_approx_eq(&expected, 4);
+    }
+
+    #[test]
+    fn test_replication_pad1d_larger_than_length() {
+        let x = TestTensor::<3>::from([[[1.0, 2.0]]]);
+
+        let output = replication_pad1d(x, [3, 0]);
+
+        let expected = TensorData::from([[[1.0, 1.0, 1.0, 1.0, 2.0]]]);
+        output.into_data().assert_approx_eq(&expected, 4);
+    }
+
+    #[test]
+    fn test_replication_pad3d() {
+        let x = TestTensor::<4>::from([[[[1.0, 2.0]], [[3.0, 4.0]]]]).reshape([1, 1, 2, 1, 2]);
+
+        let output = replication_pad3d(x, [1, 0, 1, 1, 0, 1]);
+
+        // The depth is padded at the back, the height on both sides and the width on the left.
+        let plane = |a: f32, b: f32| [a, a, b].repeat(3);
+        let expected = TensorData::new(
+            [plane(1.0, 2.0), plane(3.0, 4.0), plane(3.0, 4.0)].concat(),
+            [1, 1, 3, 3, 3],
+        );
+        output.into_data().assert_approx_eq(&expected, 4);
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_replication_pad1d_empty_length() {
+        let x = TestTensor::<3>::empty([1, 1, 0], &Default::default());
+
+        let _output = replication_pad1d(x, [1, 1]);
+    }
+}
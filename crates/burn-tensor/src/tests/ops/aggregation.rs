@@ -138,6 +138,32 @@ mod tests {
         );
     }
 
+    #[test]
+    fn test_should_sum_dim_prime_number_of_outputs() {
+        // Output counts that can't be tiled evenly must still all be written.
+        for rows in [17, 101] {
+            let tensor = TestTensorInt::<1>::arange(0..rows * 64, &Default::default())
+                .reshape([rows as usize, 64])
+                .float();
+
+            let output = tensor.clone().sum_dim(1);
+            let expected = (0..rows)
+                .map(|row| (64 * 64 * row + 2016) as f32)
+                .collect::<Vec<_>>();
+            output
+                .into_data()
+                .assert_eq(&TensorData::new(expected, [rows as usize, 1]), false);
+
+            let output = tensor.transpose().mean_dim(0);
+            let expected = (0..rows)
+                .map(|row| (64 * row) as f32 + 31.5)
+                .collect::<Vec<_>>();
+            output
+                .into_data()
+                .assert_approx_eq(&TensorData::new(expected, [1, rows as usize]), 3);
+        }
+    }
+
     #[test]
     fn test_prod_float() {
         let tensor = TestTensor::<2>::from([[2.0, 1.0, 2.0], [3.0, 4.0, 5.0]]);
@@ -203,4 +229,50 @@ mod tests {
             .into_data()
             .assert_eq(&TensorData::from([[0], [60]]), false);
     }
+
+    #[test]
+    fn test_should_sum_dim_masked() {
+        let tensor = TestTensor::<2>::from([[1.0, 2.0, 3.0], [4.0, 5.0, 6.0], [7.0, 8.0, 9.0]]);
+        let mask = TestTensorBool::<2>::from([
+            [true, false, true],
+            [false, false, false],
+            [true, true, true],
+        ]);
+
+        let (sum, count) = tensor.sum_dim_masked(1, mask);
+
+        sum.into_data()
+            .assert_eq(&TensorData::from([[4.0], [0.0], [24.0]]), false);
+        count
+            .into_data()
+            .assert_eq(&TensorData::from([[2], [0], [3]]), false);
+    }
+
+    #[test]
+    fn test_should_sum_dim_masked_skip_nan() {
+        let tensor = TestTensor::<2>::from([[1.0, f32::NAN], [f32::NAN, f32::NAN]]);
+        let mask = tensor.clone().equal(tensor.clone());
+
+        let (sum, count) = tensor.sum_dim_masked(0, mask);
+
+        sum.into_data()
+            .assert_eq(&TensorData::from([[1.0, 0.0]]), false);
+        count
+            .into_data()
+            .assert_eq(&TensorData::from([[1, 0]]), false);
+    }
+
+    #[test]
+    fn test_should_sum_dim_masked_int() {
+        let tensor = TestTensorInt::<2>::from([[1, 2, 3], [4, 5, 6]]);
+        let mask = TestTensorBool::<2>::from([[false, true, true], [true, false, false]]);
+
+        let (sum, count) = tensor.sum_dim_masked(0, mask);
+
+        sum.into_data()
+            .assert_eq(&TensorData::from([[4, 2, 3]]), false);
+        count
+            .into_data()
+            .assert_eq(&TensorData::from([[1, 1, 1]]), false);
+    }
 }
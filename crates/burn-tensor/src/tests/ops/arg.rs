@@ -68,4 +68,124 @@ mod tests {
             .into_data()
             .assert_eq(&TensorData::from([[2], [1]]), false);
     }
+
+    #[test]
+    fn test_argmax_ties_select_first_occurrence() {
+        let tensor = TestTensor::<2>::from([[1.0, 5.0, 5.0], [7.0, 2.0, 7.0]]);
+
+        tensor
+            .clone()
+            .argmax(1)
+            .into_data()
+            .assert_eq(&TensorData::from([[1], [0]]), false);
+        tensor
+            .argmax(0)
+            .into_data()
+            .assert_eq(&TensorData::from([[1, 0, 1]]), false);
+    }
+
+    #[test]
+    fn test_argmin_ties_select_first_occurrence() {
+        let tensor = TestTensorInt::<2>::from([[3, 1, 1], [0, 4, 0]]);
+
+        tensor
+            .clone()
+            .argmin(1)
+            .into_data()
+            .assert_eq(&TensorData::from([[1], [0]]), false);
+        tensor
+            .argmin(0)
+            .into_data()
+            .assert_eq(&TensorData::from([[1, 0, 1]]), false);
+    }
+
+    #[test]
+    fn test_argmax_argmin_ties_long_dim() {
+        // Long enough for backends to split the reduction across multiple threads.
+        let mut values = [0.0; 64];
+        values[5] = 1.0;
+        values[21] = 1.0;
+        values[60] = 1.0;
+        values[9] = -1.0;
+        values[40] = -1.0;
+        let tensor = TestTensor::<1>::from(values);
+
+        tensor
+            .clone()
+            .argmax(0)
+            .into_data()
+            .assert_eq(&TensorData::from([5]), false);
+        tensor
+            .argmin(0)
+            .into_data()
+            .assert_eq(&TensorData::from([9]), false);
+    }
+
+    #[test]
+    fn test_argmax_keepdim_and_squeeze() {
+        let tensor = TestTensor::<3>::from([[[1.0, 3.0], [3.0, 2.0]], [[0.0, 0.0], [4.0, 4.0]]]);
+
+        let output = tensor.argmax(2);
+        assert_eq!(output.dims(), [2, 2, 1]);
+        output
+            .clone()
+            .into_data()
+            .assert_eq(&TensorData::from([[[1], [0]], [[0], [0]]]), false);
+
+        let output = output.squeeze::<2>(2);
+        assert_eq!(output.dims(), [2, 2]);
+        output
+            .into_data()
+            .assert_eq(&TensorData::from([[1, 0], [0, 0]]), false);
+    }
+
+    #[test]
+    fn test_argmax_keepdim_flag() {
+        let tensor = TestTensor::<3>::from([[[1.0, 3.0], [3.0, 2.0]], [[0.0, 0.0], [4.0, 4.0]]]);
+
+        let output = tensor.clone().argmax_keepdim::<3>(1, true);
+        assert_eq!(output.dims(), [2, 1, 2]);
+        output
+            .into_data()
+            .assert_eq(&TensorData::from([[[1, 0]], [[1, 1]]]), false);
+
+        let output = tensor.argmax_keepdim::<2>(1, false);
+        assert_eq!(output.dims(), [2, 2]);
+        output
+            .into_data()
+            .assert_eq(&TensorData::from([[1, 0], [1, 1]]), false);
+    }
+
+    #[test]
+    fn test_argmin_keepdim_flag() {
+        let tensor = TestTensor::<3>::from([[[1.0, 3.0], [3.0, 2.0]], [[0.0, 0.0], [4.0, 4.0]]]);
+
+        let output = tensor.clone().argmin_keepdim::<3>(2, true);
+        assert_eq!(output.dims(), [2, 2, 1]);
+        output
+            .into_data()
+            .assert_eq(&TensorData::from([[[0], [1]], [[0], [0]]]), false);
+
+        let output = tensor.argmin_keepdim::<2>(2, false);
+        assert_eq!(output.dims(), [2, 2]);
+        output
+            .into_data()
+            .assert_eq(&TensorData::from([[0, 1], [0, 0]]), false);
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_argmax_keepdim_rank_mismatch_should_panic() {
+        let tensor = TestTensor::<2>::from([[1.0, 3.0], [3.0, 2.0]]);
+
+        let _ = tensor.argmax_keepdim::<2>(1, false);
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_argmax_empty_dim_should_panic() {
+        let tensor = TestTensor::<2>::empty([2, 0], &Default::default());
+
+        let _ = tensor.argmax(1);
+    }
 }
@@ -18,4 +18,66 @@ mod tests {
         let data_expected = TensorData::from([[false, true, false], [true, true, true]]);
         assert_eq!(data_expected, data_actual);
     }
+
+    #[test]
+    fn test_bool_and() {
+        let tensor1 = TestTensorBool::<2>::from([[false, true, false], [true, true, false]]);
+        let tensor2 = TestTensorBool::<2>::from([[true, true, false], [false, true, true]]);
+        let data_actual = tensor1.bool_and(tensor2).into_data();
+        let data_expected = TensorData::from([[false, true, false], [false, true, false]]);
+        assert_eq!(data_expected, data_actual);
+    }
+
+    #[test]
+    fn test_bool_and_broadcast() {
+        let tensor1 = TestTensorBool::<2>::from([[false, true, false], [true, true, false]]);
+        let tensor2 = TestTensorBool::<2>::from([[true, true, false]]);
+        let data_actual = tensor1.bool_and(tensor2).into_data();
+        let data_expected = TensorData::from([[false, true, false], [true, true, false]]);
+        assert_eq!(data_expected, data_actual);
+    }
+
+    #[test]
+    fn test_bool_or() {
+        let tensor1 = TestTensorBool::<2>::from([[false, true, false], [true, true, false]]);
+        let tensor2 = TestTensorBool::<2>::from([[true, true, false], [false, true, true]]);
+        let data_actual = tensor1.bool_or(tensor2).into_data();
+        let data_expected = TensorData::from([[true, true, false], [true, true, true]]);
+        assert_eq!(data_expected, data_actual);
+    }
+
+    #[test]
+    fn test_bool_or_broadcast() {
+        let tensor1 = TestTensorBool::<2>::from([[false, true, false], [true, true, false]]);
+        let tensor2 = TestTensorBool::<2>::from([[false], [true]]);
+        let data_actual = tensor1.bool_or(tensor2).into_data();
+        let data_expected = TensorData::from([[false, true, false], [true, true, true]]);
+        assert_eq!(data_expected, data_actual);
+    }
+
+    #[test]
+    fn test_bool_xor() {
+        let tensor1 = TestTensorBool::<2>::from([[false, true, false], [true, true, false]]);
+        let tensor2 = TestTensorBool::<2>::from([[true, true, false], [false, true, true]]);
+        let data_actual = tensor1.bool_xor(tensor2).into_data();
+        let data_expected = TensorData::from([[true, false, false], [true, false, true]]);
+        assert_eq!(data_expected, data_actual);
+    }
+
+    #[test]
+    fn test_bool_xor_broadcast() {
+        let tensor1 = TestTensorBool::<2>::from([[false, true, false], [true, true, false]]);
+        let tensor2 = TestTensorBool::<2>::from([[true, false, true]]);
+        let data_actual = tensor1.bool_xor(tensor2).into_data();
+        let data_expected = TensorData::from([[true, true, true], [false, true, true]]);
+        assert_eq!(data_expected, data_actual);
+    }
+
+    #[test]
+    fn test_bool_not() {
+        let tensor1 = TestTensorBool::<2>::from([[false, true, false], [true, true, false]]);
+        let data_actual = tensor1.bool_not().into_data();
+        let data_expected = TensorData::from([[true, false, true], [false, false, true]]);
+        assert_eq!(data_expected, data_actual);
+    }
 }
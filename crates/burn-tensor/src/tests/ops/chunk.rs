@@ -76,6 +76,41 @@ mod tests {
         }
     }
 
+    #[test]
+    fn test_chunk_fewer_chunks_than_requested() {
+        // ceil(6 / 4) = 2, so only three chunks are needed to cover the dimension.
+        let tensors: Vec<Tensor<TestBackend, 1, Int>> =
+            Tensor::arange(0..6, &Default::default()).chunk(4, 0);
+        assert_eq!(tensors.len(), 3);
+
+        let expected = vec![
+            TensorData::from([0, 1]),
+            TensorData::from([2, 3]),
+            TensorData::from([4, 5]),
+        ];
+
+        for (index, tensor) in tensors.iter().enumerate() {
+            tensor.to_data().assert_eq(&expected[index], false);
+        }
+    }
+
+    #[test]
+    fn test_chunk_remainder() {
+        let tensors: Vec<Tensor<TestBackend, 1, Int>> =
+            Tensor::arange(0..5, &Default::default()).chunk(4, 0);
+        assert_eq!(tensors.len(), 3);
+
+        let expected = vec![
+            TensorData::from([0, 1]),
+            TensorData::from([2, 3]),
+            TensorData::from([4]),
+        ];
+
+        for (index, tensor) in tensors.iter().enumerate() {
+            tensor.to_data().assert_eq(&expected[index], false);
+        }
+    }
+
     #[test]
     fn test_chunk_multi_dimension() {
         let tensors: Vec<Tensor<TestBackend, 2, Int>> =
@@ -0,0 +1,35 @@
+#[burn_tensor_testgen::testgen(coord_grid)]
+mod tests {
+    use super::*;
+    use burn_tensor::{Tensor, TensorData};
+
+    #[test]
+    fn test_coord_grid_pixels() {
+        let grid = Tensor::<TestBackend, 3>::coord_grid(2, 3, false, &Default::default());
+        let expected = TensorData::from([
+            [[0., 0.], [0., 1.], [0., 2.]],
+            [[1., 0.], [1., 1.], [1., 2.]],
+        ]);
+
+        grid.into_data().assert_approx_eq(&expected, 5);
+    }
+
+    #[test]
+    fn test_coord_grid_normalized() {
+        let grid = Tensor::<TestBackend, 3>::coord_grid(2, 3, true, &Default::default());
+        let expected = TensorData::from([
+            [[-1., -1.], [-1., 0.], [-1., 1.]],
+            [[1., -1.], [1., 0.], [1., 1.]],
+        ]);
+
+        grid.into_data().assert_approx_eq(&expected, 5);
+    }
+
+    #[test]
+    fn test_coord_grid_normalized_single_row() {
+        let grid = Tensor::<TestBackend, 3>::coord_grid(1, 2, true, &Default::default());
+        let expected = TensorData::from([[[0., -1.], [0., 1.]]]);
+
+        grid.into_data().assert_approx_eq(&expected, 5);
+    }
+}
@@ -0,0 +1,50 @@
+#[burn_tensor_testgen::testgen(cumsum)]
+mod tests {
+    use super::*;
+    use burn_tensor::TensorData;
+
+    #[test]
+    fn should_support_cumsum_ops() {
+        let tensor = TestTensor::<2>::from([[0.0, 1.0, 2.0], [3.0, 4.0, 5.0]]);
+
+        let output = tensor.cumsum(1);
+
+        output
+            .into_data()
+            .assert_approx_eq(&TensorData::from([[0.0, 1.0, 3.0], [3.0, 7.0, 12.0]]), 5);
+    }
+
+    #[test]
+    fn should_support_cumsum_ops_int() {
+        let tensor = TestTensorInt::<2>::from([[0, 1, 2], [3, 4, 5]]);
+
+        let output = tensor.cumsum(0);
+
+        output
+            .into_data()
+            .assert_eq(&TensorData::from([[0, 1, 2], [3, 5, 7]]), false);
+    }
+
+    #[test]
+    fn should_support_cumsum_non_power_of_two_dim() {
+        // Spans of 1, 2 and 4 are needed to cover 7 elements.
+        let tensor = TestTensorInt::<1>::arange(1..8, &Default::default());
+
+        let output = tensor.cumsum(0);
+
+        output
+            .into_data()
+            .assert_eq(&TensorData::from([1, 3, 6, 10, 15, 21, 28]), false);
+    }
+
+    #[test]
+    fn should_support_cumsum_transposed() {
+        let tensor = TestTensor::<2>::from([[0.0, 1.0, 2.0], [3.0, 4.0, 5.0]]).transpose();
+
+        let output = tensor.cumsum(1);
+
+        output
+            .into_data()
+            .assert_approx_eq(&TensorData::from([[0.0, 3.0], [1.0, 5.0], [2.0, 7.0]]), 5);
+    }
+}
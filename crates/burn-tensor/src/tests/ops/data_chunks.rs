@@ -0,0 +1,60 @@
+#[burn_tensor_testgen::testgen(data_chunks)]
+mod tests {
+    use super::*;
+    use burn_tensor::{Distribution, TensorData};
+
+    #[test]
+    fn concatenated_chunks_should_equal_to_data() {
+        let device = Default::default();
+        let tensor = TestTensor::<3>::random([2, 3, 4], Distribution::Default, &device);
+
+        let chunks = tensor.to_data_chunks(5).collect::<Vec<_>>();
+
+        let sizes = chunks
+            .iter()
+            .map(|chunk| chunk.num_elements())
+            .collect::<Vec<_>>();
+        assert_eq!(sizes, vec![5, 5, 5, 5, 4]);
+
+        let values = chunks
+            .iter()
+            .flat_map(|chunk| chunk.iter::<f32>())
+            .collect::<Vec<_>>();
+        TensorData::new(values, [2, 3, 4]).assert_eq(&tensor.to_data(), false);
+    }
+
+    #[test]
+    fn chunks_should_follow_the_logical_order_of_a_transposed_tensor() {
+        let device = Default::default();
+        let tensor = TestTensorInt::<1>::arange(0..6, &device)
+            .reshape([2, 3])
+            .transpose();
+
+        let values = tensor
+            .to_data_chunks(4)
+            .flat_map(|chunk| chunk.iter::<i64>().collect::<Vec<_>>())
+            .collect::<Vec<_>>();
+
+        assert_eq!(values, vec![0, 3, 1, 4, 2, 5]);
+    }
+
+    #[test]
+    fn chunk_larger_than_the_tensor_should_give_a_single_chunk() {
+        let device = Default::default();
+        let tensor = TestTensorBool::<2>::from_data([[true, false], [false, true]], &device);
+
+        let chunks = tensor.to_data_chunks(16).collect::<Vec<_>>();
+
+        assert_eq!(chunks.len(), 1);
+        chunks[0].assert_eq(&TensorData::from([true, false, false, true]), false);
+    }
+
+    #[test]
+    #[should_panic]
+    fn to_data_chunks_should_panic_with_empty_chunks() {
+        let device = Default::default();
+        let tensor = TestTensor::<1>::from_floats([1.0, 2.0], &device);
+
+        let _chunks = tensor.to_data_chunks(0);
+    }
+}
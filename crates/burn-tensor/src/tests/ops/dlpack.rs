@@ -0,0 +1,47 @@
+#[burn_tensor_testgen::testgen(dlpack)]
+mod tests {
+    use super::*;
+    use burn_tensor::backend::Backend;
+    use burn_tensor::dlpack::{DLDataType, DLDevice, DLPackTensor};
+    use burn_tensor::{Element, TensorData};
+
+    #[test]
+    fn should_round_trip_through_dlpack() {
+        let device = Default::default();
+        let data = TensorData::from([[0.0, 1.0, 2.0], [3.0, 4.0, 5.0]]);
+        let tensor = TestTensor::<2>::from_data(data.clone(), &device);
+
+        let dlpack = tensor.to_dlpack();
+
+        assert_eq!(dlpack.device(), DLDevice::CPU);
+        assert_eq!(dlpack.shape(), [2, 3]);
+        assert_eq!(dlpack.strides(), [3, 1]);
+        assert_eq!(
+            dlpack.dtype(),
+            DLDataType::from(<TestBackend as Backend>::FloatElem::dtype())
+        );
+
+        let tensor = TestTensor::<2>::from_dlpack(dlpack, &device);
+
+        tensor.into_data().assert_eq(&data, false);
+    }
+
+    #[test]
+    fn should_import_dlpack_tensor_of_another_element_type() {
+        let dlpack = DLPackTensor::from_vec(vec![1i64, 2, 3, 4], &[2, 2]);
+
+        let tensor = TestTensor::<2>::from_dlpack(dlpack, &Default::default());
+
+        tensor
+            .into_data()
+            .assert_eq(&TensorData::from([[1.0, 2.0], [3.0, 4.0]]), false);
+    }
+
+    #[test]
+    #[should_panic]
+    fn should_panic_when_importing_with_another_rank() {
+        let dlpack = DLPackTensor::from_vec(vec![1.0f32, 2.0, 3.0, 4.0], &[4]);
+
+        let _tensor = TestTensor::<2>::from_dlpack(dlpack, &Default::default());
+    }
+}
@@ -0,0 +1,52 @@
+#[burn_tensor_testgen::testgen(dynamic_partition)]
+mod tests {
+    use super::*;
+    use burn_tensor::{Tensor, TensorData};
+
+    #[test]
+    fn should_partition_and_stitch_rows() {
+        let device = Default::default();
+        let tensor = TestTensor::<2>::from([[0., 1.], [2., 3.], [4., 5.], [6., 7.], [8., 9.]]);
+        // Partition 2 doesn't get any row.
+        let partitions = TestTensorInt::<1>::from([1, 0, 1, 3, 0]);
+
+        let parts = tensor.clone().dynamic_partition(partitions.clone(), 4);
+
+        assert_eq!(parts.len(), 4);
+        let data = |partition: usize| parts[partition].as_ref().unwrap().to_data();
+        data(0).assert_eq(&TensorData::from([[2., 3.], [8., 9.]]), false);
+        data(1).assert_eq(&TensorData::from([[0., 1.], [4., 5.]]), false);
+        assert!(parts[2].is_none());
+        data(3).assert_eq(&TensorData::from([[6., 7.]]), false);
+
+        // The row indices of each partition undo the partitioning.
+        let indices = TestTensorInt::arange(0..5, &device).dynamic_partition(partitions, 4);
+        let output = Tensor::dynamic_stitch(indices, parts);
+
+        output.into_data().assert_eq(&tensor.into_data(), false);
+    }
+
+    #[test]
+    fn should_partition_int_tensor() {
+        let tensor = TestTensorInt::<1>::from([5, 6, 7, 8]);
+        let partitions = TestTensorInt::<1>::from([0, 0, 0, 0]);
+
+        let parts = tensor.dynamic_partition(partitions, 2);
+
+        parts[0]
+            .as_ref()
+            .unwrap()
+            .to_data()
+            .assert_eq(&TensorData::from([5, 6, 7, 8]), false);
+        assert!(parts[1].is_none());
+    }
+
+    #[test]
+    #[should_panic]
+    fn should_panic_when_partitions_dont_match_rows() {
+        let tensor = TestTensor::<2>::from([[0., 1.], [2., 3.]]);
+        let partitions = TestTensorInt::<1>::from([0, 1, 0]);
+
+        let _ = tensor.dynamic_partition(partitions, 2);
+    }
+}
@@ -0,0 +1,74 @@
+#[burn_tensor_testgen::testgen(fmod)]
+mod tests {
+    use super::*;
+    use burn_tensor::{Tensor, TensorData};
+
+    /// From https://pytorch.org/docs/stable/generated/torch.fmod.html
+    #[test]
+    fn should_support_fmod_scalar() {
+        let tensor = TestTensor::<1>::from([-3.0, -2.0, -1.0, 1.0, 2.0, 3.0]);
+
+        let output = tensor.fmod_scalar(2.0);
+        let expected = TensorData::from([-1.0, -0.0, -1.0, 1.0, 0.0, 1.0]);
+
+        output.into_data().assert_approx_eq(&expected, 3);
+    }
+
+    #[test]
+    fn should_support_fmod_scalar_negative_divisor() {
+        let tensor = TestTensor::<1>::from([1.0, 2.0, 3.0, 4.0, 5.0]);
+
+        let output = tensor.fmod_scalar(-1.5);
+        let expected = TensorData::from([1.0, 0.5, 0.0, 1.0, 0.5]);
+
+        output.into_data().assert_approx_eq(&expected, 3);
+    }
+
+    #[test]
+    fn should_support_fmod() {
+        let lhs = TestTensor::<1>::from([-7.5, -3.0, 2.0, 6.25]);
+        let rhs = TestTensor::<1>::from([2.0, -2.0, 3.0, -4.0]);
+
+        let output = lhs.fmod(rhs);
+        let expected = TensorData::from([-1.5, -1.0, 2.0, 2.25]);
+
+        output.into_data().assert_approx_eq(&expected, 3);
+    }
+
+    /// Values taken from the periodic time features of an exported ONNX model, where small
+    /// negative dividends must stay negative instead of wrapping around the divisor.
+    #[test]
+    fn should_keep_dividend_sign_near_zero() {
+        let lhs = TestTensor::<1>::from([-0.0001, -0.0312, 0.0078, -6.9816]);
+        let rhs = TestTensor::<1>::from([7.0, 7.0, 7.0, 7.0]);
+
+        let output = lhs.clone().fmod(rhs);
+        let expected = TensorData::from([-0.0001, -0.0312, 0.0078, -6.9816]);
+        output.into_data().assert_approx_eq(&expected, 4);
+
+        let output = lhs.fmod_scalar(7.0);
+        output.into_data().assert_approx_eq(&expected, 4);
+    }
+
+    #[test]
+    fn should_support_fmod_large_quotients() {
+        // The quotients exceed the range of a 32-bit integer.
+        let lhs = TestTensor::<1>::from([1e10, -1e10, 3e9]);
+        let rhs = TestTensor::<1>::from([2.5, 2.5, -0.5]);
+
+        let output = lhs.fmod(rhs);
+        let expected = TensorData::from([0.0, 0.0, 0.0]);
+
+        output.into_data().assert_approx_eq(&expected, 3);
+    }
+
+    #[test]
+    fn should_return_nan_for_zero_divisor() {
+        let lhs = TestTensor::<1>::from([1.0, -2.0, 0.0]);
+        let rhs = TestTensor::<1>::from([0.0, 0.0, 0.0]);
+
+        let output = lhs.fmod(rhs).into_data();
+
+        assert!(output.iter::<f32>().all(|value| value.is_nan()));
+    }
+}
@@ -200,4 +200,68 @@ mod tests {
 
         tensor.scatter(0, indices, values);
     }
+
+    #[test]
+    fn should_scatter_max_variable_size_segments() {
+        let device = Default::default();
+        // Segments of size 3, 1, 0 and 2.
+        let tensor = TestTensor::<1>::from_floats([1.0, 5.0, 3.0, -2.0, 4.0, 4.0], &device);
+        let indices = TestTensorInt::from_ints([0, 0, 0, 1, 3, 3], &device);
+
+        let (maxima, argmax) = tensor.scatter_max(0, indices, 4);
+
+        maxima.into_data().assert_eq(
+            &TensorData::from([5.0, -2.0, f32::NEG_INFINITY, 4.0]),
+            false,
+        );
+        // Empty segments get the size of the input as index, ties the lowest position.
+        argmax
+            .into_data()
+            .assert_eq(&TensorData::from([1, 3, 6, 4]), false);
+    }
+
+    #[test]
+    fn should_scatter_max_2d_dim0() {
+        let device = Default::default();
+        let tensor =
+            TestTensor::<2>::from_floats([[1.0, 8.0], [7.0, 2.0], [3.0, 9.0], [0.0, 4.0]], &device);
+        let indices = TestTensorInt::from_ints([[0, 1], [0, 0], [1, 1], [1, 0]], &device);
+
+        let (maxima, argmax) = tensor.scatter_max(0, indices, 2);
+
+        maxima
+            .into_data()
+            .assert_eq(&TensorData::from([[7.0, 4.0], [3.0, 9.0]]), false);
+        argmax
+            .into_data()
+            .assert_eq(&TensorData::from([[1, 3], [2, 2]]), false);
+    }
+
+    #[test]
+    fn should_scatter_max_one_segment_per_element() {
+        let device = Default::default();
+        let tensor = TestTensor::<2>::from_floats([[1.0, 5.0, 3.0], [2.0, 0.0, 7.0]], &device);
+        // Out of range segments are ignored.
+        let indices = TestTensorInt::from_ints([[2, 0, 1], [1, 3, -1]], &device);
+
+        let (maxima, argmax) = tensor.scatter_max(1, indices, 3);
+
+        maxima.into_data().assert_eq(
+            &TensorData::from([[5.0, 3.0, 1.0], [f32::NEG_INFINITY, 2.0, f32::NEG_INFINITY]]),
+            false,
+        );
+        argmax
+            .into_data()
+            .assert_eq(&TensorData::from([[1, 2, 0], [3, 0, 3]]), false);
+    }
+
+    #[test]
+    #[should_panic]
+    fn scatter_max_should_panic_on_mismatched_indices() {
+        let device = Default::default();
+        let tensor = TestTensor::<1>::from_floats([1.0, 2.0, 3.0], &device);
+        let indices = TestTensorInt::from_ints([0, 1], &device);
+
+        let _ = tensor.scatter_max(0, indices, 2);
+    }
 }
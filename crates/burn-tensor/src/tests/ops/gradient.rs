@@ -0,0 +1,66 @@
+#[burn_tensor_testgen::testgen(gradient)]
+mod tests {
+    use super::*;
+    use burn_tensor::{Tensor, TensorData};
+
+    #[test]
+    fn test_gradient_uniform_spacing() {
+        // f(x) = x² and g(x) = 3x sampled on [0, 2] with a spacing of 0.5.
+        let x = [0.0, 0.5, 1.0, 1.5, 2.0];
+        let tensor = TestTensor::<2>::from([x.map(|x| x * x), x.map(|x| 3.0 * x)]);
+
+        let output = tensor.gradient(1, 0.5);
+
+        // Central differences are exact for quadratics: f'(x) = 2x in the interior, while the
+        // boundaries use one-sided differences.
+        let expected = TensorData::from([[0.5, 1.0, 2.0, 3.0, 3.5], [3.0, 3.0, 3.0, 3.0, 3.0]]);
+        output.into_data().assert_approx_eq(&expected, 4);
+    }
+
+    #[test]
+    fn test_gradient_two_values() {
+        let tensor = TestTensor::<2>::from([[1.0, 4.0], [2.0, -2.0]]);
+
+        let output = tensor.gradient(0, 2.0);
+
+        let expected = TensorData::from([[0.5, -3.0], [0.5, -3.0]]);
+        output.into_data().assert_approx_eq(&expected, 4);
+    }
+
+    #[test]
+    fn test_gradient_with_coordinates() {
+        // f(x) = x² sampled on non-uniform coordinates along the first dimension.
+        let x = [0.0, 1.0, 3.0, 4.0, 7.0];
+        let tensor = TestTensor::<2>::from(x.map(|x| [x * x, -x]));
+        let coordinates = Tensor::from_floats(x, &Default::default());
+
+        let output = tensor.gradient_with_coordinates(0, coordinates);
+
+        // f'(x) = 2x in the interior, the boundaries being (1 - 0) / 1 and (49 - 16) / 3.
+        let expected = TensorData::from([
+            [1.0, -1.0],
+            [2.0, -1.0],
+            [6.0, -1.0],
+            [8.0, -1.0],
+            [11.0, -1.0],
+        ]);
+        output.into_data().assert_approx_eq(&expected, 4);
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_gradient_with_coordinates_size_mismatch() {
+        let tensor = TestTensor::<1>::from([1.0, 2.0, 3.0]);
+        let coordinates = Tensor::from_floats([0.0, 1.0], &Default::default());
+
+        let _output = tensor.gradient_with_coordinates(0, coordinates);
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_gradient_single_value() {
+        let tensor = TestTensor::<1>::from([1.0]);
+
+        let _output = tensor.gradient(0, 1.0);
+    }
+}
@@ -0,0 +1,74 @@
+#[burn_tensor_testgen::testgen(histogram)]
+mod tests {
+    use super::*;
+    use burn_tensor::TensorData;
+
+    #[test]
+    fn should_count_values_in_non_uniform_bins() {
+        let device = Default::default();
+        let tensor = TestTensor::<1>::from_floats(
+            [0.5, 1.0, 1.5, 2.0, 2.5, 4.0, 9.0, 10.0, -1.0, 10.5, 0.0],
+            &device,
+        );
+        let edges = TestTensor::from_floats([0.0, 1.0, 2.0, 5.0, 10.0], &device);
+
+        let counts = tensor.histogram_bins(edges);
+
+        // -1.0 and 10.5 are outside of the edges and dropped, 10.0 belongs to the last bin.
+        counts
+            .into_data()
+            .assert_eq(&TensorData::from([2, 2, 3, 2]), false);
+    }
+
+    #[test]
+    fn should_count_all_elements_of_a_2d_tensor() {
+        let device = Default::default();
+        let tensor = TestTensor::<2>::from_floats([[0.1, 0.2, 0.9], [0.3, 3.0, -0.1]], &device);
+        let edges = TestTensor::from_floats([0.0, 0.25, 1.0], &device);
+
+        let counts = tensor.histogram_bins(edges);
+
+        counts
+            .into_data()
+            .assert_eq(&TensorData::from([2, 2]), false);
+    }
+
+    #[test]
+    fn should_search_values_equal_to_the_edges() {
+        let device = Default::default();
+        let tensor = TestTensor::<1>::from_floats(
+            [8.0, 7.5, 7.0, 6.0, 5.0, 4.0, 3.0, 2.0, 1.0, 0.0],
+            &device,
+        );
+        let edges = TestTensor::from_floats([0.0, 1.0, 2.0, 3.0, 4.0, 5.0, 6.0, 7.0, 8.0], &device);
+
+        let counts = tensor.histogram_bins(edges);
+
+        counts
+            .into_data()
+            .assert_eq(&TensorData::from([1, 1, 1, 1, 1, 1, 1, 3]), false);
+    }
+
+    #[test]
+    fn should_drop_nan_values() {
+        let device = Default::default();
+        let tensor = TestTensor::<1>::from_floats([f32::NAN, 0.5, 1.5], &device);
+        let edges = TestTensor::from_floats([0.0, 1.0, 2.0], &device);
+
+        let counts = tensor.histogram_bins(edges);
+
+        counts
+            .into_data()
+            .assert_eq(&TensorData::from([1, 1]), false);
+    }
+
+    #[test]
+    #[should_panic]
+    fn histogram_bins_should_panic_with_a_single_edge() {
+        let device = Default::default();
+        let tensor = TestTensor::<1>::from_floats([0.5, 1.5], &device);
+        let edges = TestTensor::from_floats([0.0], &device);
+
+        let _counts = tensor.histogram_bins(edges);
+    }
+}
@@ -0,0 +1,80 @@
+#[burn_tensor_testgen::testgen(index_put)]
+mod tests {
+    use super::*;
+    use burn_tensor::{Tensor, TensorData};
+
+    #[test]
+    fn should_index_put_overwrite() {
+        let device = Default::default();
+        let tensor = TestTensor::<2>::from_floats([[1.0, 2.0, 3.0], [4.0, 5.0, 6.0]], &device);
+        let rows = TestTensorInt::from_ints([0, 1, 1], &device);
+        let cols = TestTensorInt::from_ints([2, 0, 1], &device);
+        let values = TestTensor::from_floats([10.0, 20.0, 30.0], &device);
+
+        let output = tensor.index_put(&[rows, cols], values, false);
+        let expected = TensorData::from([[1.0, 2.0, 10.0], [20.0, 30.0, 6.0]]);
+
+        output.into_data().assert_eq(&expected, false);
+    }
+
+    #[test]
+    fn should_index_put_keep_last_duplicate() {
+        let device = Default::default();
+        let tensor = TestTensor::<2>::from_floats([[1.0, 2.0, 3.0], [4.0, 5.0, 6.0]], &device);
+        let rows = TestTensorInt::from_ints([0, 1, 0, 0], &device);
+        let cols = TestTensorInt::from_ints([2, 0, 2, 2], &device);
+        let values = TestTensor::from_floats([10.0, 20.0, 30.0, 40.0], &device);
+
+        let output = tensor.index_put(&[rows, cols], values, false);
+        let expected = TensorData::from([[1.0, 2.0, 40.0], [20.0, 5.0, 6.0]]);
+
+        output.into_data().assert_eq(&expected, false);
+    }
+
+    #[test]
+    fn should_index_put_accumulate() {
+        let device = Default::default();
+        let tensor = TestTensor::<2>::from_floats([[1.0, 2.0, 3.0], [4.0, 5.0, 6.0]], &device);
+        let rows = TestTensorInt::from_ints([0, 1, 0], &device);
+        let cols = TestTensorInt::from_ints([2, 0, 2], &device);
+        let values = TestTensor::from_floats([10.0, 20.0, 30.0], &device);
+
+        let output = tensor.index_put(&[rows, cols], values, true);
+        let expected = TensorData::from([[1.0, 2.0, 43.0], [24.0, 5.0, 6.0]]);
+
+        output.into_data().assert_eq(&expected, false);
+    }
+
+    #[test]
+    fn should_index_put_broadcast_values() {
+        let device = Default::default();
+        let tensor = TestTensorInt::<2>::from_ints([[1, 2, 3], [4, 5, 6]], &device);
+        let rows = TestTensorInt::from_ints([0, 1], &device);
+        let cols = TestTensorInt::from_ints([0, 2], &device);
+        let values = TestTensorInt::from_ints([-1], &device);
+
+        let output = tensor
+            .clone()
+            .index_put(&[rows.clone(), cols.clone()], values.clone(), false);
+        output
+            .into_data()
+            .assert_eq(&TensorData::from([[-1, 2, 3], [4, 5, -1]]), false);
+
+        let output = tensor.index_put(&[rows, cols], values, true);
+        output
+            .into_data()
+            .assert_eq(&TensorData::from([[0, 2, 3], [4, 5, 5]]), false);
+    }
+
+    #[test]
+    #[should_panic]
+    fn should_panic_when_index_lengths_differ() {
+        let device = Default::default();
+        let tensor = TestTensor::<2>::zeros([2, 3], &device);
+        let rows = TestTensorInt::from_ints([0, 1], &device);
+        let cols = TestTensorInt::from_ints([0], &device);
+        let values = TestTensor::from_floats([1.0], &device);
+
+        let _ = tensor.index_put(&[rows, cols], values, false);
+    }
+}
@@ -0,0 +1,51 @@
+#[burn_tensor_testgen::testgen(interleave)]
+mod tests {
+    use super::*;
+    use burn_tensor::TensorData;
+
+    #[test]
+    fn should_interleave_vectors() {
+        let lhs = TestTensor::<1>::from([1.0, 2.0, 3.0]);
+        let rhs = TestTensor::<1>::from([-1.0, -2.0, -3.0]);
+
+        let output = lhs.interleave(rhs, 0);
+
+        output
+            .into_data()
+            .assert_eq(&TensorData::from([1.0, -1.0, 2.0, -2.0, 3.0, -3.0]), false);
+    }
+
+    #[test]
+    fn should_interleave_inner_dim() {
+        let lhs = TestTensorInt::<3>::from([[[0, 1], [2, 3]], [[4, 5], [6, 7]]]);
+        let rhs = lhs.clone().neg();
+
+        let output = lhs.interleave(rhs, 1);
+
+        let expected = TensorData::from([
+            [[0, 1], [0, -1], [2, 3], [-2, -3]],
+            [[4, 5], [-4, -5], [6, 7], [-6, -7]],
+        ]);
+        output.into_data().assert_eq(&expected, false);
+    }
+
+    #[test]
+    fn should_interleave_last_dim() {
+        let lhs = TestTensor::<2>::from([[1.0, 2.0], [3.0, 4.0]]);
+        let rhs = TestTensor::<2>::from([[5.0, 6.0], [7.0, 8.0]]);
+
+        let output = lhs.interleave(rhs, 1);
+
+        let expected = TensorData::from([[1.0, 5.0, 2.0, 6.0], [3.0, 7.0, 4.0, 8.0]]);
+        output.into_data().assert_eq(&expected, false);
+    }
+
+    #[test]
+    #[should_panic]
+    fn should_panic_when_lengths_mismatch() {
+        let lhs = TestTensor::<1>::from([1.0, 2.0, 3.0]);
+        let rhs = TestTensor::<1>::from([1.0, 2.0]);
+
+        let _output = lhs.interleave(rhs, 0);
+    }
+}
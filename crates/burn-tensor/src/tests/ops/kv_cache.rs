@@ -0,0 +1,76 @@
+#[burn_tensor_testgen::testgen(kv_cache)]
+mod tests {
+    use super::*;
+    use burn_tensor::{KvCache, Tensor, TensorData};
+
+    #[test]
+    fn should_append_steps_within_capacity() {
+        let device = Default::default();
+        let mut cache = KvCache::<TestBackend, 3>::new([1, 4, 2], [1, 4, 1], 1, &device);
+
+        let key = TestTensor::from_data([[[1.0, 2.0]]], &device);
+        let value = TestTensor::from_data([[[10.0]]], &device);
+        let (keys, values) = cache.append(key, value);
+
+        keys.into_data()
+            .assert_eq(&TensorData::from([[[1.0, 2.0]]]), false);
+        values
+            .into_data()
+            .assert_eq(&TensorData::from([[[10.0]]]), false);
+
+        // Two positions at once.
+        let key = TestTensor::from_data([[[3.0, 4.0], [5.0, 6.0]]], &device);
+        let value = TestTensor::from_data([[[20.0], [30.0]]], &device);
+        cache.append(key, value);
+
+        let key = TestTensor::from_data([[[7.0, 8.0]]], &device);
+        let value = TestTensor::from_data([[[40.0]]], &device);
+        let (keys, values) = cache.append(key, value);
+
+        keys.into_data().assert_eq(
+            &TensorData::from([[[1.0, 2.0], [3.0, 4.0], [5.0, 6.0], [7.0, 8.0]]]),
+            false,
+        );
+        values
+            .into_data()
+            .assert_eq(&TensorData::from([[[10.0], [20.0], [30.0], [40.0]]]), false);
+        // The buffers keep the shape they were allocated with.
+        assert_eq!(cache.len(), 4);
+        assert_eq!(cache.capacity(), 4);
+    }
+
+    #[test]
+    fn should_overwrite_positions_after_reset() {
+        let device = Default::default();
+        let mut cache = KvCache::<TestBackend, 2>::new([3, 2], [3, 2], 0, &device);
+
+        cache.append(
+            TestTensor::from_data([[1.0, 2.0], [3.0, 4.0]], &device),
+            TestTensor::from_data([[5.0, 6.0], [7.0, 8.0]], &device),
+        );
+        cache.reset();
+        assert!(cache.is_empty());
+
+        let (keys, values) = cache.append(
+            TestTensor::from_data([[9.0, 9.0]], &device),
+            TestTensor::from_data([[0.0, 0.0]], &device),
+        );
+
+        keys.into_data()
+            .assert_eq(&TensorData::from([[9.0, 9.0]]), false);
+        values
+            .into_data()
+            .assert_eq(&TensorData::from([[0.0, 0.0]]), false);
+        assert_eq!(cache.capacity(), 3);
+    }
+
+    #[test]
+    #[should_panic]
+    fn should_panic_when_exceeding_capacity() {
+        let device = Default::default();
+        let mut cache = KvCache::<TestBackend, 2>::new([2, 1], [2, 1], 0, &device);
+
+        cache.append(Tensor::ones([2, 1], &device), Tensor::ones([2, 1], &device));
+        cache.append(Tensor::ones([1, 1], &device), Tensor::ones([1, 1], &device));
+    }
+}
@@ -68,4 +68,63 @@ mod tests {
 
         output.into_data().assert_eq(&expected, false);
     }
+
+    #[test]
+    fn should_support_mask_where_with_broadcasted_value() {
+        let device = Default::default();
+        let tensor = TestTensor::from_data([[1.0, 7.0, 4.0], [2.0, 3.0, 5.0]], &device);
+        let mask = Tensor::<TestBackend, 2, Bool>::from_bool(
+            TensorData::from([[true, false, true], [false, true, true]]),
+            &device,
+        );
+        let value = TestTensor::from_data([[10.0, 20.0, 30.0]], &device);
+
+        let output = tensor.mask_where(mask, value);
+        let expected = TensorData::from([[10.0, 7.0, 30.0], [2.0, 20.0, 30.0]]);
+
+        output.into_data().assert_eq(&expected, false);
+    }
+
+    #[test]
+    fn should_support_mask_where_with_broadcasted_mask_and_value() {
+        let device = Default::default();
+        let tensor = TestTensor::from_data([[1.0, 7.0, 4.0], [2.0, 3.0, 5.0]], &device);
+        let mask = Tensor::<TestBackend, 2, Bool>::from_bool(
+            TensorData::from([[false, true, true]]),
+            &device,
+        );
+        let value = TestTensor::from_data([[10.0], [20.0]], &device);
+
+        let output = tensor.mask_where(mask, value);
+        let expected = TensorData::from([[1.0, 10.0, 10.0], [2.0, 20.0, 20.0]]);
+
+        output.into_data().assert_eq(&expected, false);
+    }
+
+    #[test]
+    fn should_support_mask_fill_with_broadcasted_mask() {
+        let device = Default::default();
+        let tensor = TestTensor::from_data([[1.0, 7.0], [2.0, 3.0]], &device);
+        let mask =
+            Tensor::<TestBackend, 2, Bool>::from_bool(TensorData::from([[true], [false]]), &device);
+
+        let output = tensor.mask_fill(mask, 2.0);
+        let expected = TensorData::from([[2.0, 2.0], [2.0, 3.0]]);
+
+        output.into_data().assert_eq(&expected, false);
+    }
+
+    #[test]
+    #[should_panic]
+    fn should_panic_when_mask_where_value_cannot_be_broadcasted() {
+        let device = Default::default();
+        let tensor = TestTensor::from_data([[1.0, 7.0, 4.0], [2.0, 3.0, 5.0]], &device);
+        let mask = Tensor::<TestBackend, 2, Bool>::from_bool(
+            TensorData::from([[true, false, true], [false, true, true]]),
+            &device,
+        );
+        let value = TestTensor::from_data([[10.0, 20.0]], &device);
+
+        let _output = tensor.mask_where(mask, value);
+    }
 }
@@ -133,6 +133,59 @@ mod tests {
         tensor_3.into_data().assert_eq(&expected, false);
     }
 
+    #[test]
+    fn test_bmm_transpose_rhs() {
+        let device = Default::default();
+        // [2, 2, 3]
+        let tensor_1 = TestTensor::<3>::from_floats(
+            [
+                [[1.0, 2.0, 3.0], [4.0, 5.0, 6.0]],
+                [[-1.0, 0.5, 2.0], [3.0, -2.0, 1.5]],
+            ],
+            &device,
+        );
+        // [2, 4, 3]
+        let tensor_2 = TestTensor::<3>::from_floats(
+            [
+                [
+                    [1.0, 0.0, 2.0],
+                    [0.5, 1.5, -1.0],
+                    [2.0, 2.0, 2.0],
+                    [-3.0, 1.0, 0.0],
+                ],
+                [
+                    [4.0, -1.0, 0.5],
+                    [1.0, 1.0, 1.0],
+                    [0.0, 3.0, -2.0],
+                    [2.5, 0.0, 1.0],
+                ],
+            ],
+            &device,
+        );
+
+        let output = tensor_1.clone().bmm(tensor_2.clone(), false, true);
+        let expected = tensor_1.matmul(tensor_2.swap_dims(1, 2));
+
+        output
+            .into_data()
+            .assert_approx_eq(&expected.into_data(), 3);
+    }
+
+    #[test]
+    fn test_bmm_transpose_both() {
+        let device = Default::default();
+        // [1, 3, 2]
+        let tensor_1 =
+            TestTensor::<3>::from_floats([[[1.0, 4.0], [2.0, 5.0], [3.0, 6.0]]], &device);
+        // [1, 2, 3]
+        let tensor_2 = TestTensor::<3>::from_floats([[[1.0, 2.0, 3.0], [4.0, 5.0, 6.0]]], &device);
+
+        let output = tensor_1.bmm(tensor_2, true, true);
+        let expected = TensorData::from([[[14.0, 32.0], [32.0, 77.0]]]);
+
+        output.into_data().assert_approx_eq(&expected, 3);
+    }
+
     #[test]
     #[should_panic]
     fn should_panic_when_inner_dimensions_are_not_equal() {
@@ -14,18 +14,29 @@ mod cat;
 mod chunk;
 mod clamp;
 mod close;
+mod coord_grid;
 mod cos;
 mod create_like;
+mod cumsum;
+mod data_chunks;
 mod div;
+mod dlpack;
+mod dynamic_partition;
 mod erf;
 mod exp;
 mod expand;
 mod flatten;
 mod flip;
+mod fmod;
 mod full;
 mod gather_scatter;
+mod gradient;
+mod histogram;
+mod index_put;
 mod init;
+mod interleave;
 mod iter_dim;
+mod kv_cache;
 mod log;
 mod log1p;
 mod map_comparison;
@@ -36,6 +47,7 @@ mod movedim;
 mod mul;
 mod narrow;
 mod neg;
+mod none;
 mod one_hot;
 mod padding;
 mod permute;
@@ -46,7 +58,9 @@ mod recip;
 mod remainder;
 mod repeat;
 mod reshape;
+mod segment_softmax;
 mod select;
+mod shift;
 mod sign;
 mod sin;
 mod slice;
@@ -57,6 +71,7 @@ mod stack;
 mod sub;
 mod tanh;
 mod topk;
+mod transfer;
 mod transpose;
 mod tri;
 mod tri_mask;
@@ -0,0 +1,70 @@
+#[burn_tensor_testgen::testgen(none_op)]
+mod tests {
+    use super::*;
+    use burn_tensor::{Tensor, TensorData};
+
+    #[test]
+    fn test_none() {
+        // test float tensor
+        let tensor = TestTensor::<2>::from([[0.0, 0.0, 0.0], [1.0, -1.0, 0.0]]);
+        let data_actual = tensor.none().into_data();
+        let data_expected = TensorData::from([false]);
+        assert_eq!(data_expected, data_actual);
+
+        let tensor = TestTensor::<2>::from([[0.0, 0.0, 0.0], [0.0, 0.0, 0.0]]);
+        let data_actual = tensor.none().into_data();
+        let data_expected = TensorData::from([true]);
+        assert_eq!(data_expected, data_actual);
+
+        // test int tensor
+        let tensor = TestTensorInt::<2>::from([[0, 0, 0], [1, -1, 0]]);
+        let data_actual = tensor.none().into_data();
+        let data_expected = TensorData::from([false]);
+        assert_eq!(data_expected, data_actual);
+
+        let tensor = TestTensorInt::<2>::from([[0, 0, 0], [0, 0, 0]]);
+        let data_actual = tensor.none().into_data();
+        let data_expected = TensorData::from([true]);
+        assert_eq!(data_expected, data_actual);
+
+        // test bool tensor
+        let tensor = TestTensorBool::<2>::from([[false, false, false], [true, true, false]]);
+        let data_actual = tensor.none().into_data();
+        let data_expected = TensorData::from([false]);
+        assert_eq!(data_expected, data_actual);
+
+        let tensor = TestTensorBool::<2>::from([[false, false, false], [false, false, false]]);
+        let data_actual = tensor.none().into_data();
+        let data_expected = TensorData::from([true]);
+        assert_eq!(data_expected, data_actual);
+    }
+
+    #[test]
+    fn test_none_dim() {
+        // Rows are mixed, all false and all true.
+        let tensor = TestTensor::<2>::from([[0.0, 2.0, 0.0], [0.0, 0.0, 0.0], [1.0, -1.0, 3.0]]);
+        let data_actual = tensor.none_dim(1).into_data();
+        let data_expected = TensorData::from([[false], [true], [false]]);
+        assert_eq!(data_expected, data_actual);
+
+        // test int tensor
+        let tensor = TestTensorInt::<2>::from([[0, 2, 0], [0, 0, 0], [1, -1, 3]]);
+        let data_actual = tensor.none_dim(1).into_data();
+        let data_expected = TensorData::from([[false], [true], [false]]);
+        assert_eq!(data_expected, data_actual);
+
+        // test bool tensor
+        let tensor = TestTensorBool::<2>::from([
+            [false, true, false],
+            [false, false, false],
+            [false, true, true],
+        ]);
+        let data_actual = tensor.clone().none_dim(1).into_data();
+        let data_expected = TensorData::from([[false], [true], [false]]);
+        assert_eq!(data_expected, data_actual);
+
+        let data_actual = tensor.none_dim(0).into_data();
+        let data_expected = TensorData::from([[true, false, false]]);
+        assert_eq!(data_expected, data_actual);
+    }
+}
@@ -1,7 +1,7 @@
 #[burn_tensor_testgen::testgen(one_hot)]
 mod tests {
     use super::*;
-    use burn_tensor::{Int, TensorData};
+    use burn_tensor::{Int, Tensor, TensorData};
 
     #[test]
     fn should_support_one_hot() {
@@ -37,4 +37,41 @@ mod tests {
         let device = Default::default();
         let tensor = TestTensor::<1>::one_hot(0, 0, &device);
     }
+
+    #[test]
+    fn should_support_one_hot_indices() {
+        let indices = TestTensorInt::<1>::from([2, 0, 3, 2]);
+
+        let output = indices.one_hot(4);
+        let expected = TensorData::from([
+            [0., 0., 1., 0.],
+            [1., 0., 0., 0.],
+            [0., 0., 0., 1.],
+            [0., 0., 1., 0.],
+        ]);
+
+        output.into_data().assert_eq(&expected, false);
+    }
+
+    #[test]
+    fn should_match_dense_one_hot() {
+        let device = Default::default();
+        let (num_indices, num_classes) = (6, 5);
+        let indices = TestTensorInt::<1>::from([4, 1, 1, 0, 3, 2]);
+
+        // Dense path: compare each index against every class.
+        let classes = TestTensorInt::<1>::arange(0..num_classes as i64, &device)
+            .reshape([1, num_classes])
+            .expand([num_indices, num_classes]);
+        let dense = indices
+            .clone()
+            .reshape([num_indices, 1])
+            .expand([num_indices, num_classes])
+            .equal(classes)
+            .float();
+
+        let output: Tensor<TestBackend, 2> = indices.one_hot(num_classes);
+
+        output.into_data().assert_eq(&dense.into_data(), true);
+    }
 }
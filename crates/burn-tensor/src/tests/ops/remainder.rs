@@ -100,4 +100,28 @@ mod tests {
 
         output.into_data().assert_approx_eq(&expected, 3);
     }
+
+    #[test]
+    fn should_support_remainder_tensors() {
+        let lhs = TestTensor::<1>::from([-7.5, -3.0, 2.0, 6.25, 4.0]);
+        let rhs = TestTensor::<1>::from([2.0, -2.0, 3.0, -4.0, 2.0]);
+
+        let output = lhs.remainder(rhs);
+        let expected = TensorData::from([0.5, -1.0, 2.0, -1.75, 0.0]);
+
+        output.into_data().assert_approx_eq(&expected, 3);
+    }
+
+    #[test]
+    fn should_support_remainder_int_tensors() {
+        let device = Default::default();
+        let lhs = Tensor::<TestBackend, 1, Int>::from_ints([-7, -3, 2, 6, 4, 5], &device);
+        let rhs = Tensor::<TestBackend, 1, Int>::from_ints([2, -2, 3, -4, 2, -5], &device);
+
+        let output = lhs.remainder(rhs);
+
+        output
+            .into_data()
+            .assert_eq(&TensorData::from([1, -1, 2, -2, 0, 0]), false);
+    }
 }
@@ -101,4 +101,35 @@ mod tests {
         let tensor = Tensor::<TestBackend, 1>::from_data(data, &Default::default());
         let data_actual = tensor.reshape([-2, -1]).into_data();
     }
+
+    #[test]
+    fn should_infer_dim_with_neg_one() {
+        let tensor = TestTensor::<1>::from([0.0, 1.0, 2.0, 3.0, 4.0, 5.0]);
+
+        let reshaped = tensor.reshape([-1, 2, 1]);
+
+        assert_eq!(reshaped.shape(), [3, 2, 1].into());
+        reshaped.into_data().assert_eq(
+            &TensorData::from([[[0.0], [1.0]], [[2.0], [3.0]], [[4.0], [5.0]]]),
+            false,
+        );
+    }
+
+    #[test]
+    #[should_panic(
+        expected = "Current shape: [2, 3] (6 elements), target shape: [4, 2] (8 elements)"
+    )]
+    fn should_panic_on_element_count_mismatch() {
+        let tensor = TestTensor::<2>::from([[0.0, 1.0, 2.0], [3.0, 4.0, 5.0]]);
+
+        let _reshaped = tensor.reshape([4, 2]);
+    }
+
+    #[test]
+    #[should_panic(expected = "The -1 dimension can't be inferred")]
+    fn should_panic_when_neg_one_is_not_divisible() {
+        let tensor = TestTensor::<2>::from([[0.0, 1.0, 2.0], [3.0, 4.0, 5.0]]);
+
+        let _reshaped = tensor.reshape([4, -1]);
+    }
 }
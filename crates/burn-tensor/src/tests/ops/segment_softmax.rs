@@ -0,0 +1,59 @@
+#[burn_tensor_testgen::testgen(segment_softmax)]
+mod tests {
+    use super::*;
+    use burn_tensor::TensorData;
+
+    #[test]
+    fn should_normalize_unsorted_segments() {
+        let tensor = TestTensor::<2>::from([[1.0, 2.0, 3.0, 4.0]]);
+        let segment_ids = TestTensorInt::<1>::from([1, 0, 1, 0]);
+
+        let output = tensor.segment_softmax(1, segment_ids, 2);
+
+        // exp(1) / (exp(1) + exp(3)) and exp(3) / (exp(1) + exp(3)), the same for 2 and 4.
+        let expected = TensorData::from([[0.1192, 0.1192, 0.8808, 0.8808]]);
+        output.into_data().assert_approx_eq(&expected, 3);
+    }
+
+    #[test]
+    fn should_normalize_segments_on_first_dim() {
+        let tensor = TestTensor::<2>::from([[1.0, 10.0], [2.0, 20.0], [3.0, 30.0]]);
+        let segment_ids = TestTensorInt::<1>::from([0, 1, 0]);
+
+        let output = tensor.segment_softmax(0, segment_ids, 2);
+
+        let expected = TensorData::from([[0.1192, 0.0], [1.0, 1.0], [0.8808, 1.0]]);
+        output.into_data().assert_approx_eq(&expected, 3);
+    }
+
+    #[test]
+    fn should_zero_out_of_range_segments() {
+        let tensor = TestTensor::<2>::from([[1.0, 2.0, 3.0, 4.0]]);
+        let segment_ids = TestTensorInt::<1>::from([1, -1, 1, 5]);
+
+        let output = tensor.segment_softmax(1, segment_ids, 2);
+
+        let expected = TensorData::from([[0.1192, 0.0, 0.8808, 0.0]]);
+        output.into_data().assert_approx_eq(&expected, 3);
+    }
+
+    #[test]
+    fn should_be_stable_with_large_values() {
+        let tensor = TestTensor::<1>::from([1000.0, 1001.0, -1000.0]);
+        let segment_ids = TestTensorInt::<1>::from([0, 0, 1]);
+
+        let output = tensor.segment_softmax(0, segment_ids, 2);
+
+        let expected = TensorData::from([0.2689, 0.7311, 1.0]);
+        output.into_data().assert_approx_eq(&expected, 3);
+    }
+
+    #[test]
+    #[should_panic]
+    fn should_panic_when_segment_ids_size_differs() {
+        let tensor = TestTensor::<2>::from([[1.0, 2.0, 3.0]]);
+        let segment_ids = TestTensorInt::<1>::from([0, 1]);
+
+        tensor.segment_softmax(1, segment_ids, 2);
+    }
+}
@@ -0,0 +1,45 @@
+#[burn_tensor_testgen::testgen(shift)]
+mod tests {
+    use super::*;
+    use burn_tensor::TensorData;
+
+    #[test]
+    fn should_shift_positive_with_fill() {
+        let tensor = TestTensor::<2>::from([[1.0, 2.0, 3.0, 4.0], [5.0, 6.0, 7.0, 8.0]]);
+
+        let output = tensor.shift(1, 2, -1.0);
+        let expected = TensorData::from([[-1.0, -1.0, 1.0, 2.0], [-1.0, -1.0, 5.0, 6.0]]);
+
+        output.into_data().assert_eq(&expected, false);
+    }
+
+    #[test]
+    fn should_shift_negative_with_fill() {
+        let tensor = TestTensor::<2>::from([[1.0, 2.0], [3.0, 4.0], [5.0, 6.0]]);
+
+        let output = tensor.shift(0, -1, 9.0);
+        let expected = TensorData::from([[3.0, 4.0], [5.0, 6.0], [9.0, 9.0]]);
+
+        output.into_data().assert_eq(&expected, false);
+    }
+
+    #[test]
+    fn should_fill_everything_when_shift_exceeds_dim() {
+        let tensor = TestTensor::<2>::from([[1.0, 2.0, 3.0], [4.0, 5.0, 6.0]]);
+
+        let output = tensor.shift(1, -5, 0.0);
+        let expected = TensorData::from([[0.0, 0.0, 0.0], [0.0, 0.0, 0.0]]);
+
+        output.into_data().assert_eq(&expected, false);
+    }
+
+    #[test]
+    fn should_shift_int_tensor() {
+        let tensor = TestTensorInt::<1>::from([1, 2, 3, 4]);
+
+        let output = tensor.shift(0, 1, 0);
+        let expected = TensorData::from([0, 1, 2, 3]);
+
+        output.into_data().assert_eq(&expected, false);
+    }
+}
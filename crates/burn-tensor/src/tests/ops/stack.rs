@@ -87,6 +87,28 @@ mod tests {
         let output: Tensor<TestBackend, 3> = TestTensor::stack(tensors, 0);
     }
 
+    #[test]
+    #[should_panic(
+        expected = "The tensor at index (2) has shape [1, 3] while the first tensor has shape [2, 3]."
+    )]
+    fn should_panic_with_first_mismatching_index() {
+        let device = Default::default();
+        let tensor_1 = TestTensor::<2>::zeros([2, 3], &device);
+        let tensor_2 = TestTensor::<2>::zeros([2, 3], &device);
+        let tensor_3 = TestTensor::<2>::zeros([1, 3], &device);
+        let tensor_4 = TestTensor::<2>::zeros([2, 2], &device);
+
+        let output: Tensor<TestBackend, 3> =
+            Tensor::stack(vec![tensor_1, tensor_2, tensor_3, tensor_4], 0);
+    }
+
+    #[test]
+    #[should_panic(expected = "Can't stack an empty list of tensors.")]
+    fn should_panic_with_empty_list_message() {
+        let tensors: Vec<Tensor<TestBackend, 2>> = vec![];
+        let output: Tensor<TestBackend, 3> = TestTensor::stack(tensors, 1);
+    }
+
     #[test]
     #[should_panic]
     fn should_panic_when_stack_exceeds_dimension() {
@@ -69,4 +69,93 @@ mod tests {
 
         indices.into_data().assert_eq(&indices_expected, false);
     }
+
+    #[test]
+    fn test_topk_mask() {
+        let tensor = TestTensor::<2>::from([[1., 5., 3., 4.], [8., 2., 7., 6.]]);
+
+        let output = tensor.topk_mask(2, /*dim*/ 1);
+        let expected = TensorData::from([
+            [f32::NEG_INFINITY, 5., f32::NEG_INFINITY, 4.],
+            [8., f32::NEG_INFINITY, 7., f32::NEG_INFINITY],
+        ]);
+
+        output.into_data().assert_approx_eq(&expected, 5);
+    }
+
+    #[test]
+    fn test_topk_mask_ties_keep_lowest_indices() {
+        let tensor = TestTensor::<2>::from([[3., 1., 3., 3., 0.], [2., 2., 2., 2., 2.]]);
+
+        let output = tensor.topk_mask_fill(2, /*dim*/ 1, 0.);
+        let expected = TensorData::from([[3., 0., 3., 0., 0.], [2., 2., 0., 0., 0.]]);
+
+        output.into_data().assert_approx_eq(&expected, 5);
+    }
+
+    #[test]
+    fn test_topk_mask_exactly_k_survivors() {
+        // A greater element before the ties leaves a single slot for them.
+        let tensor = TestTensor::<3>::from([[[4., 2., 9., 4., 4.]], [[1., 1., 1., 1., 1.]]]);
+
+        let output = tensor.clone().topk_mask_fill(2, /*dim*/ 2, 0.);
+        let expected = TensorData::from([[[4., 0., 9., 0., 0.]], [[1., 1., 0., 0., 0.]]]);
+
+        output.clone().into_data().assert_approx_eq(&expected, 5);
+
+        let survivors = output.not_equal_elem(0.).int().sum_dim(2);
+        survivors
+            .into_data()
+            .assert_eq(&TensorData::from([[[2]], [[2]]]), false);
+
+        // Masking along another dimension.
+        let output = tensor.topk_mask_fill(1, /*dim*/ 0, 0.);
+        let expected = TensorData::from([[[4., 2., 9., 4., 4.]], [[0., 0., 0., 0., 0.]]]);
+
+        output.into_data().assert_approx_eq(&expected, 5);
+    }
+
+    #[test]
+    fn test_top_p_mask() {
+        let probs =
+            TestTensor::<2>::from([[0.05, 0.4, 0.1, 0.3, 0.15], [0.1, 0.1, 0.7, 0.05, 0.05]]);
+
+        // 0.4 + 0.3 exceeds 0.6 on the first row, while the top token alone does on the second.
+        let output = probs.top_p_mask(0.6, /*dim*/ 1);
+        let expected = TensorData::from([[0., 0.4, 0., 0.3, 0.], [0., 0., 0.7, 0., 0.]]);
+
+        output.into_data().assert_approx_eq(&expected, 5);
+    }
+
+    #[test]
+    fn test_top_p_mask_dim() {
+        let probs = TestTensor::<2>::from([[0.2, 0.5], [0.5, 0.1], [0.3, 0.4]]);
+
+        let output = probs.clone().top_p_mask(0.75, /*dim*/ 0);
+        let expected = TensorData::from([[0., 0.5], [0.5, 0.], [0.3, 0.4]]);
+
+        output.into_data().assert_approx_eq(&expected, 5);
+
+        // Every token is kept with p = 1.
+        let output = probs.clone().top_p_mask(1.0, /*dim*/ 0);
+
+        output.into_data().assert_approx_eq(&probs.into_data(), 5);
+    }
+
+    #[test]
+    fn test_top_p_mask_single_element() {
+        let probs = TestTensor::<2>::from([[0.2], [0.8]]);
+
+        let output = probs.clone().top_p_mask(0.5, /*dim*/ 1);
+
+        output.into_data().assert_approx_eq(&probs.into_data(), 5);
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_top_p_mask_invalid_p() {
+        let probs = TestTensor::<1>::from([0.5, 0.5]);
+
+        let _ = probs.top_p_mask(0.0, /*dim*/ 0);
+    }
 }
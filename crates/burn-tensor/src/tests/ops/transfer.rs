@@ -0,0 +1,35 @@
+#[burn_tensor_testgen::testgen(transfer)]
+mod tests {
+    use super::*;
+    use burn_tensor::{Tensor, TensorData};
+
+    #[test]
+    fn should_transfer_async_like_sync() {
+        let device = Default::default();
+        let tensor = TestTensor::<2>::from_floats([[1.0, 2.0, 3.0], [4.0, 5.0, 6.0]], &device);
+
+        let expected = tensor.clone().to_device(&device);
+        let output = tensor.to_device_async(&device).wait();
+
+        assert_eq!(output.device(), expected.device());
+        output.into_data().assert_eq(&expected.into_data(), true);
+    }
+
+    #[test]
+    fn should_transfer_async_int_and_bool() {
+        let device = Default::default();
+        let tensor = TestTensorInt::<1>::from_ints([1, 2, 3], &device);
+
+        let handle_int = tensor.clone().to_device_async(&device);
+        let handle_bool = tensor.clone().greater_elem(1).to_device_async(&device);
+
+        handle_int
+            .wait()
+            .into_data()
+            .assert_eq(&tensor.into_data(), true);
+        handle_bool
+            .wait()
+            .into_data()
+            .assert_eq(&TensorData::from([false, true, true]), false);
+    }
+}
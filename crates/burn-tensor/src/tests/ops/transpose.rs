@@ -22,6 +22,57 @@ mod tests {
         output.into_data().assert_approx_eq(&expected, 3);
     }
 
+    #[test]
+    fn should_support_transpose_last2() {
+        let tensor = TestTensor::<3>::from_floats(
+            [
+                [[0.0, 1.0, 2.0], [3.0, 4.0, 5.0]],
+                [[6.0, 7.0, 8.0], [9.0, 10.0, 11.0]],
+            ],
+            &Default::default(),
+        );
+
+        let output = tensor.transpose_last2();
+        let expected = TensorData::from([
+            [[0.0, 3.0], [1.0, 4.0], [2.0, 5.0]],
+            [[6.0, 9.0], [7.0, 10.0], [8.0, 11.0]],
+        ]);
+
+        output.into_data().assert_approx_eq(&expected, 3);
+    }
+
+    #[test]
+    fn should_support_transpose_last2_int() {
+        let tensor = Tensor::<TestBackend, 3, Int>::from_data(
+            [[[0, 1, 2], [3, 4, 5]], [[6, 7, 8], [9, 10, 11]]],
+            &Default::default(),
+        );
+
+        let output = tensor.transpose_last2();
+        let expected = TensorData::from([[[0, 3], [1, 4], [2, 5]], [[6, 9], [7, 10], [8, 11]]]);
+
+        output.into_data().assert_eq(&expected, false);
+    }
+
+    #[test]
+    fn should_support_transpose_last2_bool() {
+        let tensor = Tensor::<TestBackend, 2, Bool>::from_data(
+            [[false, true, false], [true, false, true]],
+            &Default::default(),
+        );
+
+        let output = tensor.transpose_last2();
+        let expected = TensorData::from([[false, true], [true, false], [false, true]]);
+
+        output.into_data().assert_eq(&expected, true);
+    }
+
+    #[test]
+    #[should_panic]
+    fn should_panic_transpose_last2_rank_1() {
+        TestTensor::<1>::from_floats([1.0, 2.0], &Default::default()).transpose_last2();
+    }
+
     #[test]
     fn should_support_swap_dims() {
         let tensor = TestTensor::<3>::from_floats(
@@ -130,4 +130,53 @@ mod tests {
             Tensor::from_data(TensorData::from([1, 2, 3]), &Default::default());
         let output = tensor.tril(0);
     }
+
+    #[test]
+    fn test_tril_runtime_diagonal() {
+        let tensor: Tensor<TestBackend, 2, Int> = Tensor::ones([4, 4], &Default::default());
+
+        // Sliding window attention: each query sees itself and the previous `window - 1` keys.
+        let window = 2;
+        let output = tensor.clone().tril(0).sub(tensor.tril(-(window as i64)));
+        let expected = TensorData::from([[1, 0, 0, 0], [1, 1, 0, 0], [0, 1, 1, 0], [0, 0, 1, 1]]);
+
+        output.into_data().assert_eq(&expected, false);
+    }
+
+    #[test]
+    fn test_triu_runtime_diagonal() {
+        let tensor: Tensor<TestBackend, 2, Int> = Tensor::ones([3, 4], &Default::default());
+
+        // Mask the future keys of queries aligned with the end of the key sequence.
+        let (queries, keys) = (3, 4);
+        let output = tensor.triu((keys - queries) as i64 + 1);
+        let expected = TensorData::from([[0, 0, 1, 1], [0, 0, 0, 1], [0, 0, 0, 0]]);
+
+        output.into_data().assert_eq(&expected, false);
+    }
+
+    #[test]
+    fn test_tri_diagonal_out_of_bounds() {
+        let tensor: Tensor<TestBackend, 2, Int> = Tensor::ones([3, 3], &Default::default());
+
+        let expected_all = TensorData::from([[1, 1, 1], [1, 1, 1], [1, 1, 1]]);
+        let expected_none = TensorData::from([[0, 0, 0], [0, 0, 0], [0, 0, 0]]);
+
+        tensor
+            .clone()
+            .triu(-5)
+            .into_data()
+            .assert_eq(&expected_all, false);
+        tensor
+            .clone()
+            .triu(i64::MAX)
+            .into_data()
+            .assert_eq(&expected_none, false);
+        tensor
+            .clone()
+            .tril(i64::MIN)
+            .into_data()
+            .assert_eq(&expected_none, false);
+        tensor.tril(7).into_data().assert_eq(&expected_all, false);
+    }
 }
@@ -2,7 +2,7 @@
 mod tests {
     use super::*;
     use burn_tensor::backend::Backend;
-    use burn_tensor::{Tensor, TensorData};
+    use burn_tensor::{ElementConversion, Tensor, TensorData};
 
     type FloatElem = <TestBackend as Backend>::FloatElem;
     type IntElem = <TestBackend as Backend>::IntElem;
@@ -64,4 +64,26 @@ mod tests {
         var.into_data().assert_approx_eq(&var_expected, 3);
         mean.into_data().assert_approx_eq(&mean_expected, 3);
     }
+
+    #[test]
+    fn test_var_large_offset() {
+        // A large mean makes the one-pass formula E[x²] - E[x]² cancel catastrophically, while
+        // subtracting the mean first keeps the exact variance of [1, 2, 3, 4].
+        let tensor =
+            TestTensor::<2>::from_data([[10001.0, 10002.0, 10003.0, 10004.0]], &Default::default());
+
+        let mean_sq = tensor.clone().powf_scalar(2.0).mean_dim(1);
+        let sq_mean = tensor.clone().mean_dim(1).powf_scalar(2.0);
+        let one_pass = mean_sq
+            .sub(sq_mean)
+            .mul_scalar(4.0 / 3.0)
+            .into_scalar()
+            .elem::<f32>();
+
+        let output = tensor.var(1);
+        let expected = TensorData::from([[1.6667]]).convert::<FloatElem>();
+
+        output.into_data().assert_approx_eq(&expected, 3);
+        assert!((one_pass - 1.6667).abs() > 0.1);
+    }
 }
@@ -698,11 +698,25 @@ fn register_extensions(instructions: &[wgsl::Instruction]) -> Vec<wgsl::Extensio
             wgsl::Instruction::If {
                 cond: _,
                 instructions,
-            } => {
+            }
+            | wgsl::Instruction::Loop { instructions }
+            | wgsl::Instruction::RangeLoop { instructions, .. } => {
                 for extension in register_extensions(instructions) {
                     register_extension(extension);
                 }
             }
+            wgsl::Instruction::IfElse {
+                cond: _,
+                instructions_if,
+                instructions_else,
+            } => {
+                for extension in register_extensions(instructions_if)
+                    .into_iter()
+                    .chain(register_extensions(instructions_else))
+                {
+                    register_extension(extension);
+                }
+            }
             _ => {}
         }
     }
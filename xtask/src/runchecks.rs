@@ -92,6 +92,17 @@ fn cargo_build(params: Params) {
     );
 }
 
+/// Run cargo check command
+fn cargo_check(params: Params) {
+    // Run cargo check
+    run_cargo(
+        "check",
+        params + "--color=always",
+        HashMap::new(),
+        "Failed to run cargo check",
+    );
+}
+
 /// Run cargo install command
 fn cargo_install(params: Params) {
     // Run cargo install
@@ -242,6 +253,16 @@ fn no_std_checks() {
     build_and_test_no_std("burn-no-std-tests", []);
 }
 
+// Check burn-jit on wasm32 without the wasm-sync feature
+fn jit_wasm32_checks() {
+    // Install wasm32 target
+    rustup_add_target(WASM32_TARGET);
+
+    group!("Checks: burn-jit (wasm32)");
+    cargo_check(Params::from(["-p", "burn-jit", "--target", WASM32_TARGET]));
+    endgroup!();
+}
+
 // Test burn-core with tch and wgpu backend
 fn burn_core_std() {
     // Run cargo test --features test-tch, record-item-custom-serde
@@ -344,6 +365,9 @@ fn std_checks() {
         endgroup!();
     }
 
+    // Check the JIT backends on wasm32, where the operations reading back are unavailable
+    jit_wasm32_checks();
+
     // Test burn-candle with accelerate (macOS only)
     #[cfg(target_os = "macos")]
     macos_checks();